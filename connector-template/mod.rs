@@ -32,6 +32,18 @@ impl api::PaymentAuthorize for {{project-name | downcase | pascal_case}} {}
 impl api::PaymentSync for {{project-name | downcase | pascal_case}} {}
 impl api::PaymentCapture for {{project-name | downcase | pascal_case}} {}
 impl api::PaymentVoid for {{project-name | downcase | pascal_case}} {}
+impl api::PaymentIncrementalAuthorization for {{project-name | downcase | pascal_case}} {}
+
+impl
+    services::ConnectorIntegration<
+        api::IncrementalAuthorization,
+        types::PaymentsIncrementalAuthorizationData,
+        types::PaymentsResponseData,
+    > for {{project-name | downcase | pascal_case}}
+{
+    // Not Implemented (R)
+}
+
 impl api::Refund for {{project-name | downcase | pascal_case}} {}
 impl api::RefundExecute for {{project-name | downcase | pascal_case}} {}
 impl api::RefundSync for {{project-name | downcase | pascal_case}} {}
@@ -409,6 +421,60 @@ impl
     }
 }
 
+impl api::Payout for {{project-name | downcase | pascal_case}} {}
+impl api::PayoutCreate for {{project-name | downcase | pascal_case}} {}
+impl api::PayoutFulfill for {{project-name | downcase | pascal_case}} {}
+impl api::PayoutReverse for {{project-name | downcase | pascal_case}} {}
+
+impl services::ConnectorIntegration<api::PoCreate, types::PayoutsData, types::PayoutsResponseData>
+    for {{project-name | downcase | pascal_case}}
+{
+}
+
+impl services::ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for {{project-name | downcase | pascal_case}}
+{
+}
+
+impl services::ConnectorIntegration<api::PoReverse, types::PayoutsData, types::PayoutsResponseData>
+    for {{project-name | downcase | pascal_case}}
+{
+}
+
+impl api::Dispute for {{project-name | downcase | pascal_case}} {}
+impl api::AcceptDispute for {{project-name | downcase | pascal_case}} {}
+impl api::SubmitEvidence for {{project-name | downcase | pascal_case}} {}
+
+impl
+    services::ConnectorIntegration<
+        api::Accept,
+        types::AcceptDisputeRequestData,
+        types::AcceptDisputeResponse,
+    > for {{project-name | downcase | pascal_case}}
+{
+}
+
+impl
+    services::ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::SubmitEvidenceResponse,
+    > for {{project-name | downcase | pascal_case}}
+{
+}
+
+impl api::FileUpload for {{project-name | downcase | pascal_case}} {}
+impl api::UploadFile for {{project-name | downcase | pascal_case}} {}
+
+impl
+    services::ConnectorIntegration<
+        api::Upload,
+        types::UploadFileRequestData,
+        types::UploadFileResponse,
+    > for {{project-name | downcase | pascal_case}}
+{
+}
+
 #[async_trait::async_trait]
 impl api::IncomingWebhook for {{project-name | downcase | pascal_case}} {
     fn get_webhook_object_reference_id(
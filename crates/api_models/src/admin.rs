@@ -1,6 +1,9 @@
+use std::collections::HashMap;
+
 use common_utils::pii;
 use masking::{Secret, StrongSecret};
 use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
 use url;
 use utoipa::ToSchema;
 
@@ -66,6 +69,12 @@ pub struct MerchantAccountCreate {
     /// An identifier for the vault used to store payment method information.
     #[schema(example = "locker_abc123")]
     pub locker_id: Option<String>,
+
+    /// Limits on the number of requests which can be made with the API keys of the merchant
+    pub rate_limits: Option<RateLimits>,
+
+    /// The time after which unattended payments of the merchant are expired
+    pub payment_expiry: Option<PaymentExpiry>,
 }
 
 #[derive(Clone, Debug, Deserialize, ToSchema)]
@@ -127,6 +136,12 @@ pub struct MerchantAccountUpdate {
     /// An identifier for the vault used to store payment method information.
     #[schema(example = "locker_abc123")]
     pub locker_id: Option<String>,
+
+    /// Limits on the number of requests which can be made with the API keys of the merchant
+    pub rate_limits: Option<RateLimits>,
+
+    /// The time after which unattended payments of the merchant are expired
+    pub payment_expiry: Option<PaymentExpiry>,
 }
 
 #[derive(Clone, Debug, ToSchema, Serialize)]
@@ -190,6 +205,19 @@ pub struct MerchantAccountResponse {
     /// An identifier for the vault used to store payment method information.
     #[schema(example = "locker_abc123")]
     pub locker_id: Option<String>,
+
+    /// Time until which outgoing webhooks are also signed with the previous payment response hash key, following a rotation
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub previous_payment_response_hash_key_expires_at: Option<PrimitiveDateTime>,
+
+    /// Limits on the number of requests which can be made with the API keys of the merchant
+    #[schema(value_type = Option<RateLimits>)]
+    pub rate_limits: Option<serde_json::Value>,
+
+    /// The time after which unattended payments of the merchant are expired
+    #[schema(value_type = Option<PaymentExpiry>)]
+    pub payment_expiry: Option<serde_json::Value>,
 }
 
 #[derive(Clone, Debug, Deserialize, ToSchema, Serialize)]
@@ -237,7 +265,88 @@ pub struct MerchantDetails {
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(tag = "type", content = "data", rename_all = "snake_case")]
 pub enum RoutingAlgorithm {
+    /// Route all payments through a single connector
     Single(api_enums::RoutableConnectors),
+    /// Route payments through the connectors in the given order of preference
+    Priority(Vec<api_enums::RoutableConnectors>),
+    /// Distribute payments across connectors based on the given percentages
+    VolumeSplit(Vec<ConnectorVolumeSplit>),
+    /// Route payments using the output of the first matching rule, or the default output if no rule matches
+    Advanced(RoutingRules),
+    /// Route payments through the connector with the best recent authorization success rate for
+    /// payments similar to the one being routed
+    MaxConversion(MaxConversionRouting),
+    /// Route payments through the connector with the lowest estimated fee for the payment, based on
+    /// the fee schedules of the merchant's connector accounts
+    MinCost(Vec<api_enums::RoutableConnectors>),
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct ConnectorVolumeSplit {
+    pub connector: api_enums::RoutableConnectors,
+    /// Percentage of the traffic to be routed through the connector
+    pub split: u8,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct MaxConversionRouting {
+    /// The connectors to choose from. Connectors whose success rate is not known yet are tried
+    /// after the others, in the given order.
+    pub connectors: Vec<api_enums::RoutableConnectors>,
+    /// Percentage of the payments routed to a connector other than the best one, so that the
+    /// success rates of the other connectors are kept up to date. Defaults to the percentage
+    /// configured for the application.
+    pub exploration_percentage: Option<u8>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct RoutingRules {
+    /// The rules to be evaluated, in order of evaluation
+    pub rules: Vec<RoutingRule>,
+    /// The connectors to be used when none of the rules match
+    pub default_output: RoutingOutput,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct RoutingRule {
+    /// Name of the rule, used to identify which rule was applied to a payment
+    pub name: String,
+    /// All the conditions must be satisfied for the rule to match
+    #[serde(default)]
+    pub conditions: RoutingConditions,
+    /// The connectors to be used when the rule matches
+    pub output: RoutingOutput,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(tag = "type", content = "data", rename_all = "snake_case")]
+pub enum RoutingOutput {
+    Priority(Vec<api_enums::RoutableConnectors>),
+    VolumeSplit(Vec<ConnectorVolumeSplit>),
+}
+
+/// Conditions which are not provided are not checked
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct RoutingConditions {
+    pub currency: Option<Vec<api_enums::Currency>>,
+    pub amount: Option<AmountRange>,
+    pub card_network: Option<Vec<api_enums::CardNetwork>>,
+    /// Two-letter ISO country codes, matched against the country in the billing address
+    pub country: Option<Vec<String>>,
+    pub payment_method_type: Option<Vec<api_enums::PaymentMethodType>>,
+}
+
+/// Inclusive range of amounts, in the lowest denomination of the currency
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct AmountRange {
+    pub min: Option<i64>,
+    pub max: Option<i64>,
 }
 
 #[derive(Clone, Debug, Deserialize, ToSchema, Serialize)]
@@ -259,7 +368,8 @@ pub struct WebhookDetails {
     #[schema(value_type = Option<String>, example = "www.ekart.com/webhooks")]
     pub webhook_url: Option<Secret<String>>,
 
-    /// If this property is true, a webhook message is posted whenever a new payment is created
+    /// If this property is true, a webhook message is posted whenever a new payment is created. The
+    /// `payment_created` event is not posted to `webhook_url` otherwise, even if it is in `enabled_events`.
     #[schema(example = true)]
     pub payment_created_enabled: Option<bool>,
 
@@ -270,6 +380,64 @@ pub struct WebhookDetails {
     /// If this property is true, a webhook message is posted whenever a payment fails
     #[schema(example = true)]
     pub payment_failed_enabled: Option<bool>,
+
+    /// The events for which webhooks are posted to `webhook_url`. Webhooks are posted for all events if not specified.
+    #[schema(example = json!(["payment_succeeded", "refund_succeeded"]))]
+    pub enabled_events: Option<Vec<api_enums::EventType>>,
+
+    /// Additional endpoints to which webhooks are posted, each for the events it is subscribed to
+    pub endpoints: Option<Vec<WebhookEndpoint>>,
+}
+
+#[derive(Clone, Debug, Deserialize, ToSchema, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct WebhookEndpoint {
+    /// The identifier for the webhook endpoint, unique among the endpoints of the merchant
+    #[schema(max_length = 64, example = "refunds_service")]
+    pub endpoint_id: String,
+
+    /// The url for the webhook endpoint
+    #[schema(value_type = String, example = "www.ekart.com/webhooks/refunds")]
+    pub webhook_url: Secret<String>,
+
+    /// The events for which webhooks are posted to this endpoint. Webhooks are posted for all events if not specified.
+    #[schema(example = json!(["refund_succeeded", "refund_failed"]))]
+    pub enabled_events: Option<Vec<api_enums::EventType>>,
+}
+
+/// The maximum number of requests which can be made with an API key within each rate limiting
+/// window. The limits which are not specified are inherited from the merchant account, and then from
+/// the server configuration.
+#[derive(Clone, Debug, Default, Deserialize, ToSchema, Serialize, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct RateLimits {
+    /// Limit on the requests creating or confirming payments
+    #[schema(minimum = 1, example = 100)]
+    pub payment_write_requests: Option<u32>,
+
+    /// Limit on the requests retrieving or listing resources
+    #[schema(minimum = 1, example = 1000)]
+    pub read_requests: Option<u32>,
+
+    /// Limit on any other requests modifying resources
+    #[schema(minimum = 1, example = 500)]
+    pub write_requests: Option<u32>,
+}
+
+/// The time after which payments which are left unattended are expired. Payments do not expire
+/// unless configured.
+#[derive(Clone, Debug, Default, Deserialize, ToSchema, Serialize, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentExpiry {
+    /// Number of hours after which an authorized payment that has not been captured is cancelled,
+    /// releasing the hold on the customer's funds. Can be overridden for each connector.
+    #[schema(minimum = 1, example = 120)]
+    pub authorization_ttl_hours: Option<u16>,
+
+    /// Number of minutes after which a payment that is awaiting a payment method or a
+    /// confirmation is expired
+    #[schema(minimum = 1, example = 1440)]
+    pub intent_ttl_minutes: Option<u32>,
 }
 
 #[derive(Debug, Serialize, ToSchema)]
@@ -349,6 +517,59 @@ pub struct MerchantConnector {
     /// You can specify up to 50 keys, with key names up to 40 characters long and values up to 500 characters long. Metadata is useful for storing additional, structured information on an object.
     #[schema(value_type = Option<Object>,max_length = 255,example = json!({ "city": "NY", "unit": "245" }))]
     pub metadata: Option<pii::SecretSerdeValue>,
+    /// Number of hours after which an authorized payment that has not been captured is cancelled.
+    /// Overrides the `authorization_ttl_hours` of the merchant account for payments made through this connector.
+    #[schema(minimum = 1, example = 72)]
+    pub authorization_ttl_hours: Option<u16>,
+    /// The fees charged by the connector, used to estimate the fee of a payment made through the connector
+    pub fee_schedule: Option<ConnectorFeeSchedule>,
+}
+
+/// The fees charged by a connector for a payment. Fixed fees are in the lowest denomination of the
+/// currency of the payment, and percentages are of the amount of the payment. The surcharges which
+/// apply to a payment are added to the base fee.
+#[derive(Clone, Debug, Default, Deserialize, ToSchema, Serialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct ConnectorFeeSchedule {
+    /// Fee charged for every payment
+    #[serde(default)]
+    #[schema(minimum = 0, example = 30)]
+    pub fixed_fee: i64,
+
+    /// Percentage of the amount charged for every payment
+    #[serde(default)]
+    #[schema(minimum = 0, maximum = 100, example = 2.9)]
+    pub percentage_fee: f64,
+
+    /// Surcharges for payments made with cards of the given networks
+    #[serde(default)]
+    #[schema(value_type = Object, example = json!({ "AmericanExpress": { "percentage_fee": 0.5 } }))]
+    pub network_surcharges: HashMap<api_enums::CardNetwork, FeeSurcharge>,
+
+    /// Surcharges for payments in the given currencies
+    #[serde(default)]
+    #[schema(value_type = Object, example = json!({ "EUR": { "fixed_fee": 10 } }))]
+    pub currency_surcharges: HashMap<api_enums::Currency, FeeSurcharge>,
+
+    /// Two-letter ISO code of the country in which the connector account acquires payments.
+    /// Payments made with cards issued in other countries incur the cross-border surcharge.
+    #[schema(example = "US")]
+    pub acquiring_country: Option<String>,
+
+    /// Surcharge for payments made with cards issued outside the acquiring country
+    pub cross_border_surcharge: Option<FeeSurcharge>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, ToSchema, Serialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct FeeSurcharge {
+    #[serde(default)]
+    #[schema(minimum = 0, example = 10)]
+    pub fixed_fee: i64,
+
+    #[serde(default)]
+    #[schema(minimum = 0, maximum = 100, example = 1.5)]
+    pub percentage_fee: f64,
 }
 
 /// Details of all the payment methods enabled for the connector for the given merchant account
@@ -417,3 +638,59 @@ pub struct ToggleKVRequest {
     #[schema(example = true)]
     pub kv_enabled: bool,
 }
+
+#[derive(Debug, Clone, Default, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct WebhookSecretRotateRequest {
+    /// The new hash key used for signing outgoing webhooks and payment responses. A random key is generated if not provided.
+    #[schema(max_length = 255, example = "whsec_N6kLcJDnJw3pdTzRuqcdxzA2")]
+    pub payment_response_hash_key: Option<String>,
+    /// Number of seconds for which outgoing webhooks will continue to be signed with the current key as well
+    #[schema(example = 86400)]
+    pub overlap_in_seconds: Option<u32>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct WebhookSecretRotateResponse {
+    /// The identifier for the Merchant Account
+    #[schema(max_length = 255, example = "y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub merchant_id: String,
+    /// The hash key now used for signing outgoing webhooks and payment responses
+    #[schema(max_length = 255, example = "whsec_N6kLcJDnJw3pdTzRuqcdxzA2")]
+    pub payment_response_hash_key: String,
+    /// Time until which outgoing webhooks are also signed with the previous key
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub previous_payment_response_hash_key_expires_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct ConnectorHealthResponse {
+    /// Name of the connector
+    #[schema(value_type = String, example = "stripe")]
+    pub connector: api_enums::RoutableConnectors,
+    /// State of the circuit breaker of the connector
+    #[schema(value_type = CircuitBreakerState, example = "closed")]
+    pub state: api_enums::CircuitBreakerState,
+    /// Estimated number of requests made to the connector over the last window
+    #[schema(example = 120)]
+    pub requests: u64,
+    /// Estimated number of requests which failed over the last window, including the timeouts and
+    /// the 5xx responses
+    #[schema(example = 6)]
+    pub errors: u64,
+    /// Estimated number of requests which timed out over the last window
+    #[schema(example = 2)]
+    pub timeouts: u64,
+    /// Estimated number of requests to which the connector responded with a 5xx status code over
+    /// the last window
+    #[schema(example = 3)]
+    pub server_errors: u64,
+    /// Percentage of the requests which failed over the last window
+    #[schema(example = 5.0)]
+    pub error_rate: f64,
+    /// Time at which the circuit was opened, if it is not closed
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub opened_at: Option<PrimitiveDateTime>,
+}
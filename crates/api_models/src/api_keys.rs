@@ -4,6 +4,8 @@ use serde::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
 use utoipa::ToSchema;
 
+use crate::admin::RateLimits;
+
 /// The request body for creating an API Key.
 #[derive(Debug, Deserialize, ToSchema)]
 #[serde(deny_unknown_fields)]
@@ -23,6 +25,10 @@ pub struct CreateApiKeyRequest {
     /// rotating your keys once every 6 months.
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: ApiKeyExpiration,
+
+    /// Limits on the number of requests which can be made with the API Key. The limits which are
+    /// not specified are inherited from the merchant account.
+    pub rate_limits: Option<RateLimits>,
 }
 
 /// The response body for creating an API Key.
@@ -60,6 +66,10 @@ pub struct CreateApiKeyResponse {
     /// The expiration date for the API Key.
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: ApiKeyExpiration,
+
+    /// Limits on the number of requests which can be made with the API Key.
+    #[schema(value_type = Option<RateLimits>)]
+    pub rate_limits: Option<serde_json::Value>,
     /*
     /// The date and time indicating when the API Key was last used.
     #[schema(example = "2022-09-10T10:11:12Z")]
@@ -102,6 +112,10 @@ pub struct RetrieveApiKeyResponse {
     /// The expiration date for the API Key.
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: ApiKeyExpiration,
+
+    /// Limits on the number of requests which can be made with the API Key.
+    #[schema(value_type = Option<RateLimits>)]
+    pub rate_limits: Option<serde_json::Value>,
     /*
     /// The date and time indicating when the API Key was last used.
     #[schema(example = "2022-09-10T10:11:12Z")]
@@ -129,6 +143,10 @@ pub struct UpdateApiKeyRequest {
     /// rotating your keys once every 6 months.
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: Option<ApiKeyExpiration>,
+
+    /// Limits on the number of requests which can be made with the API Key. The limits which are
+    /// not specified are inherited from the merchant account.
+    pub rate_limits: Option<RateLimits>,
 }
 
 /// The response body for revoking an API Key.
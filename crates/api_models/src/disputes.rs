@@ -1 +1,109 @@
+use common_utils::custom_serde;
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
 
+use crate::enums;
+
+#[derive(Clone, Debug, Serialize, ToSchema, Eq, PartialEq)]
+pub struct DisputeResponse {
+    /// The identifier for dispute
+    pub dispute_id: String,
+    /// The identifier for payment_intent
+    pub payment_id: String,
+    /// The identifier for payment_attempt
+    pub attempt_id: String,
+    /// The dispute amount, in the lowest denomination of the currency
+    pub amount: i64,
+    /// The three-letter ISO currency code
+    pub currency: String,
+    /// Stage of the dispute
+    pub dispute_stage: enums::DisputeStage,
+    /// Status of the dispute
+    pub dispute_status: enums::DisputeStatus,
+    /// The connector against which the payment was made
+    pub connector: String,
+    /// Status of the dispute sent by the connector
+    pub connector_status: String,
+    /// Dispute id sent by the connector
+    pub connector_dispute_id: String,
+    /// Reason of the dispute sent by the connector
+    pub connector_reason: Option<String>,
+    /// Reason code of the dispute sent by the connector
+    pub connector_reason_code: Option<String>,
+    /// Evidence has to be submitted before this time to challenge the dispute
+    #[serde(with = "custom_serde::iso8601::option")]
+    pub challenge_required_by: Option<PrimitiveDateTime>,
+    /// Evidence submitted by the merchant to challenge the dispute
+    pub evidence: Option<DisputeEvidence>,
+    /// Time at which the dispute was created by the connector
+    #[serde(with = "custom_serde::iso8601::option")]
+    pub connector_created_at: Option<PrimitiveDateTime>,
+    /// Time at which the dispute was last updated by the connector
+    #[serde(with = "custom_serde::iso8601::option")]
+    pub connector_updated_at: Option<PrimitiveDateTime>,
+    /// Time at which the dispute was received
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize, ToSchema, Eq, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct DisputeListConstraints {
+    /// Limit on the number of objects to return
+    pub limit: Option<i64>,
+    /// Status of the dispute
+    pub dispute_status: Option<enums::DisputeStatus>,
+    /// Stage of the dispute
+    pub dispute_stage: Option<enums::DisputeStage>,
+    /// The connector against which the payment was made
+    pub connector: Option<String>,
+    /// The identifier for payment_intent
+    pub payment_id: Option<String>,
+    /// Time less than the dispute received time
+    #[serde(
+        default,
+        rename = "received_time.lt",
+        with = "custom_serde::iso8601::option"
+    )]
+    pub received_time_lt: Option<PrimitiveDateTime>,
+    /// Time greater than the dispute received time
+    #[serde(
+        default,
+        rename = "received_time.gt",
+        with = "custom_serde::iso8601::option"
+    )]
+    pub received_time_gt: Option<PrimitiveDateTime>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema, Eq, PartialEq)]
+pub struct DisputeListResponse {
+    /// The list of disputes
+    pub data: Vec<DisputeResponse>,
+}
+
+/// Evidence submitted to the connector to challenge a dispute
+#[derive(Clone, Debug, Default, Deserialize, Serialize, ToSchema, Eq, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct DisputeEvidence {
+    /// The cancellation policy shown to the customer
+    pub cancellation_policy: Option<String>,
+    /// Communication with the customer, showing that they received the product or service
+    pub customer_communication: Option<String>,
+    /// Name of the customer
+    pub customer_name: Option<String>,
+    /// Description of the product or service which was sold
+    pub product_description: Option<String>,
+    /// The refund policy shown to the customer
+    pub refund_policy: Option<String>,
+    /// The date on which the service was provided to the customer
+    pub service_date: Option<String>,
+    /// The name of the carrier which shipped the product
+    pub shipping_carrier: Option<String>,
+    /// The date on which the product was shipped
+    pub shipping_date: Option<String>,
+    /// Tracking number of the shipment
+    pub shipping_tracking_number: Option<String>,
+    /// Any other information which supports the merchant's case
+    pub uncategorized_text: Option<String>,
+}
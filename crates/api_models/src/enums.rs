@@ -92,6 +92,35 @@ pub enum CaptureMethod {
     Scheduled,
 }
 
+/// The status of a capture made on a payment
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    ToSchema,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum CaptureStatus {
+    /// The capture has been created, but has not been sent to the connector yet
+    #[default]
+    Started,
+    /// The amount has been captured
+    Charged,
+    /// The capture is being processed by the connector
+    Pending,
+    /// The capture failed
+    Failed,
+}
+
 #[derive(
     Clone,
     Copy,
@@ -254,6 +283,7 @@ pub enum Currency {
     Debug,
     Eq,
     PartialEq,
+    ToSchema,
     serde::Deserialize,
     serde::Serialize,
     strum::Display,
@@ -266,6 +296,83 @@ pub enum EventType {
     PaymentSucceeded,
     RefundSucceeded,
     RefundFailed,
+    DisputeOpened,
+    DisputeChallenged,
+    DisputeWon,
+    DisputeLost,
+    PaymentFailed,
+    MandateActive,
+    MandateRevoked,
+    PaymentCancelled,
+    PaymentExpired,
+    PaymentCreated,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    ToSchema,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum EventClass {
+    Payments,
+    Refunds,
+    Disputes,
+    Mandates,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    ToSchema,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum EventObjectType {
+    PaymentDetails,
+    RefundDetails,
+    DisputeDetails,
+    MandateDetails,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    ToSchema,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum WebhookDeliveryStatus {
+    Pending,
+    Delivered,
+    Failed,
+    /// No webhook endpoint of the merchant is subscribed to the event
+    Skipped,
 }
 
 #[derive(
@@ -294,6 +401,7 @@ pub enum IntentStatus {
     #[default]
     RequiresConfirmation,
     RequiresCapture,
+    Expired,
 }
 
 #[derive(
@@ -526,6 +634,61 @@ pub enum MandateStatus {
     Revoked,
 }
 
+/// The status of the payout
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    ToSchema,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PayoutStatus {
+    /// The payout has been created, but not yet submitted to the connector
+    #[default]
+    Created,
+    /// The payout has been submitted to the connector and is waiting to be fulfilled
+    RequiresFulfillment,
+    /// The payout has been fulfilled and is being processed by the connector
+    Pending,
+    Success,
+    Failed,
+    Cancelled,
+    /// The payout has been declined by the connector after being submitted, or its funds have been returned
+    Reversed,
+}
+
+/// The payout method through which funds are sent to the recipient
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    ToSchema,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PayoutType {
+    #[default]
+    Card,
+    Bank,
+}
+
 #[derive(
     Clone,
     Copy,
@@ -573,6 +736,10 @@ impl Connector {
     pub fn supports_access_token(&self) -> bool {
         matches!(self, Self::Airwallex | Self::Globalpay | Self::Payu)
     }
+
+    pub fn supports_payouts(&self) -> bool {
+        matches!(self, Self::Adyen)
+    }
 }
 
 #[derive(
@@ -580,11 +747,13 @@ impl Connector {
     Copy,
     Debug,
     Eq,
+    Hash,
     PartialEq,
     serde::Serialize,
     serde::Deserialize,
     strum::Display,
     strum::EnumString,
+    strum::EnumIter,
     frunk::LabelledGeneric,
 )]
 #[serde(rename_all = "snake_case")]
@@ -613,6 +782,18 @@ pub enum RoutableConnectors {
     Multisafepay,
 }
 
+/// State of the circuit breaker of a connector
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum CircuitBreakerState {
+    /// Requests are routed to the connector
+    Closed,
+    /// The connector is skipped by routing
+    Open,
+    /// A trial request is let through to the connector to check whether it has recovered
+    HalfOpen,
+}
+
 /// Wallets which support obtaining session object
 #[derive(Debug, serde::Deserialize, serde::Serialize, Clone, ToSchema)]
 #[serde(rename_all = "snake_case")]
@@ -755,3 +936,64 @@ impl From<AttemptStatus> for IntentStatus {
         }
     }
 }
+
+/// The stage of the dispute
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    ToSchema,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum DisputeStage {
+    /// The issuer has requested information about the payment, before raising a dispute
+    PreDispute,
+    #[default]
+    Dispute,
+    /// The dispute has been escalated to the card network after the merchant challenged it
+    PreArbitration,
+}
+
+/// The status of the dispute
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    ToSchema,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum DisputeStatus {
+    /// The dispute has been opened and requires action from the merchant
+    #[default]
+    DisputeOpened,
+    /// The merchant did not respond to the dispute in time
+    DisputeExpired,
+    /// The merchant has accepted the dispute
+    DisputeAccepted,
+    /// The dispute has been withdrawn by the customer or the issuer
+    DisputeCancelled,
+    /// The merchant has challenged the dispute by submitting evidence
+    DisputeChallenged,
+    /// The dispute has been resolved in favour of the merchant
+    DisputeWon,
+    /// The dispute has been resolved in favour of the customer
+    DisputeLost,
+}
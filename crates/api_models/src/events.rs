@@ -0,0 +1,86 @@
+use common_utils::custom_serde;
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+use crate::enums;
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct EventResponse {
+    /// The identifier for the event
+    pub event_id: String,
+    /// The identifier for the merchant account
+    pub merchant_id: String,
+    /// The type of the event
+    pub event_type: enums::EventType,
+    /// The class of the event
+    pub event_class: enums::EventClass,
+    /// The identifier of the object the event is about, such as the payment, refund or dispute id
+    pub primary_object_id: String,
+    /// The type of the object the event is about
+    pub primary_object_type: enums::EventObjectType,
+    /// The identifier of the payment the object belongs to
+    pub intent_reference_id: Option<String>,
+    /// Whether the webhook for the event has been acknowledged by any of the merchant's endpoints
+    pub is_webhook_notified: bool,
+    /// Status of the delivery of the webhook for the event
+    pub webhook_delivery_status: enums::WebhookDeliveryStatus,
+    /// Time at which the event was created
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    /// The webhook body sent to the merchant for the event
+    #[schema(value_type = Option<Object>)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_body: Option<serde_json::Value>,
+    /// The attempts made to deliver the webhook for the event, in the order they were made
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delivery_attempts: Option<Vec<EventDeliveryAttemptResponse>>,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct EventDeliveryAttemptResponse {
+    /// The identifier of the webhook endpoint the attempt was made to
+    pub endpoint_id: String,
+    /// The sequence number of the attempt to the endpoint, starting from 1
+    pub attempt_number: i32,
+    /// Whether the merchant acknowledged the webhook in this attempt
+    pub delivered: bool,
+    /// The HTTP status code returned by the merchant's webhook endpoint, if a response was received
+    pub response_status_code: Option<i32>,
+    /// The reason the attempt failed, if it did
+    pub error_message: Option<String>,
+    /// Time at which the attempt was made
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct EventListConstraints {
+    /// Limit on the number of objects to return
+    pub limit: Option<i64>,
+    /// The type of the event
+    pub event_type: Option<enums::EventType>,
+    /// The identifier of the object the event is about
+    pub object_id: Option<String>,
+    /// Time less than the event created time
+    #[serde(
+        default,
+        rename = "created_time.lt",
+        with = "custom_serde::iso8601::option"
+    )]
+    pub created_time_lt: Option<PrimitiveDateTime>,
+    /// Time greater than the event created time
+    #[serde(
+        default,
+        rename = "created_time.gt",
+        with = "custom_serde::iso8601::option"
+    )]
+    pub created_time_gt: Option<PrimitiveDateTime>,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct EventListResponse {
+    /// The list of events
+    pub data: Vec<EventResponse>,
+}
@@ -1 +1,40 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
+#[derive(Clone, Debug, Serialize, ToSchema, Eq, PartialEq)]
+pub struct CreateFileResponse {
+    /// ID of the file created
+    pub file_id: String,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    Serialize,
+    Deserialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum FilePurpose {
+    /// The file is submitted as evidence for a dispute
+    DisputeEvidence,
+}
+
+/// Multipart form accepted by the file upload endpoint, used only for the API reference
+#[derive(Debug, ToSchema)]
+pub struct CreateFileRequestForm {
+    /// The file to be uploaded
+    #[schema(value_type = String, format = Binary)]
+    pub file: Vec<u8>,
+    /// The purpose of the file
+    pub purpose: FilePurpose,
+    /// The dispute for which the file is uploaded, the file is forwarded to the connector
+    /// against which the dispute was raised
+    pub dispute_id: Option<String>,
+}
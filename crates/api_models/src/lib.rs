@@ -7,10 +7,12 @@ pub mod customers;
 pub mod disputes;
 pub mod enums;
 pub mod errors;
+pub mod events;
 pub mod files;
 pub mod mandates;
 pub mod payment_methods;
 pub mod payments;
 pub mod payouts;
 pub mod refunds;
+pub mod routing;
 pub mod webhooks;
@@ -85,6 +85,16 @@ pub struct PaymentsRequest {
     #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
     pub capture_on: Option<PrimitiveDateTime>,
 
+    /// The number of hours after the authorization at which the payment should be captured.
+    /// Applicable only when the `capture_method` is `scheduled`, and ignored if `capture_on` is provided.
+    #[schema(maximum = 168, example = 24)]
+    pub capture_delay_hours: Option<u16>,
+
+    /// Whether the connector should allow the authorization to be incremented after the payment is authorized.
+    /// Applicable only to card payments which are captured manually.
+    #[schema(example = true)]
+    pub request_incremental_authorization: Option<bool>,
+
     /// Whether to confirm the payment (if applicable)
     #[schema(default = false, example = true)]
     pub confirm: Option<bool>,
@@ -390,6 +400,10 @@ pub struct Card {
     /// The card network for the card
     #[schema(value_type = Option<CardNetwork>, example = "Visa")]
     pub card_network: Option<api_enums::CardNetwork>,
+
+    /// The two-letter ISO code of the country in which the card was issued
+    #[schema(example = "US")]
+    pub card_issuing_country: Option<String>,
 }
 
 #[derive(Eq, PartialEq, Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
@@ -436,6 +450,7 @@ pub enum AdditionalPaymentData {
     Card {
         card_issuer: Option<String>,
         card_network: Option<String>,
+        card_issuing_country: Option<String>,
     },
     BankRedirect {
         bank_name: Option<api_enums::BankNames>,
@@ -453,6 +468,7 @@ impl From<&PaymentMethodData> for AdditionalPaymentData {
                     .card_network
                     .as_ref()
                     .map(|card_network| card_network.to_string()),
+                card_issuing_country: card_data.card_issuing_country.to_owned(),
             },
             PaymentMethodData::BankRedirect(bank_redirect_data) => match bank_redirect_data {
                 BankRedirectData::Eps { bank_name, .. } => Self::BankRedirect {
@@ -728,7 +744,7 @@ pub struct PhoneDetails {
     pub country_code: Option<String>,
 }
 
-#[derive(Debug, Clone, Default, Eq, PartialEq, serde::Deserialize, ToSchema)]
+#[derive(Debug, Clone, Default, Eq, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
 pub struct PaymentsCaptureRequest {
     /// The unique identifier for the payment
     pub payment_id: Option<String>,
@@ -744,6 +760,38 @@ pub struct PaymentsCaptureRequest {
     pub statement_descriptor_prefix: Option<String>,
 }
 
+#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, ToSchema)]
+pub struct CaptureResponse {
+    /// The unique identifier of the capture
+    #[schema(example = "cap_fafa124123")]
+    pub capture_id: String,
+    /// The status of the capture
+    #[schema(value_type = CaptureStatus, example = "charged")]
+    pub status: api_enums::CaptureStatus,
+    /// The amount requested to be captured
+    #[schema(example = 6540)]
+    pub amount: i64,
+    /// The currency of the amount captured
+    #[schema(value_type = Option<Currency>, example = "USD")]
+    pub currency: Option<api_enums::Currency>,
+    /// The connector used for the capture
+    #[schema(example = "stripe")]
+    pub connector: String,
+    /// The reference to the capture provided by the connector
+    pub connector_capture_id: Option<String>,
+    /// The position of the capture among the captures made on the payment, starting from 1
+    #[schema(example = 1)]
+    pub capture_sequence: i32,
+    /// The error code returned by the connector, if the capture failed
+    pub error_code: Option<String>,
+    /// The error message returned by the connector, if the capture failed
+    pub error_message: Option<String>,
+    /// Time when the capture was created
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
 #[derive(Default, Clone, Debug, Eq, PartialEq, serde::Serialize)]
 pub struct UrlDetails {
     pub url: String,
@@ -832,6 +880,18 @@ pub struct PaymentsResponse {
     #[schema(value_type = Option<Vec<RefundResponse>>)]
     pub refunds: Option<Vec<refunds::RefundResponse>>,
 
+    /// List of captures made on the payment, when the capture method is `manual_multiple`
+    #[schema(value_type = Option<Vec<CaptureResponse>>)]
+    pub captures: Option<Vec<CaptureResponse>>,
+
+    /// Whether incremental authorization was requested for the payment
+    #[schema(example = true)]
+    pub request_incremental_authorization: Option<bool>,
+
+    /// The number of times the authorization of the payment has been incremented
+    #[schema(example = 1)]
+    pub authorization_count: Option<i32>,
+
     /// A unique identifier to link the payment to a mandate, can be use instead of payment_method_data
     #[schema(max_length = 255, example = "mandate_iwer89rnjef349dni3")]
     pub mandate_id: Option<String>,
@@ -1392,6 +1452,16 @@ pub struct PaymentsCancelRequest {
     pub cancellation_reason: Option<String>,
 }
 
+#[derive(Default, Debug, serde::Deserialize, serde::Serialize, Clone, ToSchema)]
+pub struct PaymentsIncrementalAuthorizationRequest {
+    /// The identifier for the payment
+    #[serde(skip)]
+    pub payment_id: String,
+    /// The total amount to be authorized on the payment, including the amount already authorized. It shall be in lowest denomination of the currency.
+    #[schema(minimum = 1, example = 8000)]
+    pub amount: i64,
+}
+
 #[derive(Default, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
 pub struct PaymentsStartRequest {
     /// Unique identifier for the payment. This ensures idempotency for multiple payments
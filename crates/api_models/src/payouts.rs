@@ -1 +1,257 @@
+use common_utils::pii;
+use masking::Secret;
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
 
+use crate::{enums as api_enums, payments};
+
+#[derive(Default, Debug, ToSchema, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PayoutCreateRequest {
+    /// Unique identifier for the payout. This ensures idempotency for multiple payouts that have been done by a single merchant. If not provided, this field is auto generated and returned in the API response.
+    #[schema(
+        max_length = 30,
+        min_length = 30,
+        example = "payout_mbabizu24mvu3mela5njyhpit4"
+    )]
+    pub payout_id: Option<String>,
+
+    /// The payout amount, in the lowest denomination of the currency. (i.e) in cents for USD denomination, in paisa for INR denomination etc.
+    #[schema(minimum = 1, example = 1000)]
+    pub amount: i64,
+
+    /// The three-letter ISO currency code
+    #[schema(value_type = Currency, example = "EUR")]
+    pub currency: api_enums::Currency,
+
+    /// The connector through which the payout is to be sent
+    #[schema(value_type = Connector, example = "adyen")]
+    pub connector: api_enums::Connector,
+
+    /// The payout method through which funds are sent to the recipient
+    #[schema(value_type = PayoutType, example = "card")]
+    pub payout_type: api_enums::PayoutType,
+
+    /// The details of the card or bank account to which the funds are sent. Required when `confirm` is `true`.
+    pub payout_method_data: Option<PayoutMethodData>,
+
+    /// The identifier for the customer receiving the payout
+    #[schema(max_length = 255, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: Option<String>,
+
+    /// The email address of the recipient
+    #[schema(max_length = 255, value_type = Option<String>, example = "johntest@test.com")]
+    pub email: Option<Secret<String, pii::Email>>,
+
+    /// The billing address of the recipient
+    pub billing: Option<payments::Address>,
+
+    /// Whether the payout is to be submitted to the connector immediately
+    #[schema(default = false, example = true)]
+    pub confirm: Option<bool>,
+
+    /// Whether the payout is to be fulfilled immediately after being submitted to the connector
+    #[schema(default = false, example = true)]
+    pub auto_fulfill: Option<bool>,
+
+    /// A description of the payout
+    #[schema(max_length = 255, example = "Withdrawal of marketplace earnings")]
+    pub description: Option<String>,
+
+    /// You can specify up to 50 keys, with key names up to 40 characters long and values up to 500 characters long. Metadata is useful for storing additional, structured information on an object.
+    #[schema(value_type = Option<Object>, example = r#"{ "udf1": "some-value", "udf2": "some-value" }"#)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+}
+
+#[derive(Default, Debug, ToSchema, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PayoutUpdateRequest {
+    /// The payout amount, in the lowest denomination of the currency. Can only be updated before the payout is submitted to the connector.
+    #[schema(minimum = 1, example = 1000)]
+    pub amount: Option<i64>,
+
+    /// The three-letter ISO currency code. Can only be updated before the payout is submitted to the connector.
+    #[schema(value_type = Option<Currency>, example = "EUR")]
+    pub currency: Option<api_enums::Currency>,
+
+    /// The details of the card or bank account to which the funds are sent. Required when `confirm` is `true`.
+    pub payout_method_data: Option<PayoutMethodData>,
+
+    /// The identifier for the customer receiving the payout
+    #[schema(max_length = 255, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: Option<String>,
+
+    /// The email address of the recipient
+    #[schema(max_length = 255, value_type = Option<String>, example = "johntest@test.com")]
+    pub email: Option<Secret<String, pii::Email>>,
+
+    /// The billing address of the recipient
+    pub billing: Option<payments::Address>,
+
+    /// Whether the payout is to be submitted to the connector
+    #[schema(default = false, example = true)]
+    pub confirm: Option<bool>,
+
+    /// Whether the payout is to be fulfilled immediately after being submitted to the connector
+    #[schema(default = false, example = true)]
+    pub auto_fulfill: Option<bool>,
+
+    /// A description of the payout
+    #[schema(max_length = 255, example = "Withdrawal of marketplace earnings")]
+    pub description: Option<String>,
+
+    /// You can specify up to 50 keys, with key names up to 40 characters long and values up to 500 characters long. Metadata is useful for storing additional, structured information on an object.
+    #[schema(value_type = Option<Object>, example = r#"{ "udf1": "some-value", "udf2": "some-value" }"#)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, ToSchema, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PayoutMethodData {
+    Card(PayoutCard),
+    Bank(PayoutBank),
+}
+
+#[derive(Default, Eq, PartialEq, Clone, Debug, Deserialize, Serialize, ToSchema)]
+pub struct PayoutCard {
+    /// The card number
+    #[schema(value_type = String, example = "4111111111111111")]
+    pub card_number: Secret<String, pii::CardNumber>,
+
+    /// The card's expiry month
+    #[schema(value_type = String, example = "03")]
+    pub card_exp_month: Secret<String>,
+
+    /// The card's expiry year
+    #[schema(value_type = String, example = "2030")]
+    pub card_exp_year: Secret<String>,
+
+    /// The card holder's name
+    #[schema(value_type = String, example = "John Test")]
+    pub card_holder_name: Secret<String>,
+}
+
+#[derive(Default, Eq, PartialEq, Clone, Debug, Deserialize, Serialize, ToSchema)]
+pub struct PayoutBank {
+    /// The International Bank Account Number of the recipient
+    #[schema(value_type = String, example = "NL46TEST0136169112")]
+    pub iban: Secret<String>,
+
+    /// The Bank Identifier Code of the recipient's bank
+    #[schema(value_type = Option<String>, example = "ABNANL2A")]
+    pub bic: Option<Secret<String>>,
+
+    /// The name of the recipient's bank
+    #[schema(example = "ABN AMRO")]
+    pub bank_name: Option<String>,
+
+    /// The two-letter ISO country code of the recipient's bank
+    #[schema(max_length = 2, min_length = 2, example = "NL")]
+    pub bank_country_code: Option<String>,
+
+    /// The name of the bank account holder
+    #[schema(value_type = String, example = "John Test")]
+    pub owner_name: Secret<String>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, ToSchema)]
+pub struct PayoutResponse {
+    /// The identifier for the payout
+    #[schema(example = "payout_mbabizu24mvu3mela5njyhpit4")]
+    pub payout_id: String,
+
+    /// The identifier for the merchant account
+    #[schema(example = "y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub merchant_id: String,
+
+    /// The identifier for the customer receiving the payout
+    #[schema(example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: Option<String>,
+
+    /// The payout amount, in the lowest denomination of the currency
+    #[schema(example = 1000)]
+    pub amount: i64,
+
+    /// The three-letter ISO currency code
+    #[schema(value_type = Currency, example = "EUR")]
+    pub currency: api_enums::Currency,
+
+    /// The connector through which the payout is sent
+    #[schema(example = "adyen")]
+    pub connector: String,
+
+    /// The reference for the payout returned by the connector
+    pub connector_payout_id: Option<String>,
+
+    /// The payout method through which funds are sent to the recipient
+    #[schema(value_type = PayoutType, example = "card")]
+    pub payout_type: api_enums::PayoutType,
+
+    /// The status of the payout
+    #[schema(value_type = PayoutStatus, example = "requires_fulfillment")]
+    pub status: api_enums::PayoutStatus,
+
+    /// A description of the payout
+    pub description: Option<String>,
+
+    /// You can specify up to 50 keys, with key names up to 40 characters long and values up to 500 characters long. Metadata is useful for storing additional, structured information on an object.
+    #[schema(value_type = Option<Object>)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+
+    /// The error code returned by the connector, if the payout failed
+    pub error_code: Option<String>,
+
+    /// The error message returned by the connector, if the payout failed
+    pub error_message: Option<String>,
+
+    /// The timestamp at which the payout was created
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+
+    /// The timestamp at which the payout was last updated
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub updated_at: PrimitiveDateTime,
+}
+
+#[derive(Default, Debug, Clone, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PayoutListConstraints {
+    /// Limit on the number of objects to return
+    #[schema(default = 10, maximum = 100)]
+    pub limit: Option<i64>,
+
+    /// The number of objects to skip
+    pub offset: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct PayoutListResponse {
+    /// The number of payouts included in the list
+    pub size: usize,
+    /// The list of payouts
+    pub data: Vec<PayoutResponse>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, ToSchema)]
+pub struct PayoutAccount {
+    /// The connector through which payouts can be sent
+    #[schema(example = "adyen")]
+    pub connector: String,
+
+    /// The identifier for the merchant connector account
+    #[schema(example = "mca_5apGeP94tMts6rg3U3kR")]
+    pub merchant_connector_id: String,
+
+    /// Whether the connector account is in test mode
+    #[schema(example = false)]
+    pub test_mode: Option<bool>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct PayoutAccountsResponse {
+    /// The number of accounts included in the list
+    pub size: usize,
+    /// The list of connector accounts through which the merchant can send payouts
+    pub data: Vec<PayoutAccount>,
+}
@@ -0,0 +1,63 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::enums;
+
+/// Details of a hypothetical payment to be routed, for seeing how the routing rules of the
+/// merchant would route it without making the payment
+#[derive(Clone, Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct RoutingSimulationRequest {
+    /// The amount of the payment, in the lowest denomination of the currency
+    #[schema(example = 6540)]
+    pub amount: i64,
+    /// The currency of the payment
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: enums::Currency,
+    /// The payment method of the payment
+    #[schema(value_type = PaymentMethod, example = "card")]
+    pub payment_method: enums::PaymentMethod,
+    /// The payment method type of the payment
+    #[schema(value_type = Option<PaymentMethodType>, example = "credit")]
+    pub payment_method_type: Option<enums::PaymentMethodType>,
+    /// The first six to eight digits of the card number, used to find the card network
+    #[schema(example = "424242")]
+    pub card_bin: Option<String>,
+    /// Two-letter ISO code of the country of the billing address
+    #[schema(example = "US")]
+    pub country: Option<String>,
+    /// Two-letter ISO code of the country in which the card was issued
+    #[schema(example = "US")]
+    pub card_issuing_country: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct RoutingSimulationResponse {
+    /// The connectors the payment would be routed to, in the order in which they would be tried
+    #[schema(value_type = Vec<String>, example = json!(["stripe", "adyen"]))]
+    pub connectors: Vec<enums::RoutableConnectors>,
+    /// The name of the routing rule which decided the connectors, if a rule of an advanced
+    /// routing algorithm matched the payment
+    #[schema(example = "us_visa")]
+    pub matched_rule: Option<String>,
+    /// The card network found from the card BIN
+    #[schema(value_type = Option<CardNetwork>, example = "Visa")]
+    pub card_network: Option<enums::CardNetwork>,
+    /// The connectors given by routing which were excluded by the payment method filters
+    pub excluded_connectors: Vec<ExcludedConnector>,
+}
+
+/// A connector excluded from routing by the payment method filters configured for it
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, ToSchema)]
+pub struct ExcludedConnector {
+    /// The excluded connector
+    #[schema(value_type = String, example = "adyen")]
+    pub connector: enums::RoutableConnectors,
+    /// The payment method type or card network whose filter excluded the connector
+    #[schema(example = "Visa")]
+    pub filter: String,
+    /// Whether the country of the payment is not allowed by the filter
+    pub country_not_allowed: bool,
+    /// Whether the currency of the payment is not allowed by the filter
+    pub currency_not_allowed: bool,
+}
@@ -2,7 +2,7 @@ use common_utils::custom_serde;
 use serde::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
 
-use crate::{enums as api_enums, payments, refunds};
+use crate::{disputes, enums as api_enums, mandates, payments, refunds};
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -11,12 +11,21 @@ pub enum IncomingWebhookEvent {
     PaymentIntentSuccess,
     RefundFailure,
     RefundSuccess,
+    DisputeOpened,
+    DisputeChallenged,
+    DisputeWon,
+    DisputeLost,
+    PayoutSuccess,
+    PayoutFailure,
+    PayoutReversed,
     EndpointVerification,
 }
 
 pub enum WebhookFlow {
     Payment,
     Refund,
+    Dispute,
+    Payout,
     Subscription,
     ReturnResponse,
 }
@@ -28,6 +37,13 @@ impl From<IncomingWebhookEvent> for WebhookFlow {
             IncomingWebhookEvent::PaymentIntentSuccess => Self::Payment,
             IncomingWebhookEvent::RefundSuccess => Self::Refund,
             IncomingWebhookEvent::RefundFailure => Self::Refund,
+            IncomingWebhookEvent::DisputeOpened => Self::Dispute,
+            IncomingWebhookEvent::DisputeChallenged => Self::Dispute,
+            IncomingWebhookEvent::DisputeWon => Self::Dispute,
+            IncomingWebhookEvent::DisputeLost => Self::Dispute,
+            IncomingWebhookEvent::PayoutSuccess => Self::Payout,
+            IncomingWebhookEvent::PayoutFailure => Self::Payout,
+            IncomingWebhookEvent::PayoutReversed => Self::Payout,
             IncomingWebhookEvent::EndpointVerification => Self::ReturnResponse,
         }
     }
@@ -61,6 +77,8 @@ pub struct OutgoingWebhook {
 pub enum OutgoingWebhookContent {
     PaymentDetails(payments::PaymentsResponse),
     RefundDetails(refunds::RefundResponse),
+    DisputeDetails(Box<disputes::DisputeResponse>),
+    MandateDetails(Box<mandates::MandateResponse>),
 }
 
 pub trait OutgoingWebhookType: Serialize + From<OutgoingWebhook> + Sync + Send {}
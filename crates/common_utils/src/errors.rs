@@ -72,6 +72,23 @@ pub enum CryptoError {
     SignatureVerificationFailed,
 }
 
+/// Errors raised while generating or verifying outgoing webhook signatures
+#[derive(Debug, thiserror::Error)]
+pub enum WebhookSignatureError {
+    /// The signature could not be computed for the given payload
+    #[error("Failed to sign webhook payload")]
+    SigningFailed,
+    /// The signature header is missing the timestamp or does not contain any signatures
+    #[error("Malformed webhook signature header")]
+    MalformedHeader,
+    /// The timestamp in the signature header is outside the accepted tolerance
+    #[error("Webhook timestamp is outside the tolerance window")]
+    TimestampOutsideTolerance,
+    /// None of the signatures in the header match the expected signature
+    #[error("No signature in the header matches the expected signature")]
+    NoMatchingSignature,
+}
+
 /// Allows [error_stack::Report] to change between error contexts
 /// using the dependent [ErrorSwitch] trait to define relations & mappings between traits
 pub trait ReportSwitchExt<T, U> {
@@ -11,6 +11,7 @@ pub mod fp_utils;
 pub mod pii;
 pub mod signals;
 pub mod validation;
+pub mod webhook_signature;
 
 /// Date-time utilities.
 pub mod date_time {
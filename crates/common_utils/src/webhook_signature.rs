@@ -0,0 +1,199 @@
+//! Signing and verification of outgoing webhooks
+//!
+//! Every outgoing webhook carries a [`SIGNATURE_HEADER`] of the form
+//! `t=<unix timestamp>,v1=<signature>[,v1=<signature>...]`, where each signature is the
+//! hex-encoded HMAC-SHA512 of `<unix timestamp>.<request body>` computed with one of the
+//! merchant's webhook secrets. More than one signature is present while a rotated secret is
+//! still within its overlap window, so that receivers can verify against either secret.
+
+use error_stack::{report, IntoReport, ResultExt};
+
+use crate::{
+    crypto::{HmacSha512, SignMessage, VerifySignature},
+    errors::{CustomResult, WebhookSignatureError},
+};
+
+/// Name of the header carrying the signature of an outgoing webhook
+pub const SIGNATURE_HEADER: &str = "X-Webhook-Signature";
+
+/// Scheme identifier of the signatures present in the signature header
+pub const SIGNATURE_SCHEME: &str = "v1";
+
+/// Default number of seconds a webhook timestamp may differ from the current time
+pub const DEFAULT_TIMESTAMP_TOLERANCE_IN_SECONDS: i64 = 300;
+
+fn signed_payload(timestamp: i64, payload: &[u8]) -> Vec<u8> {
+    let mut signed_payload = format!("{timestamp}.").into_bytes();
+    signed_payload.extend_from_slice(payload);
+    signed_payload
+}
+
+/// Compute the value of the signature header for the given payload, with one signature for each
+/// of the provided secrets
+pub fn generate_signature_header(
+    secrets: &[&[u8]],
+    timestamp: i64,
+    payload: &[u8],
+) -> CustomResult<String, WebhookSignatureError> {
+    let signed_payload = signed_payload(timestamp, payload);
+
+    let signatures = secrets
+        .iter()
+        .map(|secret| {
+            HmacSha512
+                .sign_message(secret, &signed_payload)
+                .change_context(WebhookSignatureError::SigningFailed)
+                .map(|signature| format!("{SIGNATURE_SCHEME}={}", hex::encode(signature)))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if signatures.is_empty() {
+        return Err(report!(WebhookSignatureError::SigningFailed))
+            .attach_printable("No secrets provided for signing the webhook");
+    }
+
+    Ok(format!("t={timestamp},{}", signatures.join(",")))
+}
+
+/// Verify the signature header received along with an outgoing webhook.
+///
+/// The verification succeeds if the timestamp in the header lies within
+/// `tolerance_in_seconds` of `current_timestamp` and any of the signatures in the header
+/// matches the signature computed over `payload` using `secret`. `payload` must be the raw
+/// request body, as received.
+pub fn verify_signature_header(
+    header: &str,
+    payload: &[u8],
+    secret: &[u8],
+    tolerance_in_seconds: i64,
+    current_timestamp: i64,
+) -> CustomResult<(), WebhookSignatureError> {
+    let mut timestamp = None;
+    let mut signatures = Vec::new();
+
+    for (key, value) in header
+        .split(',')
+        .filter_map(|element| element.trim().split_once('='))
+    {
+        match key {
+            "t" => {
+                timestamp = Some(
+                    value
+                        .parse::<i64>()
+                        .into_report()
+                        .change_context(WebhookSignatureError::MalformedHeader)
+                        .attach_printable("Invalid timestamp in webhook signature header")?,
+                )
+            }
+            SIGNATURE_SCHEME => signatures.push(value),
+            _ => {}
+        }
+    }
+
+    let timestamp = timestamp
+        .ok_or(WebhookSignatureError::MalformedHeader)
+        .into_report()
+        .attach_printable("Timestamp missing in webhook signature header")?;
+
+    if signatures.is_empty() {
+        return Err(report!(WebhookSignatureError::MalformedHeader))
+            .attach_printable("No signatures present in webhook signature header");
+    }
+
+    if (current_timestamp - timestamp).abs() > tolerance_in_seconds {
+        return Err(report!(WebhookSignatureError::TimestampOutsideTolerance));
+    }
+
+    let signed_payload = signed_payload(timestamp, payload);
+    let is_verified = signatures
+        .into_iter()
+        .filter_map(|signature| hex::decode(signature).ok())
+        .any(|signature| {
+            HmacSha512
+                .verify_signature(secret, &signature, &signed_payload)
+                .unwrap_or(false)
+        });
+
+    if is_verified {
+        Ok(())
+    } else {
+        Err(report!(WebhookSignatureError::NoMatchingSignature))
+    }
+}
+
+#[cfg(test)]
+mod webhook_signature_tests {
+    #![allow(clippy::expect_used)]
+    use super::*;
+
+    const PAYLOAD: &[u8] = br#"{"merchant_id":"merchant_1","event_type":"payment_succeeded"}"#;
+    const TIMESTAMP: i64 = 1_677_657_600;
+
+    #[test]
+    fn test_signature_header_round_trip() {
+        let header = generate_signature_header(&[b"secret_1".as_slice()], TIMESTAMP, PAYLOAD)
+            .expect("Signature header generation failed");
+
+        assert!(header.starts_with(&format!("t={TIMESTAMP},v1=")));
+        assert!(verify_signature_header(
+            &header,
+            PAYLOAD,
+            b"secret_1",
+            DEFAULT_TIMESTAMP_TOLERANCE_IN_SECONDS,
+            TIMESTAMP + 10
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn test_signature_header_with_rotated_secrets() {
+        let secrets = [b"new_secret".as_slice(), b"old_secret".as_slice()];
+        let header = generate_signature_header(&secrets, TIMESTAMP, PAYLOAD)
+            .expect("Signature header generation failed");
+
+        for secret in secrets {
+            assert!(verify_signature_header(
+                &header,
+                PAYLOAD,
+                secret,
+                DEFAULT_TIMESTAMP_TOLERANCE_IN_SECONDS,
+                TIMESTAMP
+            )
+            .is_ok());
+        }
+    }
+
+    #[test]
+    fn test_signature_header_verification_failures() {
+        let header = generate_signature_header(&[b"secret_1".as_slice()], TIMESTAMP, PAYLOAD)
+            .expect("Signature header generation failed");
+
+        let wrong_secret = verify_signature_header(&header, PAYLOAD, b"secret_2", 300, TIMESTAMP)
+            .expect_err("Verification succeeded with a wrong secret");
+        assert!(matches!(
+            wrong_secret.current_context(),
+            WebhookSignatureError::NoMatchingSignature
+        ));
+
+        let tampered_payload = verify_signature_header(&header, b"{}", b"secret_1", 300, TIMESTAMP)
+            .expect_err("Verification succeeded with a tampered payload");
+        assert!(matches!(
+            tampered_payload.current_context(),
+            WebhookSignatureError::NoMatchingSignature
+        ));
+
+        let expired = verify_signature_header(&header, PAYLOAD, b"secret_1", 300, TIMESTAMP + 301)
+            .expect_err("Verification succeeded outside the tolerance window");
+        assert!(matches!(
+            expired.current_context(),
+            WebhookSignatureError::TimestampOutsideTolerance
+        ));
+
+        let malformed = verify_signature_header("v1=abcd", PAYLOAD, b"secret_1", 300, TIMESTAMP)
+            .expect_err("Verification succeeded without a timestamp");
+        assert!(matches!(
+            malformed.current_context(),
+            WebhookSignatureError::MalformedHeader
+        ));
+    }
+}
@@ -0,0 +1,257 @@
+//! Dead letter stream of the drainer.
+//!
+//! Entries whose database operation could not be executed, even after being retried, are moved to
+//! the dead letter stream along with the error and the number of retries, instead of being lost
+//! when they are acknowledged and deleted from the drainer stream. The dead-lettered entries can be
+//! inspected, replayed to the drainer stream they were read from, or discarded with the
+//! `dead-letter` subcommand.
+
+use std::collections::HashMap;
+
+use error_stack::{IntoReport, ResultExt};
+use redis_interface as redis;
+
+use crate::{
+    errors::{self, DrainerError},
+    metrics,
+    services::Store,
+    settings::DeadLetterCommand,
+};
+
+/// Number of entries read from the dead letter stream at once when replaying all the entries
+const REPLAY_BATCH_SIZE: u64 = 100;
+
+#[derive(Debug)]
+pub struct DeadLetterEntry {
+    /// Name of the drainer stream the entry was read from
+    pub stream_name: String,
+    /// ID of the entry in the drainer stream
+    pub source_entry_id: String,
+    pub typed_sql: String,
+    pub error: String,
+    pub retry_count: u8,
+    /// Unix timestamp of when the entry was dead-lettered
+    pub failed_at: i64,
+}
+
+impl DeadLetterEntry {
+    pub fn new(
+        stream_name: &str,
+        source_entry_id: &str,
+        typed_sql: String,
+        error: &error_stack::Report<DrainerError>,
+        retry_count: u8,
+    ) -> Self {
+        Self {
+            stream_name: stream_name.to_owned(),
+            source_entry_id: source_entry_id.to_owned(),
+            typed_sql,
+            error: format!("{error:?}"),
+            retry_count,
+            failed_at: common_utils::date_time::now_unix_timestamp(),
+        }
+    }
+
+    fn to_field_value_pairs(&self) -> Vec<(&str, String)> {
+        vec![
+            ("stream_name", self.stream_name.clone()),
+            ("source_entry_id", self.source_entry_id.clone()),
+            ("typed_sql", self.typed_sql.clone()),
+            ("error", self.error.clone()),
+            ("retry_count", self.retry_count.to_string()),
+            ("failed_at", self.failed_at.to_string()),
+        ]
+    }
+
+    fn from_stream_entry(mut fields: HashMap<String, String>) -> errors::DrainerResult<Self> {
+        let mut get_field = |field: &str| {
+            fields
+                .remove(field)
+                .ok_or(DrainerError::DeserializationFailed)
+                .into_report()
+                .attach_printable_lazy(|| format!("Missing field {field} in dead letter entry"))
+        };
+
+        Ok(Self {
+            stream_name: get_field("stream_name")?,
+            source_entry_id: get_field("source_entry_id")?,
+            typed_sql: get_field("typed_sql")?,
+            error: get_field("error")?,
+            retry_count: get_field("retry_count")?
+                .parse()
+                .into_report()
+                .change_context(DrainerError::DeserializationFailed)?,
+            failed_at: get_field("failed_at")?
+                .parse()
+                .into_report()
+                .change_context(DrainerError::DeserializationFailed)?,
+        })
+    }
+}
+
+pub async fn push_entry(store: &Store, entry: &DeadLetterEntry) -> errors::DrainerResult<()> {
+    store
+        .redis_conn
+        .stream_append_entry(
+            &store.config.dead_letter_stream_name,
+            &redis::RedisEntryId::AutoGeneratedID,
+            entry.to_field_value_pairs(),
+        )
+        .await
+        .map_err(DrainerError::from)
+        .into_report()?;
+
+    metrics::ENTRIES_DEAD_LETTERED.add(
+        &metrics::CONTEXT,
+        1,
+        &[metrics::KeyValue::new("stream", entry.stream_name.clone())],
+    );
+    Ok(())
+}
+
+async fn read_entries(
+    store: &Store,
+    start_id: &str,
+    end_id: &str,
+    count: u64,
+) -> errors::DrainerResult<Vec<(String, DeadLetterEntry)>> {
+    store
+        .redis_conn
+        .stream_read_range(
+            &store.config.dead_letter_stream_name,
+            start_id,
+            end_id,
+            Some(count),
+        )
+        .await
+        .map_err(DrainerError::from)
+        .into_report()?
+        .into_iter()
+        .map(|(entry_id, fields)| {
+            DeadLetterEntry::from_stream_entry(fields)
+                .attach_printable_lazy(|| format!("Invalid dead letter entry {entry_id}"))
+                .map(|entry| (entry_id, entry))
+        })
+        .collect()
+}
+
+/// Pushes the entry back to the drainer stream it was read from, and removes it from the dead
+/// letter stream
+async fn replay_entry(
+    store: &Store,
+    entry_id: &str,
+    entry: DeadLetterEntry,
+) -> errors::DrainerResult<()> {
+    store
+        .redis_conn
+        .stream_append_entry(
+            &entry.stream_name,
+            &redis::RedisEntryId::AutoGeneratedID,
+            vec![("typed_sql", entry.typed_sql)],
+        )
+        .await
+        .map_err(DrainerError::from)
+        .into_report()?;
+
+    discard_entry(store, entry_id).await
+}
+
+async fn discard_entry(store: &Store, entry_id: &str) -> errors::DrainerResult<()> {
+    store
+        .redis_conn
+        .stream_delete_entries(&store.config.dead_letter_stream_name, entry_id)
+        .await
+        .map_err(DrainerError::from)
+        .into_report()
+        .map(|_| ())
+}
+
+pub async fn run_command(store: &Store, command: DeadLetterCommand) -> errors::DrainerResult<()> {
+    match command {
+        DeadLetterCommand::List { count } => {
+            for (entry_id, entry) in read_entries(store, "-", "+", count).await? {
+                println!(
+                    "{entry_id}: {} entry {} failed at {} after {} retries\n{}\n{}\n",
+                    entry.stream_name,
+                    entry.source_entry_id,
+                    entry.failed_at,
+                    entry.retry_count,
+                    entry.typed_sql,
+                    entry.error
+                );
+            }
+        }
+        DeadLetterCommand::Replay { all: true, .. } => {
+            // Replayed entries are removed from the stream, so that each read gets the next batch
+            loop {
+                let entries = read_entries(store, "-", "+", REPLAY_BATCH_SIZE).await?;
+                if entries.is_empty() {
+                    break;
+                }
+                for (entry_id, entry) in entries {
+                    replay_entry(store, &entry_id, entry).await?;
+                    println!("Replayed {entry_id}");
+                }
+            }
+        }
+        DeadLetterCommand::Replay { entry_ids, .. } => {
+            for entry_id in entry_ids {
+                match read_entries(store, &entry_id, &entry_id, 1).await?.pop() {
+                    Some((_, entry)) => {
+                        replay_entry(store, &entry_id, entry).await?;
+                        println!("Replayed {entry_id}");
+                    }
+                    None => println!("Entry {entry_id} not found"),
+                }
+            }
+        }
+        DeadLetterCommand::Discard { all: true, .. } => {
+            store
+                .redis_conn
+                .delete_key(&store.config.dead_letter_stream_name)
+                .await
+                .map_err(DrainerError::from)
+                .into_report()?;
+            println!("Discarded all the entries");
+        }
+        DeadLetterCommand::Discard { entry_ids, .. } => {
+            for entry_id in entry_ids {
+                discard_entry(store, &entry_id).await?;
+                println!("Discarded {entry_id}");
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_dead_letter_entry_round_trip() {
+        let entry = DeadLetterEntry {
+            stream_name: "{shard_1}_DRAINER_STREAM".to_string(),
+            source_entry_id: "1678000000000-0".to_string(),
+            typed_sql: r#"{"db_op":"delete"}"#.to_string(),
+            error: "Failed to execute the database operation".to_string(),
+            retry_count: 3,
+            failed_at: 1_678_000_000,
+        };
+        let fields = entry
+            .to_field_value_pairs()
+            .into_iter()
+            .map(|(field, value)| (field.to_string(), value))
+            .collect::<HashMap<_, _>>();
+
+        let parsed = DeadLetterEntry::from_stream_entry(fields.clone()).unwrap();
+        assert_eq!(parsed.typed_sql, entry.typed_sql);
+        assert_eq!(parsed.retry_count, 3);
+        assert_eq!(parsed.failed_at, entry.failed_at);
+
+        let mut incomplete_fields = fields;
+        incomplete_fields.remove("typed_sql");
+        assert!(DeadLetterEntry::from_stream_entry(incomplete_fields).is_err());
+    }
+}
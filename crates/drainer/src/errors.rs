@@ -15,6 +15,10 @@ pub enum DrainerError {
     SignalError(String),
     #[error("Unexpected error occurred: {0}")]
     UnexpectedError(String),
+    #[error("Failed to deserialize the stream entry")]
+    DeserializationFailed,
+    #[error("Failed to execute the database operation")]
+    DatabaseOperationFailed,
 }
 
 pub type DrainerResult<T> = error_stack::Result<T, DrainerError>;
@@ -1,4 +1,5 @@
 mod connection;
+pub mod dead_letter;
 pub mod env;
 pub mod errors;
 pub(crate) mod metrics;
@@ -10,7 +11,7 @@ use std::sync::{atomic, Arc};
 use common_utils::signals::{get_allowed_signals, oneshot};
 pub use env as logger;
 use error_stack::{IntoReport, ResultExt};
-use storage_models::kv;
+use storage_models::{address::Address, customers::Customer, kv};
 
 use crate::{connection::pg_connection, services::Store};
 
@@ -39,16 +40,20 @@ pub async fn start_drainer(
     let handle = signal.handle();
     let task_handle = tokio::spawn(common_utils::signals::signal_handler(signal, tx));
 
+    utils::create_consumer_groups(&store, number_of_streams).await;
+    let streams_in_use = utils::new_streams_in_use(number_of_streams);
+
     let active_tasks = Arc::new(atomic::AtomicU64::new(0));
     'event: loop {
         match rx.try_recv() {
             Err(oneshot::error::TryRecvError::Empty) => {
-                if utils::is_stream_available(stream_index, store.clone()).await {
+                if utils::is_stream_available(stream_index, &streams_in_use) {
                     tokio::spawn(drainer_handler(
                         store.clone(),
                         stream_index,
                         max_read_count,
                         active_tasks.clone(),
+                        streams_in_use.clone(),
                     ));
                     jobs_picked += 1;
                 }
@@ -93,22 +98,25 @@ async fn drainer_handler(
     stream_index: u8,
     max_read_count: u64,
     active_tasks: Arc<atomic::AtomicU64>,
+    streams_in_use: Arc<Vec<atomic::AtomicBool>>,
 ) -> errors::DrainerResult<()> {
     active_tasks.fetch_add(1, atomic::Ordering::Release);
 
-    let stream_name = utils::get_drainer_stream_name(store.clone(), stream_index);
-    let drainer_result = drainer(store.clone(), max_read_count, stream_name.as_str()).await;
+    let stream_name = utils::get_drainer_stream_name(&store, stream_index);
+    let drainer_result = if utils::acquire_stream_lease(&stream_name, &store).await {
+        drainer(store.clone(), max_read_count, stream_name.as_str()).await
+    } else {
+        // The stream is being drained by another drainer instance
+        Ok(())
+    };
 
-    if let Err(error) = drainer_result {
+    if let Err(error) = &drainer_result {
         logger::error!(?error)
     }
 
-    let flag_stream_name = utils::get_stream_key_flag(store.clone(), stream_index);
-    //TODO: USE THE RESULT FOR LOGGING
-    let output =
-        utils::make_stream_available(flag_stream_name.as_str(), store.redis_conn.as_ref()).await;
+    utils::make_stream_available(stream_index, &streams_in_use);
     active_tasks.fetch_sub(1, atomic::Ordering::Release);
-    output
+    drainer_result
 }
 
 async fn drainer(
@@ -116,11 +124,32 @@ async fn drainer(
     max_read_count: u64,
     stream_name: &str,
 ) -> errors::DrainerResult<()> {
-    let stream_read =
-        utils::read_from_stream(stream_name, max_read_count, store.redis_conn.as_ref()).await?; // this returns the error.
+    utils::claim_pending_entries(stream_name, max_read_count, &store).await?;
 
-    // parse_stream_entries returns error if no entries is found, handle it
-    let (entries, last_entry_id) = utils::parse_stream_entries(&stream_read, stream_name)?;
+    // Entries which were delivered to this consumer but not acknowledged, either because they were
+    // claimed from another consumer or because the previous run stopped midway, are drained first
+    let mut entries = utils::parse_stream_entries(
+        utils::read_from_stream(
+            stream_name,
+            utils::PENDING_ENTRIES_ID,
+            max_read_count,
+            &store,
+        )
+        .await?,
+        stream_name,
+    );
+    if entries.is_empty() {
+        entries = utils::parse_stream_entries(
+            utils::read_from_stream(
+                stream_name,
+                utils::UNDELIVERED_ENTRIES_ID,
+                max_read_count,
+                &store,
+            )
+            .await?,
+            stream_name,
+        );
+    }
     let read_count = entries.len();
 
     metrics::JOBS_PICKED_PER_STREAM.add(
@@ -132,113 +161,283 @@ async fn drainer(
         }],
     );
 
-    // TODO: Handle errors when deserialization fails and when DB error occurs
-    for entry in entries {
-        let typed_sql = entry.1.get("typed_sql").map_or(String::new(), Clone::clone);
-        let result = serde_json::from_str::<kv::DBOperation>(&typed_sql);
-        let db_op = match result {
-            Ok(f) => f,
-            Err(_err) => continue, // TODO: handle error
-        };
+    let mut handled_entry_ids = Vec::with_capacity(read_count);
+    for (entry_id, fields) in entries {
+        // An entry which was deleted from the stream after being delivered has no fields, and only
+        // needs to be acknowledged
+        if fields.values().all(Option::is_none) {
+            handled_entry_ids.push(entry_id);
+            continue;
+        }
 
-        let conn = pg_connection(&store.master_pool).await;
-        let insert_op = "insert";
-        let update_op = "update";
-        let payment_intent = "payment_intent";
-        let payment_attempt = "payment_attempt";
-        let refund = "refund";
-        match db_op {
-            // TODO: Handle errors
-            kv::DBOperation::Insert { insertable } => {
-                let (_, execution_time) = common_utils::date_time::time_it(|| async {
-                    match insertable {
-                        kv::Insertable::PaymentIntent(a) => {
-                            macro_util::handle_resp!(
-                                a.insert(&conn).await,
-                                insert_op,
-                                payment_intent
-                            )
-                        }
-                        kv::Insertable::PaymentAttempt(a) => {
-                            macro_util::handle_resp!(
-                                a.insert(&conn).await,
-                                insert_op,
-                                payment_attempt
-                            )
-                        }
-                        kv::Insertable::Refund(a) => {
-                            macro_util::handle_resp!(a.insert(&conn).await, insert_op, refund)
-                        }
-                    }
-                })
-                .await;
-                metrics::QUERY_EXECUTION_TIME.record(
-                    &metrics::CONTEXT,
-                    execution_time,
-                    &[metrics::KeyValue {
-                        key: "operation".into(),
-                        value: insert_op.into(),
-                    }],
-                );
-            }
-            kv::DBOperation::Update { updatable } => {
-                let (_, execution_time) = common_utils::date_time::time_it(|| async {
-                    match updatable {
-                        kv::Updateable::PaymentIntentUpdate(a) => {
-                            macro_util::handle_resp!(
-                                a.orig.update(&conn, a.update_data).await,
-                                update_op,
-                                payment_intent
-                            )
-                        }
-                        kv::Updateable::PaymentAttemptUpdate(a) => {
-                            macro_util::handle_resp!(
-                                a.orig.update(&conn, a.update_data).await,
-                                update_op,
-                                payment_attempt
-                            )
-                        }
-                        kv::Updateable::RefundUpdate(a) => {
-                            macro_util::handle_resp!(
-                                a.orig.update(&conn, a.update_data).await,
-                                update_op,
-                                refund
-                            )
-                        }
-                    }
-                })
-                .await;
-                metrics::QUERY_EXECUTION_TIME.record(
-                    &metrics::CONTEXT,
-                    execution_time,
-                    &[metrics::KeyValue {
-                        key: "operation".into(),
-                        value: update_op.into(),
-                    }],
-                );
-            }
-            kv::DBOperation::Delete => {
-                // [#224]: Implement this
-                logger::error!("Not implemented!");
+        let typed_sql = fields
+            .get("typed_sql")
+            .cloned()
+            .flatten()
+            .unwrap_or_default();
+        let (result, retry_count) = execute_with_retries(&store, &typed_sql).await;
+
+        if let Err(error) = result {
+            logger::error!(?error, %entry_id, %retry_count, "Moving entry to the dead letter stream");
+            let dead_letter_entry = dead_letter::DeadLetterEntry::new(
+                stream_name,
+                &entry_id,
+                typed_sql,
+                &error,
+                retry_count,
+            );
+
+            // The entry is left unacknowledged to be drained again, as it would be lost otherwise
+            if let Err(error) = dead_letter::push_entry(&store, &dead_letter_entry).await {
+                logger::error!(?error, %entry_id, "Failed to move entry to the dead letter stream");
+                utils::acknowledge_entries(stream_name, handled_entry_ids, &store).await?;
+                return Err(error);
             }
-        };
+        }
+        handled_entry_ids.push(entry_id);
     }
 
-    let entries_trimmed =
-        utils::trim_from_stream(stream_name, last_entry_id.as_str(), &store.redis_conn).await?;
+    let entries_acknowledged =
+        utils::acknowledge_entries(stream_name, handled_entry_ids, &store).await?;
 
-    if read_count != entries_trimmed {
+    if read_count != entries_acknowledged {
         logger::error!(
             read_entries = %read_count,
-            trimmed_entries = %entries_trimmed,
-            ?entries,
-            "Assertion Failed no. of entries read from the stream doesn't match no. of entries trimmed"
+            acknowledged_entries = %entries_acknowledged,
+            "Assertion Failed no. of entries read from the stream doesn't match no. of entries acknowledged"
         );
     }
 
     Ok(())
 }
 
+/// Executes the database operation of the entry, retrying failed queries up to the configured
+/// number of times. Returns the result of the last attempt, along with the number of retries made.
+async fn execute_with_retries(store: &Store, typed_sql: &str) -> (errors::DrainerResult<()>, u8) {
+    let mut retry_count = 0;
+    loop {
+        match execute_db_operation(store, typed_sql).await {
+            Err(error)
+                if matches!(
+                    error.current_context(),
+                    errors::DrainerError::DatabaseOperationFailed
+                ) && retry_count < store.config.max_retries =>
+            {
+                logger::warn!(?error, %retry_count, "Retrying failed database operation");
+                metrics::QUERY_EXECUTION_RETRIES.add(&metrics::CONTEXT, 1, &[]);
+                retry_count += 1;
+                tokio::time::sleep(std::time::Duration::from_millis(
+                    store.config.retry_interval.into(),
+                ))
+                .await;
+            }
+            result => return (result, retry_count),
+        }
+    }
+}
+
+/// An insert which is drained again, such as when the drainer stopped before acknowledging it,
+/// finds the row inserted already
+fn ignore_unique_violation<T>(
+    result: storage_models::StorageResult<T>,
+) -> storage_models::StorageResult<Option<T>> {
+    match result {
+        Ok(inserted) => Ok(Some(inserted)),
+        Err(error)
+            if matches!(
+                error.current_context(),
+                storage_models::errors::DatabaseError::UniqueViolation
+            ) =>
+        {
+            logger::warn!(?error, "Row was inserted already");
+            Ok(None)
+        }
+        Err(error) => Err(error),
+    }
+}
+
+async fn execute_db_operation(store: &Store, typed_sql: &str) -> errors::DrainerResult<()> {
+    let db_op = serde_json::from_str::<kv::DBOperation>(typed_sql)
+        .into_report()
+        .change_context(errors::DrainerError::DeserializationFailed)?;
+
+    let conn = pg_connection(&store.master_pool).await;
+    let insert_op = "insert";
+    let update_op = "update";
+    let delete_op = "delete";
+    let payment_intent = "payment_intent";
+    let payment_attempt = "payment_attempt";
+    let refund = "refund";
+    let customer = "customer";
+    let address = "address";
+    let connector_response = "connector_response";
+    match db_op {
+        kv::DBOperation::Insert { insertable } => {
+            let (result, execution_time) = common_utils::date_time::time_it(|| async {
+                match insertable {
+                    kv::Insertable::PaymentIntent(a) => {
+                        macro_util::handle_resp!(
+                            ignore_unique_violation(a.insert(&conn).await),
+                            insert_op,
+                            payment_intent
+                        )
+                    }
+                    kv::Insertable::PaymentAttempt(a) => {
+                        macro_util::handle_resp!(
+                            ignore_unique_violation(a.insert(&conn).await),
+                            insert_op,
+                            payment_attempt
+                        )
+                    }
+                    kv::Insertable::Refund(a) => {
+                        macro_util::handle_resp!(
+                            ignore_unique_violation(a.insert(&conn).await),
+                            insert_op,
+                            refund
+                        )
+                    }
+                    kv::Insertable::Customer(a) => {
+                        macro_util::handle_resp!(
+                            ignore_unique_violation(a.insert(&conn).await),
+                            insert_op,
+                            customer
+                        )
+                    }
+                    kv::Insertable::Address(a) => {
+                        macro_util::handle_resp!(
+                            ignore_unique_violation(a.insert(&conn).await),
+                            insert_op,
+                            address
+                        )
+                    }
+                    kv::Insertable::ConnectorResponse(a) => {
+                        macro_util::handle_resp!(
+                            ignore_unique_violation(a.insert(&conn).await),
+                            insert_op,
+                            connector_response
+                        )
+                    }
+                }
+            })
+            .await;
+            metrics::QUERY_EXECUTION_TIME.record(
+                &metrics::CONTEXT,
+                execution_time,
+                &[metrics::KeyValue {
+                    key: "operation".into(),
+                    value: insert_op.into(),
+                }],
+            );
+            result
+        }
+        kv::DBOperation::Update { updatable } => {
+            let (result, execution_time) = common_utils::date_time::time_it(|| async {
+                match updatable {
+                    kv::Updateable::PaymentIntentUpdate(a) => {
+                        macro_util::handle_resp!(
+                            a.orig.update(&conn, a.update_data).await,
+                            update_op,
+                            payment_intent
+                        )
+                    }
+                    kv::Updateable::PaymentAttemptUpdate(a) => {
+                        macro_util::handle_resp!(
+                            a.orig.update(&conn, a.update_data).await,
+                            update_op,
+                            payment_attempt
+                        )
+                    }
+                    kv::Updateable::RefundUpdate(a) => {
+                        macro_util::handle_resp!(
+                            a.orig.update(&conn, a.update_data).await,
+                            update_op,
+                            refund
+                        )
+                    }
+                    kv::Updateable::CustomerUpdate(a) => {
+                        macro_util::handle_resp!(
+                            Customer::update_by_customer_id_merchant_id(
+                                &conn,
+                                a.orig.customer_id,
+                                a.orig.merchant_id,
+                                a.update_data
+                            )
+                            .await,
+                            update_op,
+                            customer
+                        )
+                    }
+                    kv::Updateable::AddressUpdate(a) => {
+                        macro_util::handle_resp!(
+                            Address::update_by_address_id(&conn, a.orig.address_id, a.update_data)
+                                .await,
+                            update_op,
+                            address
+                        )
+                    }
+                    kv::Updateable::ConnectorResponseUpdate(a) => {
+                        macro_util::handle_resp!(
+                            a.orig.update(&conn, a.update_data).await,
+                            update_op,
+                            connector_response
+                        )
+                    }
+                }
+            })
+            .await;
+            metrics::QUERY_EXECUTION_TIME.record(
+                &metrics::CONTEXT,
+                execution_time,
+                &[metrics::KeyValue {
+                    key: "operation".into(),
+                    value: update_op.into(),
+                }],
+            );
+            result
+        }
+        kv::DBOperation::Delete { deletable } => {
+            let (result, execution_time) = common_utils::date_time::time_it(|| async {
+                match deletable {
+                    kv::Deletable::Customer {
+                        customer_id,
+                        merchant_id,
+                    } => {
+                        // The customer may have been deleted already, if the entry is drained again
+                        let result = match Customer::delete_by_customer_id_merchant_id(
+                            &conn,
+                            &customer_id,
+                            &merchant_id,
+                        )
+                        .await
+                        {
+                            Err(error)
+                                if matches!(
+                                    error.current_context(),
+                                    storage_models::errors::DatabaseError::NotFound
+                                ) =>
+                            {
+                                Ok(false)
+                            }
+                            result => result,
+                        };
+                        macro_util::handle_resp!(result, delete_op, customer)
+                    }
+                }
+            })
+            .await;
+            metrics::QUERY_EXECUTION_TIME.record(
+                &metrics::CONTEXT,
+                execution_time,
+                &[metrics::KeyValue {
+                    key: "operation".into(),
+                    value: delete_op.into(),
+                }],
+            );
+            result
+        }
+    }
+}
+
 mod macro_util {
 
     macro_rules! handle_resp {
@@ -252,6 +451,7 @@ mod macro_util {
                             value: $table.into(),
                         }
                     ]);
+                    Ok(())
                 }
                 Err(err) => {
                     logger::error!(operation = %$op_type, table = %$table, ?err);
@@ -261,6 +461,7 @@ mod macro_util {
                             value: $table.into(),
                         }
                     ]);
+                    Err(err.change_context(errors::DrainerError::DatabaseOperationFailed))
                 }
             }
         };
@@ -1,4 +1,6 @@
-use drainer::{errors, errors::DrainerResult, logger::logger, services, settings, start_drainer};
+use drainer::{
+    dead_letter, errors, errors::DrainerResult, logger::logger, services, settings, start_drainer,
+};
 use error_stack::ResultExt;
 
 #[tokio::main]
@@ -16,6 +18,12 @@ async fn main() -> DrainerResult<()> {
     let store = services::Store::new(&conf, false).await;
     let store = std::sync::Arc::new(store);
 
+    if let Some(settings::Subcommand::DeadLetter(command)) = cmd_line.subcommand {
+        dead_letter::run_command(&store, command).await?;
+        store.close().await;
+        return Ok(());
+    }
+
     let number_of_streams = store.config.drainer_num_partitions;
     let max_read_count = conf.drainer.max_read_count;
     let shutdown_intervals = conf.drainer.shutdown_interval;
@@ -40,13 +40,22 @@ pub(crate) static SUCCESSFUL_QUERY_EXECUTION: Lazy<Counter<u64>> = Lazy::new(||
         .init()
 });
 
+pub(crate) static QUERY_EXECUTION_RETRIES: Lazy<Counter<u64>> =
+    Lazy::new(|| DRAINER_METER.u64_counter("QUERY_EXECUTION_RETRIES").init());
+
+pub(crate) static ENTRIES_RECLAIMED: Lazy<Counter<u64>> =
+    Lazy::new(|| DRAINER_METER.u64_counter("ENTRIES_RECLAIMED").init());
+
+pub(crate) static ENTRIES_DEAD_LETTERED: Lazy<Counter<u64>> =
+    Lazy::new(|| DRAINER_METER.u64_counter("ENTRIES_DEAD_LETTERED").init());
+
 // Time in (ms) milliseconds
 pub(crate) static REDIS_STREAM_READ_TIME: Lazy<Histogram<f64>> =
     Lazy::new(|| DRAINER_METER.f64_histogram("REDIS_STREAM_READ_TIME").init());
 
 // Time in (ms) milliseconds
-pub(crate) static REDIS_STREAM_TRIM_TIME: Lazy<Histogram<f64>> =
-    Lazy::new(|| DRAINER_METER.f64_histogram("REDIS_STREAM_TRIM_TIME").init());
+pub(crate) static REDIS_STREAM_ACK_TIME: Lazy<Histogram<f64>> =
+    Lazy::new(|| DRAINER_METER.f64_histogram("REDIS_STREAM_ACK_TIME").init());
 
 pub(crate) static SHUTDOWN_SIGNAL_RECEIVED: Lazy<Counter<u64>> =
     Lazy::new(|| DRAINER_METER.u64_counter("SHUTDOWN_SIGNAL_RECEIVED").init());
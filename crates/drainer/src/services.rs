@@ -13,6 +13,12 @@ pub struct Store {
 pub struct StoreConfig {
     pub drainer_stream_name: String,
     pub drainer_num_partitions: u8,
+    pub dead_letter_stream_name: String,
+    pub max_retries: u8,
+    pub retry_interval: u32,
+    pub consumer_group_name: String,
+    pub consumer_name: String,
+    pub stream_lease_time: u32,
 }
 
 impl Store {
@@ -23,6 +29,16 @@ impl Store {
             config: StoreConfig {
                 drainer_stream_name: config.drainer.stream_name.clone(),
                 drainer_num_partitions: config.drainer.num_partitions,
+                dead_letter_stream_name: config.drainer.dead_letter_stream_name.clone(),
+                max_retries: config.drainer.max_retries,
+                retry_interval: config.drainer.retry_interval,
+                consumer_group_name: config.drainer.consumer_group_name.clone(),
+                consumer_name: if config.drainer.consumer_name.is_empty() {
+                    common_utils::generate_id_with_default_len("drainer")
+                } else {
+                    config.drainer.consumer_name.clone()
+                },
+                stream_lease_time: config.drainer.stream_lease_time,
             },
         }
     }
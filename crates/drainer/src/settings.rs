@@ -16,6 +16,44 @@ pub struct CmdLineConf {
     /// Application will look for "config/config.toml" if this option isn't specified.
     #[arg(short = 'f', long, value_name = "FILE")]
     pub config_path: Option<PathBuf>,
+
+    #[command(subcommand)]
+    pub subcommand: Option<Subcommand>,
+}
+
+#[derive(clap::Subcommand)]
+pub enum Subcommand {
+    /// Manage the entries which could not be written to the database.
+    #[command(subcommand)]
+    DeadLetter(DeadLetterCommand),
+}
+
+#[derive(clap::Subcommand)]
+pub enum DeadLetterCommand {
+    /// List the dead-lettered entries, oldest first.
+    List {
+        /// Maximum number of entries to list.
+        #[arg(short = 'n', long, default_value_t = 100)]
+        count: u64,
+    },
+    /// Push the entries back to the drainer streams they were read from.
+    Replay {
+        /// IDs of the entries in the dead letter stream.
+        #[arg(required_unless_present = "all")]
+        entry_ids: Vec<String>,
+        /// Replay all the dead-lettered entries.
+        #[arg(long, conflicts_with = "entry_ids")]
+        all: bool,
+    },
+    /// Delete the entries from the dead letter stream, without writing them to the database.
+    Discard {
+        /// IDs of the entries in the dead letter stream.
+        #[arg(required_unless_present = "all")]
+        entry_ids: Vec<String>,
+        /// Discard all the dead-lettered entries.
+        #[arg(long, conflicts_with = "entry_ids")]
+        all: bool,
+    },
 }
 
 #[derive(Debug, Deserialize, Clone, Default)]
@@ -46,6 +84,16 @@ pub struct DrainerSettings {
     pub max_read_count: u64,
     pub shutdown_interval: u32, // in milliseconds
     pub loop_interval: u32,     // in milliseconds
+    pub dead_letter_stream_name: String,
+    pub max_retries: u8,
+    pub retry_interval: u32, // in milliseconds
+    pub consumer_group_name: String,
+    /// Name of this drainer instance in the consumer group, generated if not specified. Each
+    /// drainer instance must have a unique consumer name.
+    pub consumer_name: String,
+    /// Number of seconds for which a drainer instance holds a stream once it starts draining it.
+    /// Must exceed the time taken to drain the entries read from the stream at once.
+    pub stream_lease_time: u32,
 }
 
 impl Default for Database {
@@ -69,6 +117,12 @@ impl Default for DrainerSettings {
             max_read_count: 100,
             shutdown_interval: 1000, // in milliseconds
             loop_interval: 500,      // in milliseconds
+            dead_letter_stream_name: "DRAINER_DEAD_LETTER_STREAM".into(),
+            max_retries: 3,
+            retry_interval: 100, // in milliseconds
+            consumer_group_name: "DRAINER_CONSUMER_GROUP".into(),
+            consumer_name: String::new(),
+            stream_lease_time: 60, // in seconds
         }
     }
 }
@@ -105,10 +159,24 @@ impl Database {
 
 impl DrainerSettings {
     fn validate(&self) -> Result<(), errors::DrainerError> {
-        common_utils::fp_utils::when(self.stream_name.is_default_or_empty(), || {
+        use common_utils::fp_utils::when;
+
+        when(self.stream_name.is_default_or_empty(), || {
             Err(errors::DrainerError::ConfigParsingError(
                 "drainer stream name must not be empty".into(),
             ))
+        })?;
+
+        when(self.dead_letter_stream_name.is_default_or_empty(), || {
+            Err(errors::DrainerError::ConfigParsingError(
+                "drainer dead letter stream name must not be empty".into(),
+            ))
+        })?;
+
+        when(self.consumer_group_name.is_default_or_empty(), || {
+            Err(errors::DrainerError::ConfigParsingError(
+                "drainer consumer group name must not be empty".into(),
+            ))
         })
     }
 }
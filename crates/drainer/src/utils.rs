@@ -1,4 +1,7 @@
-use std::{collections::HashMap, sync::Arc};
+use std::{
+    collections::HashMap,
+    sync::{atomic, Arc},
+};
 
 use error_stack::IntoReport;
 use redis_interface as redis;
@@ -8,36 +11,148 @@ use crate::{
     logger, metrics, services,
 };
 
-pub type StreamEntries = Vec<(String, HashMap<String, String>)>;
+/// Reading from this ID returns the entries delivered to the consumer which it has not
+/// acknowledged yet
+pub const PENDING_ENTRIES_ID: &str = "0";
+/// Reading from this ID returns the entries never delivered to any consumer of the group
+pub const UNDELIVERED_ENTRIES_ID: &str = ">";
+
+// The fields of an entry are `None` if the entry was deleted after being delivered
+pub type StreamEntries = Vec<(String, HashMap<String, Option<String>>)>;
 pub type StreamReadResult = HashMap<String, StreamEntries>;
 
-pub async fn is_stream_available(stream_index: u8, store: Arc<services::Store>) -> bool {
-    let stream_key_flag = get_stream_key_flag(store.clone(), stream_index);
+/// Creates the consumer group on all the drainer streams, creating the streams as well if they do
+/// not exist.
+pub async fn create_consumer_groups(store: &services::Store, number_of_streams: u8) {
+    for stream_index in 0..number_of_streams {
+        let stream_name = get_drainer_stream_name(store, stream_index);
+        // Creating the group fails if it was already created by another drainer instance
+        if let Err(error) = store
+            .redis_conn
+            .consumer_group_create(
+                &stream_name,
+                &store.config.consumer_group_name,
+                &redis::RedisEntryId::UserSpecifiedID {
+                    milliseconds: "0".to_string(),
+                    sequence_number: "0".to_string(),
+                },
+            )
+            .await
+        {
+            logger::debug!(?error, %stream_name, "Consumer group was not created");
+        }
+    }
+}
+
+/// Marks the stream as being drained by a task of this drainer instance, so that the entries of the
+/// stream are drained by one task at a time.
+pub fn is_stream_available(stream_index: u8, streams_in_use: &[atomic::AtomicBool]) -> bool {
+    streams_in_use
+        .get(usize::from(stream_index))
+        .map(|in_use| {
+            in_use
+                .compare_exchange(
+                    false,
+                    true,
+                    atomic::Ordering::AcqRel,
+                    atomic::Ordering::Acquire,
+                )
+                .is_ok()
+        })
+        .unwrap_or(false)
+}
 
+pub fn make_stream_available(stream_index: u8, streams_in_use: &[atomic::AtomicBool]) {
+    if let Some(in_use) = streams_in_use.get(usize::from(stream_index)) {
+        in_use.store(false, atomic::Ordering::Release);
+    }
+}
+
+/// Acquires or extends the lease of this drainer instance on the stream. The entries of a stream
+/// are applied to the database in order only if a single drainer instance drains the stream, so
+/// the stream is drained only while the lease is held.
+pub async fn acquire_stream_lease(stream_name: &str, store: &services::Store) -> bool {
     match store
         .redis_conn
-        .set_key_if_not_exist(stream_key_flag.as_str(), true)
+        .set_key_with_expiry_if_not_exists_or_value_matches(
+            &get_stream_lease_key(stream_name),
+            &store.config.consumer_name,
+            store.config.stream_lease_time.into(),
+        )
         .await
     {
-        Ok(resp) => resp == redis::types::SetnxReply::KeySet,
+        Ok(lease_acquired) => lease_acquired,
         Err(error) => {
-            logger::error!(?error);
-            // Add metrics or logs
+            logger::error!(?error, %stream_name, "Failed to acquire the lease on the stream");
             false
         }
     }
 }
 
+/// Transfers the entries which were delivered to other consumers but not acknowledged to this
+/// consumer. Other consumers which held the stream earlier have lost their lease, and the entries
+/// they left unacknowledged are drained before the entries which were never delivered.
+pub async fn claim_pending_entries(
+    stream_name: &str,
+    max_read_count: u64,
+    store: &services::Store,
+) -> errors::DrainerResult<()> {
+    let entry_ids = store
+        .redis_conn
+        .consumer_group_get_idle_pending_entries(
+            stream_name,
+            &store.config.consumer_group_name,
+            0,
+            max_read_count,
+        )
+        .await
+        .map_err(DrainerError::from)
+        .into_report()?;
+
+    if entry_ids.is_empty() {
+        return Ok(());
+    }
+
+    logger::info!(?entry_ids, %stream_name, "Claiming pending entries");
+    metrics::ENTRIES_RECLAIMED.add(
+        &metrics::CONTEXT,
+        u64::try_from(entry_ids.len()).unwrap_or(u64::MIN),
+        &[metrics::KeyValue::new("stream", stream_name.to_owned())],
+    );
+
+    store
+        .redis_conn
+        .consumer_group_set_message_owner::<_, ()>(
+            stream_name,
+            &store.config.consumer_group_name,
+            &store.config.consumer_name,
+            0,
+            entry_ids,
+        )
+        .await
+        .map_err(DrainerError::from)
+        .into_report()
+}
+
 pub async fn read_from_stream(
     stream_name: &str,
+    entry_id: &str,
     max_read_count: u64,
-    redis: &redis::RedisConnectionPool,
+    store: &services::Store,
 ) -> errors::DrainerResult<StreamReadResult> {
-    // "0-0" id gives first entry
-    let stream_id = "0-0";
     let (output, execution_time) = common_utils::date_time::time_it(|| async {
-        let entries = redis
-            .stream_read_entries(stream_name, stream_id, Some(max_read_count))
+        let entries = store
+            .redis_conn
+            .stream_read_with_options(
+                stream_name,
+                entry_id,
+                Some(max_read_count),
+                None,
+                Some((
+                    &store.config.consumer_group_name,
+                    &store.config.consumer_name,
+                )),
+            )
             .await
             .map_err(DrainerError::from)
             .into_report()?;
@@ -54,72 +169,51 @@ pub async fn read_from_stream(
     output
 }
 
-pub async fn trim_from_stream(
+/// Acknowledges the drained entries and deletes them from the stream.
+pub async fn acknowledge_entries(
     stream_name: &str,
-    minimum_entry_id: &str,
-    redis: &redis::RedisConnectionPool,
+    entry_ids: Vec<String>,
+    store: &services::Store,
 ) -> errors::DrainerResult<usize> {
-    let trim_kind = redis::StreamCapKind::MinID;
-    let trim_type = redis::StreamCapTrim::Exact;
-    let trim_id = minimum_entry_id;
-    let (trim_result, execution_time) =
+    if entry_ids.is_empty() {
+        return Ok(0);
+    }
+
+    let (ack_result, execution_time) =
         common_utils::date_time::time_it::<errors::DrainerResult<_>, _, _>(|| async {
-            let trim_result = redis
-                .stream_trim_entries(stream_name, (trim_kind, trim_type, trim_id))
+            let acknowledged_entries = store
+                .redis_conn
+                .stream_acknowledge_entries(
+                    stream_name,
+                    &store.config.consumer_group_name,
+                    entry_ids.clone(),
+                )
                 .await
                 .map_err(DrainerError::from)
                 .into_report()?;
 
-            // Since xtrim deletes entries below given id excluding the given id.
-            // Hence, deleting the minimum entry id
-            redis
-                .stream_delete_entries(stream_name, minimum_entry_id)
+            store
+                .redis_conn
+                .stream_delete_entries(stream_name, entry_ids.clone())
                 .await
                 .map_err(DrainerError::from)
                 .into_report()?;
 
-            Ok(trim_result)
+            Ok(acknowledged_entries)
         })
         .await;
 
-    metrics::REDIS_STREAM_TRIM_TIME.record(
+    metrics::REDIS_STREAM_ACK_TIME.record(
         &metrics::CONTEXT,
         execution_time,
         &[metrics::KeyValue::new("stream", stream_name.to_owned())],
     );
 
-    // adding 1 because we are deleting the given id too
-    Ok(trim_result? + 1)
+    ack_result
 }
 
-pub async fn make_stream_available(
-    stream_name_flag: &str,
-    redis: &redis::RedisConnectionPool,
-) -> errors::DrainerResult<()> {
-    redis
-        .delete_key(stream_name_flag)
-        .await
-        .map_err(DrainerError::from)
-        .into_report()
-}
-
-pub fn parse_stream_entries<'a>(
-    read_result: &'a StreamReadResult,
-    stream_name: &str,
-) -> errors::DrainerResult<(&'a StreamEntries, String)> {
-    read_result
-        .get(stream_name)
-        .and_then(|entries| {
-            entries
-                .last()
-                .map(|last_entry| (entries, last_entry.0.clone()))
-        })
-        .ok_or_else(|| {
-            errors::DrainerError::RedisError(error_stack::report!(
-                redis::errors::RedisError::NotFound
-            ))
-        })
-        .into_report()
+pub fn parse_stream_entries(mut read_result: StreamReadResult, stream_name: &str) -> StreamEntries {
+    read_result.remove(stream_name).unwrap_or_default()
 }
 
 // Here the output is in the format (stream_index, jobs_picked),
@@ -141,10 +235,37 @@ pub async fn increment_stream_index(
     }
 }
 
-pub(crate) fn get_stream_key_flag(store: Arc<services::Store>, stream_index: u8) -> String {
-    format!("{}_in_use", get_drainer_stream_name(store, stream_index))
+pub(crate) fn get_drainer_stream_name(store: &services::Store, stream_index: u8) -> String {
+    store.drainer_stream(format!("shard_{stream_index}").as_str())
 }
 
-pub(crate) fn get_drainer_stream_name(store: Arc<services::Store>, stream_index: u8) -> String {
-    store.drainer_stream(format!("shard_{stream_index}").as_str())
+fn get_stream_lease_key(stream_name: &str) -> String {
+    // Example: {shard_5}_drainer_stream_lease
+    format!("{stream_name}_lease")
+}
+
+pub(crate) fn new_streams_in_use(number_of_streams: u8) -> Arc<Vec<atomic::AtomicBool>> {
+    Arc::new(
+        (0..number_of_streams)
+            .map(|_| atomic::AtomicBool::new(false))
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stream_is_drained_by_one_task_at_a_time() {
+        let streams_in_use = new_streams_in_use(2);
+
+        assert!(is_stream_available(0, &streams_in_use));
+        assert!(!is_stream_available(0, &streams_in_use));
+        assert!(is_stream_available(1, &streams_in_use));
+        assert!(!is_stream_available(2, &streams_in_use));
+
+        make_stream_available(0, &streams_in_use);
+        assert!(is_stream_available(0, &streams_in_use));
+    }
 }
@@ -15,10 +15,10 @@ use common_utils::{
 };
 use error_stack::{IntoReport, ResultExt};
 use fred::{
-    interfaces::{HashesInterface, KeysInterface, StreamsInterface},
+    interfaces::{HashesInterface, KeysInterface, LuaInterface, StreamsInterface},
     types::{
         Expiration, FromRedis, MultipleIDs, MultipleKeys, MultipleOrderedPairs, MultipleStrings,
-        RedisKey, RedisMap, RedisValue, SetOptions, XCap, XReadResponse,
+        RedisKey, RedisMap, RedisValue, SetOptions, XCap, XReadResponse, XReadValue,
     },
 };
 use futures::StreamExt;
@@ -171,6 +171,160 @@ impl super::RedisConnectionPool {
             .change_context(errors::RedisError::SetFailed)
     }
 
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn set_key_if_not_exists_with_expiry<V>(
+        &self,
+        key: &str,
+        value: V,
+        seconds: i64,
+    ) -> CustomResult<SetnxReply, errors::RedisError>
+    where
+        V: TryInto<RedisValue> + Debug,
+        V::Error: Into<fred::error::RedisError>,
+    {
+        self.pool
+            .set(
+                key,
+                value,
+                Some(Expiration::EX(seconds)),
+                Some(SetOptions::NX),
+                false,
+            )
+            .await
+            .into_report()
+            .change_context(errors::RedisError::SetFailed)
+    }
+
+    /// Sets the key to the given value with the expiry, atomically, unless the key holds another
+    /// value. Returns whether the key was set.
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn set_key_with_expiry_if_not_exists_or_value_matches(
+        &self,
+        key: &str,
+        value: &str,
+        seconds: i64,
+    ) -> CustomResult<bool, errors::RedisError> {
+        const SCRIPT: &str = r#"
+            local current = redis.call("GET", KEYS[1])
+            if current == false or current == ARGV[1] then
+                redis.call("SET", KEYS[1], ARGV[1], "EX", ARGV[2])
+                return 1
+            else
+                return 0
+            end
+        "#;
+
+        self.pool
+            .eval::<i64, _, _, _>(SCRIPT, key, vec![value.to_string(), seconds.to_string()])
+            .await
+            .into_report()
+            .change_context(errors::RedisError::SetFailed)
+            .map(|keys_set| keys_set > 0)
+    }
+
+    /// Deletes the key only if it holds the given value, atomically. Returns whether the key was
+    /// deleted.
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn delete_key_if_value_matches(
+        &self,
+        key: &str,
+        value: &str,
+    ) -> CustomResult<bool, errors::RedisError> {
+        const SCRIPT: &str = r#"
+            if redis.call("GET", KEYS[1]) == ARGV[1] then
+                return redis.call("DEL", KEYS[1])
+            else
+                return 0
+            end
+        "#;
+
+        self.pool
+            .eval::<i64, _, _, _>(SCRIPT, key, value)
+            .await
+            .into_report()
+            .change_context(errors::RedisError::DeleteFailed)
+            .map(|deleted_keys| deleted_keys > 0)
+    }
+
+    /// Counts a request against a sliding window limit, atomically. The number of requests in the
+    /// sliding window is estimated from the counts of the current and previous fixed windows, with
+    /// the count of the previous window weighted by the fraction of it which still overlaps the
+    /// sliding window. The request is counted only if the estimate is below the limit.
+    ///
+    /// Returns whether the request was counted, along with the estimated number of requests in the
+    /// window including this request, if it was counted.
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn increment_sliding_window_counter(
+        &self,
+        current_window_key: &str,
+        previous_window_key: &str,
+        previous_window_weight: f64,
+        limit: u64,
+        expiry_seconds: i64,
+    ) -> CustomResult<(bool, u64), errors::RedisError> {
+        const SCRIPT: &str = r#"
+            local current = tonumber(redis.call("GET", KEYS[1]) or "0")
+            local previous = tonumber(redis.call("GET", KEYS[2]) or "0")
+            local count = math.floor(previous * tonumber(ARGV[2])) + current
+            if count >= tonumber(ARGV[1]) then
+                return {0, count}
+            end
+            redis.call("INCR", KEYS[1])
+            redis.call("EXPIRE", KEYS[1], ARGV[3])
+            return {1, count + 1}
+        "#;
+
+        let reply = self
+            .pool
+            .eval::<Vec<u64>, _, _, _>(
+                SCRIPT,
+                vec![current_window_key, previous_window_key],
+                vec![
+                    limit.to_string(),
+                    previous_window_weight.to_string(),
+                    expiry_seconds.to_string(),
+                ],
+            )
+            .await
+            .into_report()
+            .change_context(errors::RedisError::SetFailed)?;
+
+        match reply.as_slice() {
+            [counted, count] => Ok((*counted == 1, *count)),
+            _ => Err(errors::RedisError::SetFailed)
+                .into_report()
+                .attach_printable("Unexpected reply from the sliding window counter script"),
+        }
+    }
+
+    /// Increments each of the given fields of a hash by one and sets the expiry of the hash,
+    /// atomically
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn increment_hash_fields(
+        &self,
+        key: &str,
+        fields: &[&str],
+        expiry_seconds: i64,
+    ) -> CustomResult<(), errors::RedisError> {
+        const SCRIPT: &str = r#"
+            for index = 2, #ARGV do
+                redis.call("HINCRBY", KEYS[1], ARGV[index], 1)
+            end
+            redis.call("EXPIRE", KEYS[1], ARGV[1])
+            return 1
+        "#;
+
+        let mut args = vec![expiry_seconds.to_string()];
+        args.extend(fields.iter().map(ToString::to_string));
+
+        self.pool
+            .eval::<i64, _, _, _>(SCRIPT, key, args)
+            .await
+            .into_report()
+            .change_context(errors::RedisError::SetHashFieldFailed)
+            .map(|_| ())
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn set_expiry(
         &self,
@@ -197,6 +351,17 @@ impl super::RedisConnectionPool {
             .change_context(errors::RedisError::SetExpiryFailed)
     }
 
+    /// The number of seconds until the key expires, which is negative if the key does not exist or
+    /// does not expire
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn get_ttl(&self, key: &str) -> CustomResult<i64, errors::RedisError> {
+        self.pool
+            .ttl(key)
+            .await
+            .into_report()
+            .change_context(errors::RedisError::GetFailed)
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn set_hash_fields<V>(
         &self,
@@ -349,6 +514,18 @@ impl super::RedisConnectionPool {
             .change_context(errors::RedisError::GetHashFieldFailed)
     }
 
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn get_all_hash_fields<V>(&self, key: &str) -> CustomResult<V, errors::RedisError>
+    where
+        V: FromRedis + Unpin + Send + 'static,
+    {
+        self.pool
+            .hgetall(key)
+            .await
+            .into_report()
+            .change_context(errors::RedisError::GetHashFieldFailed)
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn get_hash_field_and_deserialize<V>(
         &self,
@@ -370,6 +547,21 @@ impl super::RedisConnectionPool {
             .change_context(errors::RedisError::JsonDeserializationFailed)
     }
 
+    /// Deletes the field from the hash. Returns whether the field was present in the hash.
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn delete_hash_field(
+        &self,
+        key: &str,
+        field: &str,
+    ) -> CustomResult<bool, errors::RedisError> {
+        self.pool
+            .hdel::<i64, _, _>(key, field)
+            .await
+            .into_report()
+            .change_context(errors::RedisError::DeleteHashFieldFailed)
+            .map(|deleted_fields| deleted_fields > 0)
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn stream_append_entry<F>(
         &self,
@@ -473,6 +665,21 @@ impl super::RedisConnectionPool {
             .change_context(errors::RedisError::StreamReadFailed)
     }
 
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn stream_read_range(
+        &self,
+        stream: &str,
+        start_id: &str,
+        end_id: &str,
+        count: Option<u64>,
+    ) -> CustomResult<Vec<XReadValue<String, String, String>>, errors::RedisError> {
+        self.pool
+            .xrange_values(stream, start_id, end_id, count)
+            .await
+            .into_report()
+            .change_context(errors::RedisError::StreamReadFailed)
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn stream_read_with_options<K, Ids>(
         &self,
@@ -564,6 +771,30 @@ impl super::RedisConnectionPool {
             .change_context(errors::RedisError::ConsumerGroupSetIdFailed)
     }
 
+    /// Returns the IDs of the entries which have been delivered to the consumers of the group,
+    /// but have not been acknowledged for at least `min_idle_time` milliseconds
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn consumer_group_get_idle_pending_entries(
+        &self,
+        stream: &str,
+        group: &str,
+        min_idle_time: u64,
+        count: u64,
+    ) -> CustomResult<Vec<String>, errors::RedisError> {
+        // Each pending entry is returned as (entry ID, consumer, idle time, delivery count)
+        let pending_entries: Vec<(String, String, u64, u64)> = self
+            .pool
+            .xpending(stream, group, (min_idle_time, "-", "+", count))
+            .await
+            .into_report()
+            .change_context(errors::RedisError::ConsumerGroupPendingEntriesFailed)?;
+
+        Ok(pending_entries
+            .into_iter()
+            .map(|(entry_id, _, _, _)| entry_id)
+            .collect())
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn consumer_group_set_message_owner<Ids, R>(
         &self,
@@ -38,6 +38,8 @@ pub enum RedisError {
     ConsumerGroupSetIdFailed,
     #[error("Failed to set Redis stream message owner")]
     ConsumerGroupClaimFailed,
+    #[error("Failed to get pending entries of Redis consumer group")]
+    ConsumerGroupPendingEntriesFailed,
     #[error("Failed to serialize application type to JSON")]
     JsonSerializationFailed,
     #[error("Failed to deserialize application type from JSON")]
@@ -48,6 +50,8 @@ pub enum RedisError {
     SetHashFieldFailed,
     #[error("Failed to get hash field in Redis")]
     GetHashFieldFailed,
+    #[error("Failed to delete hash field in Redis")]
+    DeleteHashFieldFailed,
     #[error("The requested value was not found in Redis")]
     NotFound,
     #[error("Invalid RedisEntryId provided")]
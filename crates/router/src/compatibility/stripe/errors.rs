@@ -67,6 +67,21 @@ pub enum StripeErrorCode {
     #[error(error_type = StripeErrorType::InvalidRequestError, code = "resource_missing", message = "No such refund")]
     RefundNotFound,
 
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "resource_missing", message = "No such payout")]
+    PayoutNotFound,
+
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "resource_missing", message = "No such dispute")]
+    DisputeNotFound { dispute_id: String },
+
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "resource_missing", message = "No such file object")]
+    FileNotFound,
+
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "resource_missing", message = "File not available")]
+    FileNotAvailable,
+
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "resource_missing", message = "No such event")]
+    EventNotFound,
+
     #[error(error_type = StripeErrorType::InvalidRequestError, code = "client_secret_invalid", message = "Expected client secret to be included in the request")]
     ClientSecretNotFound,
 
@@ -170,6 +185,24 @@ pub enum StripeErrorCode {
     #[error(error_type = StripeErrorType::InvalidRequestError, code = "", message = "The payment with the specified payment_id '{payment_id}' already exists in our records.")]
     DuplicatePayment { payment_id: String },
 
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "", message = "The payout with the specified payout_id '{payout_id}' already exists in our records.")]
+    DuplicatePayout { payout_id: String },
+
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "", message = "The dispute cannot be updated. {reason}")]
+    DisputeStatusValidationFailed { reason: String },
+
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "", message = "File validation failed. {reason}")]
+    FileValidationFailed { reason: String },
+
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "lock_timeout", message = "This {resource} cannot be accessed right now because another request is currently accessing it. Retry after some time.")]
+    LockTimeout { resource: String },
+
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "idempotency_error", message = "Keys for idempotent requests can only be used with the same parameters they were first used with. Try using a key other than the one used if you meant to execute a different request.")]
+    IdempotencyKeyReused,
+
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "rate_limit", message = "Too many requests hit the API too quickly. Retry after {retry_after_seconds} seconds.")]
+    RateLimit { retry_after_seconds: u64 },
+
     #[error(error_type = StripeErrorType::ConnectorError, code = "", message = "{code}: {message}")]
     ExternalConnectorError {
         code: String,
@@ -403,6 +436,13 @@ impl From<errors::ApiErrorResponse> for StripeErrorCode {
             errors::ApiErrorResponse::ConfigNotFound => Self::ConfigNotFound, // not a stripe code
             errors::ApiErrorResponse::DuplicateRefundRequest => Self::DuplicateRefundRequest,
             errors::ApiErrorResponse::RefundNotFound => Self::RefundNotFound,
+            errors::ApiErrorResponse::PayoutNotFound => Self::PayoutNotFound,
+            errors::ApiErrorResponse::DisputeNotFound { dispute_id } => {
+                Self::DisputeNotFound { dispute_id }
+            }
+            errors::ApiErrorResponse::FileNotFound => Self::FileNotFound,
+            errors::ApiErrorResponse::FileNotAvailable => Self::FileNotAvailable,
+            errors::ApiErrorResponse::EventNotFound => Self::EventNotFound,
             errors::ApiErrorResponse::CustomerNotFound => Self::CustomerNotFound,
             errors::ApiErrorResponse::PaymentNotFound => Self::PaymentNotFound,
             errors::ApiErrorResponse::PaymentMethodNotFound => Self::PaymentMethodNotFound,
@@ -456,7 +496,23 @@ impl From<errors::ApiErrorResponse> for StripeErrorCode {
             errors::ApiErrorResponse::DuplicatePayment { payment_id } => {
                 Self::DuplicatePayment { payment_id }
             }
+            errors::ApiErrorResponse::DuplicatePayout { payout_id } => {
+                Self::DuplicatePayout { payout_id }
+            }
+            errors::ApiErrorResponse::DisputeStatusValidationFailed { reason } => {
+                Self::DisputeStatusValidationFailed { reason }
+            }
+            errors::ApiErrorResponse::FileValidationFailed { reason } => {
+                Self::FileValidationFailed { reason }
+            }
             errors::ApiErrorResponse::NotSupported { .. } => Self::InternalServerError,
+            errors::ApiErrorResponse::ResourceBusy { resource } => Self::LockTimeout { resource },
+            errors::ApiErrorResponse::IdempotencyKeyReused => Self::IdempotencyKeyReused,
+            errors::ApiErrorResponse::RateLimitExceeded {
+                retry_after_seconds,
+            } => Self::RateLimit {
+                retry_after_seconds,
+            },
         }
     }
 }
@@ -477,6 +533,11 @@ impl actix_web::ResponseError for StripeErrorCode {
             | Self::InvalidCardType
             | Self::DuplicateRefundRequest
             | Self::RefundNotFound
+            | Self::PayoutNotFound
+            | Self::DisputeNotFound { .. }
+            | Self::FileNotFound
+            | Self::FileNotAvailable
+            | Self::EventNotFound
             | Self::CustomerNotFound
             | Self::ConfigNotFound
             | Self::ClientSecretNotFound
@@ -503,11 +564,17 @@ impl actix_web::ResponseError for StripeErrorCode {
             | Self::PaymentIntentMandateInvalid { .. }
             | Self::PaymentIntentUnexpectedState { .. }
             | Self::DuplicatePayment { .. }
+            | Self::DuplicatePayout { .. }
+            | Self::DisputeStatusValidationFailed { .. }
+            | Self::FileValidationFailed { .. }
+            | Self::IdempotencyKeyReused
             | Self::IncorrectConnectorNameGiven => StatusCode::BAD_REQUEST,
             Self::RefundFailed
             | Self::InternalServerError
             | Self::MandateActive
             | Self::CustomerRedacted => StatusCode::INTERNAL_SERVER_ERROR,
+            Self::LockTimeout { .. } => StatusCode::CONFLICT,
+            Self::RateLimit { .. } => StatusCode::TOO_MANY_REQUESTS,
             Self::ReturnUrlUnavailable => StatusCode::SERVICE_UNAVAILABLE,
             Self::ExternalConnectorError { status_code, .. } => {
                 StatusCode::from_u16(*status_code).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR)
@@ -77,6 +77,7 @@ impl From<StripeCard> for payments::Card {
             card_cvc: card.cvc,
             card_issuer: None,
             card_network: None,
+            card_issuing_country: None,
         }
     }
 }
@@ -193,7 +194,7 @@ impl From<api_enums::IntentStatus> for StripeSetupStatus {
                 logger::error!("Invalid status change");
                 Self::Canceled
             }
-            api_enums::IntentStatus::Cancelled => Self::Canceled,
+            api_enums::IntentStatus::Cancelled | api_enums::IntentStatus::Expired => Self::Canceled,
         }
     }
 }
@@ -1,4 +1,9 @@
-use api_models::webhooks::{self as api};
+use api_models::{
+    disputes::DisputeResponse,
+    enums::{DisputeStatus, MandateStatus},
+    mandates::MandateResponse,
+    webhooks::{self as api},
+};
 use serde::Serialize;
 
 use super::{
@@ -20,6 +25,90 @@ impl api::OutgoingWebhookType for StripeOutgoingWebhook {}
 pub enum StripeWebhookObject {
     PaymentIntent(StripePaymentIntentResponse),
     Refund(StripeCreateRefundResponse),
+    Dispute(StripeDisputeResponse),
+    Mandate(StripeMandateResponse),
+}
+
+#[derive(Serialize)]
+pub struct StripeDisputeResponse {
+    pub id: String,
+    pub amount: i64,
+    pub currency: String,
+    pub payment_intent: String,
+    pub reason: Option<String>,
+    pub status: StripeDisputeStatus,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StripeDisputeStatus {
+    WarningClosed,
+    NeedsResponse,
+    UnderReview,
+    Won,
+    Lost,
+}
+
+impl From<DisputeStatus> for StripeDisputeStatus {
+    fn from(status: DisputeStatus) -> Self {
+        match status {
+            DisputeStatus::DisputeOpened => Self::NeedsResponse,
+            DisputeStatus::DisputeCancelled => Self::WarningClosed,
+            DisputeStatus::DisputeChallenged => Self::UnderReview,
+            DisputeStatus::DisputeWon => Self::Won,
+            DisputeStatus::DisputeExpired
+            | DisputeStatus::DisputeAccepted
+            | DisputeStatus::DisputeLost => Self::Lost,
+        }
+    }
+}
+
+impl From<DisputeResponse> for StripeDisputeResponse {
+    fn from(res: DisputeResponse) -> Self {
+        Self {
+            id: res.dispute_id,
+            amount: res.amount,
+            currency: res.currency,
+            payment_intent: res.payment_id,
+            reason: res.connector_reason,
+            status: res.dispute_status.into(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct StripeMandateResponse {
+    pub id: String,
+    pub status: StripeMandateStatus,
+    pub payment_method: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StripeMandateStatus {
+    Active,
+    Inactive,
+    Pending,
+}
+
+impl From<MandateStatus> for StripeMandateStatus {
+    fn from(status: MandateStatus) -> Self {
+        match status {
+            MandateStatus::Active => Self::Active,
+            MandateStatus::Inactive | MandateStatus::Revoked => Self::Inactive,
+            MandateStatus::Pending => Self::Pending,
+        }
+    }
+}
+
+impl From<MandateResponse> for StripeMandateResponse {
+    fn from(res: MandateResponse) -> Self {
+        Self {
+            id: res.mandate_id,
+            status: res.status.into(),
+            payment_method: res.payment_method_id,
+        }
+    }
 }
 
 impl From<api::OutgoingWebhook> for StripeOutgoingWebhook {
@@ -40,6 +129,12 @@ impl From<api::OutgoingWebhookContent> for StripeWebhookObject {
                 Self::PaymentIntent(payment.into())
             }
             api::OutgoingWebhookContent::RefundDetails(refund) => Self::Refund(refund.into()),
+            api::OutgoingWebhookContent::DisputeDetails(dispute) => {
+                Self::Dispute((*dispute).into())
+            }
+            api::OutgoingWebhookContent::MandateDetails(mandate) => {
+                Self::Mandate((*mandate).into())
+            }
         }
     }
 }
@@ -49,6 +144,8 @@ impl StripeWebhookObject {
         match self {
             Self::PaymentIntent(p) => p.id.to_owned(),
             Self::Refund(r) => Some(r.id.to_owned()),
+            Self::Dispute(d) => Some(d.id.to_owned()),
+            Self::Mandate(m) => Some(m.id.to_owned()),
         }
     }
 }
@@ -73,12 +73,107 @@ impl Default for super::settings::Refund {
     }
 }
 
+impl Default for super::settings::PaymentRetry {
+    fn default() -> Self {
+        Self {
+            max_attempts: 1,
+            retryable_error_codes: None,
+            retry_on_server_error: false,
+            retry_on_timeout: false,
+        }
+    }
+}
+
+impl Default for super::settings::LockSettings {
+    fn default() -> Self {
+        Self {
+            redis_lock_expiry_seconds: 180,
+            delay_between_retries_in_milliseconds: 500,
+            lock_retries: 4,
+        }
+    }
+}
+
+impl Default for super::settings::Idempotency {
+    fn default() -> Self {
+        Self {
+            ttl_in_seconds: 86400,
+            in_progress_ttl_in_seconds: 180,
+        }
+    }
+}
+
+impl Default for super::settings::RateLimit {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            window_in_seconds: 60,
+            payment_write_requests: 100,
+            read_requests: 1000,
+            write_requests: 500,
+        }
+    }
+}
+
+impl Default for super::settings::CircuitBreaker {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            window_in_seconds: 60,
+            minimum_requests: 20,
+            error_rate_threshold: 50,
+            open_duration_in_seconds: 30,
+        }
+    }
+}
+
+impl Default for super::settings::SuccessRateRouting {
+    fn default() -> Self {
+        Self {
+            window_in_seconds: 3600,
+            minimum_attempts: 10,
+            default_exploration_percentage: 10,
+        }
+    }
+}
+
+impl Default for super::settings::AccessTokenRefresh {
+    fn default() -> Self {
+        Self {
+            refresh_ahead_seconds: 300,
+            delay_between_retries_in_milliseconds: 100,
+            lock_retries: 20,
+            background_refresh: true,
+        }
+    }
+}
+
+impl Default for super::settings::FileStorageConfig {
+    fn default() -> Self {
+        Self {
+            backend: super::settings::FileStorageBackend::FileSystem,
+            file_system_path: "files".into(),
+            // 10 MiB
+            max_file_size: 10 * 1024 * 1024,
+        }
+    }
+}
+
 impl Default for super::settings::EphemeralConfig {
     fn default() -> Self {
         Self { validity: 1 }
     }
 }
 
+impl Default for super::settings::WebhooksSettings {
+    fn default() -> Self {
+        Self {
+            outgoing_enabled: false,
+            secret_rotation_overlap_in_seconds: 86400, // 24 hours
+        }
+    }
+}
+
 impl Default for super::settings::SchedulerSettings {
     fn default() -> Self {
         Self {
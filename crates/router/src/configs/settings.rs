@@ -49,6 +49,7 @@ pub struct Settings {
     pub locker: Locker,
     pub connectors: Connectors,
     pub refund: Refund,
+    pub payment_retry: PaymentRetry,
     pub eph_key: EphemeralConfig,
     pub scheduler: Option<SchedulerSettings>,
     #[cfg(feature = "kv_store")]
@@ -58,6 +59,13 @@ pub struct Settings {
     pub pm_filters: ConnectorFilters,
     pub bank_config: BankRedirectConfig,
     pub api_keys: ApiKeys,
+    pub file_storage: FileStorageConfig,
+    pub lock_settings: LockSettings,
+    pub idempotency: Idempotency,
+    pub rate_limit: RateLimit,
+    pub circuit_breaker: CircuitBreaker,
+    pub success_rate_routing: SuccessRateRouting,
+    pub access_token_refresh: AccessTokenRefresh,
 }
 
 #[derive(Debug, Deserialize, Clone, Default)]
@@ -169,6 +177,128 @@ pub struct Refund {
     pub max_age: i64,
 }
 
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct PaymentRetry {
+    /// Maximum number of attempts made for a payment, including the first one. Retries are
+    /// disabled when this is `1`.
+    pub max_attempts: u8,
+    /// Connector error codes on which the payment is retried with the next connector
+    #[serde(deserialize_with = "string_set_deser")]
+    pub retryable_error_codes: Option<HashSet<String>>,
+    /// Retry when the connector responds with a 5xx status code
+    pub retry_on_server_error: bool,
+    /// Retry when the request to the connector times out. The payment might have been processed
+    /// by the connector in this case, so enable this only if the connectors deduplicate requests.
+    pub retry_on_timeout: bool,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct LockSettings {
+    /// Number of seconds after which a lock expires, in case its holder fails to release it
+    pub redis_lock_expiry_seconds: u32,
+    /// Delay between the attempts to acquire a lock held by another request, in milliseconds
+    pub delay_between_retries_in_milliseconds: u32,
+    /// Number of times acquiring a lock held by another request is retried before giving up
+    pub lock_retries: u32,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct Idempotency {
+    /// Number of seconds for which the response of a request made with an idempotency key is
+    /// replayed to the retries of the request
+    pub ttl_in_seconds: u32,
+    /// Number of seconds after which an idempotency key whose request did not complete, such as
+    /// on a crash of the server, can be used again
+    pub in_progress_ttl_in_seconds: u32,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct RateLimit {
+    /// Whether the requests made with API keys are rate limited
+    pub enabled: bool,
+    /// Length of the sliding window over which the requests made with an API key are counted
+    pub window_in_seconds: u32,
+    /// Number of requests creating or confirming payments allowed in a window, unless overridden
+    /// for the merchant or the API key
+    pub payment_write_requests: u32,
+    /// Number of requests retrieving or listing resources allowed in a window, unless overridden
+    /// for the merchant or the API key
+    pub read_requests: u32,
+    /// Number of any other requests modifying resources allowed in a window, unless overridden
+    /// for the merchant or the API key
+    pub write_requests: u32,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct CircuitBreaker {
+    /// Whether connectors failing at a high rate are skipped by routing
+    pub enabled: bool,
+    /// Length of the sliding window over which the outcomes of the requests made to a connector
+    /// are counted
+    pub window_in_seconds: u32,
+    /// Minimum number of requests made to a connector in a window before its circuit can be opened
+    pub minimum_requests: u32,
+    /// Percentage of the requests in a window which must fail for the circuit to be opened
+    pub error_rate_threshold: u8,
+    /// Number of seconds for which the circuit stays open, before a trial request is let through
+    /// to the connector
+    pub open_duration_in_seconds: u32,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct SuccessRateRouting {
+    /// Length of the sliding window over which the authorization outcomes of the payments are
+    /// counted
+    pub window_in_seconds: u32,
+    /// Minimum number of payments authorized through a connector in a window before its success
+    /// rate is used for routing
+    pub minimum_attempts: u32,
+    /// Percentage of the payments routed to a connector other than the best one, when not
+    /// configured for the merchant
+    pub default_exploration_percentage: u8,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct AccessTokenRefresh {
+    /// Number of seconds before the expiry of the access token of a connector at which it is
+    /// refreshed, so that payments do not wait for the token to be refreshed
+    pub refresh_ahead_seconds: u32,
+    /// Delay between the checks for the access token being refreshed by another request, in
+    /// milliseconds
+    pub delay_between_retries_in_milliseconds: u32,
+    /// Number of times the access token is checked for before the request refreshes it itself
+    pub lock_retries: u32,
+    /// Whether the access tokens are refreshed by the scheduler ahead of their expiry, including
+    /// when there are no payments being made
+    pub background_refresh: bool,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct FileStorageConfig {
+    /// Backend in which the uploaded files are stored
+    pub backend: FileStorageBackend,
+    /// Directory in which the files are stored when the file system backend is used
+    pub file_system_path: String,
+    /// Maximum size of an uploaded file, in bytes
+    pub max_file_size: usize,
+}
+
+#[derive(Debug, Deserialize, Clone, Copy, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum FileStorageBackend {
+    /// Store the files in a directory on the local file system, meant for development
+    #[default]
+    FileSystem,
+}
+
 #[derive(Debug, Deserialize, Clone)]
 #[serde(default)]
 pub struct EphemeralConfig {
@@ -230,7 +360,7 @@ pub struct SupportedConnectors {
 #[serde(default)]
 pub struct Connectors {
     pub aci: ConnectorParams,
-    pub adyen: ConnectorParams,
+    pub adyen: ConnectorParamsWithMoreUrls,
     pub airwallex: ConnectorParams,
     pub applepay: ConnectorParams,
     pub authorizedotnet: ConnectorParams,
@@ -248,7 +378,7 @@ pub struct Connectors {
     pub payu: ConnectorParams,
     pub rapyd: ConnectorParams,
     pub shift4: ConnectorParams,
-    pub stripe: ConnectorParams,
+    pub stripe: ConnectorParamsWithFileUploadUrl,
     pub worldline: ConnectorParams,
     pub worldpay: ConnectorParams,
 
@@ -262,6 +392,21 @@ pub struct ConnectorParams {
     pub base_url: String,
 }
 
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct ConnectorParamsWithMoreUrls {
+    pub base_url: String,
+    pub secondary_base_url: String,
+    pub dispute_base_url: String,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct ConnectorParamsWithFileUploadUrl {
+    pub base_url: String,
+    pub base_url_file_upload: String,
+}
+
 #[derive(Debug, Clone, Deserialize)]
 #[serde(default)]
 pub struct SchedulerSettings {
@@ -299,10 +444,13 @@ pub struct DrainerSettings {
     pub loop_interval: u32,     // in milliseconds
 }
 
-#[derive(Debug, Clone, Default, Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 #[serde(default)]
 pub struct WebhooksSettings {
     pub outgoing_enabled: bool,
+    /// Number of seconds for which outgoing webhooks are signed with both the old and the new key
+    /// after a merchant's webhook secret is rotated
+    pub secret_rotation_overlap_in_seconds: u32,
 }
 
 #[derive(Debug, Deserialize, Clone, Default)]
@@ -391,6 +539,13 @@ impl Settings {
         self.secrets.validate()?;
         self.locker.validate()?;
         self.connectors.validate()?;
+        self.payment_retry.validate()?;
+        self.lock_settings.validate()?;
+        self.idempotency.validate()?;
+        self.rate_limit.validate()?;
+        self.circuit_breaker.validate()?;
+        self.success_rate_routing.validate()?;
+        self.access_token_refresh.validate()?;
 
         self.scheduler
             .as_ref()
@@ -400,6 +555,7 @@ impl Settings {
         self.drainer.validate()?;
         self.jwekey.validate()?;
         self.api_keys.validate()?;
+        self.file_storage.validate()?;
 
         Ok(())
     }
@@ -142,6 +142,48 @@ impl super::settings::ConnectorParams {
     }
 }
 
+impl super::settings::ConnectorParamsWithMoreUrls {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.base_url.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "connector base URL must not be empty".into(),
+            ))
+        })?;
+
+        when(self.secondary_base_url.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "connector secondary base URL must not be empty".into(),
+            ))
+        })?;
+
+        when(self.dispute_base_url.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "connector dispute base URL must not be empty".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::ConnectorParamsWithFileUploadUrl {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.base_url.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "connector base URL must not be empty".into(),
+            ))
+        })?;
+
+        when(self.base_url_file_upload.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "connector file upload base URL must not be empty".into(),
+            ))
+        })
+    }
+}
+
 impl super::settings::SchedulerSettings {
     pub fn validate(&self) -> Result<(), ApplicationError> {
         use common_utils::fp_utils::when;
@@ -218,3 +260,153 @@ impl super::settings::ApiKeys {
         })
     }
 }
+
+impl super::settings::PaymentRetry {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.max_attempts == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "payment retry max attempts must be at least 1".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::LockSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.redis_lock_expiry_seconds == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "redis lock expiry must be greater than 0 seconds".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::Idempotency {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.ttl_in_seconds == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "idempotency key ttl must be greater than 0 seconds".into(),
+            ))
+        })?;
+
+        when(self.in_progress_ttl_in_seconds == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "idempotency key in progress ttl must be greater than 0 seconds".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::RateLimit {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.window_in_seconds == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "rate limit window must be greater than 0 seconds".into(),
+            ))
+        })?;
+
+        when(
+            self.payment_write_requests == 0 || self.read_requests == 0 || self.write_requests == 0,
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "rate limits must allow at least 1 request in a window".into(),
+                ))
+            },
+        )
+    }
+}
+
+impl super::settings::CircuitBreaker {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(
+            self.window_in_seconds == 0 || self.open_duration_in_seconds == 0,
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "circuit breaker window and open duration must be greater than 0 seconds"
+                        .into(),
+                ))
+            },
+        )?;
+
+        when(self.minimum_requests == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "circuit breaker minimum requests must be at least 1".into(),
+            ))
+        })?;
+
+        when(!(1..=100).contains(&self.error_rate_threshold), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "circuit breaker error rate threshold must be between 1 and 100".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::SuccessRateRouting {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.window_in_seconds == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "success rate routing window must be greater than 0 seconds".into(),
+            ))
+        })?;
+
+        when(self.minimum_attempts == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "success rate routing minimum attempts must be at least 1".into(),
+            ))
+        })?;
+
+        when(self.default_exploration_percentage > 100, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "success rate routing exploration percentage must not exceed 100".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::AccessTokenRefresh {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.delay_between_retries_in_milliseconds == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "access token refresh delay between retries must be greater than 0 milliseconds"
+                    .into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::FileStorageConfig {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        match self.backend {
+            super::settings::FileStorageBackend::FileSystem => {
+                when(self.file_system_path.is_default_or_empty(), || {
+                    Err(ApplicationError::InvalidConfigurationValueError(
+                        "file system path for file storage must not be empty".into(),
+                    ))
+                })?;
+            }
+        }
+
+        when(self.max_file_size == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "maximum file size must be greater than 0".into(),
+            ))
+        })
+    }
+}
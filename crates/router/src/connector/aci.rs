@@ -410,6 +410,18 @@ impl
     }
 }
 
+impl api::PaymentIncrementalAuthorization for Aci {}
+
+impl
+    services::ConnectorIntegration<
+        api::IncrementalAuthorization,
+        types::PaymentsIncrementalAuthorizationData,
+        types::PaymentsResponseData,
+    > for Aci
+{
+    // Not Implemented (R)
+}
+
 impl api::Refund for Aci {}
 impl api::RefundExecute for Aci {}
 impl api::RefundSync for Aci {}
@@ -522,6 +534,60 @@ impl services::ConnectorIntegration<api::RSync, types::RefundsData, types::Refun
 {
 }
 
+impl api::Payout for Aci {}
+impl api::PayoutCreate for Aci {}
+impl api::PayoutFulfill for Aci {}
+impl api::PayoutReverse for Aci {}
+
+impl services::ConnectorIntegration<api::PoCreate, types::PayoutsData, types::PayoutsResponseData>
+    for Aci
+{
+}
+
+impl services::ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Aci
+{
+}
+
+impl services::ConnectorIntegration<api::PoReverse, types::PayoutsData, types::PayoutsResponseData>
+    for Aci
+{
+}
+
+impl api::Dispute for Aci {}
+impl api::AcceptDispute for Aci {}
+impl api::SubmitEvidence for Aci {}
+
+impl
+    services::ConnectorIntegration<
+        api::Accept,
+        types::AcceptDisputeRequestData,
+        types::AcceptDisputeResponse,
+    > for Aci
+{
+}
+
+impl
+    services::ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::SubmitEvidenceResponse,
+    > for Aci
+{
+}
+
+impl api::FileUpload for Aci {}
+impl api::UploadFile for Aci {}
+
+impl
+    services::ConnectorIntegration<
+        api::Upload,
+        types::UploadFileRequestData,
+        types::UploadFileResponse,
+    > for Aci
+{
+}
+
 #[async_trait::async_trait]
 impl api::IncomingWebhook for Aci {
     fn get_webhook_object_reference_id(
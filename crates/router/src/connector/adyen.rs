@@ -8,6 +8,7 @@ use router_env::{instrument, tracing};
 use storage_models::enums as storage_enums;
 
 use self::transformers as adyen;
+use super::utils as connector_utils;
 use crate::{
     configs::settings,
     consts,
@@ -52,6 +53,7 @@ impl api::PaymentAuthorize for Adyen {}
 impl api::PaymentSync for Adyen {}
 impl api::PaymentVoid for Adyen {}
 impl api::PaymentCapture for Adyen {}
+impl api::PaymentIncrementalAuthorization for Adyen {}
 impl api::PreVerify for Adyen {}
 impl api::ConnectorAccessToken for Adyen {}
 
@@ -184,6 +186,108 @@ impl
     }
 }
 
+impl
+    services::ConnectorIntegration<
+        api::IncrementalAuthorization,
+        types::PaymentsIncrementalAuthorizationData,
+        types::PaymentsResponseData,
+    > for Adyen
+{
+    fn get_headers(
+        &self,
+        req: &types::PaymentsIncrementalAuthorizationRouterData,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        let mut header = vec![
+            (
+                headers::CONTENT_TYPE.to_string(),
+                self.common_get_content_type().to_string(),
+            ),
+            (headers::X_ROUTER.to_string(), "test".to_string()),
+        ];
+        let mut api_key = self.get_auth_header(&req.connector_auth_type)?;
+        header.append(&mut api_key);
+        Ok(header)
+    }
+
+    fn get_url(
+        &self,
+        req: &types::PaymentsIncrementalAuthorizationRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        let id = req.request.connector_transaction_id.as_str();
+        Ok(format!(
+            "{}{}/{}/amountUpdates",
+            self.base_url(connectors),
+            "v68/payments",
+            id
+        ))
+    }
+    fn get_request_body(
+        &self,
+        req: &types::PaymentsIncrementalAuthorizationRouterData,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        let connector_req = adyen::AdyenIncrementalAuthorizationRequest::try_from(req)?;
+        let adyen_req =
+            utils::Encode::<adyen::AdyenIncrementalAuthorizationRequest>::encode_to_string_of_json(
+                &connector_req,
+            )
+            .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+        Ok(Some(adyen_req))
+    }
+    fn build_request(
+        &self,
+        req: &types::PaymentsIncrementalAuthorizationRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Post)
+                .url(&types::PaymentsIncrementalAuthorizationType::get_url(
+                    self, req, connectors,
+                )?)
+                .headers(types::PaymentsIncrementalAuthorizationType::get_headers(
+                    self, req, connectors,
+                )?)
+                .body(types::PaymentsIncrementalAuthorizationType::get_request_body(self, req)?)
+                .build(),
+        ))
+    }
+    fn handle_response(
+        &self,
+        data: &types::PaymentsIncrementalAuthorizationRouterData,
+        res: types::Response,
+    ) -> CustomResult<types::PaymentsIncrementalAuthorizationRouterData, errors::ConnectorError>
+    {
+        let response: adyen::AdyenIncrementalAuthorizationResponse = res
+            .response
+            .parse_struct("AdyenIncrementalAuthorizationResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+    fn get_error_response(
+        &self,
+        res: types::Response,
+    ) -> CustomResult<types::ErrorResponse, errors::ConnectorError> {
+        let response: adyen::ErrorResponse = res
+            .response
+            .parse_struct("adyen::ErrorResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        Ok(types::ErrorResponse {
+            status_code: res.status_code,
+            code: response.error_code,
+            message: response.message,
+            reason: None,
+        })
+    }
+}
+
 impl
     services::ConnectorIntegration<api::PSync, types::PaymentsSyncData, types::PaymentsResponseData>
     for Adyen
@@ -289,8 +393,11 @@ impl
             .response
             .parse_struct("AdyenPaymentResponse")
             .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
-        let is_manual_capture =
-            data.request.capture_method == Some(storage_enums::CaptureMethod::Manual);
+        let is_manual_capture = matches!(
+            data.request.capture_method,
+            Some(storage_enums::CaptureMethod::Manual)
+                | Some(storage_enums::CaptureMethod::ManualMultiple)
+        );
         types::RouterData::try_from((
             types::ResponseRouterData {
                 response,
@@ -403,8 +510,11 @@ impl
             .response
             .parse_struct("AdyenPaymentResponse")
             .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
-        let is_manual_capture =
-            data.request.capture_method == Some(storage_models::enums::CaptureMethod::Manual);
+        let is_manual_capture = matches!(
+            data.request.capture_method,
+            Some(storage_models::enums::CaptureMethod::Manual)
+                | Some(storage_models::enums::CaptureMethod::ManualMultiple)
+        );
         types::RouterData::try_from((
             types::ResponseRouterData {
                 response,
@@ -638,6 +748,291 @@ impl services::ConnectorIntegration<api::RSync, types::RefundsData, types::Refun
 {
 }
 
+impl api::Payout for Adyen {}
+impl api::PayoutCreate for Adyen {}
+impl api::PayoutFulfill for Adyen {}
+impl api::PayoutReverse for Adyen {}
+
+impl services::ConnectorIntegration<api::PoCreate, types::PayoutsData, types::PayoutsResponseData>
+    for Adyen
+{
+    fn get_headers(
+        &self,
+        req: &types::PayoutsRouterData<api::PoCreate>,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        let mut header = vec![(
+            headers::CONTENT_TYPE.to_string(),
+            types::PayoutCreateType::get_content_type(self).to_string(),
+        )];
+        let mut api_header = self.get_auth_header(&req.connector_auth_type)?;
+        header.append(&mut api_header);
+        Ok(header)
+    }
+
+    fn get_url(
+        &self,
+        _req: &types::PayoutsRouterData<api::PoCreate>,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!(
+            "{}pal/servlet/Payout/v68/storeDetailAndSubmitThirdParty",
+            connectors.adyen.secondary_base_url
+        ))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::PayoutsRouterData<api::PoCreate>,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        let connector_req = adyen::AdyenPayoutCreateRequest::try_from(req)?;
+        let adyen_req = utils::Encode::<adyen::AdyenPayoutCreateRequest>::encode_to_string_of_json(
+            &connector_req,
+        )
+        .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+        Ok(Some(adyen_req))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::PayoutsRouterData<api::PoCreate>,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Post)
+                .url(&types::PayoutCreateType::get_url(self, req, connectors)?)
+                .headers(types::PayoutCreateType::get_headers(self, req, connectors)?)
+                .body(types::PayoutCreateType::get_request_body(self, req)?)
+                .build(),
+        ))
+    }
+
+    #[instrument(skip_all)]
+    fn handle_response(
+        &self,
+        data: &types::PayoutsRouterData<api::PoCreate>,
+        res: types::Response,
+    ) -> CustomResult<types::PayoutsRouterData<api::PoCreate>, errors::ConnectorError> {
+        let response: adyen::AdyenPayoutResponse = res
+            .response
+            .parse_struct("AdyenPayoutResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        logger::info!(response=?res);
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: types::Response,
+    ) -> CustomResult<types::ErrorResponse, errors::ConnectorError> {
+        let response: adyen::ErrorResponse = res
+            .response
+            .parse_struct("ErrorResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        logger::info!(response=?res);
+        Ok(types::ErrorResponse {
+            status_code: res.status_code,
+            code: response.error_code,
+            message: response.message,
+            reason: None,
+        })
+    }
+}
+
+impl services::ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Adyen
+{
+    fn get_headers(
+        &self,
+        req: &types::PayoutsRouterData<api::PoFulfill>,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        let mut header = vec![(
+            headers::CONTENT_TYPE.to_string(),
+            types::PayoutFulfillType::get_content_type(self).to_string(),
+        )];
+        let mut api_header = self.get_auth_header(&req.connector_auth_type)?;
+        header.append(&mut api_header);
+        Ok(header)
+    }
+
+    fn get_url(
+        &self,
+        _req: &types::PayoutsRouterData<api::PoFulfill>,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!(
+            "{}pal/servlet/Payout/v68/confirmThirdParty",
+            connectors.adyen.secondary_base_url
+        ))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::PayoutsRouterData<api::PoFulfill>,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        let connector_req = adyen::AdyenPayoutModifyRequest::try_from(req)?;
+        let adyen_req = utils::Encode::<adyen::AdyenPayoutModifyRequest>::encode_to_string_of_json(
+            &connector_req,
+        )
+        .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+        Ok(Some(adyen_req))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::PayoutsRouterData<api::PoFulfill>,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Post)
+                .url(&types::PayoutFulfillType::get_url(self, req, connectors)?)
+                .headers(types::PayoutFulfillType::get_headers(
+                    self, req, connectors,
+                )?)
+                .body(types::PayoutFulfillType::get_request_body(self, req)?)
+                .build(),
+        ))
+    }
+
+    #[instrument(skip_all)]
+    fn handle_response(
+        &self,
+        data: &types::PayoutsRouterData<api::PoFulfill>,
+        res: types::Response,
+    ) -> CustomResult<types::PayoutsRouterData<api::PoFulfill>, errors::ConnectorError> {
+        let response: adyen::AdyenPayoutResponse = res
+            .response
+            .parse_struct("AdyenPayoutResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        logger::info!(response=?res);
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: types::Response,
+    ) -> CustomResult<types::ErrorResponse, errors::ConnectorError> {
+        let response: adyen::ErrorResponse = res
+            .response
+            .parse_struct("ErrorResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        logger::info!(response=?res);
+        Ok(types::ErrorResponse {
+            status_code: res.status_code,
+            code: response.error_code,
+            message: response.message,
+            reason: None,
+        })
+    }
+}
+
+impl services::ConnectorIntegration<api::PoReverse, types::PayoutsData, types::PayoutsResponseData>
+    for Adyen
+{
+    fn get_headers(
+        &self,
+        req: &types::PayoutsRouterData<api::PoReverse>,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        let mut header = vec![(
+            headers::CONTENT_TYPE.to_string(),
+            types::PayoutReverseType::get_content_type(self).to_string(),
+        )];
+        let mut api_header = self.get_auth_header(&req.connector_auth_type)?;
+        header.append(&mut api_header);
+        Ok(header)
+    }
+
+    fn get_url(
+        &self,
+        _req: &types::PayoutsRouterData<api::PoReverse>,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!(
+            "{}pal/servlet/Payout/v68/declineThirdParty",
+            connectors.adyen.secondary_base_url
+        ))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::PayoutsRouterData<api::PoReverse>,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        let connector_req = adyen::AdyenPayoutModifyRequest::try_from(req)?;
+        let adyen_req = utils::Encode::<adyen::AdyenPayoutModifyRequest>::encode_to_string_of_json(
+            &connector_req,
+        )
+        .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+        Ok(Some(adyen_req))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::PayoutsRouterData<api::PoReverse>,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Post)
+                .url(&types::PayoutReverseType::get_url(self, req, connectors)?)
+                .headers(types::PayoutReverseType::get_headers(
+                    self, req, connectors,
+                )?)
+                .body(types::PayoutReverseType::get_request_body(self, req)?)
+                .build(),
+        ))
+    }
+
+    #[instrument(skip_all)]
+    fn handle_response(
+        &self,
+        data: &types::PayoutsRouterData<api::PoReverse>,
+        res: types::Response,
+    ) -> CustomResult<types::PayoutsRouterData<api::PoReverse>, errors::ConnectorError> {
+        let response: adyen::AdyenPayoutResponse = res
+            .response
+            .parse_struct("AdyenPayoutResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        logger::info!(response=?res);
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: types::Response,
+    ) -> CustomResult<types::ErrorResponse, errors::ConnectorError> {
+        let response: adyen::ErrorResponse = res
+            .response
+            .parse_struct("ErrorResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        logger::info!(response=?res);
+        Ok(types::ErrorResponse {
+            status_code: res.status_code,
+            code: response.error_code,
+            message: response.message,
+            reason: None,
+        })
+    }
+}
+
 fn get_webhook_object_from_body(
     body: &[u8],
 ) -> CustomResult<adyen::AdyenNotificationRequestItemWH, errors::ParsingError> {
@@ -653,6 +1048,145 @@ fn get_webhook_object_from_body(
     Ok(item_object.notification_request_item)
 }
 
+impl api::Dispute for Adyen {}
+impl api::AcceptDispute for Adyen {}
+impl api::SubmitEvidence for Adyen {}
+
+impl
+    services::ConnectorIntegration<
+        api::Accept,
+        types::AcceptDisputeRequestData,
+        types::AcceptDisputeResponse,
+    > for Adyen
+{
+}
+
+impl
+    services::ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::SubmitEvidenceResponse,
+    > for Adyen
+{
+}
+
+impl api::UploadFile for Adyen {}
+
+impl api::FileUpload for Adyen {
+    fn validate_file_upload(
+        &self,
+        purpose: api::FilePurpose,
+        file_size: i32,
+        file_type: mime::Mime,
+    ) -> CustomResult<(), errors::ConnectorError> {
+        match purpose {
+            api::FilePurpose::DisputeEvidence => connector_utils::validate_file_type_and_size(
+                self.id(),
+                &file_type,
+                file_size,
+                &[mime::IMAGE_JPEG, mime::APPLICATION_PDF],
+                // Adyen accepts defense documents of up to 2MB
+                2 * 1024 * 1024,
+            ),
+        }
+    }
+}
+
+impl
+    services::ConnectorIntegration<
+        api::Upload,
+        types::UploadFileRequestData,
+        types::UploadFileResponse,
+    > for Adyen
+{
+    fn get_headers(
+        &self,
+        req: &types::UploadFileRouterData,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        let mut header = vec![(
+            headers::CONTENT_TYPE.to_string(),
+            types::UploadFileType::get_content_type(self).to_string(),
+        )];
+        let mut api_header = self.get_auth_header(&req.connector_auth_type)?;
+        header.append(&mut api_header);
+        Ok(header)
+    }
+
+    fn get_url(
+        &self,
+        _req: &types::UploadFileRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!(
+            "{}ca/services/DisputeService/v30/supplyDefenseDocument",
+            connectors.adyen.dispute_base_url
+        ))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::UploadFileRouterData,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        let connector_req = adyen::AdyenSupplyDefenseDocumentRequest::try_from(req)?;
+        let adyen_req =
+            utils::Encode::<adyen::AdyenSupplyDefenseDocumentRequest>::encode_to_string_of_json(
+                &connector_req,
+            )
+            .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+        Ok(Some(adyen_req))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::UploadFileRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Post)
+                .url(&types::UploadFileType::get_url(self, req, connectors)?)
+                .headers(types::UploadFileType::get_headers(self, req, connectors)?)
+                .body(types::UploadFileType::get_request_body(self, req)?)
+                .build(),
+        ))
+    }
+
+    #[instrument(skip_all)]
+    fn handle_response(
+        &self,
+        data: &types::UploadFileRouterData,
+        res: types::Response,
+    ) -> CustomResult<types::UploadFileRouterData, errors::ConnectorError> {
+        let response: adyen::AdyenSupplyDefenseDocumentResponse = res
+            .response
+            .parse_struct("AdyenSupplyDefenseDocumentResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: types::Response,
+    ) -> CustomResult<types::ErrorResponse, errors::ConnectorError> {
+        let response: adyen::ErrorResponse = res
+            .response
+            .parse_struct("ErrorResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        Ok(types::ErrorResponse {
+            status_code: res.status_code,
+            code: response.error_code,
+            message: response.message,
+            reason: None,
+        })
+    }
+}
+
 #[async_trait::async_trait]
 impl api::IncomingWebhook for Adyen {
     fn get_webhook_source_verification_algorithm(
@@ -724,7 +1258,7 @@ impl api::IncomingWebhook for Adyen {
         let notif = get_webhook_object_from_body(request.body)
             .change_context(errors::ConnectorError::WebhookReferenceIdNotFound)?;
 
-        Ok(notif.psp_reference)
+        Ok(notif.get_reference_id())
     }
 
     fn get_webhook_event_type(
@@ -733,7 +1267,7 @@ impl api::IncomingWebhook for Adyen {
     ) -> CustomResult<api::IncomingWebhookEvent, errors::ConnectorError> {
         let notif = get_webhook_object_from_body(request.body)
             .change_context(errors::ConnectorError::WebhookEventTypeNotFound)?;
-        Ok(notif.event_code.into())
+        Ok(notif.into())
     }
 
     fn get_webhook_resource_object(
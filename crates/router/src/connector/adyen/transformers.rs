@@ -1,4 +1,5 @@
 use api_models::webhooks::IncomingWebhookEvent;
+use base64::Engine;
 use masking::PeekInterface;
 use reqwest::Url;
 use serde::{Deserialize, Serialize};
@@ -476,7 +477,8 @@ fn get_browser_info(item: &types::PaymentsAuthorizeRouterData) -> Option<AdyenBr
 
 fn get_additional_data(item: &types::PaymentsAuthorizeRouterData) -> Option<AdditionalData> {
     match item.request.capture_method {
-        Some(storage_models::enums::CaptureMethod::Manual) => Some(AdditionalData {
+        Some(storage_models::enums::CaptureMethod::Manual)
+        | Some(storage_models::enums::CaptureMethod::ManualMultiple) => Some(AdditionalData {
             authorisation_type: AuthType::PreAuth,
             manual_capture: true,
         }),
@@ -991,9 +993,15 @@ impl TryFrom<&types::PaymentsCaptureRouterData> for AdyenCaptureRequest {
     type Error = error_stack::Report<errors::ConnectorError>;
     fn try_from(item: &types::PaymentsCaptureRouterData) -> Result<Self, Self::Error> {
         let auth_type = AdyenAuthType::try_from(&item.connector_auth_type)?;
+        // Each of the partial captures needs a reference of its own to tell them apart in the
+        // webhooks
+        let reference = match &item.request.multiple_capture_data {
+            Some(multiple_capture_data) => multiple_capture_data.capture_reference.clone(),
+            None => item.payment_id.to_string(),
+        };
         Ok(Self {
             merchant_account: auth_type.merchant_account,
-            reference: item.payment_id.to_string(),
+            reference,
             amount: Amount {
                 currency: item.request.currency.to_string(),
                 value: item
@@ -1044,6 +1052,80 @@ impl TryFrom<types::PaymentsCaptureResponseRouterData<AdyenCaptureResponse>>
     }
 }
 
+#[derive(Default, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenIncrementalAuthorizationRequest {
+    merchant_account: String,
+    amount: Amount,
+    reference: String,
+}
+
+impl TryFrom<&types::PaymentsIncrementalAuthorizationRouterData>
+    for AdyenIncrementalAuthorizationRequest
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: &types::PaymentsIncrementalAuthorizationRouterData,
+    ) -> Result<Self, Self::Error> {
+        let auth_type = AdyenAuthType::try_from(&item.connector_auth_type)?;
+        // Adyen expects the total amount to be authorized, and not the amount by which the
+        // authorization is incremented
+        Ok(Self {
+            merchant_account: auth_type.merchant_account,
+            reference: item.payment_id.to_string(),
+            amount: Amount {
+                currency: item.request.currency.to_string(),
+                value: item.request.total_amount,
+            },
+        })
+    }
+}
+
+#[derive(Default, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenIncrementalAuthorizationResponse {
+    merchant_account: String,
+    payment_psp_reference: String,
+    psp_reference: String,
+    reference: String,
+    status: String,
+    amount: Amount,
+}
+
+impl
+    TryFrom<
+        types::PaymentsIncrementalAuthorizationResponseRouterData<
+            AdyenIncrementalAuthorizationResponse,
+        >,
+    > for types::PaymentsIncrementalAuthorizationRouterData
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::PaymentsIncrementalAuthorizationResponseRouterData<
+            AdyenIncrementalAuthorizationResponse,
+        >,
+    ) -> Result<Self, Self::Error> {
+        let status = match item.response.status.as_str() {
+            "received" => storage_enums::AttemptStatus::Authorized,
+            _ => storage_enums::AttemptStatus::Pending,
+        };
+        Ok(Self {
+            status,
+            response: Ok(types::PaymentsResponseData::TransactionResponse {
+                // The amount update gets a reference of its own, while the payment continues to be
+                // identified by the reference of the original authorization
+                resource_id: types::ResponseId::ConnectorTransactionId(
+                    item.response.payment_psp_reference,
+                ),
+                redirection_data: None,
+                mandate_reference: None,
+                connector_metadata: None,
+            }),
+            ..item.data
+        })
+    }
+}
+
 /*
 // This is a repeated code block from Stripe inegration. Can we avoid the repetition in every integration
 #[derive(Debug, Serialize, Deserialize)]
@@ -1118,6 +1200,433 @@ impl<F> TryFrom<types::RefundsResponseRouterData<F, AdyenRefundResponse>>
     }
 }
 
+// Payouts Request and Response Types
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenPayoutCreateRequest {
+    amount: Amount,
+    merchant_account: String,
+    reference: String,
+    recurring: AdyenPayoutRecurring,
+    shopper_email: Secret<String, Email>,
+    shopper_reference: String,
+    #[serde(flatten)]
+    payout_method: AdyenPayoutMethod,
+    billing_address: Option<Address>,
+    entity_type: AdyenPayoutEntityType,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AdyenPayoutRecurring {
+    contract: AdyenPayoutContract,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum AdyenPayoutContract {
+    Payout,
+}
+
+#[derive(Debug, Serialize)]
+pub enum AdyenPayoutEntityType {
+    NaturalPerson,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum AdyenPayoutMethod {
+    Card(AdyenPayoutCard),
+    Bank(AdyenPayoutBank),
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenPayoutCard {
+    number: Secret<String, pii::CardNumber>,
+    expiry_month: Secret<String>,
+    expiry_year: Secret<String>,
+    holder_name: Secret<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenPayoutBank {
+    iban: Secret<String>,
+    bic: Option<Secret<String>>,
+    bank_name: Option<String>,
+    country_code: Option<String>,
+    owner_name: Secret<String>,
+}
+
+/// Request used for both confirming (fulfilling) and declining (cancelling) a submitted payout
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenPayoutModifyRequest {
+    merchant_account: String,
+    original_reference: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenPayoutResponse {
+    psp_reference: String,
+    /// Returned when a payout is submitted
+    result_code: Option<String>,
+    /// Returned when a payout is confirmed or declined
+    response: Option<String>,
+    refusal_reason: Option<String>,
+}
+
+impl From<&api::PayoutMethodData> for AdyenPayoutMethod {
+    fn from(payout_method_data: &api::PayoutMethodData) -> Self {
+        match payout_method_data {
+            api::PayoutMethodData::Card(card) => Self::Card(AdyenPayoutCard {
+                number: card.card_number.clone(),
+                expiry_month: card.card_exp_month.clone(),
+                expiry_year: card.card_exp_year.clone(),
+                holder_name: card.card_holder_name.clone(),
+            }),
+            api::PayoutMethodData::Bank(bank) => Self::Bank(AdyenPayoutBank {
+                iban: bank.iban.clone(),
+                bic: bank.bic.clone(),
+                bank_name: bank.bank_name.clone(),
+                country_code: bank.bank_country_code.clone(),
+                owner_name: bank.owner_name.clone(),
+            }),
+        }
+    }
+}
+
+impl<F> TryFrom<&types::PayoutsRouterData<F>> for AdyenPayoutCreateRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::PayoutsRouterData<F>) -> Result<Self, Self::Error> {
+        let auth_type = AdyenAuthType::try_from(&item.connector_auth_type)?;
+        let payout_method_data = item.request.payout_method_data.as_ref().ok_or(
+            errors::ConnectorError::MissingRequiredField {
+                field_name: "payout_method_data",
+            },
+        )?;
+        let shopper_email =
+            item.request
+                .email
+                .clone()
+                .ok_or(errors::ConnectorError::MissingRequiredField {
+                    field_name: "email",
+                })?;
+        Ok(Self {
+            amount: Amount {
+                currency: item.request.currency.to_string(),
+                value: item.request.amount,
+            },
+            merchant_account: auth_type.merchant_account,
+            reference: item.request.payout_id.clone(),
+            recurring: AdyenPayoutRecurring {
+                contract: AdyenPayoutContract::Payout,
+            },
+            shopper_email,
+            shopper_reference: item
+                .request
+                .customer_id
+                .clone()
+                .unwrap_or_else(|| item.request.payout_id.clone()),
+            payout_method: AdyenPayoutMethod::from(payout_method_data),
+            billing_address: get_address_info(item.address.billing.as_ref()),
+            entity_type: AdyenPayoutEntityType::NaturalPerson,
+        })
+    }
+}
+
+impl<F> TryFrom<&types::PayoutsRouterData<F>> for AdyenPayoutModifyRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::PayoutsRouterData<F>) -> Result<Self, Self::Error> {
+        let auth_type = AdyenAuthType::try_from(&item.connector_auth_type)?;
+        Ok(Self {
+            merchant_account: auth_type.merchant_account,
+            original_reference: item.request.connector_payout_id.clone().ok_or(
+                errors::ConnectorError::MissingRequiredField {
+                    field_name: "connector_payout_id",
+                },
+            )?,
+        })
+    }
+}
+
+impl<F> TryFrom<types::PayoutsResponseRouterData<F, AdyenPayoutResponse>>
+    for types::PayoutsRouterData<F>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::PayoutsResponseRouterData<F, AdyenPayoutResponse>,
+    ) -> Result<Self, Self::Error> {
+        let response = item.response;
+        let outcome = response
+            .result_code
+            .as_deref()
+            .or(response.response.as_deref());
+        let status = match outcome {
+            Some("[payout-submit-received]") => storage_enums::PayoutStatus::RequiresFulfillment,
+            // The final outcome of a confirmed payout is only available through notifications
+            Some("[payout-confirm-received]") => storage_enums::PayoutStatus::Pending,
+            Some("[payout-decline-received]") => storage_enums::PayoutStatus::Reversed,
+            _ => storage_enums::PayoutStatus::Failed,
+        };
+        let payouts_response = match (status, response.refusal_reason) {
+            (storage_enums::PayoutStatus::Failed, refusal_reason) => Err(types::ErrorResponse {
+                code: outcome
+                    .map(ToString::to_string)
+                    .unwrap_or_else(|| consts::NO_ERROR_CODE.to_string()),
+                message: refusal_reason.unwrap_or_else(|| consts::NO_ERROR_MESSAGE.to_string()),
+                reason: None,
+                status_code: item.http_code,
+            }),
+            _ => Ok(types::PayoutsResponseData {
+                // Confirm and decline return a new reference, the payout is still identified by the original one
+                connector_payout_id: item
+                    .data
+                    .request
+                    .connector_payout_id
+                    .clone()
+                    .unwrap_or(response.psp_reference),
+                status,
+            }),
+        };
+        Ok(Self {
+            response: payouts_response,
+            ..item.data
+        })
+    }
+}
+
+#[cfg(test)]
+mod payout_tests {
+    #![allow(clippy::unwrap_used)]
+
+    use std::marker::PhantomData;
+
+    use super::*;
+    use crate::core::payments::PaymentAddress;
+
+    fn get_payout_router_data(
+        payout_method_data: Option<api::PayoutMethodData>,
+        connector_payout_id: Option<&str>,
+    ) -> types::PayoutsRouterData<api::PoCreate> {
+        types::RouterData {
+            flow: PhantomData,
+            merchant_id: "merchant".to_string(),
+            connector: "adyen".to_string(),
+            payment_id: "payout_123".to_string(),
+            attempt_id: "payout_123".to_string(),
+            status: storage_enums::AttemptStatus::default(),
+            payment_method: storage_enums::PaymentMethod::default(),
+            connector_auth_type: types::ConnectorAuthType::BodyKey {
+                api_key: "api_key".to_string(),
+                key1: "MerchantAccountECOM".to_string(),
+            },
+            description: None,
+            return_url: None,
+            router_return_url: None,
+            address: PaymentAddress::default(),
+            auth_type: storage_enums::AuthenticationType::default(),
+            connector_meta_data: None,
+            amount_captured: None,
+            access_token: None,
+            session_token: None,
+            reference_id: None,
+            request: types::PayoutsData {
+                payout_id: "payout_123".to_string(),
+                amount: 1000,
+                currency: storage_enums::Currency::EUR,
+                payout_type: storage_enums::PayoutType::Card,
+                payout_method_data,
+                connector_payout_id: connector_payout_id.map(ToString::to_string),
+                customer_id: Some("cus_123".to_string()),
+                email: Some(Secret::new("john@example.com".to_string())),
+            },
+            response: Ok(types::PayoutsResponseData {
+                connector_payout_id: connector_payout_id.unwrap_or_default().to_string(),
+                status: storage_enums::PayoutStatus::Created,
+            }),
+            payment_method_id: None,
+        }
+    }
+
+    fn get_payout_status(
+        connector_payout_id: Option<&str>,
+        response: AdyenPayoutResponse,
+    ) -> Result<types::PayoutsResponseData, types::ErrorResponse> {
+        types::PayoutsRouterData::try_from(types::PayoutsResponseRouterData {
+            response,
+            data: get_payout_router_data(None, connector_payout_id),
+            http_code: 200,
+        })
+        .unwrap()
+        .response
+    }
+
+    #[test]
+    fn test_payout_create_request_for_card_and_bank() {
+        let card = api::PayoutMethodData::Card(api::PayoutCard {
+            card_number: Secret::new("4111111111111111".to_string()),
+            card_exp_month: Secret::new("03".to_string()),
+            card_exp_year: Secret::new("2030".to_string()),
+            card_holder_name: Secret::new("John Test".to_string()),
+        });
+        let request = serde_json::to_value(
+            AdyenPayoutCreateRequest::try_from(&get_payout_router_data(Some(card), None)).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(request["merchantAccount"], "MerchantAccountECOM");
+        assert_eq!(request["reference"], "payout_123");
+        assert_eq!(request["shopperReference"], "cus_123");
+        assert_eq!(request["recurring"]["contract"], "PAYOUT");
+        assert_eq!(request["amount"]["value"], 1000);
+        assert_eq!(request["amount"]["currency"], "EUR");
+        assert_eq!(request["card"]["number"], "4111111111111111");
+        assert_eq!(request["card"]["holderName"], "John Test");
+
+        let bank = api::PayoutMethodData::Bank(api::PayoutBank {
+            iban: Secret::new("NL46TEST0136169112".to_string()),
+            bic: None,
+            bank_name: Some("ABN AMRO".to_string()),
+            bank_country_code: Some("NL".to_string()),
+            owner_name: Secret::new("John Test".to_string()),
+        });
+        let request = serde_json::to_value(
+            AdyenPayoutCreateRequest::try_from(&get_payout_router_data(Some(bank), None)).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(request["bank"]["iban"], "NL46TEST0136169112");
+        assert_eq!(request["bank"]["countryCode"], "NL");
+        assert!(request.get("card").is_none());
+    }
+
+    #[test]
+    fn test_payout_requests_require_recipient_and_submitted_payout() {
+        assert!(AdyenPayoutCreateRequest::try_from(&get_payout_router_data(None, None)).is_err());
+        assert!(AdyenPayoutModifyRequest::try_from(&get_payout_router_data(None, None)).is_err());
+
+        let request = AdyenPayoutModifyRequest::try_from(&get_payout_router_data(
+            None,
+            Some("8815131768219992"),
+        ))
+        .unwrap();
+        assert_eq!(request.original_reference, "8815131768219992");
+    }
+
+    #[test]
+    fn test_payout_status_transitions() {
+        let response = |result_code: Option<&str>, response: Option<&str>| AdyenPayoutResponse {
+            psp_reference: "9915131768219993".to_string(),
+            result_code: result_code.map(ToString::to_string),
+            response: response.map(ToString::to_string),
+            refusal_reason: None,
+        };
+
+        // Submitting the payout returns the reference by which it is fulfilled or reversed
+        let submitted =
+            get_payout_status(None, response(Some("[payout-submit-received]"), None)).unwrap();
+        assert_eq!(
+            submitted.status,
+            storage_enums::PayoutStatus::RequiresFulfillment
+        );
+        assert_eq!(submitted.connector_payout_id, "9915131768219993");
+
+        // Fulfilling or reversing the payout keeps the reference of the submitted payout
+        let fulfilled = get_payout_status(
+            Some("8815131768219992"),
+            response(None, Some("[payout-confirm-received]")),
+        )
+        .unwrap();
+        assert_eq!(fulfilled.status, storage_enums::PayoutStatus::Pending);
+        assert_eq!(fulfilled.connector_payout_id, "8815131768219992");
+
+        let reversed = get_payout_status(
+            Some("8815131768219992"),
+            response(None, Some("[payout-decline-received]")),
+        )
+        .unwrap();
+        assert_eq!(reversed.status, storage_enums::PayoutStatus::Reversed);
+        assert_eq!(reversed.connector_payout_id, "8815131768219992");
+    }
+
+    #[test]
+    fn test_refused_payout_is_an_error() {
+        let error = get_payout_status(
+            None,
+            AdyenPayoutResponse {
+                psp_reference: "9915131768219993".to_string(),
+                result_code: Some("Refused".to_string()),
+                response: None,
+                refusal_reason: Some("Not enough balance".to_string()),
+            },
+        )
+        .unwrap_err();
+        assert_eq!(error.code, "Refused");
+        assert_eq!(error.message, "Not enough balance");
+    }
+
+    #[test]
+    fn test_payout_notifications() {
+        let notification = |event_code: &str, success: &str, original_reference: Option<&str>| {
+            serde_json::from_value::<AdyenNotificationRequestItemWH>(serde_json::json!({
+                "additionalData": { "hmacSignature": "signature" },
+                "amount": { "value": 1000, "currency": "EUR" },
+                "originalReference": original_reference,
+                "pspReference": "9915131768219993",
+                "eventCode": event_code,
+                "merchantAccountCode": "MerchantAccountECOM",
+                "merchantReference": "payout_123",
+                "success": success,
+            }))
+            .unwrap()
+        };
+
+        for (event_code, success, event) in [
+            (
+                "PAYOUT_THIRDPARTY",
+                "true",
+                IncomingWebhookEvent::PayoutSuccess,
+            ),
+            (
+                "PAYOUT_THIRDPARTY",
+                "false",
+                IncomingWebhookEvent::PayoutFailure,
+            ),
+            ("PAYOUT_EXPIRE", "true", IncomingWebhookEvent::PayoutFailure),
+            (
+                "PAYOUT_DECLINE",
+                "true",
+                IncomingWebhookEvent::PayoutReversed,
+            ),
+            (
+                "PAIDOUT_REVERSED",
+                "true",
+                IncomingWebhookEvent::PayoutReversed,
+            ),
+        ] {
+            assert_eq!(
+                IncomingWebhookEvent::from(notification(event_code, success, None)),
+                event
+            );
+        }
+
+        // The payout is identified by the original reference of the notified modification
+        assert_eq!(
+            notification("PAIDOUT_REVERSED", "true", Some("8815131768219992")).get_reference_id(),
+            "8815131768219992"
+        );
+        assert_eq!(
+            notification("PAYOUT_THIRDPARTY", "true", None).get_reference_id(),
+            "9915131768219993"
+        );
+        // The reference of a refund is not replaced by the reference of its payment
+        assert_eq!(
+            notification("REFUND", "true", Some("8815131768219992")).get_reference_id(),
+            "9915131768219993"
+        );
+    }
+}
+
 #[derive(Debug, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ErrorResponse {
@@ -1168,6 +1677,22 @@ pub enum WebhookEventCode {
     Refund,
     CancelOrRefund,
     RefundFailed,
+    PayoutThirdparty,
+    PayoutDecline,
+    PayoutExpire,
+    PaidoutReversed,
+}
+
+impl WebhookEventCode {
+    pub fn is_payout_event(&self) -> bool {
+        matches!(
+            self,
+            Self::PayoutThirdparty
+                | Self::PayoutDecline
+                | Self::PayoutExpire
+                | Self::PaidoutReversed
+        )
+    }
 }
 
 impl From<WebhookEventCode> for IncomingWebhookEvent {
@@ -1177,6 +1702,31 @@ impl From<WebhookEventCode> for IncomingWebhookEvent {
             WebhookEventCode::Refund => Self::RefundSuccess,
             WebhookEventCode::CancelOrRefund => Self::RefundSuccess,
             WebhookEventCode::RefundFailed => Self::RefundFailure,
+            WebhookEventCode::PayoutThirdparty => Self::PayoutSuccess,
+            WebhookEventCode::PayoutDecline => Self::PayoutReversed,
+            WebhookEventCode::PayoutExpire => Self::PayoutFailure,
+            WebhookEventCode::PaidoutReversed => Self::PayoutReversed,
+        }
+    }
+}
+
+impl From<AdyenNotificationRequestItemWH> for IncomingWebhookEvent {
+    fn from(notif: AdyenNotificationRequestItemWH) -> Self {
+        match (notif.event_code, notif.success.as_str()) {
+            // The outcome of a confirmed payout is notified through the success flag
+            (WebhookEventCode::PayoutThirdparty, "false") => Self::PayoutFailure,
+            (event_code, _) => event_code.into(),
+        }
+    }
+}
+
+impl AdyenNotificationRequestItemWH {
+    /// Notifications of payout modifications carry their own reference, the payout is identified
+    /// by the original one.
+    pub fn get_reference_id(self) -> String {
+        match self.original_reference {
+            Some(original_reference) if self.event_code.is_payout_event() => original_reference,
+            _ => self.psp_reference,
         }
     }
 }
@@ -1224,3 +1774,90 @@ impl From<AdyenNotificationRequestItemWH> for AdyenResponse {
         }
     }
 }
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenDefenseDocument {
+    content: String,
+    content_type: String,
+    defense_document_type_code: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenSupplyDefenseDocumentRequest {
+    defense_documents: Vec<AdyenDefenseDocument>,
+    dispute_psp_reference: String,
+    merchant_account_code: String,
+}
+
+impl TryFrom<&types::UploadFileRouterData> for AdyenSupplyDefenseDocumentRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::UploadFileRouterData) -> Result<Self, Self::Error> {
+        let auth_type = AdyenAuthType::try_from(&item.connector_auth_type)?;
+        Ok(Self {
+            defense_documents: vec![AdyenDefenseDocument {
+                content: consts::BASE64_ENGINE.encode(&item.request.file),
+                content_type: item.request.file_type.to_string(),
+                defense_document_type_code: "DefenseMaterial".to_string(),
+            }],
+            dispute_psp_reference: item.request.connector_dispute_id.clone(),
+            merchant_account_code: auth_type.merchant_account,
+        })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenDisputeServiceResult {
+    pub success: bool,
+    pub error_message: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenSupplyDefenseDocumentResponse {
+    pub dispute_service_result: AdyenDisputeServiceResult,
+}
+
+impl
+    TryFrom<
+        types::ResponseRouterData<
+            api::Upload,
+            AdyenSupplyDefenseDocumentResponse,
+            types::UploadFileRequestData,
+            types::UploadFileResponse,
+        >,
+    > for types::UploadFileRouterData
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<
+            api::Upload,
+            AdyenSupplyDefenseDocumentResponse,
+            types::UploadFileRequestData,
+            types::UploadFileResponse,
+        >,
+    ) -> Result<Self, Self::Error> {
+        let result = item.response.dispute_service_result;
+        let response = if result.success {
+            // Adyen does not assign an identifier to defense documents
+            Ok(types::UploadFileResponse {
+                provider_file_id: item.data.request.file_key.clone(),
+            })
+        } else {
+            Err(types::ErrorResponse {
+                status_code: item.http_code,
+                code: consts::NO_ERROR_CODE.to_string(),
+                message: result
+                    .error_message
+                    .unwrap_or_else(|| consts::NO_ERROR_MESSAGE.to_string()),
+                reason: None,
+            })
+        };
+        Ok(Self {
+            response,
+            ..item.data
+        })
+    }
+}
@@ -645,6 +645,18 @@ impl ConnectorIntegration<api::Void, types::PaymentsCancelData, types::PaymentsR
     }
 }
 
+impl api::PaymentIncrementalAuthorization for Airwallex {}
+
+impl
+    services::ConnectorIntegration<
+        api::IncrementalAuthorization,
+        types::PaymentsIncrementalAuthorizationData,
+        types::PaymentsResponseData,
+    > for Airwallex
+{
+    // Not Implemented (R)
+}
+
 impl api::Refund for Airwallex {}
 impl api::RefundExecute for Airwallex {}
 impl api::RefundSync for Airwallex {}
@@ -799,6 +811,60 @@ impl ConnectorIntegration<api::RSync, types::RefundsData, types::RefundsResponse
     }
 }
 
+impl api::Payout for Airwallex {}
+impl api::PayoutCreate for Airwallex {}
+impl api::PayoutFulfill for Airwallex {}
+impl api::PayoutReverse for Airwallex {}
+
+impl services::ConnectorIntegration<api::PoCreate, types::PayoutsData, types::PayoutsResponseData>
+    for Airwallex
+{
+}
+
+impl services::ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Airwallex
+{
+}
+
+impl services::ConnectorIntegration<api::PoReverse, types::PayoutsData, types::PayoutsResponseData>
+    for Airwallex
+{
+}
+
+impl api::Dispute for Airwallex {}
+impl api::AcceptDispute for Airwallex {}
+impl api::SubmitEvidence for Airwallex {}
+
+impl
+    services::ConnectorIntegration<
+        api::Accept,
+        types::AcceptDisputeRequestData,
+        types::AcceptDisputeResponse,
+    > for Airwallex
+{
+}
+
+impl
+    services::ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::SubmitEvidenceResponse,
+    > for Airwallex
+{
+}
+
+impl api::FileUpload for Airwallex {}
+impl api::UploadFile for Airwallex {}
+
+impl
+    services::ConnectorIntegration<
+        api::Upload,
+        types::UploadFileRequestData,
+        types::UploadFileResponse,
+    > for Airwallex
+{
+}
+
 #[async_trait::async_trait]
 impl api::IncomingWebhook for Airwallex {
     fn get_webhook_source_verification_algorithm(
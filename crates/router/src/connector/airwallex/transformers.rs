@@ -136,6 +136,7 @@ impl<F, T> TryFrom<types::ResponseRouterData<F, AirwallexAuthUpdateResponse, T,
             response: Ok(types::AccessToken {
                 token: item.response.token,
                 expires,
+                lifetime: None,
             }),
             ..item.data
         })
@@ -222,6 +222,18 @@ impl
     }
 }
 
+impl api::PaymentIncrementalAuthorization for Applepay {}
+
+impl
+    services::ConnectorIntegration<
+        api::IncrementalAuthorization,
+        types::PaymentsIncrementalAuthorizationData,
+        types::PaymentsResponseData,
+    > for Applepay
+{
+    // Not Implemented (R)
+}
+
 impl api::Refund for Applepay {}
 impl api::RefundExecute for Applepay {}
 impl api::RefundSync for Applepay {}
@@ -238,6 +250,60 @@ impl services::ConnectorIntegration<api::RSync, types::RefundsData, types::Refun
 
 impl services::ConnectorRedirectResponse for Applepay {}
 
+impl api::Payout for Applepay {}
+impl api::PayoutCreate for Applepay {}
+impl api::PayoutFulfill for Applepay {}
+impl api::PayoutReverse for Applepay {}
+
+impl services::ConnectorIntegration<api::PoCreate, types::PayoutsData, types::PayoutsResponseData>
+    for Applepay
+{
+}
+
+impl services::ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Applepay
+{
+}
+
+impl services::ConnectorIntegration<api::PoReverse, types::PayoutsData, types::PayoutsResponseData>
+    for Applepay
+{
+}
+
+impl api::Dispute for Applepay {}
+impl api::AcceptDispute for Applepay {}
+impl api::SubmitEvidence for Applepay {}
+
+impl
+    services::ConnectorIntegration<
+        api::Accept,
+        types::AcceptDisputeRequestData,
+        types::AcceptDisputeResponse,
+    > for Applepay
+{
+}
+
+impl
+    services::ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::SubmitEvidenceResponse,
+    > for Applepay
+{
+}
+
+impl api::FileUpload for Applepay {}
+impl api::UploadFile for Applepay {}
+
+impl
+    services::ConnectorIntegration<
+        api::Upload,
+        types::UploadFileRequestData,
+        types::UploadFileResponse,
+    > for Applepay
+{
+}
+
 #[async_trait::async_trait]
 impl api::IncomingWebhook for Applepay {
     fn get_webhook_object_reference_id(
@@ -440,6 +440,18 @@ impl ConnectorIntegration<api::Authorize, types::PaymentsAuthorizeData, types::P
     }
 }
 
+impl api::PaymentIncrementalAuthorization for Bambora {}
+
+impl
+    services::ConnectorIntegration<
+        api::IncrementalAuthorization,
+        types::PaymentsIncrementalAuthorizationData,
+        types::PaymentsResponseData,
+    > for Bambora
+{
+    // Not Implemented (R)
+}
+
 impl api::Refund for Bambora {}
 impl api::RefundExecute for Bambora {}
 impl api::RefundSync for Bambora {}
@@ -592,6 +604,60 @@ impl ConnectorIntegration<api::RSync, types::RefundsData, types::RefundsResponse
     }
 }
 
+impl api::Payout for Bambora {}
+impl api::PayoutCreate for Bambora {}
+impl api::PayoutFulfill for Bambora {}
+impl api::PayoutReverse for Bambora {}
+
+impl services::ConnectorIntegration<api::PoCreate, types::PayoutsData, types::PayoutsResponseData>
+    for Bambora
+{
+}
+
+impl services::ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Bambora
+{
+}
+
+impl services::ConnectorIntegration<api::PoReverse, types::PayoutsData, types::PayoutsResponseData>
+    for Bambora
+{
+}
+
+impl api::Dispute for Bambora {}
+impl api::AcceptDispute for Bambora {}
+impl api::SubmitEvidence for Bambora {}
+
+impl
+    services::ConnectorIntegration<
+        api::Accept,
+        types::AcceptDisputeRequestData,
+        types::AcceptDisputeResponse,
+    > for Bambora
+{
+}
+
+impl
+    services::ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::SubmitEvidenceResponse,
+    > for Bambora
+{
+}
+
+impl api::FileUpload for Bambora {}
+impl api::UploadFile for Bambora {}
+
+impl
+    services::ConnectorIntegration<
+        api::Upload,
+        types::UploadFileRequestData,
+        types::UploadFileResponse,
+    > for Bambora
+{
+}
+
 #[async_trait::async_trait]
 impl api::IncomingWebhook for Bambora {
     fn get_webhook_object_reference_id(
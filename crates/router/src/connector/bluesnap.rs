@@ -470,6 +470,18 @@ impl ConnectorIntegration<api::Authorize, types::PaymentsAuthorizeData, types::P
     }
 }
 
+impl api::PaymentIncrementalAuthorization for Bluesnap {}
+
+impl
+    services::ConnectorIntegration<
+        api::IncrementalAuthorization,
+        types::PaymentsIncrementalAuthorizationData,
+        types::PaymentsResponseData,
+    > for Bluesnap
+{
+    // Not Implemented (R)
+}
+
 impl api::Refund for Bluesnap {}
 impl api::RefundExecute for Bluesnap {}
 impl api::RefundSync for Bluesnap {}
@@ -623,6 +635,60 @@ impl ConnectorIntegration<api::RSync, types::RefundsData, types::RefundsResponse
     }
 }
 
+impl api::Payout for Bluesnap {}
+impl api::PayoutCreate for Bluesnap {}
+impl api::PayoutFulfill for Bluesnap {}
+impl api::PayoutReverse for Bluesnap {}
+
+impl services::ConnectorIntegration<api::PoCreate, types::PayoutsData, types::PayoutsResponseData>
+    for Bluesnap
+{
+}
+
+impl services::ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Bluesnap
+{
+}
+
+impl services::ConnectorIntegration<api::PoReverse, types::PayoutsData, types::PayoutsResponseData>
+    for Bluesnap
+{
+}
+
+impl api::Dispute for Bluesnap {}
+impl api::AcceptDispute for Bluesnap {}
+impl api::SubmitEvidence for Bluesnap {}
+
+impl
+    services::ConnectorIntegration<
+        api::Accept,
+        types::AcceptDisputeRequestData,
+        types::AcceptDisputeResponse,
+    > for Bluesnap
+{
+}
+
+impl
+    services::ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::SubmitEvidenceResponse,
+    > for Bluesnap
+{
+}
+
+impl api::FileUpload for Bluesnap {}
+impl api::UploadFile for Bluesnap {}
+
+impl
+    services::ConnectorIntegration<
+        api::Upload,
+        types::UploadFileRequestData,
+        types::UploadFileResponse,
+    > for Bluesnap
+{
+}
+
 #[async_trait::async_trait]
 impl api::IncomingWebhook for Bluesnap {
     fn get_webhook_source_verification_algorithm(
@@ -507,6 +507,18 @@ impl
     }
 }
 
+impl api::PaymentIncrementalAuthorization for Braintree {}
+
+impl
+    services::ConnectorIntegration<
+        api::IncrementalAuthorization,
+        types::PaymentsIncrementalAuthorizationData,
+        types::PaymentsResponseData,
+    > for Braintree
+{
+    // Not Implemented (R)
+}
+
 impl api::Refund for Braintree {}
 impl api::RefundExecute for Braintree {}
 impl api::RefundSync for Braintree {}
@@ -673,6 +685,60 @@ impl services::ConnectorIntegration<api::RSync, types::RefundsData, types::Refun
     }
 }
 
+impl api::Payout for Braintree {}
+impl api::PayoutCreate for Braintree {}
+impl api::PayoutFulfill for Braintree {}
+impl api::PayoutReverse for Braintree {}
+
+impl services::ConnectorIntegration<api::PoCreate, types::PayoutsData, types::PayoutsResponseData>
+    for Braintree
+{
+}
+
+impl services::ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Braintree
+{
+}
+
+impl services::ConnectorIntegration<api::PoReverse, types::PayoutsData, types::PayoutsResponseData>
+    for Braintree
+{
+}
+
+impl api::Dispute for Braintree {}
+impl api::AcceptDispute for Braintree {}
+impl api::SubmitEvidence for Braintree {}
+
+impl
+    services::ConnectorIntegration<
+        api::Accept,
+        types::AcceptDisputeRequestData,
+        types::AcceptDisputeResponse,
+    > for Braintree
+{
+}
+
+impl
+    services::ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::SubmitEvidenceResponse,
+    > for Braintree
+{
+}
+
+impl api::FileUpload for Braintree {}
+impl api::UploadFile for Braintree {}
+
+impl
+    services::ConnectorIntegration<
+        api::Upload,
+        types::UploadFileRequestData,
+        types::UploadFileResponse,
+    > for Braintree
+{
+}
+
 #[async_trait::async_trait]
 impl api::IncomingWebhook for Braintree {
     fn get_webhook_object_reference_id(
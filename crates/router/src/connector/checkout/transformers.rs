@@ -2,6 +2,7 @@ use serde::{Deserialize, Serialize};
 use url::Url;
 
 use crate::{
+    consts,
     core::errors,
     pii, services,
     types::{self, api, storage::enums, transformers::ForeignFrom},
@@ -44,6 +45,14 @@ pub struct PaymentsRequest {
     #[serde(flatten)]
     pub return_url: ReturnUrl,
     pub capture: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authorization_type: Option<CheckoutAuthorizationType>,
+}
+
+#[derive(Debug, Serialize)]
+pub enum CheckoutAuthorizationType {
+    Final,
+    Estimated,
 }
 
 #[derive(Debug, Serialize)]
@@ -101,6 +110,9 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for PaymentsRequest {
             item.request.capture_method,
             Some(enums::CaptureMethod::Automatic)
         );
+        // Only an estimated authorization can be incremented later on
+        let authorization_type = (item.request.request_incremental_authorization && !capture)
+            .then_some(CheckoutAuthorizationType::Estimated);
 
         let source_var = Source::Card(CardSource {
             source_type: Some("card".to_owned()),
@@ -119,6 +131,7 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for PaymentsRequest {
             three_ds,
             return_url,
             capture,
+            authorization_type,
         })
     }
 }
@@ -320,6 +333,8 @@ pub struct PaymentCaptureRequest {
     pub amount: Option<i64>,
     pub capture_type: Option<CaptureType>,
     pub processing_channel_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reference: Option<String>,
 }
 
 impl TryFrom<&types::PaymentsCaptureRouterData> for PaymentCaptureRequest {
@@ -328,10 +343,23 @@ impl TryFrom<&types::PaymentsCaptureRouterData> for PaymentCaptureRequest {
         let connector_auth = &item.connector_auth_type;
         let auth_type: CheckoutAuthType = connector_auth.try_into()?;
         let processing_channel_id = auth_type.processing_channel_id;
+        // Non final captures leave the rest of the authorized amount available for capture
+        let (capture_type, reference) = match &item.request.multiple_capture_data {
+            Some(multiple_capture_data) => (
+                if multiple_capture_data.final_capture {
+                    CaptureType::Final
+                } else {
+                    CaptureType::NonFinal
+                },
+                Some(multiple_capture_data.capture_reference.clone()),
+            ),
+            None => (CaptureType::Final, None),
+        };
         Ok(Self {
             amount: item.request.amount_to_capture,
-            capture_type: Some(CaptureType::Final),
+            capture_type: Some(capture_type),
             processing_channel_id,
+            reference,
         })
     }
 }
@@ -356,11 +384,15 @@ impl TryFrom<types::PaymentsCaptureResponseRouterData<PaymentCaptureResponse>>
         } else {
             (enums::AttemptStatus::Pending, None)
         };
+        // The action identifies the capture among the captures made on the payment
+        let resource_id = if item.data.request.multiple_capture_data.is_some() {
+            item.response.action_id
+        } else {
+            item.data.request.connector_transaction_id.to_owned()
+        };
         Ok(Self {
             response: Ok(types::PaymentsResponseData::TransactionResponse {
-                resource_id: types::ResponseId::ConnectorTransactionId(
-                    item.data.request.connector_transaction_id.to_owned(),
-                ),
+                resource_id: types::ResponseId::ConnectorTransactionId(resource_id),
                 redirection_data: None,
                 mandate_reference: None,
                 connector_metadata: None,
@@ -372,6 +404,78 @@ impl TryFrom<types::PaymentsCaptureResponseRouterData<PaymentCaptureResponse>>
     }
 }
 
+#[derive(Debug, Serialize)]
+pub struct IncrementalAuthorizationRequest {
+    pub amount: i64,
+    pub reference: String,
+}
+
+impl TryFrom<&types::PaymentsIncrementalAuthorizationRouterData>
+    for IncrementalAuthorizationRequest
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: &types::PaymentsIncrementalAuthorizationRouterData,
+    ) -> Result<Self, Self::Error> {
+        // Checkout expects the amount by which the authorization is incremented, and not the
+        // total amount to be authorized
+        Ok(Self {
+            amount: item.request.additional_amount,
+            reference: item.payment_id.clone(),
+        })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct IncrementalAuthorizationResponse {
+    pub action_id: String,
+    pub approved: Option<bool>,
+    pub response_code: Option<String>,
+    pub response_summary: Option<String>,
+}
+
+impl
+    TryFrom<
+        types::PaymentsIncrementalAuthorizationResponseRouterData<IncrementalAuthorizationResponse>,
+    > for types::PaymentsIncrementalAuthorizationRouterData
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::PaymentsIncrementalAuthorizationResponseRouterData<
+            IncrementalAuthorizationResponse,
+        >,
+    ) -> Result<Self, Self::Error> {
+        // A declined increment leaves the payment authorized for the amount authorized earlier
+        let response = match item.response.approved {
+            Some(true) => Ok(types::PaymentsResponseData::TransactionResponse {
+                resource_id: types::ResponseId::ConnectorTransactionId(
+                    item.data.request.connector_transaction_id.clone(),
+                ),
+                redirection_data: None,
+                mandate_reference: None,
+                connector_metadata: None,
+            }),
+            Some(false) | None => Err(types::ErrorResponse {
+                code: item
+                    .response
+                    .response_code
+                    .unwrap_or_else(|| consts::NO_ERROR_CODE.to_string()),
+                message: item
+                    .response
+                    .response_summary
+                    .unwrap_or_else(|| consts::NO_ERROR_MESSAGE.to_string()),
+                reason: None,
+                status_code: item.http_code,
+            }),
+        };
+        Ok(Self {
+            status: enums::AttemptStatus::Authorized,
+            response,
+            ..item.data
+        })
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct RefundRequest {
     amount: Option<i64>,
@@ -448,6 +552,12 @@ impl TryFrom<types::RefundsResponseRouterData<api::RSync, CheckoutRefundResponse
     }
 }
 
+#[derive(Debug, Deserialize)]
+pub struct FileUploadResponse {
+    #[serde(rename = "id")]
+    pub file_id: String,
+}
+
 #[derive(Debug, Default, Eq, PartialEq, Deserialize)]
 pub struct ErrorResponse {
     pub request_id: Option<String>,
@@ -533,6 +533,18 @@ impl ConnectorIntegration<api::Void, types::PaymentsCancelData, types::PaymentsR
     }
 }
 
+impl api::PaymentIncrementalAuthorization for Cybersource {}
+
+impl
+    services::ConnectorIntegration<
+        api::IncrementalAuthorization,
+        types::PaymentsIncrementalAuthorizationData,
+        types::PaymentsResponseData,
+    > for Cybersource
+{
+    // Not Implemented (R)
+}
+
 impl api::Refund for Cybersource {}
 impl api::RefundExecute for Cybersource {}
 impl api::RefundSync for Cybersource {}
@@ -686,6 +698,60 @@ impl ConnectorIntegration<api::RSync, types::RefundsData, types::RefundsResponse
     }
 }
 
+impl api::Payout for Cybersource {}
+impl api::PayoutCreate for Cybersource {}
+impl api::PayoutFulfill for Cybersource {}
+impl api::PayoutReverse for Cybersource {}
+
+impl services::ConnectorIntegration<api::PoCreate, types::PayoutsData, types::PayoutsResponseData>
+    for Cybersource
+{
+}
+
+impl services::ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Cybersource
+{
+}
+
+impl services::ConnectorIntegration<api::PoReverse, types::PayoutsData, types::PayoutsResponseData>
+    for Cybersource
+{
+}
+
+impl api::Dispute for Cybersource {}
+impl api::AcceptDispute for Cybersource {}
+impl api::SubmitEvidence for Cybersource {}
+
+impl
+    services::ConnectorIntegration<
+        api::Accept,
+        types::AcceptDisputeRequestData,
+        types::AcceptDisputeResponse,
+    > for Cybersource
+{
+}
+
+impl
+    services::ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::SubmitEvidenceResponse,
+    > for Cybersource
+{
+}
+
+impl api::FileUpload for Cybersource {}
+impl api::UploadFile for Cybersource {}
+
+impl
+    services::ConnectorIntegration<
+        api::Upload,
+        types::UploadFileRequestData,
+        types::UploadFileResponse,
+    > for Cybersource
+{
+}
+
 #[async_trait::async_trait]
 impl api::IncomingWebhook for Cybersource {
     fn get_webhook_object_reference_id(
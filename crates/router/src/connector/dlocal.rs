@@ -37,6 +37,18 @@ impl api::PaymentAuthorize for Dlocal {}
 impl api::PaymentSync for Dlocal {}
 impl api::PaymentCapture for Dlocal {}
 impl api::PaymentVoid for Dlocal {}
+impl api::PaymentIncrementalAuthorization for Dlocal {}
+
+impl
+    services::ConnectorIntegration<
+        api::IncrementalAuthorization,
+        types::PaymentsIncrementalAuthorizationData,
+        types::PaymentsResponseData,
+    > for Dlocal
+{
+    // Not Implemented (R)
+}
+
 impl api::Refund for Dlocal {}
 impl api::RefundExecute for Dlocal {}
 impl api::RefundSync for Dlocal {}
@@ -563,6 +575,60 @@ impl ConnectorIntegration<api::RSync, types::RefundsData, types::RefundsResponse
     }
 }
 
+impl api::Payout for Dlocal {}
+impl api::PayoutCreate for Dlocal {}
+impl api::PayoutFulfill for Dlocal {}
+impl api::PayoutReverse for Dlocal {}
+
+impl services::ConnectorIntegration<api::PoCreate, types::PayoutsData, types::PayoutsResponseData>
+    for Dlocal
+{
+}
+
+impl services::ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Dlocal
+{
+}
+
+impl services::ConnectorIntegration<api::PoReverse, types::PayoutsData, types::PayoutsResponseData>
+    for Dlocal
+{
+}
+
+impl api::Dispute for Dlocal {}
+impl api::AcceptDispute for Dlocal {}
+impl api::SubmitEvidence for Dlocal {}
+
+impl
+    services::ConnectorIntegration<
+        api::Accept,
+        types::AcceptDisputeRequestData,
+        types::AcceptDisputeResponse,
+    > for Dlocal
+{
+}
+
+impl
+    services::ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::SubmitEvidenceResponse,
+    > for Dlocal
+{
+}
+
+impl api::FileUpload for Dlocal {}
+impl api::UploadFile for Dlocal {}
+
+impl
+    services::ConnectorIntegration<
+        api::Upload,
+        types::UploadFileRequestData,
+        types::UploadFileResponse,
+    > for Dlocal
+{
+}
+
 #[async_trait::async_trait]
 impl api::IncomingWebhook for Dlocal {
     fn get_webhook_object_reference_id(
@@ -505,6 +505,18 @@ impl ConnectorIntegration<api::Authorize, types::PaymentsAuthorizeData, types::P
     }
 }
 
+impl api::PaymentIncrementalAuthorization for Fiserv {}
+
+impl
+    services::ConnectorIntegration<
+        api::IncrementalAuthorization,
+        types::PaymentsIncrementalAuthorizationData,
+        types::PaymentsResponseData,
+    > for Fiserv
+{
+    // Not Implemented (R)
+}
+
 impl api::Refund for Fiserv {}
 impl api::RefundExecute for Fiserv {}
 impl api::RefundSync for Fiserv {}
@@ -663,6 +675,60 @@ impl ConnectorIntegration<api::RSync, types::RefundsData, types::RefundsResponse
     }
 }
 
+impl api::Payout for Fiserv {}
+impl api::PayoutCreate for Fiserv {}
+impl api::PayoutFulfill for Fiserv {}
+impl api::PayoutReverse for Fiserv {}
+
+impl services::ConnectorIntegration<api::PoCreate, types::PayoutsData, types::PayoutsResponseData>
+    for Fiserv
+{
+}
+
+impl services::ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Fiserv
+{
+}
+
+impl services::ConnectorIntegration<api::PoReverse, types::PayoutsData, types::PayoutsResponseData>
+    for Fiserv
+{
+}
+
+impl api::Dispute for Fiserv {}
+impl api::AcceptDispute for Fiserv {}
+impl api::SubmitEvidence for Fiserv {}
+
+impl
+    services::ConnectorIntegration<
+        api::Accept,
+        types::AcceptDisputeRequestData,
+        types::AcceptDisputeResponse,
+    > for Fiserv
+{
+}
+
+impl
+    services::ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::SubmitEvidenceResponse,
+    > for Fiserv
+{
+}
+
+impl api::FileUpload for Fiserv {}
+impl api::UploadFile for Fiserv {}
+
+impl
+    services::ConnectorIntegration<
+        api::Upload,
+        types::UploadFileRequestData,
+        types::UploadFileResponse,
+    > for Fiserv
+{
+}
+
 #[async_trait::async_trait]
 impl api::IncomingWebhook for Fiserv {
     fn get_webhook_object_reference_id(
@@ -521,6 +521,18 @@ impl ConnectorIntegration<api::Authorize, types::PaymentsAuthorizeData, types::P
     }
 }
 
+impl api::PaymentIncrementalAuthorization for Globalpay {}
+
+impl
+    services::ConnectorIntegration<
+        api::IncrementalAuthorization,
+        types::PaymentsIncrementalAuthorizationData,
+        types::PaymentsResponseData,
+    > for Globalpay
+{
+    // Not Implemented (R)
+}
+
 impl api::Refund for Globalpay {}
 impl api::RefundExecute for Globalpay {}
 impl api::RefundSync for Globalpay {}
@@ -681,6 +693,60 @@ impl ConnectorIntegration<api::RSync, types::RefundsData, types::RefundsResponse
     }
 }
 
+impl api::Payout for Globalpay {}
+impl api::PayoutCreate for Globalpay {}
+impl api::PayoutFulfill for Globalpay {}
+impl api::PayoutReverse for Globalpay {}
+
+impl services::ConnectorIntegration<api::PoCreate, types::PayoutsData, types::PayoutsResponseData>
+    for Globalpay
+{
+}
+
+impl services::ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Globalpay
+{
+}
+
+impl services::ConnectorIntegration<api::PoReverse, types::PayoutsData, types::PayoutsResponseData>
+    for Globalpay
+{
+}
+
+impl api::Dispute for Globalpay {}
+impl api::AcceptDispute for Globalpay {}
+impl api::SubmitEvidence for Globalpay {}
+
+impl
+    services::ConnectorIntegration<
+        api::Accept,
+        types::AcceptDisputeRequestData,
+        types::AcceptDisputeResponse,
+    > for Globalpay
+{
+}
+
+impl
+    services::ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::SubmitEvidenceResponse,
+    > for Globalpay
+{
+}
+
+impl api::FileUpload for Globalpay {}
+impl api::UploadFile for Globalpay {}
+
+impl
+    services::ConnectorIntegration<
+        api::Upload,
+        types::UploadFileRequestData,
+        types::UploadFileResponse,
+    > for Globalpay
+{
+}
+
 #[async_trait::async_trait]
 impl api::IncomingWebhook for Globalpay {
     fn get_webhook_object_reference_id(
@@ -95,6 +95,7 @@ impl TryFrom<GlobalpayRefreshTokenResponse> for types::AccessToken {
         Ok(Self {
             token: item.token,
             expires: item.seconds_to_expire,
+            lifetime: None,
         })
     }
 }
@@ -203,6 +204,7 @@ impl<F, T>
             response: Ok(types::AccessToken {
                 token: item.response.token,
                 expires: item.response.seconds_to_expire,
+                lifetime: None,
             }),
             ..item.data
         })
@@ -340,6 +340,18 @@ impl
 {
 }
 
+impl api::PaymentIncrementalAuthorization for Klarna {}
+
+impl
+    services::ConnectorIntegration<
+        api::IncrementalAuthorization,
+        types::PaymentsIncrementalAuthorizationData,
+        types::PaymentsResponseData,
+    > for Klarna
+{
+    // Not Implemented (R)
+}
+
 impl api::Refund for Klarna {}
 impl api::RefundExecute for Klarna {}
 impl api::RefundSync for Klarna {}
@@ -354,6 +366,60 @@ impl services::ConnectorIntegration<api::RSync, types::RefundsData, types::Refun
 {
 }
 
+impl api::Payout for Klarna {}
+impl api::PayoutCreate for Klarna {}
+impl api::PayoutFulfill for Klarna {}
+impl api::PayoutReverse for Klarna {}
+
+impl services::ConnectorIntegration<api::PoCreate, types::PayoutsData, types::PayoutsResponseData>
+    for Klarna
+{
+}
+
+impl services::ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Klarna
+{
+}
+
+impl services::ConnectorIntegration<api::PoReverse, types::PayoutsData, types::PayoutsResponseData>
+    for Klarna
+{
+}
+
+impl api::Dispute for Klarna {}
+impl api::AcceptDispute for Klarna {}
+impl api::SubmitEvidence for Klarna {}
+
+impl
+    services::ConnectorIntegration<
+        api::Accept,
+        types::AcceptDisputeRequestData,
+        types::AcceptDisputeResponse,
+    > for Klarna
+{
+}
+
+impl
+    services::ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::SubmitEvidenceResponse,
+    > for Klarna
+{
+}
+
+impl api::FileUpload for Klarna {}
+impl api::UploadFile for Klarna {}
+
+impl
+    services::ConnectorIntegration<
+        api::Upload,
+        types::UploadFileRequestData,
+        types::UploadFileResponse,
+    > for Klarna
+{
+}
+
 #[async_trait::async_trait]
 impl api::IncomingWebhook for Klarna {
     fn get_webhook_object_reference_id(
@@ -268,6 +268,18 @@ impl ConnectorIntegration<api::Authorize, types::PaymentsAuthorizeData, types::P
     }
 }
 
+impl api::PaymentIncrementalAuthorization for Multisafepay {}
+
+impl
+    services::ConnectorIntegration<
+        api::IncrementalAuthorization,
+        types::PaymentsIncrementalAuthorizationData,
+        types::PaymentsResponseData,
+    > for Multisafepay
+{
+    // Not Implemented (R)
+}
+
 impl api::Refund for Multisafepay {}
 impl api::RefundExecute for Multisafepay {}
 impl api::RefundSync for Multisafepay {}
@@ -425,6 +437,60 @@ impl ConnectorIntegration<api::RSync, types::RefundsData, types::RefundsResponse
     }
 }
 
+impl api::Payout for Multisafepay {}
+impl api::PayoutCreate for Multisafepay {}
+impl api::PayoutFulfill for Multisafepay {}
+impl api::PayoutReverse for Multisafepay {}
+
+impl services::ConnectorIntegration<api::PoCreate, types::PayoutsData, types::PayoutsResponseData>
+    for Multisafepay
+{
+}
+
+impl services::ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Multisafepay
+{
+}
+
+impl services::ConnectorIntegration<api::PoReverse, types::PayoutsData, types::PayoutsResponseData>
+    for Multisafepay
+{
+}
+
+impl api::Dispute for Multisafepay {}
+impl api::AcceptDispute for Multisafepay {}
+impl api::SubmitEvidence for Multisafepay {}
+
+impl
+    services::ConnectorIntegration<
+        api::Accept,
+        types::AcceptDisputeRequestData,
+        types::AcceptDisputeResponse,
+    > for Multisafepay
+{
+}
+
+impl
+    services::ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::SubmitEvidenceResponse,
+    > for Multisafepay
+{
+}
+
+impl api::FileUpload for Multisafepay {}
+impl api::UploadFile for Multisafepay {}
+
+impl
+    services::ConnectorIntegration<
+        api::Upload,
+        types::UploadFileRequestData,
+        types::UploadFileResponse,
+    > for Multisafepay
+{
+}
+
 #[async_trait::async_trait]
 impl api::IncomingWebhook for Multisafepay {
     fn get_webhook_object_reference_id(
@@ -552,6 +552,18 @@ impl
     }
 }
 
+impl api::PaymentIncrementalAuthorization for Nuvei {}
+
+impl
+    services::ConnectorIntegration<
+        api::IncrementalAuthorization,
+        types::PaymentsIncrementalAuthorizationData,
+        types::PaymentsResponseData,
+    > for Nuvei
+{
+    // Not Implemented (R)
+}
+
 impl api::Refund for Nuvei {}
 impl api::RefundExecute for Nuvei {}
 impl api::RefundSync for Nuvei {}
@@ -636,6 +648,60 @@ impl ConnectorIntegration<api::Execute, types::RefundsData, types::RefundsRespon
 
 impl ConnectorIntegration<api::RSync, types::RefundsData, types::RefundsResponseData> for Nuvei {}
 
+impl api::Payout for Nuvei {}
+impl api::PayoutCreate for Nuvei {}
+impl api::PayoutFulfill for Nuvei {}
+impl api::PayoutReverse for Nuvei {}
+
+impl services::ConnectorIntegration<api::PoCreate, types::PayoutsData, types::PayoutsResponseData>
+    for Nuvei
+{
+}
+
+impl services::ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Nuvei
+{
+}
+
+impl services::ConnectorIntegration<api::PoReverse, types::PayoutsData, types::PayoutsResponseData>
+    for Nuvei
+{
+}
+
+impl api::Dispute for Nuvei {}
+impl api::AcceptDispute for Nuvei {}
+impl api::SubmitEvidence for Nuvei {}
+
+impl
+    services::ConnectorIntegration<
+        api::Accept,
+        types::AcceptDisputeRequestData,
+        types::AcceptDisputeResponse,
+    > for Nuvei
+{
+}
+
+impl
+    services::ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::SubmitEvidenceResponse,
+    > for Nuvei
+{
+}
+
+impl api::FileUpload for Nuvei {}
+impl api::UploadFile for Nuvei {}
+
+impl
+    services::ConnectorIntegration<
+        api::Upload,
+        types::UploadFileRequestData,
+        types::UploadFileResponse,
+    > for Nuvei
+{
+}
+
 #[async_trait::async_trait]
 impl api::IncomingWebhook for Nuvei {
     fn get_webhook_object_reference_id(
@@ -291,6 +291,7 @@ impl<F, T> TryFrom<types::ResponseRouterData<F, PayuAuthUpdateResponse, T, types
             response: Ok(types::AccessToken {
                 token: item.response.access_token,
                 expires: item.response.expires_in,
+                lifetime: None,
             }),
             ..item.data
         })
@@ -519,6 +519,18 @@ impl
     //TODO: implement sessions flow
 }
 
+impl api::PaymentIncrementalAuthorization for Rapyd {}
+
+impl
+    services::ConnectorIntegration<
+        api::IncrementalAuthorization,
+        types::PaymentsIncrementalAuthorizationData,
+        types::PaymentsResponseData,
+    > for Rapyd
+{
+    // Not Implemented (R)
+}
+
 impl api::Refund for Rapyd {}
 impl api::RefundExecute for Rapyd {}
 impl api::RefundSync for Rapyd {}
@@ -662,6 +674,60 @@ impl services::ConnectorIntegration<api::RSync, types::RefundsData, types::Refun
     }
 }
 
+impl api::Payout for Rapyd {}
+impl api::PayoutCreate for Rapyd {}
+impl api::PayoutFulfill for Rapyd {}
+impl api::PayoutReverse for Rapyd {}
+
+impl services::ConnectorIntegration<api::PoCreate, types::PayoutsData, types::PayoutsResponseData>
+    for Rapyd
+{
+}
+
+impl services::ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Rapyd
+{
+}
+
+impl services::ConnectorIntegration<api::PoReverse, types::PayoutsData, types::PayoutsResponseData>
+    for Rapyd
+{
+}
+
+impl api::Dispute for Rapyd {}
+impl api::AcceptDispute for Rapyd {}
+impl api::SubmitEvidence for Rapyd {}
+
+impl
+    services::ConnectorIntegration<
+        api::Accept,
+        types::AcceptDisputeRequestData,
+        types::AcceptDisputeResponse,
+    > for Rapyd
+{
+}
+
+impl
+    services::ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::SubmitEvidenceResponse,
+    > for Rapyd
+{
+}
+
+impl api::FileUpload for Rapyd {}
+impl api::UploadFile for Rapyd {}
+
+impl
+    services::ConnectorIntegration<
+        api::Upload,
+        types::UploadFileRequestData,
+        types::UploadFileResponse,
+    > for Rapyd
+{
+}
+
 #[async_trait::async_trait]
 impl api::IncomingWebhook for Rapyd {
     fn get_webhook_source_verification_algorithm(
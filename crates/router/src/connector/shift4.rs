@@ -347,6 +347,18 @@ impl ConnectorIntegration<api::Authorize, types::PaymentsAuthorizeData, types::P
     }
 }
 
+impl api::PaymentIncrementalAuthorization for Shift4 {}
+
+impl
+    services::ConnectorIntegration<
+        api::IncrementalAuthorization,
+        types::PaymentsIncrementalAuthorizationData,
+        types::PaymentsResponseData,
+    > for Shift4
+{
+    // Not Implemented (R)
+}
+
 impl api::Refund for Shift4 {}
 impl api::RefundExecute for Shift4 {}
 impl api::RefundSync for Shift4 {}
@@ -490,6 +502,60 @@ impl ConnectorIntegration<api::RSync, types::RefundsData, types::RefundsResponse
     }
 }
 
+impl api::Payout for Shift4 {}
+impl api::PayoutCreate for Shift4 {}
+impl api::PayoutFulfill for Shift4 {}
+impl api::PayoutReverse for Shift4 {}
+
+impl services::ConnectorIntegration<api::PoCreate, types::PayoutsData, types::PayoutsResponseData>
+    for Shift4
+{
+}
+
+impl services::ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Shift4
+{
+}
+
+impl services::ConnectorIntegration<api::PoReverse, types::PayoutsData, types::PayoutsResponseData>
+    for Shift4
+{
+}
+
+impl api::Dispute for Shift4 {}
+impl api::AcceptDispute for Shift4 {}
+impl api::SubmitEvidence for Shift4 {}
+
+impl
+    services::ConnectorIntegration<
+        api::Accept,
+        types::AcceptDisputeRequestData,
+        types::AcceptDisputeResponse,
+    > for Shift4
+{
+}
+
+impl
+    services::ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::SubmitEvidenceResponse,
+    > for Shift4
+{
+}
+
+impl api::FileUpload for Shift4 {}
+impl api::UploadFile for Shift4 {}
+
+impl
+    services::ConnectorIntegration<
+        api::Upload,
+        types::UploadFileRequestData,
+        types::UploadFileResponse,
+    > for Shift4
+{
+}
+
 #[async_trait::async_trait]
 impl api::IncomingWebhook for Shift4 {
     fn get_webhook_object_reference_id(
@@ -55,6 +55,18 @@ impl From<Option<enums::CaptureMethod>> for StripeCaptureMethod {
     }
 }
 
+#[derive(Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StripeRequestMulticapture {
+    IfAvailable,
+}
+
+#[derive(Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StripeRequestIncrementalAuthorization {
+    IfAvailable,
+}
+
 #[derive(Debug, Default, Eq, PartialEq, Serialize)]
 #[serde(rename_all = "lowercase")]
 pub enum Auth3ds {
@@ -86,6 +98,10 @@ pub struct PaymentIntentRequest {
     #[serde(flatten)]
     pub payment_data: Option<StripePaymentMethodData>,
     pub capture_method: StripeCaptureMethod,
+    #[serde(rename = "payment_method_options[card][request_multicapture]")]
+    pub request_multicapture: Option<StripeRequestMulticapture>,
+    #[serde(rename = "payment_method_options[card][request_incremental_authorization]")]
+    pub request_incremental_authorization: Option<StripeRequestIncrementalAuthorization>,
 }
 
 #[derive(Debug, Eq, PartialEq, Serialize)]
@@ -619,6 +635,31 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for PaymentIntentRequest {
             }
         };
 
+        // Multiple captures are supported only on card payments
+        let request_multicapture = match (
+            item.request.capture_method,
+            &item.request.payment_method_data,
+        ) {
+            (Some(enums::CaptureMethod::ManualMultiple), api::PaymentMethodData::Card(_)) => {
+                Some(StripeRequestMulticapture::IfAvailable)
+            }
+            _ => None,
+        };
+
+        // Incremental authorization is supported only on card payments which are captured manually
+        let request_incremental_authorization = match (
+            item.request.request_incremental_authorization,
+            item.request.capture_method,
+            &item.request.payment_method_data,
+        ) {
+            (
+                true,
+                Some(enums::CaptureMethod::Manual | enums::CaptureMethod::ManualMultiple),
+                api::PaymentMethodData::Card(_),
+            ) => Some(StripeRequestIncrementalAuthorization::IfAvailable),
+            _ => None,
+        };
+
         Ok(Self {
             amount: item.request.amount, //hopefully we don't loose some cents here
             currency: item.request.currency.to_string(), //we need to copy the value and not transfer ownership
@@ -637,6 +678,8 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for PaymentIntentRequest {
             shipping: shipping_address,
             billing: billing_address,
             capture_method: StripeCaptureMethod::from(item.request.capture_method),
+            request_multicapture,
+            request_incremental_authorization,
             payment_data,
             mandate,
         })
@@ -1173,6 +1216,9 @@ pub struct StripeMandateOptions {
 pub struct CaptureRequest {
     /// If amount_to_capture is None stripe captures the amount in the payment intent.
     amount_to_capture: Option<i64>,
+    /// Stripe releases the uncaptured amount on the final capture. Applicable only to the
+    /// payments authorized for multiple captures.
+    final_capture: Option<bool>,
 }
 
 impl TryFrom<&types::PaymentsCaptureRouterData> for CaptureRequest {
@@ -1180,6 +1226,29 @@ impl TryFrom<&types::PaymentsCaptureRouterData> for CaptureRequest {
     fn try_from(item: &types::PaymentsCaptureRouterData) -> Result<Self, Self::Error> {
         Ok(Self {
             amount_to_capture: item.request.amount_to_capture,
+            final_capture: item
+                .request
+                .multiple_capture_data
+                .as_ref()
+                .map(|multiple_capture_data| multiple_capture_data.final_capture),
+        })
+    }
+}
+
+/// Represents the incremental authorization request body for stripe connector.
+#[derive(Debug, Serialize, Clone, Copy)]
+pub struct IncrementAuthorizationRequest {
+    /// The total amount to be authorized on the payment intent
+    amount: i64,
+}
+
+impl TryFrom<&types::PaymentsIncrementalAuthorizationRouterData> for IncrementAuthorizationRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: &types::PaymentsIncrementalAuthorizationRouterData,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            amount: item.request.total_amount,
         })
     }
 }
@@ -1252,6 +1321,220 @@ pub struct StripeWebhookObjectId {
     pub data: StripeWebhookDataId,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct StripeWebhookDisputeData {
+    pub object: DisputeResponse,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct StripeWebhookDisputeObject {
+    #[serde(rename = "type")]
+    pub event_type: String,
+    pub data: StripeWebhookDisputeData,
+}
+
+// DISPUTES :
+// Type definition for Stripe Dispute Response
+
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum DisputeStatus {
+    WarningNeedsResponse,
+    WarningUnderReview,
+    WarningClosed,
+    NeedsResponse,
+    UnderReview,
+    ChargeRefunded,
+    Won,
+    Lost,
+}
+
+impl From<DisputeStatus> for enums::DisputeStatus {
+    fn from(status: DisputeStatus) -> Self {
+        match status {
+            DisputeStatus::WarningNeedsResponse | DisputeStatus::NeedsResponse => {
+                Self::DisputeOpened
+            }
+            DisputeStatus::WarningUnderReview | DisputeStatus::UnderReview => {
+                Self::DisputeChallenged
+            }
+            DisputeStatus::WarningClosed | DisputeStatus::ChargeRefunded => Self::DisputeCancelled,
+            DisputeStatus::Won => Self::DisputeWon,
+            DisputeStatus::Lost => Self::DisputeLost,
+        }
+    }
+}
+
+impl DisputeStatus {
+    fn get_dispute_stage(self) -> enums::DisputeStage {
+        match self {
+            Self::WarningNeedsResponse | Self::WarningUnderReview | Self::WarningClosed => {
+                enums::DisputeStage::PreDispute
+            }
+            Self::NeedsResponse
+            | Self::UnderReview
+            | Self::ChargeRefunded
+            | Self::Won
+            | Self::Lost => enums::DisputeStage::Dispute,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DisputeEvidenceDetails {
+    pub due_by: Option<i64>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct FileUploadResponse {
+    #[serde(rename = "id")]
+    pub file_id: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DisputeResponse {
+    pub id: String,
+    pub amount: i64,
+    pub currency: String,
+    pub payment_intent: String,
+    pub reason: Option<String>,
+    pub status: DisputeStatus,
+    pub evidence_details: Option<DisputeEvidenceDetails>,
+    pub created: i64,
+}
+
+fn get_primitive_date_time(timestamp: i64) -> Option<time::PrimitiveDateTime> {
+    time::OffsetDateTime::from_unix_timestamp(timestamp)
+        .ok()
+        .map(|date_time| time::PrimitiveDateTime::new(date_time.date(), date_time.time()))
+}
+
+impl From<DisputeResponse> for api::DisputePayload {
+    fn from(dispute: DisputeResponse) -> Self {
+        Self {
+            amount: dispute.amount,
+            currency: dispute.currency,
+            dispute_stage: dispute.status.get_dispute_stage(),
+            connector_status: dispute.status.to_string(),
+            connector_dispute_id: dispute.id,
+            connector_reason: dispute.reason,
+            connector_reason_code: None,
+            challenge_required_by: dispute
+                .evidence_details
+                .and_then(|details| details.due_by)
+                .and_then(get_primitive_date_time),
+            created_at: get_primitive_date_time(dispute.created),
+            updated_at: None,
+        }
+    }
+}
+
+impl
+    TryFrom<
+        types::ResponseRouterData<
+            api::Accept,
+            DisputeResponse,
+            types::AcceptDisputeRequestData,
+            types::AcceptDisputeResponse,
+        >,
+    > for types::AcceptDisputeRouterData
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<
+            api::Accept,
+            DisputeResponse,
+            types::AcceptDisputeRequestData,
+            types::AcceptDisputeResponse,
+        >,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            response: Ok(types::AcceptDisputeResponse {
+                dispute_status: item.response.status.into(),
+                connector_status: Some(item.response.status.to_string()),
+            }),
+            ..item.data
+        })
+    }
+}
+
+// Type definition for Stripe Submit Evidence Request
+
+#[derive(Debug, Serialize)]
+pub struct SubmitEvidenceRequest {
+    #[serde(rename = "evidence[cancellation_policy_disclosure]")]
+    pub cancellation_policy_disclosure: Option<String>,
+    #[serde(rename = "evidence[customer_communication]")]
+    pub customer_communication: Option<String>,
+    #[serde(rename = "evidence[customer_name]")]
+    pub customer_name: Option<String>,
+    #[serde(rename = "evidence[product_description]")]
+    pub product_description: Option<String>,
+    #[serde(rename = "evidence[refund_policy_disclosure]")]
+    pub refund_policy_disclosure: Option<String>,
+    #[serde(rename = "evidence[service_date]")]
+    pub service_date: Option<String>,
+    #[serde(rename = "evidence[shipping_carrier]")]
+    pub shipping_carrier: Option<String>,
+    #[serde(rename = "evidence[shipping_date]")]
+    pub shipping_date: Option<String>,
+    #[serde(rename = "evidence[shipping_tracking_number]")]
+    pub shipping_tracking_number: Option<String>,
+    #[serde(rename = "evidence[uncategorized_text]")]
+    pub uncategorized_text: Option<String>,
+    pub submit: bool,
+}
+
+impl TryFrom<&types::SubmitEvidenceRouterData> for SubmitEvidenceRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::SubmitEvidenceRouterData) -> Result<Self, Self::Error> {
+        let evidence = item.request.evidence.clone();
+        Ok(Self {
+            cancellation_policy_disclosure: evidence.cancellation_policy,
+            customer_communication: evidence.customer_communication,
+            customer_name: evidence.customer_name,
+            product_description: evidence.product_description,
+            refund_policy_disclosure: evidence.refund_policy,
+            service_date: evidence.service_date,
+            shipping_carrier: evidence.shipping_carrier,
+            shipping_date: evidence.shipping_date,
+            shipping_tracking_number: evidence.shipping_tracking_number,
+            uncategorized_text: evidence.uncategorized_text,
+            submit: true,
+        })
+    }
+}
+
+impl
+    TryFrom<
+        types::ResponseRouterData<
+            api::Evidence,
+            DisputeResponse,
+            types::SubmitEvidenceRequestData,
+            types::SubmitEvidenceResponse,
+        >,
+    > for types::SubmitEvidenceRouterData
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<
+            api::Evidence,
+            DisputeResponse,
+            types::SubmitEvidenceRequestData,
+            types::SubmitEvidenceResponse,
+        >,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            response: Ok(types::SubmitEvidenceResponse {
+                dispute_status: item.response.status.into(),
+                connector_status: Some(item.response.status.to_string()),
+            }),
+            ..item.data
+        })
+    }
+}
+
 impl
     TryFrom<(
         api::PaymentMethodData,
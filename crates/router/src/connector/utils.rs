@@ -383,3 +383,39 @@ where
     })?;
     serializer.serialize_f64(float_value)
 }
+
+/// Multipart form with the file and its purpose, for connectors which accept file uploads as `multipart/form-data`
+pub fn construct_file_upload_form(
+    purpose: &'static str,
+    request: &types::UploadFileRequestData,
+) -> CustomResult<reqwest::multipart::Form, errors::ConnectorError> {
+    let file_data = reqwest::multipart::Part::bytes(request.file.clone())
+        .file_name(request.file_key.clone())
+        .mime_str(request.file_type.as_ref())
+        .into_report()
+        .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+    Ok(reqwest::multipart::Form::new()
+        .text("purpose", purpose)
+        .part("file", file_data))
+}
+
+/// Validates the type and size of a file against the limits of the connector
+pub fn validate_file_type_and_size(
+    connector: &str,
+    file_type: &mime::Mime,
+    file_size: i32,
+    supported_file_types: &[mime::Mime],
+    max_file_size: i32,
+) -> CustomResult<(), errors::ConnectorError> {
+    if !supported_file_types.contains(file_type) {
+        return Err(report!(errors::ConnectorError::FileValidationFailed {
+            reason: format!("file type {file_type} is not supported by {connector}"),
+        }));
+    }
+    if file_size > max_file_size {
+        return Err(report!(errors::ConnectorError::FileValidationFailed {
+            reason: format!("file size exceeds the limit of {max_file_size} bytes for {connector}"),
+        }));
+    }
+    Ok(())
+}
@@ -492,6 +492,18 @@ impl ConnectorIntegration<api::Authorize, types::PaymentsAuthorizeData, types::P
     }
 }
 
+impl api::PaymentIncrementalAuthorization for Worldline {}
+
+impl
+    services::ConnectorIntegration<
+        api::IncrementalAuthorization,
+        types::PaymentsIncrementalAuthorizationData,
+        types::PaymentsResponseData,
+    > for Worldline
+{
+    // Not Implemented (R)
+}
+
 impl api::Refund for Worldline {}
 impl api::RefundExecute for Worldline {}
 impl api::RefundSync for Worldline {}
@@ -651,6 +663,60 @@ impl ConnectorIntegration<api::RSync, types::RefundsData, types::RefundsResponse
     }
 }
 
+impl api::Payout for Worldline {}
+impl api::PayoutCreate for Worldline {}
+impl api::PayoutFulfill for Worldline {}
+impl api::PayoutReverse for Worldline {}
+
+impl services::ConnectorIntegration<api::PoCreate, types::PayoutsData, types::PayoutsResponseData>
+    for Worldline
+{
+}
+
+impl services::ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Worldline
+{
+}
+
+impl services::ConnectorIntegration<api::PoReverse, types::PayoutsData, types::PayoutsResponseData>
+    for Worldline
+{
+}
+
+impl api::Dispute for Worldline {}
+impl api::AcceptDispute for Worldline {}
+impl api::SubmitEvidence for Worldline {}
+
+impl
+    services::ConnectorIntegration<
+        api::Accept,
+        types::AcceptDisputeRequestData,
+        types::AcceptDisputeResponse,
+    > for Worldline
+{
+}
+
+impl
+    services::ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::SubmitEvidenceResponse,
+    > for Worldline
+{
+}
+
+impl api::FileUpload for Worldline {}
+impl api::UploadFile for Worldline {}
+
+impl
+    services::ConnectorIntegration<
+        api::Upload,
+        types::UploadFileRequestData,
+        types::UploadFileResponse,
+    > for Worldline
+{
+}
+
 #[async_trait::async_trait]
 impl api::IncomingWebhook for Worldline {
     fn get_webhook_object_reference_id(
@@ -433,6 +433,18 @@ impl ConnectorIntegration<api::Authorize, types::PaymentsAuthorizeData, types::P
     }
 }
 
+impl api::PaymentIncrementalAuthorization for Worldpay {}
+
+impl
+    services::ConnectorIntegration<
+        api::IncrementalAuthorization,
+        types::PaymentsIncrementalAuthorizationData,
+        types::PaymentsResponseData,
+    > for Worldpay
+{
+    // Not Implemented (R)
+}
+
 impl api::Refund for Worldpay {}
 impl api::RefundExecute for Worldpay {}
 impl api::RefundSync for Worldpay {}
@@ -588,6 +600,60 @@ impl ConnectorIntegration<api::RSync, types::RefundsData, types::RefundsResponse
     }
 }
 
+impl api::Payout for Worldpay {}
+impl api::PayoutCreate for Worldpay {}
+impl api::PayoutFulfill for Worldpay {}
+impl api::PayoutReverse for Worldpay {}
+
+impl services::ConnectorIntegration<api::PoCreate, types::PayoutsData, types::PayoutsResponseData>
+    for Worldpay
+{
+}
+
+impl services::ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Worldpay
+{
+}
+
+impl services::ConnectorIntegration<api::PoReverse, types::PayoutsData, types::PayoutsResponseData>
+    for Worldpay
+{
+}
+
+impl api::Dispute for Worldpay {}
+impl api::AcceptDispute for Worldpay {}
+impl api::SubmitEvidence for Worldpay {}
+
+impl
+    services::ConnectorIntegration<
+        api::Accept,
+        types::AcceptDisputeRequestData,
+        types::AcceptDisputeResponse,
+    > for Worldpay
+{
+}
+
+impl
+    services::ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::SubmitEvidenceResponse,
+    > for Worldpay
+{
+}
+
+impl api::FileUpload for Worldpay {}
+impl api::UploadFile for Worldpay {}
+
+impl
+    services::ConnectorIntegration<
+        api::Upload,
+        types::UploadFileRequestData,
+        types::UploadFileResponse,
+    > for Worldpay
+{
+}
+
 #[async_trait::async_trait]
 impl api::IncomingWebhook for Worldpay {
     fn get_webhook_object_reference_id(
@@ -23,6 +23,12 @@ pub(crate) const BASE64_ENGINE_URL_SAFE: base64::engine::GeneralPurpose =
     base64::engine::general_purpose::URL_SAFE;
 
 pub(crate) const API_KEY_LENGTH: usize = 64;
+
+// Length of the randomly generated payment response hash key, also used for signing webhooks
+pub(crate) const PAYMENT_RESPONSE_HASH_KEY_LENGTH: usize = 64;
+
+// Identifier of the webhook endpoint configured through `webhook_url` in the webhook details
+pub(crate) const DEFAULT_WEBHOOK_ENDPOINT_ID: &str = "default";
 pub(crate) const PUB_SUB_CHANNEL: &str = "hyperswitch_invalidate";
 
 /// Max age of 1 year in seconds. Which is `60*60*24*365`
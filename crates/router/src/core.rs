@@ -2,10 +2,15 @@ pub mod admin;
 pub mod api_keys;
 pub mod configs;
 pub mod customers;
+pub mod disputes;
 pub mod errors;
+pub mod events;
+pub mod files;
 pub mod mandate;
 pub mod payment_methods;
 pub mod payments;
+pub mod payouts;
 pub mod refunds;
+pub mod routing;
 pub mod utils;
 pub mod webhooks;
@@ -1,14 +1,21 @@
+use api_models::enums as api_enums;
 use common_utils::ext_traits::ValueExt;
 use error_stack::{report, FutureExt, ResultExt};
+use futures::future::try_join_all;
 use storage_models::{enums, merchant_account};
+use strum::IntoEnumIterator;
 use uuid::Uuid;
 
 use crate::{
     consts,
-    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payments::{expiry, fees, routing},
+    },
     db::StorageInterface,
     pii::Secret,
-    services::api as service_api,
+    routes::AppState,
+    services::{api as service_api, circuit_breaker, rate_limit},
     types::{
         self, api,
         storage::{self, MerchantAccount},
@@ -26,6 +33,13 @@ pub fn create_merchant_api_key() -> String {
     )
 }
 
+#[inline]
+fn create_payment_response_hash_key() -> String {
+    common_utils::crypto::generate_cryptographically_secure_random_string(
+        consts::PAYMENT_RESPONSE_HASH_KEY_LENGTH,
+    )
+}
+
 pub async fn create_merchant_account(
     db: &dyn StorageInterface,
     req: api::MerchantAccountCreate,
@@ -41,6 +55,34 @@ pub async fn create_merchant_account(
             })?,
     );
 
+    if let Some(ref webhook_details) = req.webhook_details {
+        validate_webhook_details(webhook_details)?;
+    }
+
+    if let Some(ref rate_limits) = req.rate_limits {
+        rate_limit::validate_rate_limits(rate_limits)?;
+    }
+    let rate_limits = req
+        .rate_limits
+        .as_ref()
+        .map(utils::Encode::<api::RateLimits>::encode_to_value)
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "rate_limits",
+        })?;
+
+    if let Some(ref payment_expiry) = req.payment_expiry {
+        expiry::validate_payment_expiry(payment_expiry)?;
+    }
+    let payment_expiry = req
+        .payment_expiry
+        .as_ref()
+        .map(utils::Encode::<api::PaymentExpiry>::encode_to_value)
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "payment_expiry",
+        })?;
+
     let webhook_details = Some(
         utils::Encode::<api::WebhookDetails>::encode_to_value(&req.webhook_details)
             .change_context(errors::ApiErrorResponse::InvalidDataValue {
@@ -49,13 +91,14 @@ pub async fn create_merchant_account(
     );
 
     if let Some(ref routing_algorithm) = req.routing_algorithm {
-        let _: api::RoutingAlgorithm = routing_algorithm
+        let routing_algorithm: api::RoutingAlgorithm = routing_algorithm
             .clone()
             .parse_value("RoutingAlgorithm")
             .change_context(errors::ApiErrorResponse::InvalidDataValue {
                 field_name: "routing_algorithm",
             })
             .attach_printable("Invalid routing algorithm given")?;
+        routing::validate_routing_algorithm(&routing_algorithm)?;
     }
 
     let merchant_account = storage::MerchantAccountNew {
@@ -74,11 +117,16 @@ pub async fn create_merchant_account(
         )
         .await?,
         enable_payment_response_hash: req.enable_payment_response_hash,
-        payment_response_hash_key: req.payment_response_hash_key,
+        payment_response_hash_key: Some(
+            req.payment_response_hash_key
+                .unwrap_or_else(create_payment_response_hash_key),
+        ),
         redirect_to_merchant_with_http_post: req.redirect_to_merchant_with_http_post,
         publishable_key,
         locker_id: req.locker_id,
         metadata: req.metadata,
+        rate_limits,
+        payment_expiry,
     };
 
     let merchant_account = db
@@ -127,15 +175,44 @@ pub async fn merchant_account_update(
     }
 
     if let Some(ref routing_algorithm) = req.routing_algorithm {
-        let _: api::RoutingAlgorithm = routing_algorithm
+        let routing_algorithm: api::RoutingAlgorithm = routing_algorithm
             .clone()
             .parse_value("RoutingAlgorithm")
             .change_context(errors::ApiErrorResponse::InvalidDataValue {
                 field_name: "routing_algorithm",
             })
             .attach_printable("Invalid routing algorithm given")?;
+        routing::validate_routing_algorithm(&routing_algorithm)?;
     }
 
+    if let Some(ref webhook_details) = req.webhook_details {
+        validate_webhook_details(webhook_details)?;
+    }
+
+    if let Some(ref rate_limits) = req.rate_limits {
+        rate_limit::validate_rate_limits(rate_limits)?;
+    }
+    let rate_limits = req
+        .rate_limits
+        .as_ref()
+        .map(utils::Encode::<api::RateLimits>::encode_to_value)
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "rate_limits",
+        })?;
+
+    if let Some(ref payment_expiry) = req.payment_expiry {
+        expiry::validate_payment_expiry(payment_expiry)?;
+    }
+    let payment_expiry = req
+        .payment_expiry
+        .as_ref()
+        .map(utils::Encode::<api::PaymentExpiry>::encode_to_value)
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "payment_expiry",
+        })?;
+
     let updated_merchant_account = storage::MerchantAccountUpdate::Update {
         merchant_name: req.merchant_name,
 
@@ -171,6 +248,8 @@ pub async fn merchant_account_update(
         metadata: req.metadata,
         api_key: None,
         publishable_key: None,
+        rate_limits,
+        payment_expiry,
     };
 
     let response = db
@@ -229,6 +308,28 @@ async fn get_parent_merchant(
     })
 }
 
+fn validate_webhook_details(webhook_details: &api::WebhookDetails) -> RouterResult<()> {
+    let mut endpoint_ids = std::collections::HashSet::new();
+    for endpoint in webhook_details.endpoints.iter().flatten() {
+        let is_valid_endpoint_id = !endpoint.endpoint_id.is_empty()
+            && endpoint.endpoint_id.len() <= 64
+            && endpoint.endpoint_id != consts::DEFAULT_WEBHOOK_ENDPOINT_ID
+            && endpoint_ids.insert(endpoint.endpoint_id.as_str());
+        if !is_valid_endpoint_id {
+            return Err(report!(errors::ApiErrorResponse::InvalidDataValue {
+                field_name: "webhook_details.endpoints",
+            }))
+            .attach_printable_lazy(|| {
+                format!(
+                    "Webhook endpoint ids must be unique, non-empty, at most 64 characters long and other than `{}`",
+                    consts::DEFAULT_WEBHOOK_ENDPOINT_ID
+                )
+            });
+        }
+    }
+    Ok(())
+}
+
 async fn validate_merchant_id<S: Into<String>>(
     db: &dyn StorageInterface,
     merchant_id: S,
@@ -272,6 +373,20 @@ pub async fn create_payment_connector(
         None => None,
     };
 
+    expiry::validate_authorization_ttl_hours(req.authorization_ttl_hours)?;
+
+    if let Some(ref fee_schedule) = req.fee_schedule {
+        fees::validate_fee_schedule(fee_schedule)?;
+    }
+    let fee_schedule = req
+        .fee_schedule
+        .as_ref()
+        .map(utils::Encode::<api::ConnectorFeeSchedule>::encode_to_value)
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "fee_schedule",
+        })?;
+
     // Validate Merchant api details and return error if not in correct format
     let _: types::ConnectorAuthType = req
         .connector_account_details
@@ -292,6 +407,8 @@ pub async fn create_payment_connector(
         test_mode: req.test_mode,
         disabled: req.disabled,
         metadata: req.metadata,
+        authorization_ttl_hours: req.authorization_ttl_hours.map(i32::from),
+        fee_schedule,
     };
 
     let mca = store
@@ -383,6 +500,20 @@ pub async fn update_payment_connector(
             error.to_not_found_response(errors::ApiErrorResponse::MerchantConnectorAccountNotFound)
         })?;
 
+    expiry::validate_authorization_ttl_hours(req.authorization_ttl_hours)?;
+
+    if let Some(ref fee_schedule) = req.fee_schedule {
+        fees::validate_fee_schedule(fee_schedule)?;
+    }
+    let fee_schedule = req
+        .fee_schedule
+        .as_ref()
+        .map(utils::Encode::<api::ConnectorFeeSchedule>::encode_to_value)
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "fee_schedule",
+        })?;
+
     let payment_methods_enabled = req.payment_methods_enabled.map(|pm_enabled| {
         pm_enabled
             .iter()
@@ -402,6 +533,8 @@ pub async fn update_payment_connector(
         test_mode: req.test_mode,
         disabled: req.disabled,
         metadata: req.metadata,
+        authorization_ttl_hours: req.authorization_ttl_hours.map(i32::from),
+        fee_schedule,
     };
 
     let updated_mca = db
@@ -432,6 +565,15 @@ pub async fn update_payment_connector(
         disabled: updated_mca.disabled,
         payment_methods_enabled: updated_pm_enabled,
         metadata: updated_mca.metadata,
+        authorization_ttl_hours: updated_mca
+            .authorization_ttl_hours
+            .and_then(|hours| u16::try_from(hours).ok()),
+        fee_schedule: updated_mca
+            .fee_schedule
+            .map(|fee_schedule| fee_schedule.parse_value("ConnectorFeeSchedule"))
+            .transpose()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse the fee schedule of the connector")?,
     };
     Ok(service_api::ApplicationResponse::Json(response))
 }
@@ -518,6 +660,61 @@ pub async fn kv_for_merchant(
     ))
 }
 
+pub async fn rotate_webhook_secret(
+    db: &dyn StorageInterface,
+    merchant_id: String,
+    req: api::WebhookSecretRotateRequest,
+    default_overlap_in_seconds: u32,
+) -> RouterResponse<api::WebhookSecretRotateResponse> {
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(&merchant_id)
+        .await
+        .map_err(|error| {
+            error.to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)
+        })?;
+
+    let payment_response_hash_key = match req.payment_response_hash_key {
+        Some(key) if key.is_empty() || key.len() > 255 => {
+            Err(report!(errors::ApiErrorResponse::InvalidDataValue {
+                field_name: "payment_response_hash_key",
+            }))
+        }
+        Some(key) => Ok(key),
+        None => Ok(create_payment_response_hash_key()),
+    }?;
+
+    // The current key keeps signing outgoing webhooks until the overlap window elapses, giving
+    // the merchant time to switch over to the new key
+    let overlap_in_seconds = req.overlap_in_seconds.unwrap_or(default_overlap_in_seconds);
+    let previous_payment_response_hash_key_expires_at = merchant_account
+        .payment_response_hash_key
+        .as_ref()
+        .map(|_| {
+            common_utils::date_time::now() + time::Duration::seconds(i64::from(overlap_in_seconds))
+        });
+
+    let merchant_account_update =
+        merchant_account::MerchantAccountUpdate::PaymentResponseHashKeyUpdate {
+            previous_payment_response_hash_key: merchant_account.payment_response_hash_key.clone(),
+            previous_payment_response_hash_key_expires_at,
+            payment_response_hash_key: payment_response_hash_key.clone(),
+        };
+    let updated_merchant_account = db
+        .update_merchant(merchant_account, merchant_account_update)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to rotate the merchant's payment response hash key")?;
+
+    Ok(service_api::ApplicationResponse::Json(
+        api::WebhookSecretRotateResponse {
+            merchant_id: updated_merchant_account.merchant_id,
+            payment_response_hash_key,
+            previous_payment_response_hash_key_expires_at: updated_merchant_account
+                .previous_payment_response_hash_key_expires_at,
+        },
+    ))
+}
+
 pub async fn check_merchant_account_kv_status(
     db: &dyn StorageInterface,
     merchant_id: String,
@@ -542,3 +739,17 @@ pub async fn check_merchant_account_kv_status(
         },
     ))
 }
+
+pub async fn get_connector_health(
+    state: &AppState,
+) -> RouterResponse<Vec<api::ConnectorHealthResponse>> {
+    let connector_health = try_join_all(
+        api_enums::RoutableConnectors::iter()
+            .map(|connector| circuit_breaker::get_connector_health(state, connector)),
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed while fetching the health of the connectors")?;
+
+    Ok(service_api::ApplicationResponse::Json(connector_health))
+}
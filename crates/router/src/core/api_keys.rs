@@ -8,8 +8,11 @@ use crate::{
     consts,
     core::errors::{self, RouterResponse, StorageErrorExt},
     db::StorageInterface,
-    services::ApplicationResponse,
-    types::{api, storage, transformers::ForeignInto},
+    services::{rate_limit, ApplicationResponse},
+    types::{
+        api, storage,
+        transformers::{ForeignInto, ForeignTryInto},
+    },
     utils,
 };
 #[cfg(feature = "kms")]
@@ -125,6 +128,18 @@ pub async fn create_api_key(
     let hash_key = HASH_KEY
         .get_or_try_init(|| get_hash_key(api_key_config))
         .await?;
+    if let Some(ref rate_limits) = api_key.rate_limits {
+        rate_limit::validate_rate_limits(rate_limits)?;
+    }
+    let rate_limits = api_key
+        .rate_limits
+        .as_ref()
+        .map(utils::Encode::<api::RateLimits>::encode_to_value)
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "rate_limits",
+        })?;
+
     let plaintext_api_key = PlaintextApiKey::new(consts::API_KEY_LENGTH);
     let api_key = storage::ApiKeyNew {
         key_id: PlaintextApiKey::new_key_id(),
@@ -136,6 +151,7 @@ pub async fn create_api_key(
         created_at: date_time::now(),
         expires_at: api_key.expiration.into(),
         last_used: None,
+        rate_limits,
     };
 
     let api_key = store
@@ -170,8 +186,18 @@ pub async fn update_api_key(
     key_id: &str,
     api_key: api::UpdateApiKeyRequest,
 ) -> RouterResponse<api::RetrieveApiKeyResponse> {
+    if let Some(ref rate_limits) = api_key.rate_limits {
+        rate_limit::validate_rate_limits(rate_limits)?;
+    }
+    let api_key_update =
+        api_key
+            .foreign_try_into()
+            .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                field_name: "rate_limits",
+            })?;
+
     let api_key = store
-        .update_api_key(key_id.to_owned(), api_key.foreign_into())
+        .update_api_key(key_id.to_owned(), api_key_update)
         .await
         .map_err(|err| err.to_not_found_response(errors::ApiErrorResponse::ApiKeyNotFound))?;
 
@@ -37,22 +37,25 @@ pub async fn create_customer(
             .clone()
             .parse_value("AddressDetails")
             .change_context(errors::ApiErrorResponse::AddressNotFound)?;
-        db.insert_address(storage::AddressNew {
-            city: customer_address.city,
-            country: customer_address.country,
-            line1: customer_address.line1,
-            line2: customer_address.line2,
-            line3: customer_address.line3,
-            zip: customer_address.zip,
-            state: customer_address.state,
-            first_name: customer_address.first_name,
-            last_name: customer_address.last_name,
-            phone_number: customer_data.phone.clone(),
-            country_code: customer_data.phone_country_code.clone(),
-            customer_id: customer_id.to_string(),
-            merchant_id: merchant_id.to_string(),
-            ..Default::default()
-        })
+        db.insert_address(
+            storage::AddressNew {
+                city: customer_address.city,
+                country: customer_address.country,
+                line1: customer_address.line1,
+                line2: customer_address.line2,
+                line3: customer_address.line3,
+                zip: customer_address.zip,
+                state: customer_address.state,
+                first_name: customer_address.first_name,
+                last_name: customer_address.last_name,
+                phone_number: customer_data.phone.clone(),
+                country_code: customer_data.phone_country_code.clone(),
+                customer_id: customer_id.to_string(),
+                merchant_id: merchant_id.to_string(),
+                ..Default::default()
+            },
+            merchant_account.storage_scheme,
+        )
         .await
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Failed while inserting new address")?;
@@ -69,18 +72,30 @@ pub async fn create_customer(
         metadata: customer_data.metadata,
     };
 
-    let customer = match db.insert_customer(new_customer).await {
+    let customer = match db
+        .insert_customer(new_customer, merchant_account.storage_scheme)
+        .await
+    {
         Ok(customer) => customer,
         Err(error) => {
-            if error.current_context().is_db_unique_violation() {
-                db.find_customer_by_customer_id_merchant_id(customer_id, merchant_id)
-                    .await
-                    .map_err(|err| {
-                        err.to_not_found_response(errors::ApiErrorResponse::InternalServerError)
-                            .attach_printable(format!(
-                                "Failed while fetching Customer, customer_id: {customer_id}",
-                            ))
-                    })?
+            if error.current_context().is_db_unique_violation()
+                || matches!(
+                    error.current_context(),
+                    errors::StorageError::DuplicateValue { .. }
+                )
+            {
+                db.find_customer_by_customer_id_merchant_id(
+                    customer_id,
+                    merchant_id,
+                    merchant_account.storage_scheme,
+                )
+                .await
+                .map_err(|err| {
+                    err.to_not_found_response(errors::ApiErrorResponse::InternalServerError)
+                        .attach_printable(format!(
+                            "Failed while fetching Customer, customer_id: {customer_id}",
+                        ))
+                })?
             } else {
                 Err(error
                     .change_context(errors::ApiErrorResponse::InternalServerError)
@@ -101,7 +116,11 @@ pub async fn retrieve_customer(
     req: customers::CustomerId,
 ) -> RouterResponse<customers::CustomerResponse> {
     let response = db
-        .find_customer_by_customer_id_merchant_id(&req.customer_id, &merchant_account.merchant_id)
+        .find_customer_by_customer_id_merchant_id(
+            &req.customer_id,
+            &merchant_account.merchant_id,
+            merchant_account.storage_scheme,
+        )
         .await
         .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::CustomerNotFound))?;
 
@@ -116,9 +135,13 @@ pub async fn delete_customer(
 ) -> RouterResponse<customers::CustomerDeleteResponse> {
     let db = &state.store;
 
-    db.find_customer_by_customer_id_merchant_id(&req.customer_id, &merchant_account.merchant_id)
-        .await
-        .map_err(|err| err.to_not_found_response(errors::ApiErrorResponse::CustomerNotFound))?;
+    db.find_customer_by_customer_id_merchant_id(
+        &req.customer_id,
+        &merchant_account.merchant_id,
+        merchant_account.storage_scheme,
+    )
+    .await
+    .map_err(|err| err.to_not_found_response(errors::ApiErrorResponse::CustomerNotFound))?;
 
     let customer_mandates = db
         .find_mandate_by_merchant_id_customer_id(&merchant_account.merchant_id, &req.customer_id)
@@ -182,6 +205,7 @@ pub async fn delete_customer(
             &req.customer_id,
             &merchant_account.merchant_id,
             update_address,
+            merchant_account.storage_scheme,
         )
         .await
     {
@@ -207,6 +231,7 @@ pub async fn delete_customer(
         req.customer_id.clone(),
         merchant_account.merchant_id,
         updated_customer,
+        merchant_account.storage_scheme,
     )
     .await
     .change_context(errors::ApiErrorResponse::CustomerNotFound)?;
@@ -231,6 +256,7 @@ pub async fn update_customer(
     db.find_customer_by_customer_id_merchant_id(
         &update_customer.customer_id,
         &merchant_account.merchant_id,
+        merchant_account.storage_scheme,
     )
     .await
     .map_err(|err| err.to_not_found_response(errors::ApiErrorResponse::CustomerNotFound))?;
@@ -258,6 +284,7 @@ pub async fn update_customer(
             &update_customer.customer_id,
             &merchant_account.merchant_id,
             update_address,
+            merchant_account.storage_scheme,
         )
         .await
         .change_context(errors::ApiErrorResponse::InternalServerError)
@@ -279,6 +306,7 @@ pub async fn update_customer(
                 metadata: update_customer.metadata,
                 description: update_customer.description,
             },
+            merchant_account.storage_scheme,
         )
         .await
         .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::CustomerNotFound))?;
@@ -0,0 +1,336 @@
+use error_stack::{report, IntoReport, ResultExt};
+use router_env::{instrument, tracing};
+
+use crate::{
+    consts,
+    core::{
+        errors::{self, ConnectorErrorExt, RouterResponse, RouterResult, StorageErrorExt},
+        payments, utils as core_utils,
+    },
+    routes::AppState,
+    services,
+    types::{
+        self,
+        api::{self, disputes},
+        storage::{self, enums},
+        transformers::ForeignFrom,
+    },
+    utils,
+};
+
+#[instrument(skip(state))]
+pub async fn retrieve_dispute(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    dispute_id: String,
+) -> RouterResponse<api::DisputeResponse> {
+    let dispute = find_dispute(state, &merchant_account, &dispute_id).await?;
+    Ok(services::ApplicationResponse::Json(
+        api::DisputeResponse::foreign_from(dispute),
+    ))
+}
+
+#[instrument(skip(state))]
+pub async fn retrieve_disputes_list(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    constraints: api::DisputeListConstraints,
+) -> RouterResponse<api::DisputeListResponse> {
+    let disputes = state
+        .store
+        .find_disputes_by_merchant_id(&merchant_account.merchant_id, constraints)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to retrieve disputes")?;
+
+    Ok(services::ApplicationResponse::Json(
+        api::DisputeListResponse {
+            data: disputes
+                .into_iter()
+                .map(api::DisputeResponse::foreign_from)
+                .collect(),
+        },
+    ))
+}
+
+#[instrument(skip(state))]
+pub async fn accept_dispute(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    dispute_id: String,
+) -> RouterResponse<api::DisputeResponse> {
+    let db = &*state.store;
+    let dispute = find_dispute(state, &merchant_account, &dispute_id).await?;
+    validate_dispute_is_open(&dispute, "accepted")?;
+
+    let (payment_intent, payment_attempt) =
+        find_disputed_payment(state, &merchant_account, &dispute).await?;
+
+    let router_data: types::AcceptDisputeRouterData = core_utils::construct_dispute_router_data(
+        state,
+        &merchant_account,
+        &payment_intent,
+        &payment_attempt,
+        &dispute,
+        types::AcceptDisputeRequestData {
+            dispute_id: dispute.dispute_id.clone(),
+            connector_dispute_id: dispute.connector_dispute_id.clone(),
+        },
+    )
+    .await?;
+
+    let response = call_connector(state, &dispute.connector, &router_data)
+        .await?
+        .map_err(|error| connector_error_response(&dispute.connector, error))?;
+
+    let dispute_update = storage::DisputeUpdate::StatusUpdate {
+        dispute_status: response.dispute_status,
+        connector_status: response.connector_status,
+    };
+    let updated_dispute = db
+        .update_dispute(dispute, dispute_update)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!("Unable to update dispute with dispute_id: {dispute_id}")
+        })?;
+
+    Ok(services::ApplicationResponse::Json(
+        api::DisputeResponse::foreign_from(updated_dispute),
+    ))
+}
+
+#[instrument(skip(state))]
+pub async fn submit_evidence(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    dispute_id: String,
+    evidence: api::DisputeEvidence,
+) -> RouterResponse<api::DisputeResponse> {
+    let db = &*state.store;
+    let dispute = find_dispute(state, &merchant_account, &dispute_id).await?;
+    validate_dispute_is_open(&dispute, "challenged")?;
+
+    let (payment_intent, payment_attempt) =
+        find_disputed_payment(state, &merchant_account, &dispute).await?;
+
+    let router_data: types::SubmitEvidenceRouterData = core_utils::construct_dispute_router_data(
+        state,
+        &merchant_account,
+        &payment_intent,
+        &payment_attempt,
+        &dispute,
+        types::SubmitEvidenceRequestData {
+            dispute_id: dispute.dispute_id.clone(),
+            connector_dispute_id: dispute.connector_dispute_id.clone(),
+            evidence: evidence.clone(),
+        },
+    )
+    .await?;
+
+    let response = call_connector(state, &dispute.connector, &router_data)
+        .await?
+        .map_err(|error| connector_error_response(&dispute.connector, error))?;
+
+    let encoded_evidence = utils::Encode::<api::DisputeEvidence>::encode_to_value(&evidence)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to encode dispute evidence")?;
+
+    let updated_dispute = db
+        .update_dispute(
+            dispute,
+            storage::DisputeUpdate::EvidenceUpdate {
+                evidence: encoded_evidence,
+                dispute_status: response.dispute_status,
+                connector_status: response.connector_status,
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!("Unable to update dispute with dispute_id: {dispute_id}")
+        })?;
+
+    Ok(services::ApplicationResponse::Json(
+        api::DisputeResponse::foreign_from(updated_dispute),
+    ))
+}
+
+pub async fn find_dispute(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    dispute_id: &str,
+) -> RouterResult<storage::Dispute> {
+    state
+        .store
+        .find_dispute_by_merchant_id_dispute_id(&merchant_account.merchant_id, dispute_id)
+        .await
+        .map_err(|error| {
+            error.to_not_found_response(errors::ApiErrorResponse::DisputeNotFound {
+                dispute_id: dispute_id.to_owned(),
+            })
+        })
+}
+
+pub async fn find_disputed_payment(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    dispute: &storage::Dispute,
+) -> RouterResult<(storage::PaymentIntent, storage::PaymentAttempt)> {
+    let db = &*state.store;
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            &dispute.payment_id,
+            &merchant_account.merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound))?;
+
+    let payment_attempt = db
+        .find_payment_attempt_by_merchant_id_attempt_id(
+            &merchant_account.merchant_id,
+            &dispute.attempt_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound))?;
+
+    Ok((payment_intent, payment_attempt))
+}
+
+/// Disputes can be accepted or challenged only while the merchant is expected to respond to them
+fn validate_dispute_is_open(dispute: &storage::Dispute, action: &str) -> RouterResult<()> {
+    utils::when(
+        dispute.dispute_status != enums::DisputeStatus::DisputeOpened,
+        || {
+            Err(report!(
+                errors::ApiErrorResponse::DisputeStatusValidationFailed {
+                    reason: format!(
+                        "This dispute cannot be {action} because the dispute is in {} status",
+                        dispute.dispute_status
+                    ),
+                }
+            ))
+        },
+    )
+}
+
+pub async fn call_connector<F, Req, Res>(
+    state: &AppState,
+    connector_name: &str,
+    router_data: &types::RouterData<F, Req, Res>,
+) -> RouterResult<Result<Res, types::ErrorResponse>>
+where
+    F: Clone + std::fmt::Debug + 'static,
+    Req: Clone + std::fmt::Debug + 'static,
+    Res: Clone + std::fmt::Debug + 'static,
+    dyn api::Connector: services::ConnectorIntegration<F, Req, Res>,
+{
+    let connector_data = api::ConnectorData::get_connector_by_name(
+        &state.conf.connectors,
+        connector_name,
+        api::GetToken::Connector,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to get the connector")?;
+
+    let connector_integration: services::BoxedConnectorIntegration<'_, F, Req, Res> =
+        connector_data.connector.get_connector_integration();
+
+    let router_data_res = services::execute_connector_processing_step(
+        state,
+        connector_integration,
+        router_data,
+        payments::CallConnectorAction::Trigger,
+    )
+    .await
+    .map_err(|error| error.to_payment_failed_response())?;
+
+    Ok(router_data_res.response)
+}
+
+pub fn connector_error_response(
+    connector_name: &str,
+    error: types::ErrorResponse,
+) -> error_stack::Report<errors::ApiErrorResponse> {
+    report!(errors::ApiErrorResponse::ExternalConnectorError {
+        code: error.code,
+        message: error.message,
+        connector: connector_name.to_owned(),
+        status_code: error.status_code,
+        reason: error.reason,
+    })
+}
+
+/// Creates or updates the dispute with the details received in a connector webhook
+#[instrument(skip_all)]
+pub async fn upsert_dispute_from_webhook(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    connector_name: &str,
+    payment_attempt: &storage::PaymentAttempt,
+    dispute_details: disputes::DisputePayload,
+    dispute_status: enums::DisputeStatus,
+) -> RouterResult<storage::Dispute> {
+    let db = &*state.store;
+    let existing_dispute = db
+        .find_dispute_by_merchant_id_payment_id_connector_dispute_id(
+            &merchant_account.merchant_id,
+            &payment_attempt.payment_id,
+            &dispute_details.connector_dispute_id,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the dispute")?;
+
+    match existing_dispute {
+        Some(dispute) => {
+            let dispute_update = storage::DisputeUpdate::Update {
+                dispute_stage: dispute_details.dispute_stage,
+                dispute_status,
+                connector_status: dispute_details.connector_status,
+                connector_reason: dispute_details.connector_reason,
+                connector_reason_code: dispute_details.connector_reason_code,
+                challenge_required_by: dispute_details.challenge_required_by,
+                connector_updated_at: dispute_details.updated_at,
+            };
+            db.update_dispute(dispute, dispute_update)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to update the dispute")
+        }
+        None => {
+            let currency = dispute_details
+                .currency
+                .to_uppercase()
+                .parse::<enums::Currency>()
+                .into_report()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Invalid currency received in the dispute webhook")?;
+
+            let new_dispute = storage::DisputeNew {
+                dispute_id: utils::generate_id(consts::ID_LENGTH, "dp"),
+                amount: dispute_details.amount,
+                currency,
+                dispute_stage: dispute_details.dispute_stage,
+                dispute_status,
+                payment_id: payment_attempt.payment_id.clone(),
+                attempt_id: payment_attempt.attempt_id.clone(),
+                merchant_id: merchant_account.merchant_id.clone(),
+                connector: connector_name.to_owned(),
+                connector_status: dispute_details.connector_status,
+                connector_dispute_id: dispute_details.connector_dispute_id,
+                connector_reason: dispute_details.connector_reason,
+                connector_reason_code: dispute_details.connector_reason_code,
+                challenge_required_by: dispute_details.challenge_required_by,
+                connector_created_at: dispute_details.created_at,
+                connector_updated_at: dispute_details.updated_at,
+            };
+            db.insert_dispute(new_dispute)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to insert the dispute")
+        }
+    }
+}
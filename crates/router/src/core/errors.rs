@@ -265,6 +265,8 @@ pub enum ConnectorError {
     MissingConnectorTransactionID,
     #[error("Missing connector refund ID")]
     MissingConnectorRefundID,
+    #[error("File validation failed: {reason}")]
+    FileValidationFailed { reason: String },
     #[error("Webhooks not implemented for this connector")]
     WebhooksNotImplemented,
     #[error("Failed to decode webhook event body")]
@@ -353,6 +355,8 @@ pub enum ProcessTrackerError {
     EParsingError(error_stack::Report<ParsingError>),
     #[error("Validation Error Received: {0}")]
     EValidationError(error_stack::Report<ValidationError>),
+    #[error("Received Error WebhooksFlowError: {0}")]
+    EWebhooksFlowError(error_stack::Report<WebhooksFlowError>),
 }
 
 macro_rules! error_to_process_tracker_error {
@@ -398,6 +402,21 @@ error_to_process_tracker_error!(
     ProcessTrackerError::EValidationError(error_stack::Report<ValidationError>)
 );
 
+error_to_process_tracker_error!(
+    error_stack::Report<WebhooksFlowError>,
+    ProcessTrackerError::EWebhooksFlowError(error_stack::Report<WebhooksFlowError>)
+);
+
+#[derive(Debug, thiserror::Error)]
+pub enum FileStorageError {
+    #[error("Failed to upload the file to the file storage")]
+    UploadFailed,
+    #[error("Failed to retrieve the file from the file storage")]
+    RetrieveFailed,
+    #[error("Failed to delete the file from the file storage")]
+    DeleteFailed,
+}
+
 #[derive(Debug, thiserror::Error)]
 pub enum WebhooksFlowError {
     #[error("Merchant webhook config not found")]
@@ -410,8 +429,22 @@ pub enum WebhooksFlowError {
     PaymentsCoreFailed,
     #[error("Refunds core flow failed")]
     RefundsCoreFailed,
+    #[error("Disputes core flow failed")]
+    DisputeCoreFailed,
+    #[error("Payouts core flow failed")]
+    PayoutsCoreFailed,
+    #[error("Mandates core flow failed")]
+    MandateCoreFailed,
     #[error("Webhook event creation failed")]
     WebhookEventCreationFailed,
+    #[error("Webhook event updation failed")]
+    WebhookEventUpdationFailed,
+    #[error("Failed to record the webhook delivery attempt")]
+    WebhookDeliveryAttemptCreationFailed,
+    #[error("Failed to schedule the outgoing webhook delivery task")]
+    OutgoingWebhookTaskCreationFailed,
+    #[error("Failed to sign the outgoing webhook")]
+    OutgoingWebhookSigningFailed,
     #[error("Unable to fork webhooks flow for outgoing webhooks")]
     ForkFlowFailed,
     #[error("Webhook api call to merchant failed")]
@@ -420,4 +453,6 @@ pub enum WebhooksFlowError {
     NotReceivedByMerchant,
     #[error("Resource not found")]
     ResourceNotFound,
+    #[error("Webhook source verification failed")]
+    WebhookSourceVerificationFailed,
 }
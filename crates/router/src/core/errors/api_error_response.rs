@@ -83,6 +83,12 @@ pub enum ApiErrorResponse {
     GenericUnauthorized { message: String },
     #[error(error_type = ErrorType::InvalidRequestError, code = "IR_19", message = "{message}")]
     NotSupported { message: String },
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_20", message = "The {resource} is being processed by another request. Retry after some time")]
+    ResourceBusy { resource: String },
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_21", message = "Keys for idempotent requests can only be used with the same parameters they were first used with. Use a different key to make a different request")]
+    IdempotencyKeyReused,
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_22", message = "Too many requests have been made with this API key. Retry after {retry_after_seconds} seconds")]
+    RateLimitExceeded { retry_after_seconds: u64 },
 
     #[error(error_type = ErrorType::ConnectorError, code = "CE_00", message = "{code}: {message}", ignore = "status_code")]
     ExternalConnectorError {
@@ -121,8 +127,20 @@ pub enum ApiErrorResponse {
     DuplicatePaymentMethod,
     #[error(error_type = ErrorType::DuplicateRequest, code = "HE_01", message = "The payment with the specified payment_id '{payment_id}' already exists in our records")]
     DuplicatePayment { payment_id: String },
+    #[error(error_type = ErrorType::DuplicateRequest, code = "HE_01", message = "The payout with the specified payout_id '{payout_id}' already exists in our records")]
+    DuplicatePayout { payout_id: String },
     #[error(error_type = ErrorType::ObjectNotFound, code = "HE_02", message = "Refund does not exist in our records")]
     RefundNotFound,
+    #[error(error_type = ErrorType::ObjectNotFound, code = "HE_02", message = "Payout does not exist in our records")]
+    PayoutNotFound,
+    #[error(error_type = ErrorType::ObjectNotFound, code = "HE_02", message = "Dispute does not exist in our records")]
+    DisputeNotFound { dispute_id: String },
+    #[error(error_type = ErrorType::ObjectNotFound, code = "HE_02", message = "File does not exist in our records")]
+    FileNotFound,
+    #[error(error_type = ErrorType::ObjectNotFound, code = "HE_02", message = "File not available")]
+    FileNotAvailable,
+    #[error(error_type = ErrorType::ObjectNotFound, code = "HE_02", message = "Event does not exist in our records")]
+    EventNotFound,
     #[error(error_type = ErrorType::ObjectNotFound, code = "HE_02", message = "Customer does not exist in our records")]
     CustomerNotFound,
     #[error(error_type = ErrorType::ObjectNotFound, code = "RE_02", message = "Config key does not exist in our records.")]
@@ -149,6 +167,10 @@ pub enum ApiErrorResponse {
     MandateValidationFailed { reason: String },
     #[error(error_type= ErrorType::ValidationError, code = "HE_03", message = "The payment has not succeeded yet. Please pass a successful payment to initiate refund")]
     PaymentNotSucceeded,
+    #[error(error_type = ErrorType::ValidationError, code = "HE_03", message = "Dispute status validation failed")]
+    DisputeStatusValidationFailed { reason: String },
+    #[error(error_type = ErrorType::ValidationError, code = "HE_03", message = "File validation failed")]
+    FileValidationFailed { reason: String },
     #[error(error_type= ErrorType::ObjectNotFound, code = "HE_04", message = "Successful payment not found for the given payment id")]
     SuccessfulPaymentNotFound,
     #[error(error_type = ErrorType::ObjectNotFound, code = "HE_04", message = "The connector provided in the request is incorrect or not available")]
@@ -208,6 +230,8 @@ impl actix_web::ResponseError for ApiErrorResponse {
             Self::RefundAmountExceedsPaymentAmount => StatusCode::BAD_REQUEST, // 400
             Self::MaximumRefundCount => StatusCode::BAD_REQUEST, // 400
             Self::PreconditionFailed { .. } => StatusCode::BAD_REQUEST, // 400
+            Self::ResourceBusy { .. } => StatusCode::CONFLICT, // 409
+            Self::RateLimitExceeded { .. } => StatusCode::TOO_MANY_REQUESTS, // 429
 
             Self::PaymentAuthorizationFailed { .. }
             | Self::PaymentAuthenticationFailed { .. }
@@ -218,11 +242,20 @@ impl actix_web::ResponseError for ApiErrorResponse {
             | Self::RefundNotPossible { .. }
             | Self::VerificationFailed { .. }
             | Self::PaymentUnexpectedState { .. }
-            | Self::MandateValidationFailed { .. } => StatusCode::BAD_REQUEST, // 400
+            | Self::MandateValidationFailed { .. }
+            | Self::DisputeStatusValidationFailed { .. }
+            | Self::FileValidationFailed { .. } => StatusCode::BAD_REQUEST, // 400
 
             Self::InternalServerError => StatusCode::INTERNAL_SERVER_ERROR, // 500
-            Self::DuplicateRefundRequest | Self::DuplicatePayment { .. } => StatusCode::BAD_REQUEST, // 400
+            Self::DuplicateRefundRequest
+            | Self::DuplicatePayment { .. }
+            | Self::DuplicatePayout { .. } => StatusCode::BAD_REQUEST, // 400
             Self::RefundNotFound
+            | Self::PayoutNotFound
+            | Self::DisputeNotFound { .. }
+            | Self::FileNotFound
+            | Self::EventNotFound
+            | Self::FileNotAvailable
             | Self::CustomerNotFound
             | Self::MandateActive
             | Self::CustomerRedacted
@@ -244,9 +277,9 @@ impl actix_web::ResponseError for ApiErrorResponse {
             | Self::DuplicateMerchantConnectorAccount
             | Self::DuplicatePaymentMethod
             | Self::DuplicateMandate => StatusCode::BAD_REQUEST, // 400
-            Self::ReturnUrlUnavailable => StatusCode::SERVICE_UNAVAILABLE, // 503
-            Self::PaymentNotSucceeded => StatusCode::BAD_REQUEST,          // 400
-            Self::NotImplemented { .. } => StatusCode::NOT_IMPLEMENTED,    // 501
+            Self::ReturnUrlUnavailable => StatusCode::SERVICE_UNAVAILABLE,  // 503
+            Self::PaymentNotSucceeded => StatusCode::BAD_REQUEST,           // 400
+            Self::NotImplemented { .. } => StatusCode::NOT_IMPLEMENTED,     // 501
         }
     }
 
@@ -343,6 +376,15 @@ impl common_utils::errors::ErrorSwitch<api_models::errors::types::ApiErrorRespon
             Self::GenericUnauthorized { message } => {
                 AER::Unauthorized(ApiError::new("IR", 18, message.to_string(), None))
             }
+            Self::ResourceBusy { resource } => {
+                AER::Conflict(ApiError::new("IR", 20, format!("The {resource} is being processed by another request. Retry after some time"), None))
+            }
+            Self::IdempotencyKeyReused => {
+                AER::BadRequest(ApiError::new("IR", 21, "Keys for idempotent requests can only be used with the same parameters they were first used with. Use a different key to make a different request", None))
+            }
+            Self::RateLimitExceeded { retry_after_seconds } => {
+                AER::TooManyRequests(ApiError::new("IR", 22, format!("Too many requests have been made with this API key. Retry after {retry_after_seconds} seconds"), None))
+            }
             Self::ExternalConnectorError {
                 code,
                 message,
@@ -378,9 +420,27 @@ impl common_utils::errors::ErrorSwitch<api_models::errors::types::ApiErrorRespon
             Self::DuplicatePayment { payment_id } => {
                 AER::BadRequest(ApiError::new("HE", 1, format!("The payment with the specified payment_id '{payment_id}' already exists in our records"), None))
             }
+            Self::DuplicatePayout { payout_id } => {
+                AER::BadRequest(ApiError::new("HE", 1, format!("The payout with the specified payout_id '{payout_id}' already exists in our records"), None))
+            }
             Self::RefundNotFound => {
                 AER::NotFound(ApiError::new("HE", 2, "Refund does not exist in our records.", None))
             }
+            Self::PayoutNotFound => {
+                AER::NotFound(ApiError::new("HE", 2, "Payout does not exist in our records", None))
+            }
+            Self::DisputeNotFound { .. } => {
+                AER::NotFound(ApiError::new("HE", 2, "Dispute does not exist in our records", None))
+            }
+            Self::FileNotFound => {
+                AER::NotFound(ApiError::new("HE", 2, "File does not exist in our records", None))
+            }
+            Self::FileNotAvailable => {
+                AER::NotFound(ApiError::new("HE", 2, "File not available", None))
+            }
+            Self::EventNotFound => {
+                AER::NotFound(ApiError::new("HE", 2, "Event does not exist in our records", None))
+            }
             Self::CustomerNotFound => {
                 AER::NotFound(ApiError::new("HE", 2, "Customer does not exist in our records", None))
             }
@@ -413,6 +473,12 @@ impl common_utils::errors::ErrorSwitch<api_models::errors::types::ApiErrorRespon
                 AER::BadRequest(ApiError::new("HE", 3, "Mandate Validation Failed", Some(Extra { reason: Some(reason.clone()), ..Default::default() })))
             }
             Self::PaymentNotSucceeded => AER::BadRequest(ApiError::new("HE", 3, "The payment has not succeeded yet. Please pass a successful payment to initiate refund", None)),
+            Self::DisputeStatusValidationFailed { reason } => {
+                AER::BadRequest(ApiError::new("HE", 3, "Dispute status validation failed", Some(Extra { reason: Some(reason.clone()), ..Default::default() })))
+            }
+            Self::FileValidationFailed { reason } => {
+                AER::BadRequest(ApiError::new("HE", 3, "File validation failed", Some(Extra { reason: Some(reason.clone()), ..Default::default() })))
+            }
             Self::SuccessfulPaymentNotFound => {
                 AER::NotFound(ApiError::new("HE", 4, "Successful payment not found for the given payment id", None))
             }
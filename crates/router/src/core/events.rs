@@ -0,0 +1,151 @@
+use error_stack::{report, ResultExt};
+use router_env::{instrument, tracing};
+
+use super::webhooks;
+use crate::{
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    logger,
+    routes::AppState,
+    services,
+    types::{api, storage, transformers::ForeignFrom},
+    utils::OptionExt,
+};
+
+#[instrument(skip(state))]
+pub async fn list_events(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    constraints: api::EventListConstraints,
+) -> RouterResponse<api::EventListResponse> {
+    let events = state
+        .store
+        .find_events_by_merchant_id(&merchant_account.merchant_id, constraints)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to retrieve events")?;
+
+    Ok(services::ApplicationResponse::Json(
+        api::EventListResponse {
+            data: events
+                .into_iter()
+                .map(api::EventResponse::foreign_from)
+                .collect(),
+        },
+    ))
+}
+
+#[instrument(skip(state))]
+pub async fn retrieve_event(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    event_id: String,
+) -> RouterResponse<api::EventResponse> {
+    let event = find_event(state, &merchant_account, &event_id).await?;
+    let response = get_event_details(state, event).await?;
+    Ok(services::ApplicationResponse::Json(response))
+}
+
+/// Sends the webhook of the event once more to each of the merchant's webhook endpoints
+/// subscribed to the event, outside of the automatic retries. The delivery attempts are recorded
+/// irrespective of whether the merchant acknowledges them.
+#[instrument(skip(state))]
+pub async fn redeliver_event(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    event_id: String,
+) -> RouterResponse<api::EventResponse> {
+    let event = find_event(state, &merchant_account, &event_id).await?;
+    let request_body = event
+        .request_body
+        .clone()
+        .get_required_value("request_body")
+        .change_context(errors::ApiErrorResponse::PreconditionFailed {
+            message: "The event was created before webhook payloads were recorded and cannot be redelivered".to_string(),
+        })?;
+
+    let webhook_endpoints =
+        webhooks::utils::get_subscribed_webhook_endpoints(&merchant_account, event.event_type)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to obtain the webhook endpoints of the merchant")?;
+    if webhook_endpoints.is_empty() {
+        return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "No webhook endpoint of the merchant is subscribed to the event".to_string(),
+        }));
+    }
+
+    for webhook_endpoint in webhook_endpoints {
+        let delivery_result = webhooks::trigger_webhook_to_merchant(
+            state,
+            &merchant_account,
+            event.clone(),
+            &webhook_endpoint,
+            &request_body,
+        )
+        .await;
+        if let Err(error) = delivery_result {
+            match error.current_context() {
+                // The failed attempt is visible in the delivery attempts of the event
+                errors::WebhooksFlowError::CallToMerchantFailed
+                | errors::WebhooksFlowError::NotReceivedByMerchant => {
+                    logger::info!(
+                        ?error,
+                        endpoint_id = %webhook_endpoint.endpoint_id,
+                        "Manual redelivery of the webhook failed"
+                    );
+                }
+                _ => {
+                    return Err(error
+                        .change_context(errors::ApiErrorResponse::InternalServerError)
+                        .attach_printable("Failed to redeliver the webhook"));
+                }
+            }
+        }
+    }
+
+    // The event is fetched again as a successful delivery updates its delivery status
+    let event = find_event(state, &merchant_account, &event_id).await?;
+    let response = get_event_details(state, event).await?;
+    Ok(services::ApplicationResponse::Json(response))
+}
+
+async fn find_event(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    event_id: &str,
+) -> RouterResult<storage::Event> {
+    let event = state
+        .store
+        .find_event_by_event_id(event_id)
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::EventNotFound))?;
+
+    // Events are looked up by their globally unique id, so ownership is checked separately
+    if event.merchant_id.as_deref() != Some(merchant_account.merchant_id.as_str()) {
+        return Err(report!(errors::ApiErrorResponse::EventNotFound));
+    }
+    Ok(event)
+}
+
+async fn get_event_details(
+    state: &AppState,
+    event: storage::Event,
+) -> RouterResult<api::EventResponse> {
+    let delivery_attempts = state
+        .store
+        .find_event_delivery_attempts_by_event_id(&event.event_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to retrieve the delivery attempts of the event")?;
+
+    let request_body = event.request_body.clone();
+    Ok(api::EventResponse {
+        request_body,
+        delivery_attempts: Some(
+            delivery_attempts
+                .into_iter()
+                .map(api::EventDeliveryAttemptResponse::foreign_from)
+                .collect(),
+        ),
+        ..api::EventResponse::foreign_from(event)
+    })
+}
@@ -0,0 +1,265 @@
+use std::str::FromStr;
+
+use error_stack::{report, IntoReport, ResultExt};
+use router_env::{instrument, tracing};
+
+use crate::{
+    consts,
+    core::{
+        disputes,
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        utils as core_utils,
+    },
+    logger,
+    routes::AppState,
+    services,
+    types::{
+        self, api,
+        storage::{self, enums as storage_enums},
+    },
+    utils,
+};
+
+#[instrument(skip(state))]
+pub async fn files_create_core(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    create_file_request: api::CreateFileRequest,
+) -> RouterResponse<api::CreateFileResponse> {
+    let db = &*state.store;
+    let file_id = utils::generate_id(consts::ID_LENGTH, "file");
+    let file_key = get_file_key(&merchant_account.merchant_id, &file_id);
+
+    let file_new = storage::FileMetadataNew {
+        file_id: file_id.clone(),
+        merchant_id: merchant_account.merchant_id.clone(),
+        file_name: create_file_request.file_name.clone(),
+        file_size: create_file_request.file_size,
+        file_type: create_file_request.file_type.to_string(),
+        provider_file_id: None,
+        file_upload_provider: None,
+        available: false,
+    };
+    let file_metadata = db
+        .insert_file_metadata(file_new)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to insert file metadata")?;
+
+    state
+        .file_storage_client
+        .upload_file(&file_key, create_file_request.file.clone())
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to upload file to the file storage")?;
+
+    let (provider_file_id, file_upload_provider) = match &create_file_request.dispute_id {
+        Some(dispute_id) => {
+            let upload_result = upload_file_to_connector(
+                state,
+                &merchant_account,
+                &create_file_request,
+                dispute_id,
+                &file_key,
+            )
+            .await;
+            let (provider_file_id, file_upload_provider) = match upload_result {
+                Ok(upload_response) => upload_response,
+                Err(error) => {
+                    delete_unavailable_file(state, &merchant_account, &file_id).await;
+                    return Err(error);
+                }
+            };
+            (Some(provider_file_id), file_upload_provider)
+        }
+        None => (None, storage_enums::FileUploadProvider::Router),
+    };
+
+    let file_metadata_update = storage::FileMetadataUpdate::Update {
+        provider_file_id,
+        file_upload_provider: Some(file_upload_provider),
+        available: true,
+    };
+    db.update_file_metadata(file_metadata, file_metadata_update)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!("Unable to update file metadata with file_id: {file_id}")
+        })?;
+
+    Ok(services::ApplicationResponse::Json(
+        api::CreateFileResponse { file_id },
+    ))
+}
+
+#[instrument(skip(state))]
+pub async fn files_retrieve_core(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    file_id: String,
+) -> RouterResponse<serde_json::Value> {
+    let file_metadata = find_file_metadata(state, &merchant_account, &file_id).await?;
+    utils::when(!file_metadata.available, || {
+        Err(report!(errors::ApiErrorResponse::FileNotAvailable))
+    })?;
+
+    let file_type = mime::Mime::from_str(&file_metadata.file_type)
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to parse the file type of the stored file")?;
+    let file_data = state
+        .file_storage_client
+        .retrieve_file(&get_file_key(&merchant_account.merchant_id, &file_id))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to retrieve file from the file storage")?;
+
+    Ok(services::ApplicationResponse::FileData((
+        file_data, file_type,
+    )))
+}
+
+#[instrument(skip(state))]
+pub async fn files_delete_core(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    file_id: String,
+) -> RouterResponse<serde_json::Value> {
+    let file_metadata = find_file_metadata(state, &merchant_account, &file_id).await?;
+
+    // Files forwarded to a connector are only removed from our records, connectors do not
+    // allow deleting documents which may already be attached to a dispute. A file which is not
+    // available may not have been stored at all, so failing to delete it is not an error.
+    if let Err(error) = state
+        .file_storage_client
+        .delete_file(&get_file_key(&merchant_account.merchant_id, &file_id))
+        .await
+    {
+        if file_metadata.available {
+            return Err(error
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Unable to delete file from the file storage"));
+        }
+        logger::warn!(
+            ?error,
+            "Unable to delete unavailable file from the file storage"
+        );
+    }
+
+    state
+        .store
+        .delete_file_metadata_by_merchant_id_file_id(&merchant_account.merchant_id, &file_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!("Unable to delete file metadata with file_id: {file_id}")
+        })?;
+
+    Ok(services::ApplicationResponse::StatusOk)
+}
+
+/// Removes a file which could not be forwarded to the connector, so that neither the stored file
+/// nor its metadata are left behind. The original error is returned to the merchant, the failures
+/// to clean up are only logged.
+async fn delete_unavailable_file(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    file_id: &str,
+) {
+    if let Err(error) = state
+        .file_storage_client
+        .delete_file(&get_file_key(&merchant_account.merchant_id, file_id))
+        .await
+    {
+        logger::error!(?error, "Unable to delete file from the file storage");
+    }
+    if let Err(error) = state
+        .store
+        .delete_file_metadata_by_merchant_id_file_id(&merchant_account.merchant_id, file_id)
+        .await
+    {
+        logger::error!(?error, "Unable to delete file metadata");
+    }
+}
+
+/// Files are stored under the merchant's namespace in the file storage
+fn get_file_key(merchant_id: &str, file_id: &str) -> String {
+    format!("{merchant_id}/{file_id}")
+}
+
+async fn find_file_metadata(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    file_id: &str,
+) -> RouterResult<storage::FileMetadata> {
+    state
+        .store
+        .find_file_metadata_by_merchant_id_file_id(&merchant_account.merchant_id, file_id)
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::FileNotFound))
+}
+
+/// Forwards the file to the connector against which the dispute was raised, returning the
+/// identifier of the file at the connector
+async fn upload_file_to_connector(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    create_file_request: &api::CreateFileRequest,
+    dispute_id: &str,
+    file_key: &str,
+) -> RouterResult<(String, storage_enums::FileUploadProvider)> {
+    let dispute = disputes::find_dispute(state, merchant_account, dispute_id).await?;
+
+    let connector_data = api::ConnectorData::get_connector_by_name(
+        &state.conf.connectors,
+        &dispute.connector,
+        api::GetToken::Connector,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to get the connector")?;
+    connector_data
+        .connector
+        .validate_file_upload(
+            create_file_request.purpose,
+            create_file_request.file_size,
+            create_file_request.file_type.clone(),
+        )
+        .map_err(|error| match error.current_context() {
+            errors::ConnectorError::FileValidationFailed { reason } => {
+                report!(errors::ApiErrorResponse::FileValidationFailed {
+                    reason: reason.to_owned(),
+                })
+            }
+            _ => error.change_context(errors::ApiErrorResponse::InternalServerError),
+        })?;
+
+    let file_upload_provider = storage_enums::FileUploadProvider::from_str(&dispute.connector)
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Connector does not support file upload")?;
+
+    let (payment_intent, payment_attempt) =
+        disputes::find_disputed_payment(state, merchant_account, &dispute).await?;
+
+    let router_data: types::UploadFileRouterData = core_utils::construct_dispute_router_data(
+        state,
+        merchant_account,
+        &payment_intent,
+        &payment_attempt,
+        &dispute,
+        types::UploadFileRequestData {
+            file_key: file_key.to_owned(),
+            file: create_file_request.file.clone(),
+            file_type: create_file_request.file_type.clone(),
+            file_size: create_file_request.file_size,
+            connector_dispute_id: dispute.connector_dispute_id.clone(),
+        },
+    )
+    .await?;
+
+    let response = disputes::call_connector(state, &dispute.connector, &router_data)
+        .await?
+        .map_err(|error| disputes::connector_error_response(&dispute.connector, error))?;
+
+    Ok((response.provider_file_id, file_upload_provider))
+}
@@ -2,10 +2,9 @@ use error_stack::{report, ResultExt};
 use router_env::{instrument, logger, tracing};
 use storage_models::enums as storage_enums;
 
-use super::payments::helpers;
+use super::{payments::helpers, webhooks};
 use crate::{
     core::errors::{self, RouterResponse, StorageErrorExt},
-    db::StorageInterface,
     routes::AppState,
     services,
     types::{
@@ -35,13 +34,14 @@ pub async fn get_mandate(
     ))
 }
 
-#[instrument(skip(db))]
+#[instrument(skip(state))]
 pub async fn revoke_mandate(
-    db: &dyn StorageInterface,
+    state: &AppState,
     merchant_account: storage::MerchantAccount,
     req: mandates::MandateId,
 ) -> RouterResponse<mandates::MandateRevokedResponse> {
-    let mandate = db
+    let mandate = state
+        .store
         .update_mandate_by_merchant_id_mandate_id(
             &merchant_account.merchant_id,
             &req.mandate_id,
@@ -52,12 +52,13 @@ pub async fn revoke_mandate(
         .await
         .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::MandateNotFound))?;
 
-    Ok(services::ApplicationResponse::Json(
-        mandates::MandateRevokedResponse {
-            mandate_id: mandate.mandate_id,
-            status: mandate.mandate_status.foreign_into(),
-        },
-    ))
+    let response = mandates::MandateRevokedResponse {
+        mandate_id: mandate.mandate_id.clone(),
+        status: mandate.mandate_status.foreign_into(),
+    };
+    trigger_mandate_webhook(state, &merchant_account, mandate).await;
+
+    Ok(services::ApplicationResponse::Json(response))
 }
 
 #[instrument(skip(state))]
@@ -136,7 +137,10 @@ where
                     .change_context(errors::ApiErrorResponse::MandateNotFound),
             }?;
 
-            resp.payment_method_id = Some(mandate.payment_method_id);
+            resp.payment_method_id = Some(mandate.payment_method_id.clone());
+            if mandate.mandate_status == storage_enums::MandateStatus::Revoked {
+                trigger_mandate_webhook(state, merchant_account, mandate).await;
+            }
         }
         None => {
             if resp.request.get_setup_mandate_details().is_some() {
@@ -173,15 +177,17 @@ where
                             mandate_id: new_mandate_data.mandate_id.clone(),
                             connector_mandate_id: new_mandate_data.connector_mandate_id.clone(),
                         });
-                    state
-                        .store
-                        .insert_mandate(new_mandate_data)
-                        .await
-                        .map_err(|err| {
-                            err.to_duplicate_response(
-                                errors::ApiErrorResponse::DuplicateRefundRequest,
-                            )
-                        })?;
+                    let mandate =
+                        state
+                            .store
+                            .insert_mandate(new_mandate_data)
+                            .await
+                            .map_err(|err| {
+                                err.to_duplicate_response(
+                                    errors::ApiErrorResponse::DuplicateRefundRequest,
+                                )
+                            })?;
+                    trigger_mandate_webhook(state, merchant_account, mandate).await;
                 };
             } else if resp.request.get_setup_future_usage().is_some() {
                 helpers::call_payment_method(
@@ -199,6 +205,25 @@ where
     Ok(resp)
 }
 
+/// Notifies the merchant of mandates becoming active or being revoked. The webhook is best
+/// effort, failing to send it does not fail the operation that changed the mandate.
+async fn trigger_mandate_webhook(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    mandate: storage::Mandate,
+) {
+    if !matches!(
+        mandate.mandate_status,
+        storage_enums::MandateStatus::Active | storage_enums::MandateStatus::Revoked
+    ) {
+        return;
+    }
+
+    if let Err(error) = webhooks::create_mandate_event(state, merchant_account, mandate).await {
+        logger::error!(?error, "Failed to trigger the mandate webhook");
+    }
+}
+
 pub trait MandateBehaviour {
     fn get_amount(&self) -> i64;
     fn get_setup_future_usage(&self) -> Option<storage_models::enums::FutureUsage>;
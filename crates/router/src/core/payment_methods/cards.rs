@@ -447,7 +447,12 @@ pub async fn list_payment_methods(
     let address = payment_intent
         .as_ref()
         .async_map(|pi| async {
-            helpers::get_address_by_id(db, pi.shipping_address_id.clone()).await
+            helpers::get_address_by_id(
+                db,
+                pi.shipping_address_id.clone(),
+                merchant_account.storage_scheme,
+            )
+            .await
         })
         .await
         .transpose()?
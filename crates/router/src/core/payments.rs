@@ -1,20 +1,23 @@
 pub mod access_token;
+pub mod expiry;
+pub mod fees;
 pub mod flows;
 pub mod helpers;
 pub mod operations;
+pub mod retry;
+pub mod routing;
 pub mod transformers;
 
 use std::{fmt::Debug, marker::PhantomData, time::Instant};
 
-use common_utils::ext_traits::AsyncExt;
 use error_stack::{IntoReport, ResultExt};
 use futures::future::join_all;
 use router_env::{instrument, tracing};
 use time;
 
 pub use self::operations::{
-    PaymentCancel, PaymentCapture, PaymentConfirm, PaymentCreate, PaymentMethodValidate,
-    PaymentResponse, PaymentSession, PaymentStatus, PaymentUpdate,
+    PaymentCancel, PaymentCapture, PaymentConfirm, PaymentCreate, PaymentIncrementalAuthorization,
+    PaymentMethodValidate, PaymentResponse, PaymentSession, PaymentStatus, PaymentUpdate,
 };
 use self::{
     flows::{ConstructFlowSpecificData, Feature},
@@ -22,8 +25,9 @@ use self::{
 };
 use crate::{
     core::{
-        errors::{self, RouterResponse, RouterResult},
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
         payment_methods::vault,
+        webhooks,
     },
     db::StorageInterface,
     logger, pii,
@@ -33,8 +37,9 @@ use crate::{
     types::{
         self, api,
         storage::{self, enums as storage_enums},
+        transformers::ForeignInto,
     },
-    utils::OptionExt,
+    utils::{self, OptionExt},
 };
 
 #[instrument(skip_all)]
@@ -69,133 +74,230 @@ where
 
     tracing::Span::current().record("payment_id", &format!("{:?}", validate_result.payment_id));
 
-    let (operation, mut payment_data, customer_details) = operation
-        .to_get_tracker()?
-        .get_trackers(
-            state,
-            &validate_result.payment_id,
-            &req,
-            validate_result.mandate_type,
-            &merchant_account,
-        )
-        .await?;
-
-    let (operation, customer) = operation
-        .to_domain()?
-        .get_or_create_customer_details(
-            &*state.store,
-            &mut payment_data,
-            customer_details,
-            validate_result.merchant_id,
-        )
-        .await
-        .change_context(errors::ApiErrorResponse::InternalServerError)
-        .attach_printable("Failed while fetching/creating customer")?;
-
-    let (operation, payment_method_data) = operation
-        .to_domain()?
-        .make_pm_data(state, &mut payment_data, validate_result.storage_scheme)
-        .await?;
-
-    payment_data.payment_method_data = payment_method_data;
-
-    let connector_details = operation
-        .to_domain()?
-        .get_connector(
-            &merchant_account,
-            state,
-            &req,
-            payment_data.payment_attempt.connector.as_ref(),
+    let lock_resource_id = if validate_result.requires_lock {
+        Some(
+            get_payment_id_for_locking(
+                state,
+                validate_result.merchant_id,
+                &validate_result.payment_id,
+                validate_result.storage_scheme,
+            )
+            .await?,
         )
-        .await?;
+    } else {
+        None
+    };
 
-    let connector = match should_call_connector(&operation, &payment_data) {
-        true => Some(
-            route_connector(
+    let operation_result = async {
+        let (operation, mut payment_data, customer_details) = operation
+            .to_get_tracker()?
+            .get_trackers(
                 state,
+                &validate_result.payment_id,
+                &req,
+                validate_result.mandate_type,
                 &merchant_account,
-                &mut payment_data,
-                connector_details,
             )
-            .await?,
-        ),
-        false => None,
-    };
+            .await?;
 
-    let (operation, mut payment_data) = operation
-        .to_update_tracker()?
-        .update_trackers(
-            &*state.store,
-            &validate_result.payment_id,
-            payment_data,
-            customer.clone(),
-            validate_result.storage_scheme,
-        )
-        .await?;
+        let (operation, customer) = operation
+            .to_domain()?
+            .get_or_create_customer_details(
+                &*state.store,
+                &mut payment_data,
+                customer_details,
+                validate_result.merchant_id,
+                validate_result.storage_scheme,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while fetching/creating customer")?;
 
-    operation
-        .to_domain()?
-        .add_task_to_process_tracker(state, &payment_data.payment_attempt)
-        .await?;
+        let (operation, payment_method_data) = operation
+            .to_domain()?
+            .make_pm_data(state, &mut payment_data, validate_result.storage_scheme)
+            .await?;
 
-    if let Some(connector_details) = connector {
-        payment_data = match connector_details {
-            api::ConnectorCallType::Single(connector) => {
-                call_connector_service(
-                    state,
-                    &merchant_account,
-                    &validate_result.payment_id,
-                    connector,
-                    &operation,
-                    payment_data,
-                    &customer,
-                    call_connector_action,
-                )
-                .await?
-            }
-            api::ConnectorCallType::Multiple(connectors) => {
-                call_multiple_connectors_service(
-                    state,
-                    &merchant_account,
-                    connectors,
-                    &operation,
-                    payment_data,
-                    &customer,
-                )
-                .await?
-            }
-            api::ConnectorCallType::Routing => {
-                let connector = payment_data
-                    .payment_attempt
-                    .connector
-                    .clone()
-                    .get_required_value("connector")
-                    .change_context(errors::ApiErrorResponse::InternalServerError)
-                    .attach_printable("No connector selected for routing")?;
+        payment_data.payment_method_data = payment_method_data;
 
-                let connector_data = api::ConnectorData::get_connector_by_name(
-                    &state.conf.connectors,
-                    &connector,
-                    api::GetToken::Connector,
-                )
-                .change_context(errors::ApiErrorResponse::InternalServerError)?;
+        let connector_details = operation
+            .to_domain()?
+            .get_connector(
+                &merchant_account,
+                state,
+                &req,
+                payment_data.payment_attempt.connector.as_ref(),
+            )
+            .await?;
 
-                call_connector_service(
+        let connector = match should_call_connector(&operation, &payment_data) {
+            true => Some(
+                route_connector(
                     state,
                     &merchant_account,
-                    &validate_result.payment_id,
-                    connector_data,
-                    &operation,
-                    payment_data,
-                    &customer,
-                    call_connector_action,
+                    &mut payment_data,
+                    connector_details,
                 )
-                .await?
-            }
+                .await?,
+            ),
+            false => None,
         };
-        vault::Vault::delete_locker_payment_method_by_lookup_key(state, &payment_data.token).await
+
+        let (operation, mut payment_data) = operation
+            .to_update_tracker()?
+            .update_trackers(
+                &*state.store,
+                &validate_result.payment_id,
+                payment_data,
+                customer.clone(),
+                validate_result.storage_scheme,
+            )
+            .await?;
+
+        operation
+            .to_domain()?
+            .add_task_to_process_tracker(state, &payment_data.payment_attempt)
+            .await?;
+
+        if let Some(connector_details) = connector {
+            payment_data = match connector_details {
+                api::ConnectorCallType::Single(connector) => {
+                    call_connector_service(
+                        state,
+                        &merchant_account,
+                        &validate_result.payment_id,
+                        connector,
+                        &operation,
+                        payment_data,
+                        &customer,
+                        call_connector_action,
+                    )
+                    .await?
+                }
+                api::ConnectorCallType::Multiple(connectors) => {
+                    call_multiple_connectors_service(
+                        state,
+                        &merchant_account,
+                        connectors,
+                        &operation,
+                        payment_data,
+                        &customer,
+                    )
+                    .await?
+                }
+                api::ConnectorCallType::Retryable(connectors) => {
+                    // Only the authorize flow is retried with the other connectors
+                    if is_confirm_operation(&operation)
+                        && matches!(call_connector_action, CallConnectorAction::Trigger)
+                    {
+                        retry::call_connector_service_with_retries(
+                            state,
+                            &merchant_account,
+                            &validate_result.payment_id,
+                            connectors,
+                            payment_data,
+                            &customer,
+                        )
+                        .await?
+                    } else {
+                        let connector = connectors
+                            .into_iter()
+                            .next()
+                            .get_required_value("connector")
+                            .change_context(errors::ApiErrorResponse::InternalServerError)
+                            .attach_printable("No connector selected for routing")?;
+
+                        call_connector_service(
+                            state,
+                            &merchant_account,
+                            &validate_result.payment_id,
+                            connector,
+                            &operation,
+                            payment_data,
+                            &customer,
+                            call_connector_action,
+                        )
+                        .await?
+                    }
+                }
+                api::ConnectorCallType::Routing => {
+                    let connector = payment_data
+                        .payment_attempt
+                        .connector
+                        .clone()
+                        .get_required_value("connector")
+                        .change_context(errors::ApiErrorResponse::InternalServerError)
+                        .attach_printable("No connector selected for routing")?;
+
+                    let connector_data = api::ConnectorData::get_connector_by_name(
+                        &state.conf.connectors,
+                        &connector,
+                        api::GetToken::Connector,
+                    )
+                    .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+                    call_connector_service(
+                        state,
+                        &merchant_account,
+                        &validate_result.payment_id,
+                        connector_data,
+                        &operation,
+                        payment_data,
+                        &customer,
+                        call_connector_action,
+                    )
+                    .await?
+                }
+            };
+            vault::Vault::delete_locker_payment_method_by_lookup_key(state, &payment_data.token)
+                .await
+        }
+        RouterResult::Ok((payment_data, req, customer))
+    };
+
+    match lock_resource_id {
+        Some(payment_id) => {
+            let resource = services::locking::LockResource::Payment {
+                merchant_id: &merchant_account.merchant_id,
+                payment_id: &payment_id,
+            };
+            services::locking::with_lock(state, resource, operation_result).await
+        }
+        None => operation_result.await,
+    }
+}
+
+/// The payment is locked by its payment id, which is looked up when the operation identifies the
+/// payment by its connector transaction id or attempt id instead
+async fn get_payment_id_for_locking(
+    state: &AppState,
+    merchant_id: &str,
+    payment_id: &api::PaymentIdType,
+    storage_scheme: storage_enums::MerchantStorageScheme,
+) -> RouterResult<String> {
+    let db = &*state.store;
+    let payment_attempt = match payment_id {
+        api::PaymentIdType::PaymentIntentId(payment_id) => return Ok(payment_id.to_owned()),
+        api::PaymentIdType::ConnectorTransactionId(connector_transaction_id) => {
+            db.find_payment_attempt_by_merchant_id_connector_txn_id(
+                merchant_id,
+                connector_transaction_id,
+                storage_scheme,
+            )
+            .await
+        }
+        api::PaymentIdType::PaymentAttemptId(attempt_id) => {
+            db.find_payment_attempt_by_merchant_id_attempt_id(
+                merchant_id,
+                attempt_id,
+                storage_scheme,
+            )
+            .await
+        }
     }
-    Ok((payment_data, req, customer))
+    .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound))?;
+
+    Ok(payment_attempt.payment_id)
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -226,13 +328,24 @@ where
 {
     let (payment_data, req, customer) = payments_operation_core(
         state,
-        merchant_account,
+        merchant_account.clone(),
         operation.clone(),
         req,
         call_connector_action,
     )
     .await?;
 
+    if is_create_operation(&operation) {
+        create_payment_created_event(
+            state,
+            &merchant_account,
+            payment_data.clone(),
+            customer.clone(),
+            &operation,
+        )
+        .await;
+    }
+
     Res::generate_response(
         Some(req),
         payment_data,
@@ -247,6 +360,56 @@ fn is_start_pay<Op: Debug>(operation: &Op) -> bool {
     format!("{operation:?}").eq("PaymentStart")
 }
 
+fn is_confirm_operation<Op: Debug>(operation: &Op) -> bool {
+    format!("{operation:?}").eq("PaymentConfirm")
+}
+
+fn is_create_operation<Op: Debug>(operation: &Op) -> bool {
+    format!("{operation:?}").eq("PaymentCreate")
+}
+
+/// Sends the `payment_created` event of a payment which has just been created. The payment is not
+/// failed if its event could not be created.
+async fn create_payment_created_event<F, Op>(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    payment_data: PaymentData<F>,
+    customer: Option<storage::Customer>,
+    operation: &Op,
+) where
+    F: Clone,
+    Op: Debug + Clone,
+{
+    let payments_response = match <api::PaymentsResponse as transformers::ToResponse<
+        (),
+        PaymentData<F>,
+        Op,
+    >>::generate_response(
+        None,
+        payment_data,
+        customer,
+        services::AuthFlow::Merchant,
+        &state.conf.server,
+        operation.clone(),
+    ) {
+        Ok(services::ApplicationResponse::Json(payments_response)) => payments_response,
+        Ok(_) => return,
+        Err(error) => {
+            logger::error!(
+                ?error,
+                "Failed to generate the payment for the created event"
+            );
+            return;
+        }
+    };
+
+    if let Err(error) =
+        webhooks::create_payment_created_event(state, merchant_account, payments_response).await
+    {
+        logger::error!(?error, "Failed to create the event for the created payment");
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
 pub async fn handle_payments_redirect_response<'a, F>(
     state: &AppState,
@@ -349,16 +512,60 @@ where
     // To perform router related operation for PaymentResponse
     PaymentResponse: Operation<F, Req>,
 {
-    let db = &*state.store;
-
     let stime_connector = Instant::now();
 
+    let router_data = call_connector(
+        state,
+        merchant_account,
+        &connector,
+        &payment_data,
+        customer,
+        call_connector_action,
+    )
+    .await?;
+
+    let response = update_payment_data_with_connector_response(
+        state,
+        merchant_account,
+        payment_id,
+        payment_data,
+        router_data,
+    )
+    .await?;
+
+    let etime_connector = Instant::now();
+    let duration_connector = etime_connector.saturating_duration_since(stime_connector);
+    tracing::info!(duration = format!("Duration taken: {}", duration_connector.as_millis()));
+
+    Ok(response)
+}
+
+/// Calls the connector and returns its response, without updating the payment with it
+#[instrument(skip_all)]
+pub async fn call_connector<F, Req>(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    connector: &api::ConnectorData,
+    payment_data: &PaymentData<F>,
+    customer: &Option<storage::Customer>,
+    call_connector_action: CallConnectorAction,
+) -> RouterResult<types::RouterData<F, Req, types::PaymentsResponseData>>
+where
+    F: Send + Clone,
+
+    // To create connector flow specific interface data
+    PaymentData<F>: ConstructFlowSpecificData<F, Req, types::PaymentsResponseData>,
+    types::RouterData<F, Req, types::PaymentsResponseData>: Feature<F, Req> + Send,
+
+    // To construct connector flow specific api
+    dyn api::Connector: services::api::ConnectorIntegration<F, Req, types::PaymentsResponseData>,
+{
     let mut router_data = payment_data
         .construct_router_data(state, connector.connector.id(), merchant_account)
         .await?;
 
     let add_access_token_result = router_data
-        .add_access_token(state, &connector, merchant_account)
+        .add_access_token(state, connector, merchant_account)
         .await?;
 
     access_token::update_router_data_with_access_token_result(
@@ -367,13 +574,13 @@ where
         &call_connector_action,
     );
 
-    let router_data_res = if !(add_access_token_result.connector_supports_access_token
+    if !(add_access_token_result.connector_supports_access_token
         && router_data.access_token.is_none())
     {
         router_data
             .decide_flows(
                 state,
-                &connector,
+                connector,
                 customer,
                 call_connector_action,
                 merchant_account,
@@ -381,30 +588,67 @@ where
             .await
     } else {
         Ok(router_data)
-    };
+    }
+}
 
-    let response = router_data_res
-        .async_and_then(|response| async {
-            let operation = helpers::response_operation::<F, Req>();
-            let payment_data = operation
-                .to_post_update_tracker()?
-                .update_tracker(
-                    db,
-                    payment_id,
-                    payment_data,
-                    response,
-                    merchant_account.storage_scheme,
-                )
-                .await?;
-            Ok(payment_data)
-        })
+/// Updates the payment attempt, payment intent and connector response with the response of the
+/// connector
+#[instrument(skip_all)]
+pub async fn update_payment_data_with_connector_response<F, Req>(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    payment_id: &api::PaymentIdType,
+    payment_data: PaymentData<F>,
+    router_data: types::RouterData<F, Req, types::PaymentsResponseData>,
+) -> RouterResult<PaymentData<F>>
+where
+    F: Send + Clone,
+
+    // To perform router related operation for PaymentResponse
+    PaymentResponse: Operation<F, Req>,
+{
+    let operation = helpers::response_operation::<F, Req>();
+    let previous_status = payment_data.payment_attempt.status;
+    let payment_slice = services::success_rate::PaymentSlice::new(
+        &payment_data.payment_attempt,
+        payment_data.payment_method_data.as_ref(),
+    );
+
+    let payment_data = operation
+        .to_post_update_tracker()?
+        .update_tracker(
+            &*state.store,
+            payment_id,
+            payment_data,
+            router_data,
+            merchant_account.storage_scheme,
+        )
         .await?;
 
-    let etime_connector = Instant::now();
-    let duration_connector = etime_connector.saturating_duration_since(stime_connector);
-    tracing::info!(duration = format!("Duration taken: {}", duration_connector.as_millis()));
+    services::success_rate::record_authorization_outcome(
+        state,
+        previous_status,
+        &payment_data.payment_attempt,
+        &payment_slice,
+    )
+    .await;
 
-    Ok(response)
+    // The authorization is not failed because its cancellation could not be scheduled
+    if previous_status != storage_enums::AttemptStatus::Authorized
+        && payment_data.payment_attempt.status == storage_enums::AttemptStatus::Authorized
+    {
+        if let Err(error) = expiry::add_authorization_expiry_task(
+            &*state.store,
+            merchant_account,
+            &payment_data.payment_attempt,
+        )
+        .await
+        {
+            logger::error!(?error, "Failed to schedule the expiry of the authorization");
+        }
+    }
+
+    Ok(payment_data)
 }
 
 pub async fn call_multiple_connectors_service<F, Op, Req>(
@@ -511,6 +755,7 @@ where
     pub force_sync: Option<bool>,
     pub payment_method_data: Option<api::PaymentMethodData>,
     pub refunds: Vec<storage::Refund>,
+    pub captures: Vec<storage::Capture>,
     pub sessions_token: Vec<api::SessionToken>,
     pub card_cvc: Option<pii::Secret<String>>,
     pub email: Option<masking::Secret<String, pii::Email>>,
@@ -598,6 +843,10 @@ pub fn should_call_connector<Op: Debug, F: Clone>(
                 storage_enums::IntentStatus::RequiresCapture
             )
         }
+        "PaymentIncrementalAuthorization" => matches!(
+            payment_data.payment_intent.status,
+            storage_enums::IntentStatus::RequiresCapture
+        ),
         "PaymentSession" => true,
         _ => false,
     }
@@ -667,6 +916,211 @@ pub async fn add_process_sync_task(
     Ok(())
 }
 
+const PAYMENTS_CAPTURE_RUNNER: &str = "PAYMENTS_CAPTURE_WORKFLOW";
+const PAYMENTS_CAPTURE_TASK: &str = "PAYMENTS_CAPTURE";
+
+fn get_capture_process_tracker_id(payment_attempt: &storage::PaymentAttempt) -> String {
+    pt_utils::get_process_tracker_id(
+        PAYMENTS_CAPTURE_RUNNER,
+        PAYMENTS_CAPTURE_TASK,
+        &payment_attempt.attempt_id,
+        &payment_attempt.merchant_id,
+    )
+}
+
+/// Schedules the capture of a payment authorized with the `scheduled` capture method, at the
+/// `capture_on` time of the payment attempt or `capture_delay_hours` after the authorization.
+pub async fn add_process_capture_task(
+    db: &dyn StorageInterface,
+    payment_attempt: &storage::PaymentAttempt,
+) -> Result<(), errors::ProcessTrackerError> {
+    let process_tracker_id = get_capture_process_tracker_id(payment_attempt);
+
+    // The authorization of the payment may be observed more than once, for instance by a sync
+    // following the authorization, but the capture is scheduled only once
+    if db.find_process_by_id(&process_tracker_id).await?.is_some() {
+        return Ok(());
+    }
+
+    let schedule_time = match (
+        payment_attempt.capture_on,
+        payment_attempt.capture_delay_hours,
+    ) {
+        (Some(capture_on), _) => capture_on,
+        (None, Some(capture_delay_hours)) => common_utils::date_time::now()
+            .saturating_add(time::Duration::hours(capture_delay_hours.into())),
+        (None, None) => return Err(errors::ProcessTrackerError::MissingRequiredField),
+    };
+
+    let tracking_data = api::PaymentsCaptureRequest {
+        payment_id: Some(payment_attempt.payment_id.clone()),
+        merchant_id: Some(payment_attempt.merchant_id.clone()),
+        ..Default::default()
+    };
+    let process_tracker_entry =
+        <storage::ProcessTracker as storage::ProcessTrackerExt>::make_process_tracker_new(
+            process_tracker_id,
+            PAYMENTS_CAPTURE_TASK,
+            PAYMENTS_CAPTURE_RUNNER,
+            tracking_data,
+            schedule_time,
+        )?;
+
+    db.insert_process(process_tracker_entry).await?;
+    Ok(())
+}
+
+/// Cancels the pending scheduled capture of the payment. The payment remains authorized, and can
+/// then be captured or cancelled through the API.
+#[instrument(skip_all)]
+pub async fn cancel_scheduled_capture(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    payment_id: String,
+) -> RouterResponse<api::PaymentsResponse> {
+    // The payment is locked as its capture does, so that a capture in progress completes before
+    // the task is cancelled
+    let resource = services::locking::LockResource::Payment {
+        merchant_id: &merchant_account.merchant_id,
+        payment_id: &payment_id,
+    };
+    services::locking::with_lock(
+        state,
+        resource,
+        cancel_capture_task(state, &merchant_account, &payment_id),
+    )
+    .await?;
+
+    payments_core::<api::PSync, api::PaymentsResponse, _, _, _>(
+        state,
+        merchant_account.clone(),
+        PaymentStatus,
+        api::PaymentsRetrieveRequest {
+            resource_id: api::PaymentIdType::PaymentIntentId(payment_id),
+            merchant_id: Some(merchant_account.merchant_id),
+            force_sync: false,
+            connector: None,
+            param: None,
+        },
+        services::AuthFlow::Merchant,
+        CallConnectorAction::Trigger,
+    )
+    .await
+}
+
+async fn cancel_capture_task(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    payment_id: &str,
+) -> RouterResult<()> {
+    let db = &*state.store;
+    let payment_attempt = db
+        .find_payment_attempt_by_payment_id_merchant_id(
+            payment_id,
+            &merchant_account.merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound))?;
+
+    // The task is only finished after the capture, once the lock is released
+    utils::when(
+        payment_attempt.status != storage_enums::AttemptStatus::Authorized,
+        || {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: "The payment does not have a pending scheduled capture".to_string(),
+            })
+        },
+    )?;
+
+    let process = db
+        .find_process_by_id(&get_capture_process_tracker_id(&payment_attempt))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while fetching the scheduled capture task")?
+        .filter(|process| process.status != storage_enums::ProcessTrackerStatus::Finish)
+        .ok_or(errors::ApiErrorResponse::PreconditionFailed {
+            message: "The payment does not have a pending scheduled capture".to_string(),
+        })?;
+
+    <storage::ProcessTracker as storage::ProcessTrackerExt>::finish_with_status(
+        process,
+        db,
+        "CANCELLED_BY_MERCHANT".to_string(),
+    )
+    .await
+    .into_report()
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed while cancelling the scheduled capture task")
+}
+
+/// Details of the payment against which the routing rules are evaluated
+pub fn get_routing_input<F>(payment_data: &PaymentData<F>) -> routing::RoutingInput
+where
+    F: Clone,
+{
+    let (card_network, card_issuing_country) = match payment_data.payment_method_data {
+        Some(api::PaymentMethodData::Card(ref card)) => {
+            (card.card_network.clone(), card.card_issuing_country.clone())
+        }
+        _ => (None, None),
+    };
+
+    routing::RoutingInput {
+        amount: payment_data.payment_attempt.amount,
+        currency: payment_data.currency.foreign_into(),
+        card_network,
+        country: payment_data
+            .address
+            .billing
+            .as_ref()
+            .and_then(|billing| billing.address.as_ref())
+            .and_then(|address| address.country.clone()),
+        payment_method_type: payment_data
+            .payment_attempt
+            .payment_method_type
+            .map(ForeignInto::foreign_into),
+        card_issuing_country,
+    }
+}
+
+/// Details of the connectors needed by the routing algorithm to rank them for the payment
+pub async fn get_connector_stats(
+    state: &AppState,
+    merchant_id: &str,
+    routing_algorithm: &api::RoutingAlgorithm,
+    routing_input: &routing::RoutingInput,
+    payment_slice: &services::success_rate::PaymentSlice,
+) -> routing::ConnectorStats {
+    match routing_algorithm {
+        api::RoutingAlgorithm::MaxConversion(max_conversion) => routing::ConnectorStats {
+            success_rates: services::success_rate::get_success_rates(
+                state,
+                merchant_id,
+                &max_conversion.connectors,
+                payment_slice,
+            )
+            .await,
+            default_exploration_percentage: state
+                .conf
+                .success_rate_routing
+                .default_exploration_percentage,
+            ..routing::ConnectorStats::default()
+        },
+        api::RoutingAlgorithm::MinCost(connectors) => routing::ConnectorStats {
+            estimated_fees: fees::get_estimated_fees(
+                &*state.store,
+                merchant_id,
+                connectors,
+                routing_input,
+            )
+            .await,
+            ..routing::ConnectorStats::default()
+        },
+        _ => routing::ConnectorStats::default(),
+    }
+}
+
 pub async fn route_connector<F>(
     state: &AppState,
     merchant_account: &storage::MerchantAccount,
@@ -691,23 +1145,78 @@ where
                 .change_context(errors::ApiErrorResponse::InternalServerError)
                 .attach_printable("Could not decode merchant routing rules")?;
 
-            let connector_name = match routing_algorithm {
-                api::RoutingAlgorithm::Single(conn) => conn.to_string(),
-            };
+            let routing_input = get_routing_input(payment_data);
 
-            let connector_data = api::ConnectorData::get_connector_by_name(
-                &state.conf.connectors,
-                &connector_name,
-                api::GetToken::Connector,
+            let payment_slice = services::success_rate::PaymentSlice::new(
+                &payment_data.payment_attempt,
+                payment_data.payment_method_data.as_ref(),
+            );
+            let connector_stats = get_connector_stats(
+                state,
+                &merchant_account.merchant_id,
+                &routing_algorithm,
+                &routing_input,
+                &payment_slice,
             )
-            .change_context(errors::ApiErrorResponse::InternalServerError)
-            .attach_printable("Routing algorithm gave invalid connector")?;
+            .await;
 
-            payment_data.payment_attempt.connector = Some(connector_name);
+            let routing_decision =
+                routing::perform_routing(&routing_algorithm, &routing_input, &connector_stats)?;
+            logger::debug!(?routing_decision, "Routing decision for the payment");
 
-            Ok(api::ConnectorCallType::Single(connector_data))
+            // Connectors which are failing are skipped, so that the payment goes to the next
+            // connector given by routing instead
+            let routed_connectors = services::circuit_breaker::filter_available_connectors(
+                state,
+                routing_decision.connectors,
+            )
+            .await;
+
+            let connectors = routed_connectors
+                .iter()
+                .map(|connector| {
+                    api::ConnectorData::get_connector_by_name(
+                        &state.conf.connectors,
+                        &connector.to_string(),
+                        api::GetToken::Connector,
+                    )
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Routing algorithm gave invalid connector")
+                })
+                .collect::<RouterResult<Vec<_>>>()?;
+
+            payment_data.payment_attempt.connector =
+                routed_connectors.first().map(ToString::to_string);
+            // Fees are only estimated when routing by cost, in which case they are reused from the
+            // ranking of the connectors
+            payment_data.payment_attempt.estimated_fee = routed_connectors
+                .first()
+                .and_then(|connector| connector_stats.estimated_fees.get(connector))
+                .copied();
+
+            // Retries create a new payment attempt for every connector tried, which is only
+            // supported when the attempts are stored in postgres
+            if state.conf.payment_retry.max_attempts > 1
+                && connectors.len() > 1
+                && merchant_account.storage_scheme
+                    == storage_enums::MerchantStorageScheme::PostgresOnly
+            {
+                Ok(api::ConnectorCallType::Retryable(connectors))
+            } else {
+                let connector_data = connectors
+                    .into_iter()
+                    .next()
+                    .get_required_value("connector")
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Routing algorithm did not return any connector")?;
+
+                Ok(api::ConnectorCallType::Single(connector_data))
+            }
         }
 
-        call_type @ api::ConnectorCallType::Multiple(_) => Ok(call_type),
+        call_type
+        @ (api::ConnectorCallType::Multiple(_) | api::ConnectorCallType::Retryable(_)) => {
+            Ok(call_type)
+        }
     }
 }
@@ -1,18 +1,28 @@
 use std::fmt::Debug;
 
-use common_utils::ext_traits::AsyncExt;
+use common_utils::{date_time, ext_traits::AsyncExt};
 use error_stack::{IntoReport, ResultExt};
+use router_env::{instrument, tracing};
 
 use crate::{
     core::{
         errors::{self, RouterResult},
         payments,
     },
+    logger,
     routes::AppState,
-    services,
-    types::{self, api as api_types, storage},
+    scheduler::utils as pt_utils,
+    services::{self, locking},
+    types::{
+        self, api as api_types,
+        storage::{self, enums as storage_enums, ProcessTrackerExt},
+    },
+    utils::ValueExt,
 };
 
+const ACCESS_TOKEN_REFRESH_RUNNER: &str = "ACCESS_TOKEN_REFRESH_WORKFLOW";
+const ACCESS_TOKEN_REFRESH_TASK: &str = "ACCESS_TOKEN_REFRESH";
+
 /// This function replaces the request and response type of routerdata with the
 /// request and response type passed
 /// # Arguments
@@ -83,17 +93,16 @@ pub async fn add_access_token<
     router_data: &types::RouterData<F, Req, Res>,
 ) -> RouterResult<types::AddAccessTokenResult> {
     if connector.connector_name.supports_access_token() {
-        let merchant_id = &merchant_account.merchant_id;
-        let store = &*state.store;
-        let old_access_token = store
-            .get_access_token(merchant_id, connector.connector.id())
-            .await
-            .change_context(errors::ApiErrorResponse::InternalServerError)
-            .attach_printable("DB error when accessing the access token")?;
+        let old_access_token = get_access_token(
+            state,
+            &merchant_account.merchant_id,
+            connector.connector.id(),
+        )
+        .await?;
 
         let res = match old_access_token {
-            Some(access_token) => Ok(Some(access_token)),
-            None => {
+            Some(access_token) if !is_refresh_due(state, &access_token) => Ok(Some(access_token)),
+            old_access_token => {
                 let cloned_router_data = router_data.clone();
                 let refresh_token_request_data = types::AccessTokenRequestData::try_from(
                     router_data.connector_auth_type.clone(),
@@ -111,30 +120,14 @@ pub async fn add_access_token<
                         refresh_token_request_data,
                         refresh_token_response_data,
                     );
-                refresh_connector_auth(
+                refresh_access_token(
                     state,
                     connector,
                     merchant_account,
                     &refresh_token_router_data,
+                    old_access_token,
                 )
                 .await?
-                .async_map(|access_token| async {
-                    //Store the access token in db
-                    let store = &*state.store;
-                    // This error should not be propagated, we don't want payments to fail once we have
-                    // the access token, the next request will create new access token
-                    let _ = store
-                        .set_access_token(
-                            merchant_id,
-                            connector.connector.id(),
-                            access_token.clone(),
-                        )
-                        .await
-                        .change_context(errors::ApiErrorResponse::InternalServerError)
-                        .attach_printable("DB error when setting the access token");
-                    Some(access_token)
-                })
-                .await
             }
         };
 
@@ -150,6 +143,274 @@ pub async fn add_access_token<
     }
 }
 
+pub async fn get_access_token(
+    state: &AppState,
+    merchant_id: &str,
+    connector_name: &str,
+) -> RouterResult<Option<types::AccessToken>> {
+    state
+        .store
+        .get_access_token(merchant_id, connector_name)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("DB error when accessing the access token")
+}
+
+/// Number of seconds until the access token is to be refreshed. Tokens which live for less than
+/// twice the refresh ahead duration are refreshed half way through their lifetime instead, so that
+/// they are not refreshed all the time.
+fn get_seconds_until_refresh(refresh_ahead_seconds: u32, access_token: &types::AccessToken) -> i64 {
+    let refresh_ahead_seconds = i64::from(refresh_ahead_seconds);
+    let lifetime = access_token.lifetime.unwrap_or(access_token.expires);
+    let refresh_after_seconds = lifetime
+        .saturating_sub(refresh_ahead_seconds)
+        .max(lifetime / 2);
+
+    access_token
+        .expires
+        .saturating_sub(lifetime.saturating_sub(refresh_after_seconds))
+}
+
+/// Whether the access token is close enough to its expiry to be refreshed
+pub fn is_refresh_due(state: &AppState, access_token: &types::AccessToken) -> bool {
+    get_seconds_until_refresh(
+        state.conf.access_token_refresh.refresh_ahead_seconds,
+        access_token,
+    ) <= 0
+}
+
+/// Refreshes the access token of the connector, such that only one request refreshes it at a
+/// time. While another request is refreshing the token, a token which is about to expire is used
+/// as is, and a request without a token waits for the refreshed token to be stored. The request
+/// refreshes the token itself if it is not stored in time.
+#[instrument(skip_all)]
+pub async fn refresh_access_token(
+    state: &AppState,
+    connector: &api_types::ConnectorData,
+    merchant_account: &storage::MerchantAccount,
+    router_data: &types::RouterData<
+        api_types::AccessTokenAuth,
+        types::AccessTokenRequestData,
+        types::AccessToken,
+    >,
+    current_access_token: Option<types::AccessToken>,
+) -> RouterResult<Result<Option<types::AccessToken>, types::ErrorResponse>> {
+    let merchant_id = &merchant_account.merchant_id;
+    let connector_name = connector.connector.id();
+    let lock_resource = locking::LockResource::AccessToken {
+        merchant_id,
+        connector: connector_name,
+    };
+
+    let lock = match current_access_token {
+        Some(ref access_token) => match locking::try_acquire_lock(state, lock_resource).await? {
+            Some(lock) => Some(lock),
+            None => return Ok(Ok(Some(access_token.clone()))),
+        },
+        None => {
+            let config = &state.conf.access_token_refresh;
+            let mut lock = None;
+            for attempt in 0..=config.lock_retries {
+                if attempt > 0 {
+                    tokio::time::sleep(std::time::Duration::from_millis(
+                        config.delay_between_retries_in_milliseconds.into(),
+                    ))
+                    .await;
+
+                    if let Some(access_token) =
+                        get_access_token(state, merchant_id, connector_name).await?
+                    {
+                        return Ok(Ok(Some(access_token)));
+                    }
+                }
+
+                lock = locking::try_acquire_lock(state, lock_resource).await?;
+                if lock.is_some() {
+                    break;
+                }
+            }
+
+            if lock.is_none() {
+                logger::warn!(
+                    connector = %connector_name,
+                    "Access token was not refreshed by another request in time, refreshing it without the lock"
+                );
+            }
+            lock
+        }
+    };
+
+    // The token may have been refreshed by another request since it was read
+    let refresh_result = match get_access_token(state, merchant_id, connector_name).await {
+        Ok(Some(access_token)) if !is_refresh_due(state, &access_token) => {
+            Ok(Ok(Some(access_token)))
+        }
+        Ok(_) => {
+            refresh_and_store_access_token(state, connector, merchant_account, router_data).await
+        }
+        Err(error) => Err(error),
+    };
+    if let Some(lock) = lock {
+        lock.release(state).await;
+    }
+
+    Ok(match (refresh_result?, current_access_token) {
+        // The current token is still valid, so the refresh is left to a later request
+        (Err(error), Some(access_token)) => {
+            logger::warn!(
+                ?error,
+                "Failed to refresh the access token ahead of its expiry"
+            );
+            Ok(Some(access_token))
+        }
+        (refreshed_access_token, _) => refreshed_access_token,
+    })
+}
+
+async fn refresh_and_store_access_token(
+    state: &AppState,
+    connector: &api_types::ConnectorData,
+    merchant_account: &storage::MerchantAccount,
+    router_data: &types::RouterData<
+        api_types::AccessTokenAuth,
+        types::AccessTokenRequestData,
+        types::AccessToken,
+    >,
+) -> RouterResult<Result<Option<types::AccessToken>, types::ErrorResponse>> {
+    let merchant_id = &merchant_account.merchant_id;
+    let connector_name = connector.connector.id();
+
+    Ok(
+        refresh_connector_auth(state, connector, merchant_account, router_data)
+            .await?
+            .async_map(|access_token| async {
+                //Store the access token in db
+                let store = &*state.store;
+                // This error should not be propagated, we don't want payments to fail once we have
+                // the access token, the next request will create new access token
+                if let Err(error) = store
+                    .set_access_token(merchant_id, connector_name, access_token.clone())
+                    .await
+                {
+                    logger::error!(?error, "DB error when setting the access token");
+                }
+
+                if let Err(error) =
+                    add_access_token_refresh_task(state, merchant_id, connector_name, &access_token)
+                        .await
+                {
+                    logger::error!(?error, "Failed to schedule the refresh of the access token");
+                }
+                Some(access_token)
+            })
+            .await,
+    )
+}
+
+/// Time at which the access token is to be refreshed by the scheduler
+pub fn get_refresh_schedule_time(
+    state: &AppState,
+    access_token: &types::AccessToken,
+) -> time::PrimitiveDateTime {
+    let refresh_after_seconds = get_seconds_until_refresh(
+        state.conf.access_token_refresh.refresh_ahead_seconds,
+        access_token,
+    )
+    .max(0);
+
+    date_time::now().saturating_add(time::Duration::seconds(refresh_after_seconds))
+}
+
+/// Schedules the refresh of the access token by the scheduler, unless it is already scheduled. The
+/// task reschedules itself every time it refreshes the token, and is resumed when it has stopped,
+/// such as after a failed refresh, once a payment refreshes the token.
+async fn add_access_token_refresh_task(
+    state: &AppState,
+    merchant_id: &str,
+    connector_name: &str,
+    access_token: &types::AccessToken,
+) -> Result<(), errors::ProcessTrackerError> {
+    if !state.conf.access_token_refresh.background_refresh {
+        return Ok(());
+    }
+
+    let db = &*state.store;
+    let process_tracker_id = pt_utils::get_process_tracker_id(
+        ACCESS_TOKEN_REFRESH_RUNNER,
+        ACCESS_TOKEN_REFRESH_TASK,
+        connector_name,
+        merchant_id,
+    );
+    let schedule_time = get_refresh_schedule_time(state, access_token);
+
+    match db.find_process_by_id(&process_tracker_id).await? {
+        Some(process) if process.status != storage_enums::ProcessTrackerStatus::Finish => Ok(()),
+        Some(process) => process.retry(db, schedule_time).await,
+        None => {
+            let process_tracker_entry = storage::ProcessTracker::make_process_tracker_new(
+                process_tracker_id,
+                ACCESS_TOKEN_REFRESH_TASK,
+                ACCESS_TOKEN_REFRESH_RUNNER,
+                storage::AccessTokenRefreshTrackingData {
+                    merchant_id: merchant_id.to_string(),
+                    connector: connector_name.to_string(),
+                },
+                schedule_time,
+            )?;
+
+            db.insert_process(process_tracker_entry).await?;
+            Ok(())
+        }
+    }
+}
+
+/// Router data for refreshing the access token of the connector account outside of a payment
+pub fn construct_access_token_router_data(
+    merchant_connector_account: storage::MerchantConnectorAccount,
+    reference_id: &str,
+) -> RouterResult<
+    types::RouterData<
+        api_types::AccessTokenAuth,
+        types::AccessTokenRequestData,
+        types::AccessToken,
+    >,
+> {
+    let auth_type: types::ConnectorAuthType = merchant_connector_account
+        .connector_account_details
+        .parse_value("ConnectorAuthType")
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+    let request = types::AccessTokenRequestData::try_from(auth_type.clone())
+        .into_report()
+        .attach_printable(
+            "Could not create access token request, invalid connector account credentials",
+        )?;
+
+    Ok(types::RouterData {
+        flow: std::marker::PhantomData,
+        merchant_id: merchant_connector_account.merchant_id,
+        connector: merchant_connector_account.connector_name,
+        // The refresh is not tied to a payment, the given reference is used instead
+        payment_id: reference_id.to_string(),
+        attempt_id: reference_id.to_string(),
+        status: storage_enums::AttemptStatus::default(),
+        payment_method: storage_enums::PaymentMethod::default(),
+        connector_auth_type: auth_type,
+        description: None,
+        return_url: None,
+        router_return_url: None,
+        payment_method_id: None,
+        address: payments::PaymentAddress::default(),
+        auth_type: storage_enums::AuthenticationType::default(),
+        connector_meta_data: merchant_connector_account.metadata,
+        amount_captured: None,
+        request,
+        response: Err(types::ErrorResponse::default()),
+        access_token: None,
+        session_token: None,
+        reference_id: None,
+    })
+}
+
 pub async fn refresh_connector_auth(
     state: &AppState,
     connector: &api_types::ConnectorData,
@@ -179,3 +440,34 @@ pub async fn refresh_connector_auth(
 
     Ok(access_token_router_data.response)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_access_token(expires: i64, lifetime: Option<i64>) -> types::AccessToken {
+        types::AccessToken {
+            token: "token".to_string(),
+            expires,
+            lifetime,
+        }
+    }
+
+    #[test]
+    fn test_refresh_is_due_half_way_through_short_lifetimes() {
+        let seconds_until_refresh =
+            |access_token: types::AccessToken| get_seconds_until_refresh(300, &access_token);
+
+        // Refreshed the refresh ahead duration before the expiry
+        assert_eq!(seconds_until_refresh(make_access_token(301, Some(3600))), 1);
+        assert_eq!(seconds_until_refresh(make_access_token(300, Some(3600))), 0);
+
+        // Refreshed half way through the lifetime, which is shorter than twice the refresh ahead
+        // duration
+        assert_eq!(seconds_until_refresh(make_access_token(250, Some(400))), 50);
+        assert_eq!(seconds_until_refresh(make_access_token(200, Some(400))), 0);
+
+        // A token just issued by the connector lives for the time left until it expires
+        assert_eq!(seconds_until_refresh(make_access_token(400, None)), 200);
+    }
+}
@@ -0,0 +1,202 @@
+use error_stack::{report, IntoReport, ResultExt};
+use router_env::{instrument, tracing};
+
+use crate::{
+    core::errors::{self, RouterResult},
+    db::StorageInterface,
+    scheduler::utils as pt_utils,
+    types::{
+        api,
+        storage::{self, ProcessTrackerExt},
+    },
+    utils::ValueExt,
+};
+
+const PAYMENTS_EXPIRY_RUNNER: &str = "PAYMENTS_EXPIRY_WORKFLOW";
+/// Task which cancels an authorized payment that has not been captured in time
+pub const AUTHORIZATION_EXPIRY_TASK: &str = "AUTHORIZATION_EXPIRY";
+/// Task which expires a payment that has not been confirmed in time
+pub const INTENT_EXPIRY_TASK: &str = "INTENT_EXPIRY";
+
+pub fn validate_payment_expiry(payment_expiry: &api::PaymentExpiry) -> RouterResult<()> {
+    validate_authorization_ttl_hours(payment_expiry.authorization_ttl_hours)?;
+
+    if payment_expiry.intent_ttl_minutes == Some(0) {
+        return Err(report!(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "payment_expiry.intent_ttl_minutes",
+        }))
+        .attach_printable("Payments must be allowed at least a minute before they expire");
+    }
+    Ok(())
+}
+
+pub fn validate_authorization_ttl_hours(authorization_ttl_hours: Option<u16>) -> RouterResult<()> {
+    if authorization_ttl_hours == Some(0) {
+        return Err(report!(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "authorization_ttl_hours",
+        }))
+        .attach_printable("Authorizations must be held for at least an hour before they expire");
+    }
+    Ok(())
+}
+
+fn get_payment_expiry(
+    merchant_account: &storage::MerchantAccount,
+) -> RouterResult<Option<api::PaymentExpiry>> {
+    merchant_account
+        .payment_expiry
+        .clone()
+        .map(|payment_expiry| payment_expiry.parse_value("PaymentExpiry"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the payment expiry of the merchant")
+}
+
+/// The number of hours for which an authorization is held before it is cancelled. The time
+/// configured for the connector takes precedence over that of the merchant, as the duration for
+/// which the funds can be held varies across connectors.
+pub fn get_authorization_ttl_hours(
+    merchant_account: &storage::MerchantAccount,
+    merchant_connector_account: Option<&storage::MerchantConnectorAccount>,
+) -> RouterResult<Option<i32>> {
+    let connector_ttl_hours =
+        merchant_connector_account.and_then(|account| account.authorization_ttl_hours);
+
+    Ok(match connector_ttl_hours {
+        Some(ttl_hours) => Some(ttl_hours),
+        None => get_payment_expiry(merchant_account)?
+            .and_then(|payment_expiry| payment_expiry.authorization_ttl_hours)
+            .map(i32::from),
+    })
+}
+
+async fn add_payment_expiry_task(
+    db: &dyn StorageInterface,
+    task: &str,
+    tracking_data: storage::PaymentExpiryTrackingData,
+    txn_id: &str,
+    schedule_time: time::PrimitiveDateTime,
+) -> Result<(), errors::ProcessTrackerError> {
+    let process_tracker_id = pt_utils::get_process_tracker_id(
+        PAYMENTS_EXPIRY_RUNNER,
+        task,
+        txn_id,
+        &tracking_data.merchant_id,
+    );
+
+    // The status change which starts the expiry may be observed more than once, for instance by
+    // a sync following the authorization, but the expiry is scheduled only once
+    if db.find_process_by_id(&process_tracker_id).await?.is_some() {
+        return Ok(());
+    }
+
+    let process_tracker_entry = storage::ProcessTracker::make_process_tracker_new(
+        process_tracker_id,
+        task,
+        PAYMENTS_EXPIRY_RUNNER,
+        tracking_data,
+        schedule_time,
+    )?;
+
+    db.insert_process(process_tracker_entry).await?;
+    Ok(())
+}
+
+/// Schedules the expiry of a payment which is awaiting a payment method or a confirmation, if
+/// the merchant has configured a time to live for such payments
+#[instrument(skip_all)]
+pub async fn add_intent_expiry_task(
+    db: &dyn StorageInterface,
+    merchant_account: &storage::MerchantAccount,
+    payment_intent: &storage::PaymentIntent,
+) -> RouterResult<()> {
+    let intent_ttl_minutes = match get_payment_expiry(merchant_account)?
+        .and_then(|payment_expiry| payment_expiry.intent_ttl_minutes)
+    {
+        Some(intent_ttl_minutes) => intent_ttl_minutes,
+        None => return Ok(()),
+    };
+
+    let schedule_time = payment_intent
+        .created_at
+        .saturating_add(time::Duration::minutes(intent_ttl_minutes.into()));
+
+    add_payment_expiry_task(
+        db,
+        INTENT_EXPIRY_TASK,
+        storage::PaymentExpiryTrackingData {
+            merchant_id: payment_intent.merchant_id.clone(),
+            payment_id: payment_intent.payment_id.clone(),
+        },
+        &payment_intent.payment_id,
+        schedule_time,
+    )
+    .await
+    .into_report()
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed while scheduling the expiry of the payment")
+}
+
+/// Schedules the cancellation of an authorized payment, if a time to live for authorizations is
+/// configured for the merchant or the connector the payment was authorized with
+#[instrument(skip_all)]
+pub async fn add_authorization_expiry_task(
+    db: &dyn StorageInterface,
+    merchant_account: &storage::MerchantAccount,
+    payment_attempt: &storage::PaymentAttempt,
+) -> RouterResult<()> {
+    // The connector account may have been deleted since the payment was authorized, in which case
+    // the time configured for the merchant is used
+    let merchant_connector_account = match payment_attempt.connector.as_deref() {
+        Some(connector) => db
+            .find_merchant_connector_account_by_merchant_id_connector(
+                &payment_attempt.merchant_id,
+                connector,
+            )
+            .await
+            .ok(),
+        None => None,
+    };
+
+    let authorization_ttl_hours =
+        match get_authorization_ttl_hours(merchant_account, merchant_connector_account.as_ref())? {
+            Some(authorization_ttl_hours) => authorization_ttl_hours,
+            None => return Ok(()),
+        };
+
+    let schedule_time = common_utils::date_time::now()
+        .saturating_add(time::Duration::hours(authorization_ttl_hours.into()));
+
+    add_payment_expiry_task(
+        db,
+        AUTHORIZATION_EXPIRY_TASK,
+        storage::PaymentExpiryTrackingData {
+            merchant_id: payment_attempt.merchant_id.clone(),
+            payment_id: payment_attempt.payment_id.clone(),
+        },
+        &payment_attempt.attempt_id,
+        schedule_time,
+    )
+    .await
+    .into_report()
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed while scheduling the cancellation of the authorization")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_zero_ttl_is_rejected() {
+        let payment_expiry = |authorization_ttl_hours, intent_ttl_minutes| api::PaymentExpiry {
+            authorization_ttl_hours,
+            intent_ttl_minutes,
+        };
+
+        assert!(validate_payment_expiry(&payment_expiry(Some(72), Some(30))).is_ok());
+        assert!(validate_payment_expiry(&payment_expiry(None, None)).is_ok());
+        assert!(validate_payment_expiry(&payment_expiry(Some(0), None)).is_err());
+        assert!(validate_payment_expiry(&payment_expiry(None, Some(0))).is_err());
+    }
+}
@@ -0,0 +1,235 @@
+use std::collections::HashMap;
+
+use api_models::{admin as admin_api, enums as api_enums};
+use error_stack::{report, ResultExt};
+use router_env::{instrument, tracing};
+
+use super::routing::RoutingInput;
+use crate::{
+    core::errors::{self, RouterResult},
+    db::StorageInterface,
+    logger,
+    types::storage,
+    utils::ValueExt,
+};
+
+pub fn validate_fee_schedule(fee_schedule: &admin_api::ConnectorFeeSchedule) -> RouterResult<()> {
+    let base_fee = admin_api::FeeSurcharge {
+        fixed_fee: fee_schedule.fixed_fee,
+        percentage_fee: fee_schedule.percentage_fee,
+    };
+
+    let all_fees_valid = std::iter::once(&base_fee)
+        .chain(fee_schedule.network_surcharges.values())
+        .chain(fee_schedule.currency_surcharges.values())
+        .chain(fee_schedule.cross_border_surcharge.as_ref())
+        .all(|fee| fee.fixed_fee >= 0 && (0.0..=100.0).contains(&fee.percentage_fee));
+    if !all_fees_valid {
+        return Err(report!(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "fee_schedule",
+        }))
+        .attach_printable("Fees cannot be negative, and percentages cannot exceed 100");
+    }
+
+    let is_valid_country =
+        |country: &String| country.len() == 2 && country.chars().all(|c| c.is_ascii_alphabetic());
+    if !fee_schedule
+        .acquiring_country
+        .as_ref()
+        .map_or(true, is_valid_country)
+    {
+        return Err(report!(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "fee_schedule.acquiring_country",
+        }))
+        .attach_printable("The acquiring country must be a two-letter ISO country code");
+    }
+    Ok(())
+}
+
+/// Estimated fee of the payment, in the lowest denomination of its currency
+pub fn estimate_fee(fee_schedule: &admin_api::ConnectorFeeSchedule, input: &RoutingInput) -> i64 {
+    let is_cross_border = match (
+        fee_schedule.acquiring_country.as_ref(),
+        input.card_issuing_country.as_ref(),
+    ) {
+        (Some(acquiring_country), Some(issuing_country)) => {
+            !acquiring_country.eq_ignore_ascii_case(issuing_country)
+        }
+        _ => false,
+    };
+
+    let surcharges = input
+        .card_network
+        .as_ref()
+        .and_then(|network| fee_schedule.network_surcharges.get(network))
+        .into_iter()
+        .chain(fee_schedule.currency_surcharges.get(&input.currency))
+        .chain(
+            fee_schedule
+                .cross_border_surcharge
+                .as_ref()
+                .filter(|_| is_cross_border),
+        );
+
+    let (fixed_fee, percentage_fee) = surcharges.fold(
+        (fee_schedule.fixed_fee, fee_schedule.percentage_fee),
+        |(fixed_fee, percentage_fee), surcharge| {
+            (
+                fixed_fee.saturating_add(surcharge.fixed_fee),
+                percentage_fee + surcharge.percentage_fee,
+            )
+        },
+    );
+
+    #[allow(clippy::as_conversions)]
+    let percentage_amount = (input.amount as f64 * percentage_fee / 100.0).round() as i64;
+    fixed_fee.saturating_add(percentage_amount)
+}
+
+/// Estimated fee of the payment when made through the connector, `None` if no fee schedule is
+/// configured for the connector account of the merchant
+#[instrument(skip_all)]
+pub async fn get_estimated_fee(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    connector: &str,
+    input: &RoutingInput,
+) -> RouterResult<Option<i64>> {
+    let merchant_connector_account = match db
+        .find_merchant_connector_account_by_merchant_id_connector(merchant_id, connector)
+        .await
+    {
+        Ok(merchant_connector_account) => merchant_connector_account,
+        Err(error) if error.current_context().is_db_not_found() => return Ok(None),
+        Err(error) => {
+            return Err(error)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed while fetching the merchant connector account")
+        }
+    };
+
+    let fee_schedule: admin_api::ConnectorFeeSchedule =
+        match merchant_connector_account.fee_schedule {
+            Some(fee_schedule) => fee_schedule
+                .parse_value("ConnectorFeeSchedule")
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to parse the fee schedule of the connector")?,
+            None => return Ok(None),
+        };
+
+    Ok(Some(estimate_fee(&fee_schedule, input)))
+}
+
+/// Estimated fees of the payment when made through each of the connectors. Connectors without a
+/// fee schedule, or whose fee could not be estimated, are left out and so ranked last.
+pub async fn get_estimated_fees(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    connectors: &[api_enums::RoutableConnectors],
+    input: &RoutingInput,
+) -> HashMap<api_enums::RoutableConnectors, i64> {
+    let mut estimated_fees = HashMap::with_capacity(connectors.len());
+    for connector in connectors {
+        match get_estimated_fee(db, merchant_id, &connector.to_string(), input).await {
+            Ok(Some(fee)) => {
+                estimated_fees.insert(*connector, fee);
+            }
+            Ok(None) => {}
+            Err(error) => {
+                logger::error!(?error, %connector, "Failed to estimate the fee of the connector")
+            }
+        }
+    }
+    estimated_fees
+}
+
+/// Estimated fee of the payment when made through the connector, recorded on the payment attempt
+/// only when the merchant routes payments by cost
+pub async fn get_min_cost_routing_fee(
+    db: &dyn StorageInterface,
+    merchant_account: &storage::MerchantAccount,
+    connector: &str,
+    input: &RoutingInput,
+) -> Option<i64> {
+    let routing_algorithm = merchant_account
+        .routing_algorithm
+        .clone()
+        .parse_value::<admin_api::RoutingAlgorithm>("RoutingAlgorithm");
+    if !matches!(
+        routing_algorithm,
+        Ok(admin_api::RoutingAlgorithm::MinCost(_))
+    ) {
+        return None;
+    }
+
+    get_estimated_fee(db, &merchant_account.merchant_id, connector, input)
+        .await
+        .map_err(|error| {
+            logger::error!(?error, %connector, "Failed to estimate the fee of the connector")
+        })
+        .ok()
+        .flatten()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fee_includes_applicable_surcharges() {
+        let fee_schedule = admin_api::ConnectorFeeSchedule {
+            fixed_fee: 30,
+            percentage_fee: 2.9,
+            network_surcharges: HashMap::from([(
+                api_enums::CardNetwork::AmericanExpress,
+                admin_api::FeeSurcharge {
+                    fixed_fee: 0,
+                    percentage_fee: 0.5,
+                },
+            )]),
+            currency_surcharges: HashMap::from([(
+                api_enums::Currency::EUR,
+                admin_api::FeeSurcharge {
+                    fixed_fee: 10,
+                    percentage_fee: 0.0,
+                },
+            )]),
+            acquiring_country: Some("US".to_string()),
+            cross_border_surcharge: Some(admin_api::FeeSurcharge {
+                fixed_fee: 0,
+                percentage_fee: 1.5,
+            }),
+        };
+        let input = |currency, card_network, card_issuing_country: &str| RoutingInput {
+            amount: 10000,
+            currency,
+            card_network,
+            country: None,
+            payment_method_type: None,
+            card_issuing_country: Some(card_issuing_country.to_string()),
+        };
+
+        assert_eq!(
+            estimate_fee(
+                &fee_schedule,
+                &input(
+                    api_enums::Currency::USD,
+                    Some(api_enums::CardNetwork::Visa),
+                    "us"
+                )
+            ),
+            320
+        );
+        assert_eq!(
+            estimate_fee(
+                &fee_schedule,
+                &input(
+                    api_enums::Currency::EUR,
+                    Some(api_enums::CardNetwork::AmericanExpress),
+                    "DE"
+                )
+            ),
+            530
+        );
+    }
+}
@@ -1,6 +1,7 @@
 pub mod authorize_flow;
 pub mod cancel_flow;
 pub mod capture_flow;
+pub mod incremental_authorization_flow;
 pub mod psync_flow;
 pub mod session_flow;
 pub mod verfiy_flow;
@@ -40,12 +40,13 @@ pub async fn get_address_for_payment_request(
     address_id: Option<&str>,
     merchant_id: &str,
     customer_id: &Option<String>,
+    storage_scheme: storage_enums::MerchantStorageScheme,
 ) -> CustomResult<Option<storage::Address>, errors::ApiErrorResponse> {
     Ok(match req_address {
         Some(address) => {
             match address_id {
                 Some(id) => Some(
-                    db.update_address(id.to_owned(), address.foreign_into())
+                    db.update_address(id.to_owned(), address.foreign_into(), storage_scheme)
                         .await
                         .map_err(|err| {
                             err.to_not_found_response(errors::ApiErrorResponse::AddressNotFound)
@@ -60,17 +61,20 @@ pub async fn get_address_for_payment_request(
 
                     let address_details = address.address.clone().unwrap_or_default();
                     Some(
-                        db.insert_address(storage::AddressNew {
-                            phone_number: address.phone.as_ref().and_then(|a| a.number.clone()),
-                            country_code: address
-                                .phone
-                                .as_ref()
-                                .and_then(|a| a.country_code.clone()),
-                            customer_id: customer_id.to_string(),
-                            merchant_id: merchant_id.to_string(),
-
-                            ..address_details.foreign_into()
-                        })
+                        db.insert_address(
+                            storage::AddressNew {
+                                phone_number: address.phone.as_ref().and_then(|a| a.number.clone()),
+                                country_code: address
+                                    .phone
+                                    .as_ref()
+                                    .and_then(|a| a.country_code.clone()),
+                                customer_id: customer_id.to_string(),
+                                merchant_id: merchant_id.to_string(),
+
+                                ..address_details.foreign_into()
+                            },
+                            storage_scheme,
+                        )
                         .await
                         .map_err(|_| errors::ApiErrorResponse::InternalServerError)?,
                     )
@@ -78,9 +82,11 @@ pub async fn get_address_for_payment_request(
             }
         }
         None => match address_id {
-            Some(id) => Some(db.find_address(id).await).transpose().map_err(|err| {
-                err.to_not_found_response(errors::ApiErrorResponse::AddressNotFound)
-            })?,
+            Some(id) => Some(db.find_address(id, storage_scheme).await)
+                .transpose()
+                .map_err(|err| {
+                    err.to_not_found_response(errors::ApiErrorResponse::AddressNotFound)
+                })?,
             None => None,
         },
     })
@@ -89,10 +95,11 @@ pub async fn get_address_for_payment_request(
 pub async fn get_address_by_id(
     db: &dyn StorageInterface,
     address_id: Option<String>,
+    storage_scheme: storage_enums::MerchantStorageScheme,
 ) -> CustomResult<Option<storage::Address>, errors::ApiErrorResponse> {
     match address_id {
         None => Ok(None),
-        Some(address_id) => Ok(db.find_address(&address_id).await.ok()),
+        Some(address_id) => Ok(db.find_address(&address_id, storage_scheme).await.ok()),
     }
 }
 
@@ -589,12 +596,17 @@ pub async fn get_customer_from_details<F: Clone>(
     customer_id: Option<String>,
     merchant_id: &str,
     payment_data: &mut PaymentData<F>,
+    storage_scheme: storage_enums::MerchantStorageScheme,
 ) -> CustomResult<Option<storage::Customer>, errors::StorageError> {
     match customer_id {
         None => Ok(None),
         Some(c_id) => {
             let customer = db
-                .find_customer_optional_by_customer_id_merchant_id(&c_id, merchant_id)
+                .find_customer_optional_by_customer_id_merchant_id(
+                    &c_id,
+                    merchant_id,
+                    storage_scheme,
+                )
                 .await?;
             payment_data.email = payment_data
                 .email
@@ -629,6 +641,7 @@ pub async fn create_customer_if_not_exist<'a, F: Clone, R>(
     payment_data: &mut PaymentData<F>,
     req: Option<CustomerDetails>,
     merchant_id: &str,
+    storage_scheme: storage_enums::MerchantStorageScheme,
 ) -> CustomResult<(BoxedOperation<'a, F, R>, Option<storage::Customer>), errors::StorageError> {
     let req = req
         .get_required_value("customer")
@@ -636,7 +649,11 @@ pub async fn create_customer_if_not_exist<'a, F: Clone, R>(
     let optional_customer = match req.customer_id.as_ref() {
         Some(customer_id) => {
             let customer_data = db
-                .find_customer_optional_by_customer_id_merchant_id(customer_id, merchant_id)
+                .find_customer_optional_by_customer_id_merchant_id(
+                    customer_id,
+                    merchant_id,
+                    storage_scheme,
+                )
                 .await?;
             Some(match customer_data {
                 Some(c) => Ok(c),
@@ -651,14 +668,18 @@ pub async fn create_customer_if_not_exist<'a, F: Clone, R>(
                         ..storage::CustomerNew::default()
                     };
 
-                    db.insert_customer(new_customer).await
+                    db.insert_customer(new_customer, storage_scheme).await
                 }
             })
         }
         None => match &payment_data.payment_intent.customer_id {
             None => None,
             Some(customer_id) => db
-                .find_customer_optional_by_customer_id_merchant_id(customer_id, merchant_id)
+                .find_customer_optional_by_customer_id_merchant_id(
+                    customer_id,
+                    merchant_id,
+                    storage_scheme,
+                )
                 .await?
                 .map(Ok),
         },
@@ -839,6 +860,100 @@ pub(crate) fn validate_amount_to_capture(
     )
 }
 
+/// The connectors which support capturing a payment through multiple partial captures
+const MULTIPLE_CAPTURE_CONNECTORS: [&str; 3] = ["adyen", "checkout", "stripe"];
+
+#[instrument(skip_all)]
+pub(crate) fn validate_multiple_capture_connector(connector: &str) -> RouterResult<()> {
+    utils::when(!MULTIPLE_CAPTURE_CONNECTORS.contains(&connector), || {
+        Err(report!(errors::ApiErrorResponse::NotSupported {
+            message: format!("Multiple captures are not supported by {connector}"),
+        }))
+    })
+}
+
+/// The connectors which support incrementing the authorization of a payment
+const INCREMENTAL_AUTHORIZATION_CONNECTORS: [&str; 3] = ["adyen", "checkout", "stripe"];
+
+#[instrument(skip_all)]
+pub(crate) fn validate_incremental_authorization_connector(connector: &str) -> RouterResult<()> {
+    utils::when(
+        !INCREMENTAL_AUTHORIZATION_CONNECTORS.contains(&connector),
+        || {
+            Err(report!(errors::ApiErrorResponse::NotSupported {
+                message: format!("Incremental authorization is not supported by {connector}"),
+            }))
+        },
+    )
+}
+
+/// The authorization can only be incremented, so the amount to be authorized must exceed the
+/// amount already authorized on the payment
+#[instrument(skip_all)]
+pub(crate) fn validate_incremental_authorization_amount(
+    amount_authorized: i64,
+    amount: i64,
+) -> RouterResult<()> {
+    utils::when(amount <= amount_authorized, || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "amount must be greater than the amount already authorized ({amount_authorized})"
+            ),
+        }))
+    })
+}
+
+/// The amount of the payment attempt which is yet to be captured. The captures which are pending
+/// with the connector are considered as captured, so that they cannot be captured again.
+pub(crate) fn get_amount_capturable(amount: i64, captures: &[storage::Capture]) -> i64 {
+    amount
+        - captures
+            .iter()
+            .filter(|capture| capture.status != storage_enums::CaptureStatus::Failed)
+            .map(|capture| capture.amount)
+            .sum::<i64>()
+}
+
+/// The total amount charged by the captures made on the payment attempt
+pub(crate) fn get_amount_captured(captures: &[storage::Capture]) -> i64 {
+    captures
+        .iter()
+        .filter(|capture| capture.status == storage_enums::CaptureStatus::Charged)
+        .map(|capture| capture.amount)
+        .sum()
+}
+
+/// The statuses of the payment attempt and the payment intent, from the captures made on the
+/// payment attempt
+pub(crate) fn get_multiple_capture_status(
+    amount: i64,
+    captures: &[storage::Capture],
+) -> (storage_enums::AttemptStatus, storage_enums::IntentStatus) {
+    let amount_captured = get_amount_captured(captures);
+    if amount_captured >= amount {
+        (
+            storage_enums::AttemptStatus::Charged,
+            storage_enums::IntentStatus::Succeeded,
+        )
+    } else if get_amount_capturable(amount, captures) <= 0 {
+        // The rest of the amount is being captured by the captures pending with the connector
+        (
+            storage_enums::AttemptStatus::CaptureInitiated,
+            storage_enums::IntentStatus::Processing,
+        )
+    } else if amount_captured > 0 {
+        (
+            storage_enums::AttemptStatus::PartialCharged,
+            storage_enums::IntentStatus::RequiresCapture,
+        )
+    } else {
+        (
+            storage_enums::AttemptStatus::Authorized,
+            storage_enums::IntentStatus::RequiresCapture,
+        )
+    }
+}
+
 #[instrument(skip_all)]
 pub(crate) fn validate_payment_method_fields_present(
     req: &api::PaymentsRequest,
@@ -866,6 +981,54 @@ pub(crate) fn validate_payment_method_fields_present(
     Ok(())
 }
 
+/// Authorizations are usually held by the issuer for about a week, so captures cannot be
+/// scheduled any later than this after the authorization
+const MAX_CAPTURE_DELAY_HOURS: u16 = 168;
+
+#[instrument(skip_all)]
+pub(crate) fn validate_scheduled_capture_fields(req: &api::PaymentsRequest) -> RouterResult<()> {
+    let is_scheduled_capture = req.capture_method == Some(api_enums::CaptureMethod::Scheduled);
+
+    utils::when(
+        is_scheduled_capture && req.capture_on.is_none() && req.capture_delay_hours.is_none(),
+        || {
+            Err(errors::ApiErrorResponse::MissingRequiredField {
+                field_name: "capture_on or capture_delay_hours",
+            })
+        },
+    )?;
+
+    utils::when(
+        req.capture_delay_hours
+            .map_or(false, |hours| hours > MAX_CAPTURE_DELAY_HOURS),
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "capture_delay_hours cannot be greater than {MAX_CAPTURE_DELAY_HOURS}"
+                ),
+            })
+        },
+    )?;
+
+    // The payment is authorized at the earliest now, so this bounds the delay after the
+    // authorization as well
+    let latest_capture_on = common_utils::date_time::now()
+        .saturating_add(time::Duration::hours(i64::from(MAX_CAPTURE_DELAY_HOURS)));
+    utils::when(
+        req.capture_on
+            .map_or(false, |capture_on| capture_on > latest_capture_on),
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "capture_on cannot be more than {MAX_CAPTURE_DELAY_HOURS} hours from now"
+                ),
+            })
+        },
+    )?;
+
+    Ok(())
+}
+
 pub fn check_force_psync_precondition(
     status: &storage_enums::AttemptStatus,
     connector_transaction_id: &Option<String>,
@@ -1244,4 +1407,152 @@ mod tests {
         let pi_cs = Some("2".to_string());
         assert!(authenticate_client_secret(req_cs.as_ref(), pi_cs.as_ref()).is_err())
     }
+
+    fn make_capture(amount: i64, status: storage_enums::CaptureStatus) -> storage::Capture {
+        storage::Capture {
+            id: 0,
+            capture_id: "cap_1".to_string(),
+            payment_id: "pay_1".to_string(),
+            merchant_id: "merchant_1".to_string(),
+            attempt_id: "pay_1_1".to_string(),
+            status,
+            amount,
+            currency: Some(storage_enums::Currency::USD),
+            connector: "stripe".to_string(),
+            connector_capture_id: None,
+            capture_sequence: 1,
+            error_message: None,
+            error_code: None,
+            created_at: common_utils::date_time::now(),
+            modified_at: common_utils::date_time::now(),
+        }
+    }
+
+    #[test]
+    fn test_amount_capturable_excludes_failed_captures() {
+        let captures = [
+            make_capture(300, storage_enums::CaptureStatus::Charged),
+            make_capture(200, storage_enums::CaptureStatus::Failed),
+            make_capture(100, storage_enums::CaptureStatus::Pending),
+        ];
+
+        assert_eq!(get_amount_capturable(1000, &captures), 600);
+        assert_eq!(get_amount_captured(&captures), 300);
+    }
+
+    #[test]
+    fn test_amount_capturable_without_captures_is_the_amount() {
+        assert_eq!(get_amount_capturable(1000, &[]), 1000);
+        assert_eq!(get_amount_captured(&[]), 0);
+
+        let failed_captures = [make_capture(1000, storage_enums::CaptureStatus::Failed)];
+        assert_eq!(get_amount_capturable(1000, &failed_captures), 1000);
+    }
+
+    #[test]
+    fn test_amount_capturable_is_zero_once_fully_captured() {
+        let captures = [
+            make_capture(600, storage_enums::CaptureStatus::Charged),
+            make_capture(400, storage_enums::CaptureStatus::Started),
+        ];
+
+        assert_eq!(get_amount_capturable(1000, &captures), 0);
+        assert_eq!(get_amount_captured(&captures), 600);
+    }
+
+    #[test]
+    fn test_multiple_capture_status_is_charged_once_the_amount_is_captured() {
+        let captures = [
+            make_capture(600, storage_enums::CaptureStatus::Charged),
+            make_capture(400, storage_enums::CaptureStatus::Charged),
+        ];
+
+        assert_eq!(
+            get_multiple_capture_status(1000, &captures),
+            (
+                storage_enums::AttemptStatus::Charged,
+                storage_enums::IntentStatus::Succeeded
+            )
+        );
+    }
+
+    #[test]
+    fn test_multiple_capture_status_is_partial_charged_with_amount_left_to_capture() {
+        let captures = [
+            make_capture(300, storage_enums::CaptureStatus::Charged),
+            make_capture(200, storage_enums::CaptureStatus::Pending),
+            make_capture(500, storage_enums::CaptureStatus::Failed),
+        ];
+
+        assert_eq!(
+            get_multiple_capture_status(1000, &captures),
+            (
+                storage_enums::AttemptStatus::PartialCharged,
+                storage_enums::IntentStatus::RequiresCapture
+            )
+        );
+    }
+
+    #[test]
+    fn test_multiple_capture_status_is_capture_initiated_when_the_rest_is_pending() {
+        let captures = [
+            make_capture(300, storage_enums::CaptureStatus::Charged),
+            make_capture(700, storage_enums::CaptureStatus::Pending),
+        ];
+
+        assert_eq!(
+            get_multiple_capture_status(1000, &captures),
+            (
+                storage_enums::AttemptStatus::CaptureInitiated,
+                storage_enums::IntentStatus::Processing
+            )
+        );
+    }
+
+    #[test]
+    fn test_multiple_capture_status_is_authorized_when_nothing_is_captured() {
+        let captures = [
+            make_capture(400, storage_enums::CaptureStatus::Failed),
+            make_capture(300, storage_enums::CaptureStatus::Pending),
+        ];
+
+        assert_eq!(
+            get_multiple_capture_status(1000, &captures),
+            (
+                storage_enums::AttemptStatus::Authorized,
+                storage_enums::IntentStatus::RequiresCapture
+            )
+        );
+    }
+
+    #[test]
+    fn test_scheduled_capture_requires_capture_time() {
+        let scheduled_capture_request = |capture_delay_hours| api::PaymentsRequest {
+            capture_method: Some(api_enums::CaptureMethod::Scheduled),
+            capture_delay_hours,
+            ..Default::default()
+        };
+
+        assert!(validate_scheduled_capture_fields(&scheduled_capture_request(None)).is_err());
+        assert!(validate_scheduled_capture_fields(&scheduled_capture_request(Some(24))).is_ok());
+        assert!(validate_scheduled_capture_fields(&scheduled_capture_request(Some(169))).is_err());
+
+        let capture_on_request = |hours_from_now| api::PaymentsRequest {
+            capture_method: Some(api_enums::CaptureMethod::Scheduled),
+            capture_on: Some(
+                common_utils::date_time::now()
+                    .saturating_add(time::Duration::hours(hours_from_now)),
+            ),
+            ..Default::default()
+        };
+        assert!(validate_scheduled_capture_fields(&capture_on_request(24)).is_ok());
+        assert!(validate_scheduled_capture_fields(&capture_on_request(169)).is_err());
+    }
+
+    #[test]
+    fn test_incremental_authorization_amount_must_exceed_authorized_amount() {
+        assert!(validate_incremental_authorization_amount(1000, 1500).is_ok());
+        assert!(validate_incremental_authorization_amount(1000, 1000).is_err());
+        assert!(validate_incremental_authorization_amount(1000, 500).is_err());
+    }
 }
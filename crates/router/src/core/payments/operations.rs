@@ -2,6 +2,7 @@ pub mod payment_cancel;
 pub mod payment_capture;
 pub mod payment_confirm;
 pub mod payment_create;
+pub mod payment_incremental_authorization;
 pub mod payment_method_validate;
 pub mod payment_response;
 pub mod payment_session;
@@ -16,6 +17,7 @@ use router_env::{instrument, tracing};
 pub use self::{
     payment_cancel::PaymentCancel, payment_capture::PaymentCapture,
     payment_confirm::PaymentConfirm, payment_create::PaymentCreate,
+    payment_incremental_authorization::PaymentIncrementalAuthorization,
     payment_method_validate::PaymentMethodValidate, payment_response::PaymentResponse,
     payment_session::PaymentSession, payment_start::PaymentStart, payment_status::PaymentStatus,
     payment_update::PaymentUpdate,
@@ -69,6 +71,9 @@ pub struct ValidateResult<'a> {
     pub payment_id: api::PaymentIdType,
     pub mandate_type: Option<api::MandateTxnType>,
     pub storage_scheme: enums::MerchantStorageScheme,
+    /// Whether the operation modifies the payment, in which case the payment is locked while
+    /// the operation is performed
+    pub requires_lock: bool,
 }
 
 #[allow(clippy::type_complexity)]
@@ -102,6 +107,7 @@ pub trait Domain<F: Clone, R>: Send + Sync {
         payment_data: &mut PaymentData<F>,
         request: Option<CustomerDetails>,
         merchant_id: &str,
+        storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<(BoxedOperation<'a, F, R>, Option<storage::Customer>), errors::StorageError>;
 
     #[allow(clippy::too_many_arguments)]
@@ -170,6 +176,7 @@ where
         payment_data: &mut PaymentData<F>,
         _request: Option<CustomerDetails>,
         merchant_id: &str,
+        storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<
         (
             BoxedOperation<'a, F, api::PaymentsRetrieveRequest>,
@@ -184,6 +191,7 @@ where
                 payment_data.payment_intent.customer_id.clone(),
                 merchant_id,
                 payment_data,
+                storage_scheme,
             )
             .await?,
         ))
@@ -226,6 +234,7 @@ where
         payment_data: &mut PaymentData<F>,
         _request: Option<CustomerDetails>,
         merchant_id: &str,
+        storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<
         (
             BoxedOperation<'a, F, api::PaymentsCaptureRequest>,
@@ -240,6 +249,7 @@ where
                 payment_data.payment_intent.customer_id.clone(),
                 merchant_id,
                 payment_data,
+                storage_scheme,
             )
             .await?,
         ))
@@ -268,6 +278,65 @@ where
     }
 }
 
+#[async_trait]
+impl<
+        F: Clone + Send,
+        Op: Send + Sync + Operation<F, api::PaymentsIncrementalAuthorizationRequest>,
+    > Domain<F, api::PaymentsIncrementalAuthorizationRequest> for Op
+where
+    for<'a> &'a Op: Operation<F, api::PaymentsIncrementalAuthorizationRequest>,
+{
+    #[instrument(skip_all)]
+    async fn get_or_create_customer_details<'a>(
+        &'a self,
+        db: &dyn StorageInterface,
+        payment_data: &mut PaymentData<F>,
+        _request: Option<CustomerDetails>,
+        merchant_id: &str,
+        storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<
+        (
+            BoxedOperation<'a, F, api::PaymentsIncrementalAuthorizationRequest>,
+            Option<storage::Customer>,
+        ),
+        errors::StorageError,
+    > {
+        Ok((
+            Box::new(self),
+            helpers::get_customer_from_details(
+                db,
+                payment_data.payment_intent.customer_id.clone(),
+                merchant_id,
+                payment_data,
+                storage_scheme,
+            )
+            .await?,
+        ))
+    }
+    #[instrument(skip_all)]
+    async fn make_pm_data<'a>(
+        &'a self,
+        _state: &'a AppState,
+        _payment_data: &mut PaymentData<F>,
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> RouterResult<(
+        BoxedOperation<'a, F, api::PaymentsIncrementalAuthorizationRequest>,
+        Option<api::PaymentMethodData>,
+    )> {
+        Ok((Box::new(self), None))
+    }
+
+    async fn get_connector<'a>(
+        &'a self,
+        _merchant_account: &storage::MerchantAccount,
+        state: &AppState,
+        _request: &api::PaymentsIncrementalAuthorizationRequest,
+        previously_used_connector: Option<&String>,
+    ) -> CustomResult<api::ConnectorCallType, errors::ApiErrorResponse> {
+        helpers::get_connector_default(state, previously_used_connector).await
+    }
+}
+
 #[async_trait]
 impl<F: Clone + Send, Op: Send + Sync + Operation<F, api::PaymentsCancelRequest>>
     Domain<F, api::PaymentsCancelRequest> for Op
@@ -281,6 +350,7 @@ where
         payment_data: &mut PaymentData<F>,
         _request: Option<CustomerDetails>,
         merchant_id: &str,
+        storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<
         (
             BoxedOperation<'a, F, api::PaymentsCancelRequest>,
@@ -295,6 +365,7 @@ where
                 payment_data.payment_intent.customer_id.clone(),
                 merchant_id,
                 payment_data,
+                storage_scheme,
             )
             .await?,
         ))
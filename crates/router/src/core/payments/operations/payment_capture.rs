@@ -1,11 +1,12 @@
 use std::marker::PhantomData;
 
 use async_trait::async_trait;
-use error_stack::ResultExt;
+use error_stack::{IntoReport, ResultExt};
 use router_env::{instrument, tracing};
 
 use super::{BoxedOperation, Domain, GetTracker, Operation, UpdateTracker, ValidateRequest};
 use crate::{
+    consts,
     core::{
         errors::{self, RouterResult, StorageErrorExt},
         payments::{self, helpers, operations},
@@ -17,7 +18,7 @@ use crate::{
         storage::{self, enums},
         transformers::ForeignInto,
     },
-    utils::OptionExt,
+    utils::{self, OptionExt},
 };
 
 #[derive(Debug, Clone, Copy, router_derive::PaymentOperation)]
@@ -59,8 +60,6 @@ impl<F: Send + Clone> GetTracker<F, payments::PaymentData<F>, api::PaymentsCaptu
 
         helpers::validate_status(payment_intent.status)?;
 
-        helpers::validate_amount_to_capture(payment_intent.amount, request.amount_to_capture)?;
-
         payment_attempt = db
             .find_payment_attempt_by_payment_id_merchant_id(
                 &payment_id,
@@ -72,16 +71,61 @@ impl<F: Send + Clone> GetTracker<F, payments::PaymentData<F>, api::PaymentsCaptu
                 error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)
             })?;
 
-        payment_attempt
-            .amount_to_capture
-            .update_value(request.amount_to_capture);
-
         let capture_method = payment_attempt
             .capture_method
             .get_required_value("capture_method")?;
 
         helpers::validate_capture_method(capture_method)?;
 
+        let captures = if capture_method == enums::CaptureMethod::ManualMultiple {
+            helpers::validate_multiple_capture_connector(
+                payment_attempt
+                    .connector
+                    .as_deref()
+                    .get_required_value("connector")?,
+            )?;
+
+            let captures = db
+                .find_all_captures_by_merchant_id_payment_id_attempt_id(
+                    merchant_id,
+                    &payment_id,
+                    &payment_attempt.attempt_id,
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed while fetching the captures of the payment")?;
+
+            let amount_capturable =
+                helpers::get_amount_capturable(payment_attempt.amount, &captures);
+
+            helpers::validate_amount_to_capture(amount_capturable, request.amount_to_capture)?;
+            utils::when(
+                amount_capturable <= 0
+                    || request
+                        .amount_to_capture
+                        .map_or(false, |amount| amount <= 0),
+                || {
+                    Err(errors::ApiErrorResponse::InvalidRequestData {
+                        message: format!(
+                            "amount_to_capture must be greater than 0 and at most the amount \
+                             yet to be captured ({amount_capturable})"
+                        ),
+                    })
+                },
+            )?;
+
+            // Each capture captures the remaining amount unless an amount is specified
+            payment_attempt.amount_to_capture =
+                Some(request.amount_to_capture.unwrap_or(amount_capturable));
+            captures
+        } else {
+            helpers::validate_amount_to_capture(payment_intent.amount, request.amount_to_capture)?;
+            payment_attempt
+                .amount_to_capture
+                .update_value(request.amount_to_capture);
+            vec![]
+        };
+
         currency = payment_attempt.currency.get_required_value("currency")?;
 
         amount = payment_attempt.amount.into();
@@ -104,6 +148,7 @@ impl<F: Send + Clone> GetTracker<F, payments::PaymentData<F>, api::PaymentsCaptu
             payment_intent.shipping_address_id.as_deref(),
             merchant_id,
             &payment_intent.customer_id,
+            storage_scheme,
         )
         .await?;
 
@@ -113,6 +158,7 @@ impl<F: Send + Clone> GetTracker<F, payments::PaymentData<F>, api::PaymentsCaptu
             payment_intent.billing_address_id.as_deref(),
             merchant_id,
             &payment_intent.customer_id,
+            storage_scheme,
         )
         .await?;
 
@@ -136,6 +182,7 @@ impl<F: Send + Clone> GetTracker<F, payments::PaymentData<F>, api::PaymentsCaptu
                 confirm: None,
                 payment_method_data: None,
                 refunds: vec![],
+                captures,
                 connector_response,
                 sessions_token: vec![],
                 card_cvc: None,
@@ -152,9 +199,9 @@ impl<F: Clone> UpdateTracker<F, payments::PaymentData<F>, api::PaymentsCaptureRe
     #[instrument(skip_all)]
     async fn update_trackers<'b>(
         &'b self,
-        _db: &dyn StorageInterface,
+        db: &dyn StorageInterface,
         _payment_id: &api::PaymentIdType,
-        payment_data: payments::PaymentData<F>,
+        mut payment_data: payments::PaymentData<F>,
         _customer: Option<storage::Customer>,
         _storage_scheme: enums::MerchantStorageScheme,
     ) -> RouterResult<(
@@ -164,6 +211,38 @@ impl<F: Clone> UpdateTracker<F, payments::PaymentData<F>, api::PaymentsCaptureRe
     where
         F: 'b + Send,
     {
+        if payment_data.payment_attempt.capture_method == Some(enums::CaptureMethod::ManualMultiple)
+        {
+            let payment_attempt = &payment_data.payment_attempt;
+            let capture_sequence = i32::try_from(payment_data.captures.len() + 1)
+                .into_report()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Too many captures made on the payment")?;
+
+            let capture = db
+                .insert_capture(storage::CaptureNew {
+                    capture_id: utils::generate_id(consts::ID_LENGTH, "cap"),
+                    payment_id: payment_attempt.payment_id.clone(),
+                    merchant_id: payment_attempt.merchant_id.clone(),
+                    attempt_id: payment_attempt.attempt_id.clone(),
+                    status: enums::CaptureStatus::Started,
+                    amount: payment_attempt
+                        .amount_to_capture
+                        .unwrap_or(payment_attempt.amount),
+                    currency: payment_attempt.currency,
+                    connector: payment_attempt
+                        .connector
+                        .clone()
+                        .get_required_value("connector")?,
+                    capture_sequence,
+                })
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed while inserting the capture")?;
+
+            payment_data.captures.push(capture);
+        }
+
         Ok((Box::new(self), payment_data))
     }
 }
@@ -190,6 +269,7 @@ impl<F: Send + Clone> ValidateRequest<F, api::PaymentsCaptureRequest> for Paymen
                 payment_id: api::PaymentIdType::PaymentIntentId(payment_id.to_owned()),
                 mandate_type: None,
                 storage_scheme: merchant_account.storage_scheme,
+                requires_lock: true,
             },
         ))
     }
@@ -12,10 +12,13 @@ use crate::{
     consts,
     core::{
         errors::{self, CustomResult, RouterResult, StorageErrorExt},
-        payments::{self, helpers, operations, CustomerDetails, PaymentAddress, PaymentData},
+        payments::{
+            self, expiry, helpers, operations, CustomerDetails, PaymentAddress, PaymentData,
+        },
         utils as core_utils,
     },
     db::StorageInterface,
+    logger,
     routes::AppState,
     types::{
         self,
@@ -75,6 +78,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
             None,
             merchant_id,
             &request.customer_id,
+            storage_scheme,
         )
         .await?;
 
@@ -84,6 +88,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
             None,
             merchant_id,
             &request.customer_id,
+            storage_scheme,
         )
         .await?;
 
@@ -135,6 +140,17 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
                     payment_id: payment_id.clone(),
                 })
             })?;
+
+        // Payments confirmed at creation do not wait on the customer or the merchant. The payment
+        // is not failed because its expiry could not be scheduled
+        if !request.confirm.unwrap_or(false) {
+            if let Err(error) =
+                expiry::add_intent_expiry_task(db, merchant_account, &payment_intent).await
+            {
+                logger::error!(?error, "Failed to schedule the expiry of the payment");
+            }
+        }
+
         connector_response = db
             .insert_connector_response(
                 Self::make_connector_response(&payment_attempt),
@@ -188,6 +204,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
                 confirm: request.confirm,
                 payment_method_data: request.payment_method_data.clone(),
                 refunds: vec![],
+                captures: vec![],
                 force_sync: None,
                 connector_response,
                 sessions_token: vec![],
@@ -213,6 +230,7 @@ impl<F: Clone + Send> Domain<F, api::PaymentsRequest> for PaymentCreate {
         payment_data: &mut PaymentData<F>,
         request: Option<CustomerDetails>,
         merchant_id: &str,
+        storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<
         (
             BoxedOperation<'a, F, api::PaymentsRequest>,
@@ -226,6 +244,7 @@ impl<F: Clone + Send> Domain<F, api::PaymentsRequest> for PaymentCreate {
             payment_data,
             request,
             merchant_id,
+            storage_scheme,
         )
         .await
     }
@@ -374,6 +393,8 @@ impl<F: Send + Clone> ValidateRequest<F, api::PaymentsRequest> for PaymentCreate
 
         helpers::validate_payment_method_fields_present(request)?;
 
+        helpers::validate_scheduled_capture_fields(request)?;
+
         let payment_id = core_utils::get_or_generate_id("payment_id", &given_payment_id, "pay")?;
 
         let mandate_type = helpers::validate_mandate(request)?;
@@ -402,6 +423,7 @@ impl<F: Send + Clone> ValidateRequest<F, api::PaymentsRequest> for PaymentCreate
                 payment_id: api::PaymentIdType::PaymentIntentId(payment_id),
                 mandate_type,
                 storage_scheme: merchant_account.storage_scheme,
+                requires_lock: true,
             },
         ))
     }
@@ -448,6 +470,7 @@ impl PaymentCreate {
             payment_method,
             capture_method: request.capture_method.map(ForeignInto::foreign_into),
             capture_on: request.capture_on,
+            capture_delay_hours: request.capture_delay_hours.map(i32::from),
             confirm: request.confirm.unwrap_or(false),
             created_at,
             modified_at,
@@ -503,6 +526,7 @@ impl PaymentCreate {
             statement_descriptor_name: request.statement_descriptor_name.clone(),
             statement_descriptor_suffix: request.statement_descriptor_suffix.clone(),
             metadata: metadata.map(masking::Secret::new),
+            request_incremental_authorization: request.request_incremental_authorization,
             ..storage::PaymentIntentNew::default()
         })
     }
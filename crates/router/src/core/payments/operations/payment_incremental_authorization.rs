@@ -0,0 +1,213 @@
+use std::marker::PhantomData;
+
+use async_trait::async_trait;
+use error_stack::ResultExt;
+use router_derive;
+use router_env::{instrument, tracing};
+
+use super::{BoxedOperation, Domain, GetTracker, Operation, UpdateTracker, ValidateRequest};
+use crate::{
+    core::{
+        errors::{self, RouterResult, StorageErrorExt},
+        payments::{helpers, operations, CustomerDetails, PaymentAddress, PaymentData},
+    },
+    db::StorageInterface,
+    routes::AppState,
+    types::{
+        api::{self, PaymentIdTypeExt},
+        storage::{self, enums, Customer},
+        transformers::ForeignInto,
+    },
+    utils::{self, OptionExt},
+};
+
+#[derive(Debug, Clone, Copy, router_derive::PaymentOperation)]
+#[operation(ops = "all", flow = "incrementalauthorization")]
+pub struct PaymentIncrementalAuthorization;
+
+#[async_trait]
+impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsIncrementalAuthorizationRequest>
+    for PaymentIncrementalAuthorization
+{
+    #[instrument(skip_all)]
+    async fn get_trackers<'a>(
+        &'a self,
+        state: &'a AppState,
+        payment_id: &api::PaymentIdType,
+        request: &api::PaymentsIncrementalAuthorizationRequest,
+        _mandate_type: Option<api::MandateTxnType>,
+        merchant_account: &storage::MerchantAccount,
+    ) -> RouterResult<(
+        BoxedOperation<'a, F, api::PaymentsIncrementalAuthorizationRequest>,
+        PaymentData<F>,
+        Option<CustomerDetails>,
+    )> {
+        let db = &*state.store;
+        let merchant_id = &merchant_account.merchant_id;
+        let storage_scheme = merchant_account.storage_scheme;
+        let payment_id = payment_id
+            .get_payment_intent_id()
+            .change_context(errors::ApiErrorResponse::PaymentNotFound)?;
+
+        let payment_intent = db
+            .find_payment_intent_by_payment_id_merchant_id(&payment_id, merchant_id, storage_scheme)
+            .await
+            .map_err(|error| {
+                error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)
+            })?;
+
+        let payment_attempt = db
+            .find_payment_attempt_by_payment_id_merchant_id(
+                &payment_id,
+                merchant_id,
+                storage_scheme,
+            )
+            .await
+            .map_err(|error| {
+                error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)
+            })?;
+
+        // Once a part of the payment is captured, the authorization can no longer be incremented
+        utils::when(
+            payment_intent.status != enums::IntentStatus::RequiresCapture
+                || payment_attempt.status != enums::AttemptStatus::Authorized,
+            || {
+                Err(errors::ApiErrorResponse::PaymentUnexpectedState {
+                    field_name: "payment.status".to_string(),
+                    current_flow: "incremental_authorization".to_string(),
+                    current_value: payment_intent.status.to_string(),
+                    states: "requires_capture".to_string(),
+                })
+            },
+        )?;
+
+        utils::when(
+            payment_intent.request_incremental_authorization != Some(true),
+            || {
+                Err(errors::ApiErrorResponse::PreconditionFailed {
+                    message: "Incremental authorization was not requested for the payment"
+                        .to_string(),
+                })
+            },
+        )?;
+
+        helpers::validate_incremental_authorization_connector(
+            payment_attempt
+                .connector
+                .as_deref()
+                .get_required_value("connector")?,
+        )?;
+        helpers::validate_incremental_authorization_amount(payment_attempt.amount, request.amount)?;
+
+        let shipping_address = helpers::get_address_for_payment_request(
+            db,
+            None,
+            payment_intent.shipping_address_id.as_deref(),
+            merchant_id,
+            &payment_intent.customer_id,
+            storage_scheme,
+        )
+        .await?;
+        let billing_address = helpers::get_address_for_payment_request(
+            db,
+            None,
+            payment_intent.billing_address_id.as_deref(),
+            merchant_id,
+            &payment_intent.customer_id,
+            storage_scheme,
+        )
+        .await?;
+
+        let connector_response = db
+            .find_connector_response_by_payment_id_merchant_id_attempt_id(
+                &payment_attempt.payment_id,
+                &payment_attempt.merchant_id,
+                &payment_attempt.attempt_id,
+                storage_scheme,
+            )
+            .await
+            .map_err(|error| {
+                error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)
+            })?;
+        let currency = payment_attempt.currency.get_required_value("currency")?;
+        // The payment attempt keeps the amount authorized so far, until the connector has
+        // authorized the requested amount
+        let amount = request.amount.into();
+
+        Ok((
+            Box::new(self),
+            PaymentData {
+                flow: PhantomData,
+                payment_intent,
+                payment_attempt,
+                currency,
+                amount,
+                email: None,
+                mandate_id: None,
+                setup_mandate: None,
+                token: None,
+                address: PaymentAddress {
+                    shipping: shipping_address.as_ref().map(|a| a.foreign_into()),
+                    billing: billing_address.as_ref().map(|a| a.foreign_into()),
+                },
+                confirm: None,
+                payment_method_data: None,
+                force_sync: None,
+                refunds: vec![],
+                captures: vec![],
+                connector_response,
+                sessions_token: vec![],
+                card_cvc: None,
+            },
+            None,
+        ))
+    }
+}
+
+#[async_trait]
+impl<F: Clone> UpdateTracker<F, PaymentData<F>, api::PaymentsIncrementalAuthorizationRequest>
+    for PaymentIncrementalAuthorization
+{
+    #[instrument(skip_all)]
+    async fn update_trackers<'b>(
+        &'b self,
+        _db: &dyn StorageInterface,
+        _payment_id: &api::PaymentIdType,
+        payment_data: PaymentData<F>,
+        _customer: Option<Customer>,
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> RouterResult<(
+        BoxedOperation<'b, F, api::PaymentsIncrementalAuthorizationRequest>,
+        PaymentData<F>,
+    )>
+    where
+        F: 'b + Send,
+    {
+        Ok((Box::new(self), payment_data))
+    }
+}
+
+impl<F: Send + Clone> ValidateRequest<F, api::PaymentsIncrementalAuthorizationRequest>
+    for PaymentIncrementalAuthorization
+{
+    #[instrument(skip_all)]
+    fn validate_request<'a, 'b>(
+        &'b self,
+        request: &api::PaymentsIncrementalAuthorizationRequest,
+        merchant_account: &'a storage::MerchantAccount,
+    ) -> RouterResult<(
+        BoxedOperation<'b, F, api::PaymentsIncrementalAuthorizationRequest>,
+        operations::ValidateResult<'a>,
+    )> {
+        Ok((
+            Box::new(self),
+            operations::ValidateResult {
+                merchant_id: &merchant_account.merchant_id,
+                payment_id: api::PaymentIdType::PaymentIntentId(request.payment_id.to_owned()),
+                mandate_type: None,
+                storage_scheme: merchant_account.storage_scheme,
+                requires_lock: true,
+            },
+        ))
+    }
+}
@@ -54,6 +54,7 @@ impl<F: Send + Clone> ValidateRequest<F, api::VerifyRequest> for PaymentMethodVa
                 payment_id: api::PaymentIdType::PaymentIntentId(validation_id),
                 mandate_type,
                 storage_scheme: merchant_account.storage_scheme,
+                requires_lock: true,
             },
         ))
     }
@@ -149,6 +150,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::VerifyRequest> for Paym
                 address: types::PaymentAddress::default(),
                 force_sync: None,
                 refunds: vec![],
+                captures: vec![],
                 sessions_token: vec![],
                 card_cvc: None,
             },
@@ -219,6 +221,7 @@ where
         payment_data: &mut PaymentData<F>,
         request: Option<payments::CustomerDetails>,
         merchant_id: &str,
+        storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<
         (
             BoxedOperation<'a, F, api::VerifyRequest>,
@@ -232,6 +235,7 @@ where
             payment_data,
             request,
             merchant_id,
+            storage_scheme,
         )
         .await
     }
@@ -1,13 +1,13 @@
 use async_trait::async_trait;
 use common_utils::fp_utils;
-use error_stack::ResultExt;
+use error_stack::{IntoReport, ResultExt};
 use router_derive;
 
 use super::{Operation, PostUpdateTracker};
 use crate::{
     core::{
         errors::{self, RouterResult, StorageErrorExt},
-        payments::PaymentData,
+        payments::{self, helpers, PaymentData},
     },
     db::StorageInterface,
     services::RedirectForm,
@@ -16,13 +16,13 @@ use crate::{
         storage::{self, enums},
         transformers::ForeignInto,
     },
-    utils,
+    utils::{self, OptionExt},
 };
 
 #[derive(Debug, Clone, Copy, router_derive::PaymentOperation)]
 #[operation(
     ops = "post_tracker",
-    flow = "syncdata,authorizedata,canceldata,capturedata,verifydata,sessiondata"
+    flow = "syncdata,authorizedata,canceldata,capturedata,verifydata,sessiondata,incrementalauthorizationdata"
 )]
 pub struct PaymentResponse;
 
@@ -154,9 +154,58 @@ impl<F: Clone> PostUpdateTracker<F, PaymentData<F>, types::PaymentsCaptureData>
         let router_response = router_data.response.clone();
         let connector = router_data.connector.clone();
 
-        payment_data = payment_response_update_tracker(
+        payment_data = if router_data.request.multiple_capture_data.is_some() {
+            multiple_capture_response_update_tracker(db, payment_data, router_data, storage_scheme)
+                .await?
+        } else {
+            payment_response_update_tracker(
+                db,
+                payment_id,
+                payment_data,
+                router_data,
+                storage_scheme,
+            )
+            .await?
+        };
+
+        router_response.map_err(|error_response| {
+            errors::ApiErrorResponse::ExternalConnectorError {
+                message: error_response.message,
+                code: error_response.code,
+                status_code: error_response.status_code,
+                reason: error_response.reason,
+                connector,
+            }
+        })?;
+
+        Ok(payment_data)
+    }
+}
+
+#[async_trait]
+impl<F: Clone> PostUpdateTracker<F, PaymentData<F>, types::PaymentsIncrementalAuthorizationData>
+    for PaymentResponse
+{
+    async fn update_tracker<'b>(
+        &'b self,
+        db: &dyn StorageInterface,
+        _payment_id: &api::PaymentIdType,
+        mut payment_data: PaymentData<F>,
+        router_data: types::RouterData<
+            F,
+            types::PaymentsIncrementalAuthorizationData,
+            types::PaymentsResponseData,
+        >,
+        storage_scheme: enums::MerchantStorageScheme,
+    ) -> RouterResult<PaymentData<F>>
+    where
+        F: 'b + Send,
+    {
+        let router_response = router_data.response.clone();
+        let connector = router_data.connector.clone();
+
+        payment_data = incremental_authorization_response_update_tracker(
             db,
-            payment_id,
             payment_data,
             router_data,
             storage_scheme,
@@ -389,5 +438,138 @@ async fn payment_response_update_tracker<F: Clone, T>(
         .await
         .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound))?;
 
+    if payment_data.payment_attempt.status == enums::AttemptStatus::Authorized
+        && payment_data.payment_attempt.capture_method == Some(enums::CaptureMethod::Scheduled)
+    {
+        payments::add_process_capture_task(db, &payment_data.payment_attempt)
+            .await
+            .into_report()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while scheduling the capture of the payment")?;
+    }
+
+    Ok(payment_data)
+}
+
+/// Updates the capture made in this request with the connector response, and derives the status
+/// of the payment from the total amount charged by all the captures made on the payment. The
+/// connector transaction ID of the payment attempt is left untouched, as the connectors return
+/// the reference of the capture rather than that of the payment.
+async fn multiple_capture_response_update_tracker<F: Clone>(
+    db: &dyn StorageInterface,
+    mut payment_data: PaymentData<F>,
+    router_data: types::RouterData<F, types::PaymentsCaptureData, types::PaymentsResponseData>,
+    storage_scheme: enums::MerchantStorageScheme,
+) -> RouterResult<PaymentData<F>> {
+    // The capture made in this request is the last one
+    let capture = payment_data
+        .captures
+        .pop()
+        .get_required_value("capture")
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    let capture_update = match router_data.response {
+        Err(err) => storage::CaptureUpdate::ErrorUpdate {
+            status: enums::CaptureStatus::Failed,
+            error_message: Some(err.message),
+            error_code: Some(err.code),
+        },
+        Ok(types::PaymentsResponseData::TransactionResponse { resource_id, .. }) => {
+            storage::CaptureUpdate::ResponseUpdate {
+                status: router_data.status.foreign_into(),
+                connector_capture_id: match resource_id {
+                    types::ResponseId::NoResponseId => None,
+                    types::ResponseId::ConnectorTransactionId(id)
+                    | types::ResponseId::EncodedData(id) => Some(id),
+                },
+            }
+        }
+        Ok(_) => storage::CaptureUpdate::ResponseUpdate {
+            status: router_data.status.foreign_into(),
+            connector_capture_id: None,
+        },
+    };
+
+    let capture = db
+        .update_capture(capture, capture_update)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while updating the capture")?;
+    payment_data.captures.push(capture);
+
+    let amount = payment_data.payment_attempt.amount;
+    let amount_captured = helpers::get_amount_captured(&payment_data.captures);
+    let (attempt_status, intent_status) =
+        helpers::get_multiple_capture_status(amount, &payment_data.captures);
+
+    payment_data.payment_attempt = db
+        .update_payment_attempt(
+            payment_data.payment_attempt,
+            storage::PaymentAttemptUpdate::StatusUpdate {
+                status: attempt_status,
+            },
+            storage_scheme,
+        )
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound))?;
+
+    payment_data.payment_intent = db
+        .update_payment_intent(
+            payment_data.payment_intent,
+            storage::PaymentIntentUpdate::ResponseUpdate {
+                status: intent_status,
+                amount_captured: Some(amount_captured),
+                return_url: None,
+            },
+            storage_scheme,
+        )
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound))?;
+
+    Ok(payment_data)
+}
+
+/// Records the amount authorized on the payment once the connector has authorized the increment.
+/// A declined increment leaves the payment untouched, as the payment remains authorized for the
+/// amount authorized earlier.
+async fn incremental_authorization_response_update_tracker<F: Clone>(
+    db: &dyn StorageInterface,
+    mut payment_data: PaymentData<F>,
+    router_data: types::RouterData<
+        F,
+        types::PaymentsIncrementalAuthorizationData,
+        types::PaymentsResponseData,
+    >,
+    storage_scheme: enums::MerchantStorageScheme,
+) -> RouterResult<PaymentData<F>> {
+    if router_data.response.is_err() || router_data.status != enums::AttemptStatus::Authorized {
+        return Ok(payment_data);
+    }
+
+    let amount = router_data.request.total_amount;
+
+    payment_data.payment_attempt = db
+        .update_payment_attempt(
+            payment_data.payment_attempt,
+            storage::PaymentAttemptUpdate::AmountUpdate { amount },
+            storage_scheme,
+        )
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound))?;
+
+    let authorization_count = payment_data.payment_intent.authorization_count.unwrap_or(0) + 1;
+
+    payment_data.payment_intent = db
+        .update_payment_intent(
+            payment_data.payment_intent,
+            storage::PaymentIntentUpdate::IncrementalAuthorizationUpdate {
+                amount,
+                authorization_count: Some(authorization_count),
+            },
+            storage_scheme,
+        )
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound))?;
+
     Ok(payment_data)
 }
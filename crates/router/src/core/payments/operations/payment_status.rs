@@ -57,6 +57,7 @@ impl<F: Clone + Send> Domain<F, api::PaymentsRequest> for PaymentStatus {
         payment_data: &mut PaymentData<F>,
         request: Option<CustomerDetails>,
         merchant_id: &str,
+        storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<
         (
             BoxedOperation<'a, F, api::PaymentsRequest>,
@@ -70,6 +71,7 @@ impl<F: Clone + Send> Domain<F, api::PaymentsRequest> for PaymentStatus {
             payment_data,
             request,
             merchant_id,
+            storage_scheme,
         )
         .await
     }
@@ -227,10 +229,18 @@ async fn get_tracker_for_sync<
     currency = payment_attempt.currency.get_required_value("currency")?;
     amount = payment_attempt.amount.into();
 
-    let shipping_address =
-        helpers::get_address_by_id(db, payment_intent.shipping_address_id.clone()).await?;
-    let billing_address =
-        helpers::get_address_by_id(db, payment_intent.billing_address_id.clone()).await?;
+    let shipping_address = helpers::get_address_by_id(
+        db,
+        payment_intent.shipping_address_id.clone(),
+        storage_scheme,
+    )
+    .await?;
+    let billing_address = helpers::get_address_by_id(
+        db,
+        payment_intent.billing_address_id.clone(),
+        storage_scheme,
+    )
+    .await?;
 
     let refunds = db
         .find_refund_by_payment_id_merchant_id(&payment_id_str, merchant_id, storage_scheme)
@@ -243,6 +253,24 @@ async fn get_tracker_for_sync<
             )
         })?;
 
+    let captures = match payment_attempt.capture_method {
+        Some(enums::CaptureMethod::ManualMultiple) => db
+            .find_all_captures_by_merchant_id_payment_id_attempt_id(
+                merchant_id,
+                &payment_id_str,
+                &payment_attempt.attempt_id,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable_lazy(|| {
+                format!(
+                    "Failed while getting capture list for, payment_id: {}, merchant_id: {}",
+                    &payment_id_str, merchant_id
+                )
+            })?,
+        _ => vec![],
+    };
+
     let contains_encoded_data = connector_response.encoded_data.is_some();
 
     Ok((
@@ -272,6 +300,7 @@ async fn get_tracker_for_sync<
             ),
             payment_attempt,
             refunds,
+            captures,
             sessions_token: vec![],
             card_cvc: None,
         },
@@ -302,6 +331,7 @@ impl<F: Send + Clone> ValidateRequest<F, api::PaymentsRetrieveRequest> for Payme
                 payment_id: request.resource_id.clone(),
                 mandate_type: None,
                 storage_scheme: merchant_account.storage_scheme,
+                requires_lock: request.force_sync,
             },
         ))
     }
@@ -0,0 +1,315 @@
+use error_stack::ResultExt;
+use router_env::{instrument, tracing};
+use uuid::Uuid;
+
+use super::{
+    flows::{ConstructFlowSpecificData, Feature},
+    operations::{Operation, PaymentCreate, PaymentResponse},
+    CallConnectorAction, PaymentData,
+};
+use crate::{
+    configs::settings::PaymentRetry,
+    core::errors::{self, RouterResult},
+    logger,
+    routes::AppState,
+    services,
+    types::{self, api, storage},
+    utils::OptionExt,
+};
+
+/// Failure of a payment attempt which allows the payment to be retried with another connector
+#[derive(Debug, Clone)]
+enum RetryableFailure {
+    /// The connector returned an error response
+    ErrorResponse(types::ErrorResponse),
+    /// The connector did not respond before the request timed out
+    Timeout,
+}
+
+/// Calls the connectors in order until the payment succeeds, fails with an error which cannot be
+/// retried, or the maximum number of attempts is reached. A new payment attempt is created for
+/// every connector tried after the first one.
+#[instrument(skip_all)]
+pub async fn call_connector_service_with_retries<F, Req>(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    payment_id: &api::PaymentIdType,
+    connectors: Vec<api::ConnectorData>,
+    mut payment_data: PaymentData<F>,
+    customer: &Option<storage::Customer>,
+) -> RouterResult<PaymentData<F>>
+where
+    F: Send + Clone,
+
+    // To create connector flow specific interface data
+    PaymentData<F>: ConstructFlowSpecificData<F, Req, types::PaymentsResponseData>,
+    types::RouterData<F, Req, types::PaymentsResponseData>: Feature<F, Req> + Send,
+
+    // To construct connector flow specific api
+    dyn api::Connector: services::api::ConnectorIntegration<F, Req, types::PaymentsResponseData>,
+
+    // To perform router related operation for PaymentResponse
+    PaymentResponse: Operation<F, Req>,
+{
+    let retry_config = &state.conf.payment_retry;
+    let mut connectors = connectors
+        .into_iter()
+        .take(usize::from(retry_config.max_attempts));
+
+    let mut connector = connectors
+        .next()
+        .get_required_value("connector")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("No connector available for the payment")?;
+
+    loop {
+        let router_data_res = super::call_connector(
+            state,
+            merchant_account,
+            &connector,
+            &payment_data,
+            customer,
+            CallConnectorAction::Trigger,
+        )
+        .await;
+
+        let retryable_failure = match &router_data_res {
+            Ok(router_data) => match &router_data.response {
+                Err(error_response) => get_retryable_failure(retry_config, error_response),
+                Ok(_) => None,
+            },
+            Err(error) => error
+                .downcast_ref::<errors::ApiClientError>()
+                .filter(|error| {
+                    retry_config.retry_on_timeout
+                        && matches!(error, errors::ApiClientError::RequestTimeoutReceived)
+                })
+                .map(|_| RetryableFailure::Timeout),
+        };
+
+        match (retryable_failure, connectors.next()) {
+            (Some(failure), Some(next_connector)) => {
+                logger::info!(
+                    ?failure,
+                    failed_connector = %connector.connector_name,
+                    next_connector = %next_connector.connector_name,
+                    "Retrying payment with the next connector"
+                );
+
+                payment_data = create_retry_attempt(
+                    state,
+                    merchant_account,
+                    payment_data,
+                    failure,
+                    &next_connector,
+                )
+                .await?;
+                connector = next_connector;
+            }
+            _ => {
+                return super::update_payment_data_with_connector_response(
+                    state,
+                    merchant_account,
+                    payment_id,
+                    payment_data,
+                    router_data_res?,
+                )
+                .await;
+            }
+        }
+    }
+}
+
+fn get_retryable_failure(
+    retry_config: &PaymentRetry,
+    error_response: &types::ErrorResponse,
+) -> Option<RetryableFailure> {
+    let is_retryable_code = retry_config
+        .retryable_error_codes
+        .as_ref()
+        .map_or(false, |codes| codes.contains(&error_response.code));
+    let is_server_error =
+        retry_config.retry_on_server_error && (500..=599).contains(&error_response.status_code);
+
+    (is_retryable_code || is_server_error)
+        .then(|| RetryableFailure::ErrorResponse(error_response.clone()))
+}
+
+/// Marks the current payment attempt as failed and creates a new attempt, along with its
+/// connector response, for the next connector
+#[instrument(skip_all)]
+async fn create_retry_attempt<F>(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    mut payment_data: PaymentData<F>,
+    failure: RetryableFailure,
+    next_connector: &api::ConnectorData,
+) -> RouterResult<PaymentData<F>>
+where
+    F: Send + Clone,
+{
+    let db = &*state.store;
+    let storage_scheme = merchant_account.storage_scheme;
+
+    let (error_code, error_message) = match failure {
+        RetryableFailure::ErrorResponse(error_response) => {
+            (error_response.code, error_response.message)
+        }
+        RetryableFailure::Timeout => (
+            "TIMEOUT".to_string(),
+            "Request to the connector timed out".to_string(),
+        ),
+    };
+
+    let estimated_fee = super::fees::get_min_cost_routing_fee(
+        db,
+        merchant_account,
+        &next_connector.connector_name.to_string(),
+        &super::get_routing_input(&payment_data),
+    )
+    .await;
+
+    let previous_status = payment_data.payment_attempt.status;
+    let payment_slice = services::success_rate::PaymentSlice::new(
+        &payment_data.payment_attempt,
+        payment_data.payment_method_data.as_ref(),
+    );
+
+    let failed_attempt = db
+        .update_payment_attempt(
+            payment_data.payment_attempt,
+            storage::PaymentAttemptUpdate::ErrorUpdate {
+                connector: None,
+                status: storage::enums::AttemptStatus::Failure,
+                error_code: Some(error_code),
+                error_message: Some(error_message),
+            },
+            storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update the failed payment attempt")?;
+
+    services::success_rate::record_authorization_outcome(
+        state,
+        previous_status,
+        &failed_attempt,
+        &payment_slice,
+    )
+    .await;
+
+    let new_attempt = make_retry_attempt(&failed_attempt, next_connector, estimated_fee);
+
+    payment_data.payment_attempt = db
+        .insert_payment_attempt(new_attempt, storage_scheme)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert a payment attempt for the retry")?;
+
+    payment_data.connector_response = db
+        .insert_connector_response(
+            PaymentCreate::make_connector_response(&payment_data.payment_attempt),
+            storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert a connector response for the retry")?;
+
+    Ok(payment_data)
+}
+
+fn make_retry_attempt(
+    failed_attempt: &storage::PaymentAttempt,
+    next_connector: &api::ConnectorData,
+    estimated_fee: Option<i64>,
+) -> storage::PaymentAttemptNew {
+    let created_at @ modified_at @ last_synced = Some(common_utils::date_time::now());
+
+    storage::PaymentAttemptNew {
+        payment_id: failed_attempt.payment_id.clone(),
+        merchant_id: failed_attempt.merchant_id.clone(),
+        attempt_id: Uuid::new_v4().simple().to_string(),
+        status: storage::enums::AttemptStatus::Pending,
+        amount: failed_attempt.amount,
+        currency: failed_attempt.currency,
+        save_to_locker: failed_attempt.save_to_locker,
+        connector: Some(next_connector.connector_name.to_string()),
+        offer_amount: failed_attempt.offer_amount,
+        surcharge_amount: failed_attempt.surcharge_amount,
+        tax_amount: failed_attempt.tax_amount,
+        payment_method_id: failed_attempt.payment_method_id.clone(),
+        payment_method: failed_attempt.payment_method,
+        capture_method: failed_attempt.capture_method,
+        capture_on: failed_attempt.capture_on,
+        confirm: failed_attempt.confirm,
+        authentication_type: failed_attempt.authentication_type,
+        created_at,
+        modified_at,
+        last_synced,
+        amount_to_capture: failed_attempt.amount_to_capture,
+        mandate_id: failed_attempt.mandate_id.clone(),
+        browser_info: failed_attempt.browser_info.clone(),
+        payment_token: failed_attempt.payment_token.clone(),
+        payment_experience: failed_attempt.payment_experience,
+        payment_method_type: failed_attempt.payment_method_type,
+        payment_method_data: failed_attempt.payment_method_data.clone(),
+        capture_delay_hours: failed_attempt.capture_delay_hours,
+        estimated_fee,
+        ..storage::PaymentAttemptNew::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use std::collections::HashSet;
+
+    use super::*;
+
+    fn error_response(code: &str, status_code: u16) -> types::ErrorResponse {
+        types::ErrorResponse {
+            code: code.to_string(),
+            message: "Payment failed".to_string(),
+            reason: None,
+            status_code,
+        }
+    }
+
+    #[test]
+    fn retries_on_configured_error_codes() {
+        let retry_config = PaymentRetry {
+            max_attempts: 2,
+            retryable_error_codes: Some(HashSet::from(["processor_declined".to_string()])),
+            retry_on_server_error: false,
+            retry_on_timeout: false,
+        };
+
+        assert!(
+            get_retryable_failure(&retry_config, &error_response("processor_declined", 402))
+                .is_some()
+        );
+        assert!(
+            get_retryable_failure(&retry_config, &error_response("card_declined", 402)).is_none()
+        );
+        assert!(
+            get_retryable_failure(&retry_config, &error_response("internal_error", 500)).is_none()
+        );
+    }
+
+    #[test]
+    fn retries_on_server_errors_when_enabled() {
+        let retry_config = PaymentRetry {
+            max_attempts: 2,
+            retry_on_server_error: true,
+            ..PaymentRetry::default()
+        };
+
+        assert!(matches!(
+            get_retryable_failure(&retry_config, &error_response("internal_error", 503)),
+            Some(RetryableFailure::ErrorResponse(error)) if error.status_code == 503
+        ));
+        assert!(
+            get_retryable_failure(&retry_config, &error_response("invalid_card", 400)).is_none()
+        );
+    }
+}
@@ -0,0 +1,667 @@
+use std::{cmp::Ordering, collections::HashMap};
+
+use api_models::{admin as admin_api, enums as api_enums, routing as routing_api};
+use error_stack::report;
+use rand::Rng;
+
+use crate::{
+    configs::settings,
+    core::errors::{self, RouterResult},
+    utils,
+};
+
+/// Prefixes of the card numbers issued by each card network, as ranges of the leading digits along
+/// with the number of digits they span. More specific ranges come first, as the first matching
+/// range decides the network.
+const CARD_NETWORK_PREFIXES: &[(u32, u32, usize, api_enums::CardNetwork)] = &[
+    (4, 4, 1, api_enums::CardNetwork::Visa),
+    (2221, 2720, 4, api_enums::CardNetwork::Mastercard),
+    (51, 55, 2, api_enums::CardNetwork::Mastercard),
+    (34, 34, 2, api_enums::CardNetwork::AmericanExpress),
+    (37, 37, 2, api_enums::CardNetwork::AmericanExpress),
+    (3528, 3589, 4, api_enums::CardNetwork::JCB),
+    (300, 305, 3, api_enums::CardNetwork::DinersClub),
+    (36, 36, 2, api_enums::CardNetwork::DinersClub),
+    (38, 39, 2, api_enums::CardNetwork::DinersClub),
+    (6011, 6011, 4, api_enums::CardNetwork::Discover),
+    (644, 649, 3, api_enums::CardNetwork::Discover),
+    (6521, 6522, 4, api_enums::CardNetwork::RuPay),
+    (60, 60, 2, api_enums::CardNetwork::RuPay),
+    (65, 65, 2, api_enums::CardNetwork::Discover),
+    (62, 62, 2, api_enums::CardNetwork::UnionPay),
+    (6759, 6759, 4, api_enums::CardNetwork::Maestro),
+    (50, 50, 2, api_enums::CardNetwork::Maestro),
+    (56, 58, 2, api_enums::CardNetwork::Maestro),
+];
+
+/// Details of the payment against which the routing rules are evaluated
+#[derive(Debug, Clone)]
+pub struct RoutingInput {
+    pub amount: i64,
+    pub currency: api_enums::Currency,
+    pub card_network: Option<api_enums::CardNetwork>,
+    pub country: Option<String>,
+    pub payment_method_type: Option<api_enums::PaymentMethodType>,
+    /// Two-letter ISO code of the country in which the card was issued
+    pub card_issuing_country: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RoutingDecision {
+    /// Connectors in the order in which they should be tried
+    pub connectors: Vec<api_enums::RoutableConnectors>,
+    /// Name of the rule which decided the connectors, `None` if no rule was involved
+    pub matched_rule: Option<String>,
+}
+
+/// Details of the connectors gathered for the payment being routed, used by the routing algorithms
+/// which rank the connectors
+#[derive(Debug, Clone, Default)]
+pub struct ConnectorStats {
+    /// Authorization success rates of the connectors for payments similar to the one being
+    /// routed, in percent. Connectors with too few recent payments are left out.
+    pub success_rates: HashMap<api_enums::RoutableConnectors, f64>,
+    /// Percentage of the payments used to explore connectors other than the best one, unless
+    /// configured in the routing algorithm
+    pub default_exploration_percentage: u8,
+    /// Estimated fees of the payment when made through the connectors, in the lowest denomination
+    /// of its currency. Connectors without a fee schedule are left out.
+    pub estimated_fees: HashMap<api_enums::RoutableConnectors, i64>,
+}
+
+pub fn perform_routing(
+    algorithm: &admin_api::RoutingAlgorithm,
+    input: &RoutingInput,
+    connector_stats: &ConnectorStats,
+) -> RouterResult<RoutingDecision> {
+    let (connectors, matched_rule) = match algorithm {
+        admin_api::RoutingAlgorithm::Single(connector) => (vec![*connector], None),
+        admin_api::RoutingAlgorithm::Priority(connectors) => (connectors.clone(), None),
+        admin_api::RoutingAlgorithm::VolumeSplit(splits) => (perform_volume_split(splits), None),
+        admin_api::RoutingAlgorithm::Advanced(routing_rules) => {
+            match routing_rules
+                .rules
+                .iter()
+                .find(|rule| conditions_match(&rule.conditions, input))
+            {
+                Some(rule) => (evaluate_output(&rule.output), Some(rule.name.clone())),
+                None => (evaluate_output(&routing_rules.default_output), None),
+            }
+        }
+        admin_api::RoutingAlgorithm::MaxConversion(max_conversion) => {
+            let mut connectors =
+                rank_by_success_rate(&max_conversion.connectors, &connector_stats.success_rates);
+            explore_connectors(
+                &mut connectors,
+                max_conversion
+                    .exploration_percentage
+                    .unwrap_or(connector_stats.default_exploration_percentage),
+            );
+            (connectors, None)
+        }
+        admin_api::RoutingAlgorithm::MinCost(connectors) => (
+            rank_by_estimated_fee(connectors, &connector_stats.estimated_fees),
+            None,
+        ),
+    };
+
+    utils::when(connectors.is_empty(), || {
+        Err(report!(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Routing algorithm did not return any connector"))
+    })?;
+
+    Ok(RoutingDecision {
+        connectors,
+        matched_rule,
+    })
+}
+
+fn evaluate_output(output: &admin_api::RoutingOutput) -> Vec<api_enums::RoutableConnectors> {
+    match output {
+        admin_api::RoutingOutput::Priority(connectors) => connectors.clone(),
+        admin_api::RoutingOutput::VolumeSplit(splits) => perform_volume_split(splits),
+    }
+}
+
+/// Picks a connector at random weighted by the configured splits. The picked connector is placed
+/// first and the rest follow in decreasing order of their splits, so that they can be used as
+/// fallbacks.
+fn perform_volume_split(
+    splits: &[admin_api::ConnectorVolumeSplit],
+) -> Vec<api_enums::RoutableConnectors> {
+    let total: u32 = splits.iter().map(|split| u32::from(split.split)).sum();
+    if total == 0 {
+        return Vec::new();
+    }
+
+    let mut pick = rand::thread_rng().gen_range(0..total);
+    let picked_index = splits
+        .iter()
+        .position(|split| {
+            let split = u32::from(split.split);
+            if pick < split {
+                true
+            } else {
+                pick -= split;
+                false
+            }
+        })
+        .unwrap_or_default();
+
+    let mut remaining = splits
+        .iter()
+        .enumerate()
+        .filter(|(index, _)| *index != picked_index)
+        .map(|(_, split)| split)
+        .collect::<Vec<_>>();
+    remaining.sort_by(|a, b| b.split.cmp(&a.split));
+
+    splits
+        .get(picked_index)
+        .into_iter()
+        .chain(remaining)
+        .map(|split| split.connector)
+        .collect()
+}
+
+/// Orders the connectors by their success rates, highest first. Connectors whose success rate is
+/// not known follow, in the configured order.
+fn rank_by_success_rate(
+    connectors: &[api_enums::RoutableConnectors],
+    success_rates: &HashMap<api_enums::RoutableConnectors, f64>,
+) -> Vec<api_enums::RoutableConnectors> {
+    let mut ranked = connectors.to_vec();
+    ranked.sort_by(|a, b| match (success_rates.get(a), success_rates.get(b)) {
+        (Some(a), Some(b)) => b.partial_cmp(a).unwrap_or(Ordering::Equal),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => Ordering::Equal,
+    });
+    ranked
+}
+
+/// Orders the connectors by the estimated fees of the payment, cheapest first. Connectors whose fee
+/// cannot be estimated follow, in the configured order.
+fn rank_by_estimated_fee(
+    connectors: &[api_enums::RoutableConnectors],
+    estimated_fees: &HashMap<api_enums::RoutableConnectors, i64>,
+) -> Vec<api_enums::RoutableConnectors> {
+    let mut ranked = connectors.to_vec();
+    ranked.sort_by_key(|connector| match estimated_fees.get(connector) {
+        Some(fee) => (false, *fee),
+        None => (true, 0),
+    });
+    ranked
+}
+
+/// Moves a connector other than the best one to the front for the given percentage of the
+/// payments, so that the success rates of all the connectors keep being measured
+fn explore_connectors(
+    connectors: &mut [api_enums::RoutableConnectors],
+    exploration_percentage: u8,
+) {
+    let mut rng = rand::thread_rng();
+    if connectors.len() < 2 || rng.gen_range(0..100) >= exploration_percentage {
+        return;
+    }
+
+    let explored_index = rng.gen_range(1..connectors.len());
+    if let Some(connectors) = connectors.get_mut(..=explored_index) {
+        connectors.rotate_right(1);
+    }
+}
+
+fn conditions_match(conditions: &admin_api::RoutingConditions, input: &RoutingInput) -> bool {
+    let currency_matches = conditions
+        .currency
+        .as_ref()
+        .map_or(true, |currencies| currencies.contains(&input.currency));
+
+    let amount_matches = conditions.amount.as_ref().map_or(true, |range| {
+        range.min.map_or(true, |min| input.amount >= min)
+            && range.max.map_or(true, |max| input.amount <= max)
+    });
+
+    let card_network_matches = conditions.card_network.as_ref().map_or(true, |networks| {
+        input
+            .card_network
+            .as_ref()
+            .map_or(false, |network| networks.contains(network))
+    });
+
+    let country_matches = conditions.country.as_ref().map_or(true, |countries| {
+        input.country.as_ref().map_or(false, |country| {
+            countries
+                .iter()
+                .any(|allowed| allowed.eq_ignore_ascii_case(country))
+        })
+    });
+
+    let payment_method_type_matches =
+        conditions
+            .payment_method_type
+            .as_ref()
+            .map_or(true, |payment_method_types| {
+                input
+                    .payment_method_type
+                    .map_or(false, |pm_type| payment_method_types.contains(&pm_type))
+            });
+
+    currency_matches
+        && amount_matches
+        && card_network_matches
+        && country_matches
+        && payment_method_type_matches
+}
+
+/// The card network of the card, found from the leading digits of the card number
+pub fn get_card_network_from_bin(card_bin: &str) -> Option<api_enums::CardNetwork> {
+    CARD_NETWORK_PREFIXES
+        .iter()
+        .find(|(start, end, digits, _)| {
+            card_bin
+                .get(..*digits)
+                .and_then(|prefix| prefix.parse::<u32>().ok())
+                .map_or(false, |prefix| (*start..=*end).contains(&prefix))
+        })
+        .map(|(_, _, _, card_network)| card_network.clone())
+}
+
+/// Removes the connectors whose payment method filters do not allow the country or the currency of
+/// the payment, keeping the order of the rest. Card payments are filtered by the card network and
+/// the other payments by the payment method type, as when listing the payment methods. All the
+/// connectors are kept if none of them is allowed. The filters are only reported by the routing
+/// simulation, they are not applied to live payments.
+pub fn apply_connector_filters(
+    config: &settings::ConnectorFilters,
+    connectors: Vec<api_enums::RoutableConnectors>,
+    input: &RoutingInput,
+) -> (
+    Vec<api_enums::RoutableConnectors>,
+    Vec<routing_api::ExcludedConnector>,
+) {
+    let filter_key = match input.payment_method_type {
+        Some(api_enums::PaymentMethodType::Credit | api_enums::PaymentMethodType::Debit) | None => {
+            input
+                .card_network
+                .clone()
+                .map(settings::PaymentMethodFilterKey::CardNetwork)
+        }
+        Some(payment_method_type) => Some(settings::PaymentMethodFilterKey::PaymentMethodType(
+            payment_method_type,
+        )),
+    };
+    let filter_key = match filter_key {
+        Some(filter_key) => filter_key,
+        None => return (connectors, Vec::new()),
+    };
+
+    let mut allowed_connectors = Vec::with_capacity(connectors.len());
+    let mut excluded_connectors = Vec::new();
+    for connector in &connectors {
+        let filter = config
+            .0
+            .get(&connector.to_string())
+            .and_then(|filters| filters.0.get(&filter_key));
+        let (country_not_allowed, currency_not_allowed) = match filter {
+            Some(filter) => (
+                filter
+                    .country
+                    .as_ref()
+                    .zip(input.country.as_ref())
+                    .map_or(false, |(countries, country)| !countries.contains(country)),
+                filter
+                    .currency
+                    .as_ref()
+                    .map_or(false, |currencies| !currencies.contains(&input.currency)),
+            ),
+            None => (false, false),
+        };
+
+        if country_not_allowed || currency_not_allowed {
+            excluded_connectors.push(routing_api::ExcludedConnector {
+                connector: *connector,
+                filter: match filter_key {
+                    settings::PaymentMethodFilterKey::PaymentMethodType(payment_method_type) => {
+                        payment_method_type.to_string()
+                    }
+                    settings::PaymentMethodFilterKey::CardNetwork(ref card_network) => {
+                        card_network.to_string()
+                    }
+                },
+                country_not_allowed,
+                currency_not_allowed,
+            });
+        } else {
+            allowed_connectors.push(*connector);
+        }
+    }
+
+    if allowed_connectors.is_empty() {
+        return (connectors, excluded_connectors);
+    }
+    (allowed_connectors, excluded_connectors)
+}
+
+pub fn validate_routing_algorithm(
+    algorithm: &admin_api::RoutingAlgorithm,
+) -> Result<(), errors::ApiErrorResponse> {
+    match algorithm {
+        admin_api::RoutingAlgorithm::Single(_) => Ok(()),
+        admin_api::RoutingAlgorithm::Priority(connectors) => validate_priority(connectors),
+        admin_api::RoutingAlgorithm::VolumeSplit(splits) => validate_volume_split(splits),
+        admin_api::RoutingAlgorithm::Advanced(routing_rules) => {
+            routing_rules.rules.iter().try_for_each(|rule| {
+                utils::when(rule.name.is_empty(), || {
+                    Err(invalid_routing_algorithm("rule name cannot be empty"))
+                })?;
+                validate_output(&rule.output)
+            })?;
+            validate_output(&routing_rules.default_output)
+        }
+        admin_api::RoutingAlgorithm::MaxConversion(max_conversion) => {
+            validate_priority(&max_conversion.connectors)?;
+            utils::when(
+                max_conversion
+                    .exploration_percentage
+                    .map_or(false, |percentage| percentage > 100),
+                || {
+                    Err(invalid_routing_algorithm(
+                        "exploration percentage must not exceed 100",
+                    ))
+                },
+            )
+        }
+        admin_api::RoutingAlgorithm::MinCost(connectors) => validate_priority(connectors),
+    }
+}
+
+fn validate_output(output: &admin_api::RoutingOutput) -> Result<(), errors::ApiErrorResponse> {
+    match output {
+        admin_api::RoutingOutput::Priority(connectors) => validate_priority(connectors),
+        admin_api::RoutingOutput::VolumeSplit(splits) => validate_volume_split(splits),
+    }
+}
+
+fn validate_priority(
+    connectors: &[api_enums::RoutableConnectors],
+) -> Result<(), errors::ApiErrorResponse> {
+    utils::when(connectors.is_empty(), || {
+        Err(invalid_routing_algorithm(
+            "priority list must contain at least one connector",
+        ))
+    })
+}
+
+fn validate_volume_split(
+    splits: &[admin_api::ConnectorVolumeSplit],
+) -> Result<(), errors::ApiErrorResponse> {
+    let total: u32 = splits.iter().map(|split| u32::from(split.split)).sum();
+    utils::when(total != 100, || {
+        Err(invalid_routing_algorithm(
+            "volume splits must add up to 100",
+        ))
+    })
+}
+
+fn invalid_routing_algorithm(reason: &str) -> errors::ApiErrorResponse {
+    errors::ApiErrorResponse::InvalidRequestData {
+        message: format!("Invalid routing algorithm: {reason}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn input() -> RoutingInput {
+        RoutingInput {
+            amount: 1000,
+            currency: api_enums::Currency::USD,
+            card_network: Some(api_enums::CardNetwork::Visa),
+            country: Some("US".to_string()),
+            payment_method_type: None,
+            card_issuing_country: Some("US".to_string()),
+        }
+    }
+
+    fn advanced_algorithm() -> admin_api::RoutingAlgorithm {
+        serde_json::from_value(serde_json::json!({
+            "type": "advanced",
+            "data": {
+                "rules": [
+                    {
+                        "name": "large_eur_payments",
+                        "conditions": {
+                            "currency": ["EUR"],
+                            "amount": { "min": 10000 }
+                        },
+                        "output": { "type": "priority", "data": ["adyen", "checkout"] }
+                    },
+                    {
+                        "name": "us_visa",
+                        "conditions": {
+                            "card_network": ["Visa"],
+                            "country": ["us"]
+                        },
+                        "output": { "type": "priority", "data": ["stripe", "adyen"] }
+                    }
+                ],
+                "default_output": { "type": "priority", "data": ["checkout"] }
+            }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn advanced_routing_uses_first_matching_rule() {
+        let decision =
+            perform_routing(&advanced_algorithm(), &input(), &ConnectorStats::default()).unwrap();
+        assert_eq!(
+            decision,
+            RoutingDecision {
+                connectors: vec![
+                    api_enums::RoutableConnectors::Stripe,
+                    api_enums::RoutableConnectors::Adyen
+                ],
+                matched_rule: Some("us_visa".to_string()),
+            }
+        );
+    }
+
+    #[test]
+    fn advanced_routing_falls_back_to_default_output() {
+        let mut input = input();
+        input.card_network = None;
+
+        let decision =
+            perform_routing(&advanced_algorithm(), &input, &ConnectorStats::default()).unwrap();
+        assert_eq!(
+            decision.connectors,
+            vec![api_enums::RoutableConnectors::Checkout]
+        );
+        assert_eq!(decision.matched_rule, None);
+    }
+
+    #[test]
+    fn volume_split_orders_fallbacks_by_split() {
+        let splits = vec![
+            admin_api::ConnectorVolumeSplit {
+                connector: api_enums::RoutableConnectors::Adyen,
+                split: 0,
+            },
+            admin_api::ConnectorVolumeSplit {
+                connector: api_enums::RoutableConnectors::Checkout,
+                split: 100,
+            },
+            admin_api::ConnectorVolumeSplit {
+                connector: api_enums::RoutableConnectors::Stripe,
+                split: 0,
+            },
+        ];
+
+        assert_eq!(
+            perform_volume_split(&splits),
+            vec![
+                api_enums::RoutableConnectors::Checkout,
+                api_enums::RoutableConnectors::Adyen,
+                api_enums::RoutableConnectors::Stripe
+            ]
+        );
+    }
+
+    #[test]
+    fn volume_split_must_add_up_to_hundred() {
+        let algorithm = admin_api::RoutingAlgorithm::VolumeSplit(vec![
+            admin_api::ConnectorVolumeSplit {
+                connector: api_enums::RoutableConnectors::Adyen,
+                split: 50,
+            },
+            admin_api::ConnectorVolumeSplit {
+                connector: api_enums::RoutableConnectors::Stripe,
+                split: 40,
+            },
+        ]);
+
+        assert!(validate_routing_algorithm(&algorithm).is_err());
+    }
+
+    #[test]
+    fn max_conversion_ranks_connectors_by_success_rate() {
+        let algorithm =
+            admin_api::RoutingAlgorithm::MaxConversion(admin_api::MaxConversionRouting {
+                connectors: vec![
+                    api_enums::RoutableConnectors::Adyen,
+                    api_enums::RoutableConnectors::Checkout,
+                    api_enums::RoutableConnectors::Stripe,
+                    api_enums::RoutableConnectors::Worldpay,
+                ],
+                exploration_percentage: Some(0),
+            });
+        let connector_stats = ConnectorStats {
+            success_rates: HashMap::from([
+                (api_enums::RoutableConnectors::Stripe, 92.5),
+                (api_enums::RoutableConnectors::Checkout, 80.0),
+            ]),
+            default_exploration_percentage: 100,
+            ..ConnectorStats::default()
+        };
+
+        let decision = perform_routing(&algorithm, &input(), &connector_stats).unwrap();
+        assert_eq!(
+            decision.connectors,
+            vec![
+                api_enums::RoutableConnectors::Stripe,
+                api_enums::RoutableConnectors::Checkout,
+                api_enums::RoutableConnectors::Adyen,
+                api_enums::RoutableConnectors::Worldpay
+            ]
+        );
+    }
+
+    #[test]
+    fn exploration_moves_another_connector_first() {
+        let ranked = vec![
+            api_enums::RoutableConnectors::Stripe,
+            api_enums::RoutableConnectors::Checkout,
+            api_enums::RoutableConnectors::Adyen,
+        ];
+
+        let mut connectors = ranked.clone();
+        explore_connectors(&mut connectors, 100);
+        assert_ne!(connectors[0], api_enums::RoutableConnectors::Stripe);
+        assert!(ranked
+            .iter()
+            .all(|connector| connectors.contains(connector)));
+
+        let mut connectors = ranked.clone();
+        explore_connectors(&mut connectors, 0);
+        assert_eq!(connectors, ranked);
+    }
+
+    #[test]
+    fn card_network_is_found_from_bin() {
+        assert_eq!(
+            get_card_network_from_bin("424242"),
+            Some(api_enums::CardNetwork::Visa)
+        );
+        assert_eq!(
+            get_card_network_from_bin("222300"),
+            Some(api_enums::CardNetwork::Mastercard)
+        );
+        assert_eq!(
+            get_card_network_from_bin("378282"),
+            Some(api_enums::CardNetwork::AmericanExpress)
+        );
+        assert_eq!(
+            get_card_network_from_bin("601111"),
+            Some(api_enums::CardNetwork::Discover)
+        );
+        assert_eq!(get_card_network_from_bin("999999"), None);
+    }
+
+    #[test]
+    fn connector_filters_exclude_disallowed_connectors() {
+        let config: settings::ConnectorFilters = serde_json::from_value(serde_json::json!({
+            "stripe": { "Visa": { "currency": "EUR,GBP" } },
+            "adyen": { "Visa": { "country": "US,GB", "currency": "USD" } },
+        }))
+        .unwrap();
+        let connectors = vec![
+            api_enums::RoutableConnectors::Stripe,
+            api_enums::RoutableConnectors::Adyen,
+            api_enums::RoutableConnectors::Checkout,
+        ];
+
+        let (allowed, excluded) = apply_connector_filters(&config, connectors.clone(), &input());
+        assert_eq!(
+            allowed,
+            vec![
+                api_enums::RoutableConnectors::Adyen,
+                api_enums::RoutableConnectors::Checkout
+            ]
+        );
+        assert_eq!(
+            excluded,
+            vec![routing_api::ExcludedConnector {
+                connector: api_enums::RoutableConnectors::Stripe,
+                filter: "Visa".to_string(),
+                country_not_allowed: false,
+                currency_not_allowed: true,
+            }]
+        );
+
+        let mut input = input();
+        input.card_network = None;
+        let (allowed, excluded) = apply_connector_filters(&config, connectors.clone(), &input);
+        assert_eq!(allowed, connectors);
+        assert!(excluded.is_empty());
+    }
+
+    #[test]
+    fn min_cost_ranks_connectors_by_estimated_fee() {
+        let algorithm = admin_api::RoutingAlgorithm::MinCost(vec![
+            api_enums::RoutableConnectors::Adyen,
+            api_enums::RoutableConnectors::Checkout,
+            api_enums::RoutableConnectors::Stripe,
+        ]);
+        let connector_stats = ConnectorStats {
+            estimated_fees: HashMap::from([
+                (api_enums::RoutableConnectors::Stripe, 320),
+                (api_enums::RoutableConnectors::Checkout, 290),
+            ]),
+            ..ConnectorStats::default()
+        };
+
+        let decision = perform_routing(&algorithm, &input(), &connector_stats).unwrap();
+        assert_eq!(
+            decision.connectors,
+            vec![
+                api_enums::RoutableConnectors::Checkout,
+                api_enums::RoutableConnectors::Stripe,
+                api_enums::RoutableConnectors::Adyen
+            ]
+        );
+    }
+}
@@ -138,6 +138,7 @@ where
             payment_data.payment_attempt,
             payment_data.payment_intent,
             payment_data.refunds,
+            payment_data.captures,
             payment_data.payment_method_data,
             customer,
             auth_flow,
@@ -227,6 +228,7 @@ pub fn payments_to_payments_response<R, Op>(
     payment_attempt: storage::PaymentAttempt,
     payment_intent: storage::PaymentIntent,
     refunds: Vec<storage::Refund>,
+    captures: Vec<storage::Capture>,
     payment_method_data: Option<api::PaymentMethodData>,
     customer: Option<storage::Customer>,
     auth_flow: services::AuthFlow,
@@ -249,6 +251,16 @@ where
     } else {
         Some(refunds.into_iter().map(ForeignInto::foreign_into).collect())
     };
+    let captures_response = if captures.is_empty() {
+        None
+    } else {
+        Some(
+            captures
+                .into_iter()
+                .map(ForeignInto::foreign_into)
+                .collect(),
+        )
+    };
 
     Ok(match payment_request {
         Some(_request) => {
@@ -301,6 +313,11 @@ where
                         .set_mandate_id(mandate_id)
                         .set_description(payment_intent.description)
                         .set_refunds(refunds_response) // refunds.iter().map(refund_to_refund_response),
+                        .set_captures(captures_response)
+                        .set_request_incremental_authorization(
+                            payment_intent.request_incremental_authorization,
+                        )
+                        .set_authorization_count(payment_intent.authorization_count)
                         .set_payment_method(
                             payment_attempt
                                 .payment_method
@@ -364,6 +381,9 @@ where
             customer_id: payment_intent.customer_id,
             description: payment_intent.description,
             refunds: refunds_response,
+            captures: captures_response,
+            request_incremental_authorization: payment_intent.request_incremental_authorization,
+            authorization_count: payment_intent.authorization_count,
             payment_method: payment_attempt
                 .payment_method
                 .map(ForeignInto::foreign_into),
@@ -433,7 +453,9 @@ impl<F: Clone> TryFrom<PaymentData<F>> for types::PaymentsAuthorizeData {
             confirm: payment_data.payment_attempt.confirm,
             statement_descriptor_suffix: payment_data.payment_intent.statement_descriptor_suffix,
             statement_descriptor: payment_data.payment_intent.statement_descriptor_name,
-            capture_method: payment_data.payment_attempt.capture_method,
+            capture_method: get_connector_capture_method(
+                payment_data.payment_attempt.capture_method,
+            ),
             amount: payment_data.amount.into(),
             currency: payment_data.currency,
             browser_info,
@@ -441,10 +463,25 @@ impl<F: Clone> TryFrom<PaymentData<F>> for types::PaymentsAuthorizeData {
             payment_experience: payment_data.payment_attempt.payment_experience,
             order_details,
             payment_method_type: payment_data.payment_attempt.payment_method_type,
+            request_incremental_authorization: payment_data
+                .payment_intent
+                .request_incremental_authorization
+                .unwrap_or(false),
         })
     }
 }
 
+/// Scheduled captures are made by the router through the process tracker, so the payment is
+/// authorized with the connector as a manual capture payment
+fn get_connector_capture_method(
+    capture_method: Option<enums::CaptureMethod>,
+) -> Option<enums::CaptureMethod> {
+    capture_method.map(|capture_method| match capture_method {
+        enums::CaptureMethod::Scheduled => enums::CaptureMethod::Manual,
+        capture_method => capture_method,
+    })
+}
+
 impl<F: Clone> TryFrom<PaymentData<F>> for types::PaymentsSyncData {
     type Error = errors::ApiErrorResponse;
 
@@ -457,7 +494,9 @@ impl<F: Clone> TryFrom<PaymentData<F>> for types::PaymentsSyncData {
                 None => types::ResponseId::NoResponseId,
             },
             encoded_data: payment_data.connector_response.encoded_data,
-            capture_method: payment_data.payment_attempt.capture_method,
+            capture_method: get_connector_capture_method(
+                payment_data.payment_attempt.capture_method,
+            ),
         })
     }
 }
@@ -466,6 +505,23 @@ impl<F: Clone> TryFrom<PaymentData<F>> for types::PaymentsCaptureData {
     type Error = errors::ApiErrorResponse;
 
     fn try_from(payment_data: PaymentData<F>) -> Result<Self, Self::Error> {
+        let multiple_capture_data = match payment_data.payment_attempt.capture_method {
+            Some(enums::CaptureMethod::ManualMultiple) => {
+                payment_data
+                    .captures
+                    .last()
+                    .map(|capture| types::MultipleCaptureData {
+                        capture_sequence: capture.capture_sequence,
+                        capture_reference: capture.capture_id.clone(),
+                        final_capture: helpers::get_amount_capturable(
+                            payment_data.payment_attempt.amount,
+                            &payment_data.captures,
+                        ) <= 0,
+                    })
+            }
+            _ => None,
+        };
+
         Ok(Self {
             amount_to_capture: payment_data.payment_attempt.amount_to_capture,
             currency: payment_data.currency,
@@ -474,6 +530,7 @@ impl<F: Clone> TryFrom<PaymentData<F>> for types::PaymentsCaptureData {
                 .connector_transaction_id
                 .ok_or(errors::ApiErrorResponse::MerchantConnectorAccountNotFound)?,
             amount: payment_data.amount.into(),
+            multiple_capture_data,
         })
     }
 }
@@ -496,6 +553,27 @@ impl<F: Clone> TryFrom<PaymentData<F>> for types::PaymentsCancelData {
     }
 }
 
+impl<F: Clone> TryFrom<PaymentData<F>> for types::PaymentsIncrementalAuthorizationData {
+    type Error = errors::ApiErrorResponse;
+
+    fn try_from(payment_data: PaymentData<F>) -> Result<Self, Self::Error> {
+        // The amount of the payment data is the amount requested to be authorized, while that of
+        // the payment attempt is the amount authorized so far
+        let total_amount: i64 = payment_data.amount.into();
+        Ok(Self {
+            total_amount,
+            additional_amount: total_amount - payment_data.payment_attempt.amount,
+            currency: payment_data.currency,
+            connector_transaction_id: payment_data
+                .payment_attempt
+                .connector_transaction_id
+                .ok_or(errors::ApiErrorResponse::MissingRequiredField {
+                    field_name: "connector_transaction_id",
+                })?,
+        })
+    }
+}
+
 impl<F: Clone> TryFrom<PaymentData<F>> for types::PaymentsSessionData {
     type Error = error_stack::Report<errors::ApiErrorResponse>;
 
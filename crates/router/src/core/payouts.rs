@@ -0,0 +1,534 @@
+use std::{marker::PhantomData, str::FromStr};
+
+use common_utils::pii::Email;
+use error_stack::{report, ResultExt};
+use masking::Secret;
+use router_env::{instrument, tracing};
+
+use crate::{
+    core::{
+        errors::{self, ConnectorErrorExt, RouterResponse, RouterResult, StorageErrorExt},
+        payments::{self, PaymentAddress},
+        utils as core_utils,
+    },
+    logger,
+    routes::AppState,
+    services,
+    types::{
+        self,
+        api::{self, payouts},
+        storage::{self, enums},
+        transformers::{ForeignFrom, ForeignInto},
+    },
+    utils::{self, ValueExt},
+};
+
+/// Recipient details which are sent to the connector while submitting a payout, these are not
+/// persisted along with the payout.
+#[derive(Debug, Clone, Default)]
+pub struct PayoutRecipientDetails {
+    pub payout_method_data: Option<payouts::PayoutMethodData>,
+    pub email: Option<Secret<String, Email>>,
+    pub billing: Option<api::Address>,
+}
+
+// ********************************************** PAYOUT CREATE **********************************************
+
+#[instrument(skip_all)]
+pub async fn payouts_create_core(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    req: payouts::PayoutCreateRequest,
+) -> RouterResponse<payouts::PayoutResponse> {
+    let db = &*state.store;
+    let merchant_id = &merchant_account.merchant_id;
+
+    utils::when(req.amount <= 0, || {
+        Err(report!(errors::ApiErrorResponse::InvalidDataFormat {
+            field_name: "amount".to_string(),
+            expected_format: "positive integer".to_string()
+        })
+        .attach_printable("amount less than zero"))
+    })?;
+
+    utils::when(!req.connector.supports_payouts(), || {
+        Err(report!(errors::ApiErrorResponse::NotSupported {
+            message: format!("Payouts are not supported by {}", req.connector)
+        }))
+    })?;
+
+    let payout_id = core_utils::get_or_generate_id("payout_id", &req.payout_id, "payout")?;
+
+    let payout_new = storage::PayoutsNew {
+        payout_id: payout_id.clone(),
+        merchant_id: merchant_id.to_string(),
+        customer_id: req.customer_id,
+        connector: req.connector.to_string(),
+        connector_payout_id: None,
+        payout_type: req.payout_type.foreign_into(),
+        amount: req.amount,
+        currency: req.currency.foreign_into(),
+        status: enums::PayoutStatus::Created,
+        description: req.description,
+        metadata: req.metadata,
+        created_at: None,
+        modified_at: None,
+    };
+
+    let payout = db.insert_payout(payout_new).await.map_err(|error| {
+        error.to_duplicate_response(errors::ApiErrorResponse::DuplicatePayout { payout_id })
+    })?;
+
+    let recipient = PayoutRecipientDetails {
+        payout_method_data: req.payout_method_data,
+        email: req.email,
+        billing: req.billing,
+    };
+
+    let payout = if req.confirm.unwrap_or(false) {
+        confirm_payout(
+            state,
+            &merchant_account,
+            payout,
+            recipient,
+            req.auto_fulfill.unwrap_or(false),
+        )
+        .await?
+    } else {
+        payout
+    };
+
+    Ok(services::ApplicationResponse::Json(
+        payouts::PayoutResponse::foreign_from(payout),
+    ))
+}
+
+// ********************************************** PAYOUT RETRIEVE **********************************************
+
+#[instrument(skip_all)]
+pub async fn payouts_retrieve_core(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    payout_id: String,
+) -> RouterResponse<payouts::PayoutResponse> {
+    let payout = find_payout(state, &merchant_account, &payout_id).await?;
+
+    Ok(services::ApplicationResponse::Json(
+        payouts::PayoutResponse::foreign_from(payout),
+    ))
+}
+
+// ********************************************** PAYOUT UPDATE **********************************************
+
+#[instrument(skip_all)]
+pub async fn payouts_update_core(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    payout_id: String,
+    req: payouts::PayoutUpdateRequest,
+) -> RouterResponse<payouts::PayoutResponse> {
+    let db = &*state.store;
+    let payout = find_payout(state, &merchant_account, &payout_id).await?;
+
+    utils::when(payout.status != enums::PayoutStatus::Created, || {
+        Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!(
+                "This payout cannot be updated because it has a status of {}. The expected state is created",
+                payout.status
+            ),
+        }))
+    })?;
+
+    utils::when(req.amount.map_or(false, |amount| amount <= 0), || {
+        Err(report!(errors::ApiErrorResponse::InvalidDataFormat {
+            field_name: "amount".to_string(),
+            expected_format: "positive integer".to_string()
+        })
+        .attach_printable("amount less than zero"))
+    })?;
+
+    let payout_update = storage::PayoutsUpdate::Update {
+        amount: req.amount,
+        currency: req.currency.map(ForeignInto::foreign_into),
+        customer_id: req.customer_id,
+        description: req.description,
+        metadata: req.metadata,
+    };
+
+    let payout = db
+        .update_payout(payout, payout_update)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!("Failed while updating payout: payout_id: {payout_id}")
+        })?;
+
+    let recipient = PayoutRecipientDetails {
+        payout_method_data: req.payout_method_data,
+        email: req.email,
+        billing: req.billing,
+    };
+
+    let payout = if req.confirm.unwrap_or(false) {
+        confirm_payout(
+            state,
+            &merchant_account,
+            payout,
+            recipient,
+            req.auto_fulfill.unwrap_or(false),
+        )
+        .await?
+    } else {
+        payout
+    };
+
+    Ok(services::ApplicationResponse::Json(
+        payouts::PayoutResponse::foreign_from(payout),
+    ))
+}
+
+// ********************************************** PAYOUT FULFILL **********************************************
+
+#[instrument(skip_all)]
+pub async fn payouts_fulfill_core(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    payout_id: String,
+) -> RouterResponse<payouts::PayoutResponse> {
+    let payout = find_payout(state, &merchant_account, &payout_id).await?;
+
+    utils::when(
+        payout.status != enums::PayoutStatus::RequiresFulfillment,
+        || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: format!(
+                    "This payout cannot be fulfilled because it has a status of {}. The expected state is requires_fulfillment",
+                    payout.status
+                ),
+            }))
+        },
+    )?;
+
+    let payout = call_connector_payout::<api::PoFulfill>(
+        state,
+        &merchant_account,
+        payout,
+        PayoutRecipientDetails::default(),
+    )
+    .await?;
+
+    Ok(services::ApplicationResponse::Json(
+        payouts::PayoutResponse::foreign_from(payout),
+    ))
+}
+
+// ********************************************** PAYOUT REVERSE **********************************************
+
+#[instrument(skip_all)]
+pub async fn payouts_reverse_core(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    payout_id: String,
+) -> RouterResponse<payouts::PayoutResponse> {
+    let payout = find_payout(state, &merchant_account, &payout_id).await?;
+
+    utils::when(
+        payout.status != enums::PayoutStatus::RequiresFulfillment,
+        || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: format!(
+                    "This payout cannot be reversed because it has a status of {}. The expected state is requires_fulfillment",
+                    payout.status
+                ),
+            }))
+        },
+    )?;
+
+    let payout = call_connector_payout::<api::PoReverse>(
+        state,
+        &merchant_account,
+        payout,
+        PayoutRecipientDetails::default(),
+    )
+    .await?;
+
+    Ok(services::ApplicationResponse::Json(
+        payouts::PayoutResponse::foreign_from(payout),
+    ))
+}
+
+// ********************************************** PAYOUT CANCEL **********************************************
+
+#[instrument(skip_all)]
+pub async fn payouts_cancel_core(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    payout_id: String,
+) -> RouterResponse<payouts::PayoutResponse> {
+    let db = &*state.store;
+    let payout = find_payout(state, &merchant_account, &payout_id).await?;
+
+    // A payout which has been submitted to the connector is reversed instead
+    utils::when(payout.status != enums::PayoutStatus::Created, || {
+        Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!(
+                "This payout cannot be cancelled because it has a status of {}. The expected state is created",
+                payout.status
+            ),
+        }))
+    })?;
+
+    let payout = db
+        .update_payout(
+            payout,
+            storage::PayoutsUpdate::StatusUpdate {
+                connector_payout_id: None,
+                status: enums::PayoutStatus::Cancelled,
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!("Failed while updating payout: payout_id: {payout_id}")
+        })?;
+
+    Ok(services::ApplicationResponse::Json(
+        payouts::PayoutResponse::foreign_from(payout),
+    ))
+}
+
+// ********************************************** PAYOUT ACCOUNTS **********************************************
+
+#[instrument(skip_all)]
+pub async fn payouts_accounts_core(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+) -> RouterResponse<payouts::PayoutAccountsResponse> {
+    let db = &*state.store;
+
+    let data: Vec<payouts::PayoutAccount> = db
+        .find_merchant_connector_account_by_merchant_id_and_disabled_list(
+            &merchant_account.merchant_id,
+            false,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while listing merchant connector accounts")?
+        .into_iter()
+        .filter(|merchant_connector_account| {
+            api_models::enums::Connector::from_str(&merchant_connector_account.connector_name)
+                .map_or(false, |connector| connector.supports_payouts())
+        })
+        .map(ForeignInto::foreign_into)
+        .collect();
+
+    Ok(services::ApplicationResponse::Json(
+        payouts::PayoutAccountsResponse {
+            size: data.len(),
+            data,
+        },
+    ))
+}
+
+// ********************************************** PAYOUT LIST **********************************************
+
+#[instrument(skip_all)]
+pub async fn payouts_list_core(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    constraints: payouts::PayoutListConstraints,
+) -> RouterResponse<payouts::PayoutListResponse> {
+    let db = &*state.store;
+    let limit = constraints.limit.unwrap_or(10).clamp(1, 100);
+
+    let data: Vec<payouts::PayoutResponse> = db
+        .list_payouts_by_merchant_id(
+            &merchant_account.merchant_id,
+            Some(limit),
+            constraints.offset,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while listing payouts")?
+        .into_iter()
+        .map(ForeignInto::foreign_into)
+        .collect();
+
+    Ok(services::ApplicationResponse::Json(
+        payouts::PayoutListResponse {
+            size: data.len(),
+            data,
+        },
+    ))
+}
+
+// ********************************************** UTILS **********************************************
+
+async fn find_payout(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    payout_id: &str,
+) -> RouterResult<storage::Payouts> {
+    state
+        .store
+        .find_payout_by_merchant_id_payout_id(&merchant_account.merchant_id, payout_id)
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::PayoutNotFound))
+}
+
+/// Submits the payout to the connector and, if requested, fulfills it right after.
+async fn confirm_payout(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    payout: storage::Payouts,
+    recipient: PayoutRecipientDetails,
+    auto_fulfill: bool,
+) -> RouterResult<storage::Payouts> {
+    utils::when(recipient.payout_method_data.is_none(), || {
+        Err(report!(errors::ApiErrorResponse::MissingRequiredField {
+            field_name: "payout_method_data"
+        }))
+    })?;
+
+    let payout =
+        call_connector_payout::<api::PoCreate>(state, merchant_account, payout, recipient).await?;
+
+    if auto_fulfill && payout.status == enums::PayoutStatus::RequiresFulfillment {
+        call_connector_payout::<api::PoFulfill>(
+            state,
+            merchant_account,
+            payout,
+            PayoutRecipientDetails::default(),
+        )
+        .await
+    } else {
+        Ok(payout)
+    }
+}
+
+#[instrument(skip_all)]
+async fn call_connector_payout<F>(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    payout: storage::Payouts,
+    recipient: PayoutRecipientDetails,
+) -> RouterResult<storage::Payouts>
+where
+    F: Clone + std::fmt::Debug + 'static,
+    dyn api::Connector:
+        services::ConnectorIntegration<F, types::PayoutsData, types::PayoutsResponseData>,
+{
+    let connector: api::ConnectorData = api::ConnectorData::get_connector_by_name(
+        &state.conf.connectors,
+        &payout.connector,
+        api::GetToken::Connector,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to get the connector")?;
+
+    let router_data =
+        construct_payout_router_data::<F>(state, merchant_account, &payout, recipient).await?;
+
+    logger::debug!(payout_router_data=?router_data);
+
+    let connector_integration: services::BoxedConnectorIntegration<
+        '_,
+        F,
+        types::PayoutsData,
+        types::PayoutsResponseData,
+    > = connector.connector.get_connector_integration();
+    let router_data_res = services::execute_connector_processing_step(
+        state,
+        connector_integration,
+        &router_data,
+        payments::CallConnectorAction::Trigger,
+    )
+    .await
+    .map_err(|error| error.to_payment_failed_response())?;
+
+    let payout_update = match router_data_res.response {
+        Err(err) => storage::PayoutsUpdate::ErrorUpdate {
+            status: enums::PayoutStatus::Failed,
+            error_message: Some(err.message),
+            error_code: Some(err.code),
+        },
+        Ok(response) => storage::PayoutsUpdate::StatusUpdate {
+            connector_payout_id: Some(response.connector_payout_id),
+            status: response.status,
+        },
+    };
+
+    let payout_id = payout.payout_id.clone();
+    state
+        .store
+        .update_payout(payout, payout_update)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| format!("Failed while updating payout: payout_id: {payout_id}"))
+}
+
+#[instrument(skip_all)]
+pub async fn construct_payout_router_data<F>(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    payout: &storage::Payouts,
+    recipient: PayoutRecipientDetails,
+) -> RouterResult<types::PayoutsRouterData<F>> {
+    let db = &*state.store;
+    let merchant_connector_account = db
+        .find_merchant_connector_account_by_merchant_id_connector(
+            &merchant_account.merchant_id,
+            &payout.connector,
+        )
+        .await
+        .map_err(|error| {
+            error.to_not_found_response(errors::ApiErrorResponse::MerchantConnectorAccountNotFound)
+        })?;
+
+    let auth_type: types::ConnectorAuthType = merchant_connector_account
+        .connector_account_details
+        .parse_value("ConnectorAuthType")
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    let router_data = types::RouterData {
+        flow: PhantomData,
+        merchant_id: merchant_account.merchant_id.clone(),
+        connector: merchant_connector_account.connector_name,
+        // Payouts are not tied to a payment, the payout id is used as the reference instead
+        payment_id: payout.payout_id.clone(),
+        attempt_id: payout.payout_id.clone(),
+        status: enums::AttemptStatus::default(),
+        payment_method: enums::PaymentMethod::default(),
+        connector_auth_type: auth_type,
+        description: payout.description.clone(),
+        return_url: None,
+        router_return_url: None,
+        payment_method_id: None,
+        address: PaymentAddress {
+            shipping: None,
+            billing: recipient.billing,
+        },
+        auth_type: enums::AuthenticationType::default(),
+        connector_meta_data: merchant_connector_account.metadata,
+        amount_captured: None,
+        request: types::PayoutsData {
+            payout_id: payout.payout_id.clone(),
+            amount: payout.amount,
+            currency: payout.currency,
+            payout_type: payout.payout_type,
+            payout_method_data: recipient.payout_method_data,
+            connector_payout_id: payout.connector_payout_id.clone(),
+            customer_id: payout.customer_id.clone(),
+            email: recipient.email,
+        },
+        response: Ok(types::PayoutsResponseData {
+            connector_payout_id: payout.connector_payout_id.clone().unwrap_or_default(),
+            status: payout.status,
+        }),
+        access_token: None,
+        session_token: None,
+        reference_id: None,
+    };
+
+    Ok(router_data)
+}
@@ -13,7 +13,7 @@ use crate::{
     db, logger,
     routes::AppState,
     scheduler::{process_data, utils as process_tracker_utils, workflows::payment_sync},
-    services,
+    services::{self, locking},
     types::{
         self,
         api::{self, refunds},
@@ -30,6 +30,27 @@ pub async fn refund_create_core(
     state: &AppState,
     merchant_account: storage::merchant_account::MerchantAccount,
     req: refunds::RefundRequest,
+) -> RouterResponse<refunds::RefundResponse> {
+    let merchant_id = merchant_account.merchant_id.clone();
+    let payment_id = req.payment_id.clone();
+
+    // The payment is locked as the refund is validated against the other refunds of the payment
+    let lock_resource = locking::LockResource::Payment {
+        merchant_id: &merchant_id,
+        payment_id: &payment_id,
+    };
+    locking::with_lock(
+        state,
+        lock_resource,
+        create_refund_for_payment(state, merchant_account, req),
+    )
+    .await
+}
+
+async fn create_refund_for_payment(
+    state: &AppState,
+    merchant_account: storage::merchant_account::MerchantAccount,
+    req: refunds::RefundRequest,
 ) -> RouterResponse<refunds::RefundResponse> {
     let db = &*state.store;
     let (merchant_id, payment_intent, payment_attempt, amount);
@@ -45,17 +66,6 @@ pub async fn refund_create_core(
         .await
         .change_context(errors::ApiErrorResponse::SuccessfulPaymentNotFound)?;
 
-    // Amount is not passed in request refer from payment attempt.
-    amount = req.amount.unwrap_or(payment_attempt.amount); // [#298]: Need to that capture amount
-                                                           //[#299]: Can we change the flow based on some workflow idea
-    utils::when(amount <= 0, || {
-        Err(report!(errors::ApiErrorResponse::InvalidDataFormat {
-            field_name: "amount".to_string(),
-            expected_format: "positive integer".to_string()
-        })
-        .attach_printable("amount less than zero"))
-    })?;
-
     payment_intent = db
         .find_payment_intent_by_payment_id_merchant_id(
             &req.payment_id,
@@ -65,8 +75,25 @@ pub async fn refund_create_core(
         .await
         .change_context(errors::ApiErrorResponse::PaymentNotFound)?;
 
+    // Amount is not passed in request refer from the amount captured on the payment.
+    amount = req.amount.unwrap_or(
+        payment_intent
+            .amount_captured
+            .unwrap_or(payment_attempt.amount),
+    ); //[#299]: Can we change the flow based on some workflow idea
+    utils::when(amount <= 0, || {
+        Err(report!(errors::ApiErrorResponse::InvalidDataFormat {
+            field_name: "amount".to_string(),
+            expected_format: "positive integer".to_string()
+        })
+        .attach_printable("amount less than zero"))
+    })?;
+
+    // A payment captured through multiple captures can be refunded as soon as it is partially
+    // captured
     utils::when(
-        payment_intent.status != enums::IntentStatus::Succeeded,
+        payment_intent.status != enums::IntentStatus::Succeeded
+            && payment_attempt.status != enums::AttemptStatus::PartialCharged,
         || {
             Err(report!(errors::ApiErrorResponse::PaymentNotSucceeded)
                 .attach_printable("unable to refund for a unsuccessful payment intent"))
@@ -215,19 +242,65 @@ pub async fn refund_retrieve_core(
     merchant_account: storage::MerchantAccount,
     refund_id: String,
 ) -> RouterResult<storage::Refund> {
-    let db = &*state.store;
-    let (merchant_id, payment_intent, payment_attempt, refund, response);
+    // A refund in a terminal state is not synced with the connector, so it is not locked either
+    let refund = find_refund(state, &merchant_account, &refund_id).await?;
+    if !should_sync_refund(refund.refund_status) {
+        return Ok(refund);
+    }
 
-    merchant_id = &merchant_account.merchant_id;
+    let merchant_id = merchant_account.merchant_id.clone();
+    let lock_resource = locking::LockResource::Refund {
+        merchant_id: &merchant_id,
+        refund_id: &refund_id,
+    };
+    locking::with_lock(
+        state,
+        lock_resource,
+        retrieve_and_sync_refund(state, merchant_account, refund_id.clone()),
+    )
+    .await
+}
+
+fn should_sync_refund(refund_status: enums::RefundStatus) -> bool {
+    !matches!(
+        refund_status,
+        enums::RefundStatus::Success
+            | enums::RefundStatus::Failure
+            | enums::RefundStatus::TransactionFailure
+    )
+}
 
-    refund = db
+async fn find_refund(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    refund_id: &str,
+) -> RouterResult<storage::Refund> {
+    state
+        .store
         .find_refund_by_merchant_id_refund_id(
-            merchant_id,
-            refund_id.as_str(),
+            &merchant_account.merchant_id,
+            refund_id,
             merchant_account.storage_scheme,
         )
         .await
-        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::RefundNotFound))?;
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::RefundNotFound))
+}
+
+async fn retrieve_and_sync_refund(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    refund_id: String,
+) -> RouterResult<storage::Refund> {
+    let db = &*state.store;
+    let (merchant_id, payment_intent, payment_attempt, refund, response);
+
+    merchant_id = &merchant_account.merchant_id;
+
+    // The refund is fetched again, as it may have been synced while waiting for the lock
+    refund = find_refund(state, &merchant_account, &refund_id).await?;
+    if !should_sync_refund(refund.refund_status) {
+        return Ok(refund);
+    }
 
     let payment_id = refund.payment_id.as_str();
     payment_intent = db
@@ -469,8 +542,14 @@ pub async fn validate_and_create_refund(
                 ),
             })?;
 
-            validator::validate_refund_amount(payment_attempt.amount, &all_refunds, refund_amount)
-                .change_context(errors::ApiErrorResponse::RefundAmountExceedsPaymentAmount)?;
+            validator::validate_refund_amount(
+                payment_intent
+                    .amount_captured
+                    .unwrap_or(payment_attempt.amount),
+                &all_refunds,
+                refund_amount,
+            )
+            .change_context(errors::ApiErrorResponse::RefundAmountExceedsPaymentAmount)?;
 
             validator::validate_maximum_refund_against_payment_attempt(
                 &all_refunds,
@@ -949,3 +1028,17 @@ pub async fn retry_refund_sync_task(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_only_refunds_in_progress_are_synced() {
+        assert!(should_sync_refund(enums::RefundStatus::Pending));
+        assert!(should_sync_refund(enums::RefundStatus::ManualReview));
+        assert!(!should_sync_refund(enums::RefundStatus::Success));
+        assert!(!should_sync_refund(enums::RefundStatus::Failure));
+        assert!(!should_sync_refund(enums::RefundStatus::TransactionFailure));
+    }
+}
@@ -29,7 +29,10 @@ pub enum RefundValidationError {
 pub fn validate_success_transaction(
     transaction: &storage::PaymentAttempt,
 ) -> CustomResult<(), RefundValidationError> {
-    if transaction.status != enums::AttemptStatus::Charged {
+    if !matches!(
+        transaction.status,
+        enums::AttemptStatus::Charged | enums::AttemptStatus::PartialCharged
+    ) {
         Err(report!(RefundValidationError::UnsuccessfulPaymentAttempt))?
     }
 
@@ -0,0 +1,85 @@
+use error_stack::{report, ResultExt};
+use router_env::{instrument, tracing};
+
+use super::payments::{self, routing};
+use crate::{
+    core::errors::{self, RouterResponse},
+    routes::AppState,
+    services,
+    types::{api, storage, transformers::ForeignInto},
+    utils::ValueExt,
+};
+
+/// Routes the hypothetical payment with the routing algorithm of the merchant, the same way as a
+/// payment being confirmed, without creating or updating anything. The circuits of the connectors
+/// are not checked, as checking them may let a request through to a recovering connector. The
+/// result of the volume split and max conversion algorithms is one of the possible outcomes, as
+/// these algorithms pick connectors at random.
+#[instrument(skip(state))]
+pub async fn simulate_routing(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    req: api::RoutingSimulationRequest,
+) -> RouterResponse<api::RoutingSimulationResponse> {
+    let card_network = match req.card_bin {
+        Some(ref card_bin) => {
+            if !(6..=8).contains(&card_bin.len()) || !card_bin.chars().all(|c| c.is_ascii_digit()) {
+                return Err(report!(errors::ApiErrorResponse::InvalidDataValue {
+                    field_name: "card_bin",
+                }))
+                .attach_printable(
+                    "The card BIN must be the first six to eight digits of the card",
+                );
+            }
+            routing::get_card_network_from_bin(card_bin)
+        }
+        None => None,
+    };
+
+    let routing_algorithm: api::RoutingAlgorithm = merchant_account
+        .routing_algorithm
+        .clone()
+        .parse_value("RoutingAlgorithm")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Could not decode merchant routing rules")?;
+
+    let routing_input = routing::RoutingInput {
+        amount: req.amount,
+        currency: req.currency,
+        card_network: card_network.clone(),
+        country: req.country,
+        payment_method_type: req.payment_method_type,
+        card_issuing_country: req.card_issuing_country,
+    };
+    let payment_slice = services::success_rate::PaymentSlice {
+        payment_method: Some(req.payment_method.foreign_into()),
+        card_network: card_network.as_ref().map(ToString::to_string),
+        currency: Some(req.currency.foreign_into()),
+        card_issuing_country: routing_input.card_issuing_country.clone(),
+    };
+
+    let connector_stats = payments::get_connector_stats(
+        state,
+        &merchant_account.merchant_id,
+        &routing_algorithm,
+        &routing_input,
+        &payment_slice,
+    )
+    .await;
+    let routing_decision =
+        routing::perform_routing(&routing_algorithm, &routing_input, &connector_stats)?;
+    let (connectors, excluded_connectors) = routing::apply_connector_filters(
+        &state.conf.pm_filters,
+        routing_decision.connectors,
+        &routing_input,
+    );
+
+    Ok(services::ApplicationResponse::Json(
+        api::RoutingSimulationResponse {
+            connectors,
+            matched_rule: routing_decision.matched_rule,
+            card_network,
+            excluded_connectors,
+        },
+    ))
+}
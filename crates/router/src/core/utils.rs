@@ -89,6 +89,61 @@ pub async fn construct_refund_router_data<'a, F>(
     Ok(router_data)
 }
 
+#[instrument(skip_all)]
+pub async fn construct_dispute_router_data<F, Req, Res>(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    payment_intent: &storage::PaymentIntent,
+    payment_attempt: &storage::PaymentAttempt,
+    dispute: &storage::Dispute,
+    request: Req,
+) -> RouterResult<types::RouterData<F, Req, Res>> {
+    let db = &*state.store;
+    let merchant_connector_account = db
+        .find_merchant_connector_account_by_merchant_id_connector(
+            &merchant_account.merchant_id,
+            &dispute.connector,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::MerchantConnectorAccountNotFound)?;
+
+    let auth_type: types::ConnectorAuthType = merchant_connector_account
+        .connector_account_details
+        .parse_value("ConnectorAuthType")
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    let payment_method = payment_attempt
+        .payment_method
+        .get_required_value("payment_method_type")?;
+
+    let router_data = types::RouterData {
+        flow: PhantomData,
+        merchant_id: merchant_account.merchant_id.clone(),
+        connector: dispute.connector.clone(),
+        payment_id: payment_attempt.payment_id.clone(),
+        attempt_id: payment_attempt.attempt_id.clone(),
+        status: payment_attempt.status,
+        payment_method,
+        connector_auth_type: auth_type,
+        description: None,
+        return_url: payment_intent.return_url.clone(),
+        router_return_url: None,
+        payment_method_id: payment_attempt.payment_method_id.clone(),
+        address: PaymentAddress::default(),
+        auth_type: payment_attempt.authentication_type.unwrap_or_default(),
+        connector_meta_data: merchant_connector_account.metadata,
+        amount_captured: payment_intent.amount_captured,
+        request,
+        // Stays unchanged if the connector does not support the flow
+        response: Err(types::ErrorResponse::get_not_implemented()),
+        access_token: None,
+        session_token: None,
+        reference_id: None,
+    };
+
+    Ok(router_data)
+}
+
 pub fn get_or_generate_id(
     key: &str,
     provided_id: &Option<String>,
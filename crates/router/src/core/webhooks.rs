@@ -1,26 +1,28 @@
 pub mod transformers;
 pub mod utils;
 
+use common_utils::webhook_signature;
 use error_stack::{IntoReport, ResultExt};
-use masking::ExposeInterface;
 use router_env::{instrument, tracing};
 
 use crate::{
     consts,
     core::{
+        disputes,
         errors::{self, CustomResult, RouterResponse},
         payments, refunds,
     },
     db::StorageInterface,
     logger,
     routes::AppState,
+    scheduler::{utils as pt_utils, workflows::outgoing_webhook_retry},
     services,
     types::{
-        api,
-        storage::{self, enums},
-        transformers::{ForeignInto, ForeignTryInto},
+        api::{self, mandates::MandateResponseExt},
+        storage::{self, enums, ProcessTrackerExt},
+        transformers::{ForeignFrom, ForeignInto, ForeignTryInto},
     },
-    utils::{generate_id, Encode, OptionExt, ValueExt},
+    utils::{generate_id, Encode, OptionExt},
 };
 
 const OUTGOING_WEBHOOK_TIMEOUT_MS: u64 = 5000;
@@ -168,6 +170,245 @@ async fn refunds_incoming_webhook_flow<W: api::OutgoingWebhookType>(
     Ok(())
 }
 
+#[instrument(skip_all)]
+async fn disputes_incoming_webhook_flow<W: api::OutgoingWebhookType>(
+    state: AppState,
+    merchant_account: storage::MerchantAccount,
+    webhook_details: api::IncomingWebhookDetails,
+    source_verified: bool,
+    connector: &(dyn api::Connector + Sync),
+    request_details: &api::IncomingWebhookRequestDetails<'_>,
+    event_type: api_models::webhooks::IncomingWebhookEvent,
+) -> CustomResult<(), errors::WebhooksFlowError> {
+    // Dispute details cannot be fetched from the connector, so unverified webhooks are dropped
+    if !source_verified {
+        logger::error!("Webhook source verification failed for dispute webhook flow");
+        Err(errors::WebhooksFlowError::WebhookSourceVerificationFailed).into_report()?;
+    }
+
+    let db = &*state.store;
+    let dispute_details = connector
+        .get_dispute_details(request_details)
+        .change_context(errors::WebhooksFlowError::DisputeCoreFailed)?;
+
+    let payment_attempt = db
+        .find_payment_attempt_by_merchant_id_connector_txn_id(
+            &merchant_account.merchant_id,
+            &webhook_details.object_reference_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::WebhooksFlowError::ResourceNotFound)
+        .attach_printable("Failed fetching the payment attempt for the dispute")?;
+
+    let connector_name = payment_attempt
+        .connector
+        .clone()
+        .get_required_value("connector")
+        .change_context(errors::WebhooksFlowError::DisputeCoreFailed)?;
+
+    let dispute_status: enums::DisputeStatus = event_type
+        .foreign_try_into()
+        .into_report()
+        .change_context(errors::WebhooksFlowError::DisputeCoreFailed)?;
+
+    let dispute = disputes::upsert_dispute_from_webhook(
+        &state,
+        &merchant_account,
+        &connector_name,
+        &payment_attempt,
+        dispute_details,
+        dispute_status,
+    )
+    .await
+    .change_context(errors::WebhooksFlowError::DisputeCoreFailed)?;
+
+    let event_type: enums::EventType = dispute
+        .dispute_status
+        .foreign_try_into()
+        .into_report()
+        .change_context(errors::WebhooksFlowError::DisputeCoreFailed)?;
+    let dispute_id = dispute.dispute_id.clone();
+    let dispute_response = api::DisputeResponse::foreign_from(dispute);
+
+    create_event_and_trigger_outgoing_webhook::<W>(
+        state,
+        merchant_account,
+        event_type,
+        enums::EventClass::Disputes,
+        None,
+        dispute_id,
+        enums::EventObjectType::DisputeDetails,
+        api::OutgoingWebhookContent::DisputeDetails(Box::new(dispute_response)),
+    )
+    .await?;
+
+    Ok(())
+}
+
+#[instrument(skip_all)]
+async fn payouts_incoming_webhook_flow(
+    state: AppState,
+    merchant_account: storage::MerchantAccount,
+    webhook_details: api::IncomingWebhookDetails,
+    connector_name: &str,
+    source_verified: bool,
+    event_type: api_models::webhooks::IncomingWebhookEvent,
+) -> CustomResult<(), errors::WebhooksFlowError> {
+    // The status of a payout cannot be fetched from the connector, so unverified webhooks are dropped
+    if !source_verified {
+        logger::error!("Webhook source verification failed for payout webhook flow");
+        Err(errors::WebhooksFlowError::WebhookSourceVerificationFailed).into_report()?;
+    }
+
+    let db = &*state.store;
+    let payout = db
+        .find_payout_by_merchant_id_connector_payout_id_connector(
+            &merchant_account.merchant_id,
+            &webhook_details.object_reference_id,
+            connector_name,
+        )
+        .await
+        .change_context(errors::WebhooksFlowError::ResourceNotFound)
+        .attach_printable("Failed fetching the payout")?;
+
+    let payout_status: enums::PayoutStatus = event_type
+        .foreign_try_into()
+        .into_report()
+        .change_context(errors::WebhooksFlowError::PayoutsCoreFailed)?;
+
+    let is_status_update_allowed = match payout.status {
+        enums::PayoutStatus::RequiresFulfillment | enums::PayoutStatus::Pending => true,
+        // The funds of a successful payout can still be returned by the recipient's bank
+        enums::PayoutStatus::Success => payout_status == enums::PayoutStatus::Reversed,
+        _ => false,
+    };
+
+    // Webhooks may be delivered more than once and out of order
+    if !is_status_update_allowed {
+        logger::info!(
+            "Ignoring payout webhook, payout {} with status {} cannot be moved to {}",
+            payout.payout_id,
+            payout.status,
+            payout_status
+        );
+        return Ok(());
+    }
+
+    let payout_id = payout.payout_id.clone();
+    db.update_payout(
+        payout,
+        storage::PayoutsUpdate::StatusUpdate {
+            connector_payout_id: None,
+            status: payout_status,
+        },
+    )
+    .await
+    .change_context(errors::WebhooksFlowError::PayoutsCoreFailed)
+    .attach_printable_lazy(|| format!("Failed while updating payout: payout_id: {payout_id}"))?;
+
+    Ok(())
+}
+
+/// Creates the event for the current status of the mandate and sends it to the webhook
+/// endpoints subscribed to it. Mandate events are not triggered by connector webhooks, so the
+/// webhook is always sent in the native format.
+#[instrument(skip_all)]
+pub async fn create_mandate_event(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    mandate: storage::Mandate,
+) -> CustomResult<(), errors::WebhooksFlowError> {
+    let event_type: enums::EventType = mandate
+        .mandate_status
+        .foreign_try_into()
+        .into_report()
+        .change_context(errors::WebhooksFlowError::MandateCoreFailed)?;
+    let mandate_id = mandate.mandate_id.clone();
+    let mandate_response =
+        api::mandates::MandateResponse::from_db_mandate(state, mandate, merchant_account)
+            .await
+            .change_context(errors::WebhooksFlowError::MandateCoreFailed)?;
+
+    create_event_and_trigger_outgoing_webhook::<api::OutgoingWebhook>(
+        state.clone(),
+        merchant_account.clone(),
+        event_type,
+        enums::EventClass::Mandates,
+        None,
+        mandate_id,
+        enums::EventObjectType::MandateDetails,
+        api::OutgoingWebhookContent::MandateDetails(Box::new(mandate_response)),
+    )
+    .await
+}
+
+/// Creates the event for the current status of the payment and sends it to the webhook endpoints
+/// subscribed to it, for the changes to the payment which are made by the router itself rather
+/// than reported by a connector webhook. Payments in a status without an event are skipped.
+#[instrument(skip_all)]
+pub async fn create_payment_event(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    payments_response: api::PaymentsResponse,
+) -> CustomResult<(), errors::WebhooksFlowError> {
+    let event_type: enums::EventType = match payments_response.status.foreign_try_into() {
+        Ok(event_type) => event_type,
+        Err(_) => return Ok(()),
+    };
+
+    create_payment_event_of_type(state, merchant_account, event_type, payments_response).await
+}
+
+/// Creates the `payment_created` event for a payment which has just been created, whatever its
+/// status, and sends it to the webhook endpoints subscribed to it. Unlike the other events, it is
+/// not recorded when no endpoint is subscribed to it, as it would be for every payment.
+#[instrument(skip_all)]
+pub async fn create_payment_created_event(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    payments_response: api::PaymentsResponse,
+) -> CustomResult<(), errors::WebhooksFlowError> {
+    if utils::get_subscribed_webhook_endpoints(merchant_account, enums::EventType::PaymentCreated)?
+        .is_empty()
+    {
+        return Ok(());
+    }
+
+    create_payment_event_of_type(
+        state,
+        merchant_account,
+        enums::EventType::PaymentCreated,
+        payments_response,
+    )
+    .await
+}
+
+async fn create_payment_event_of_type(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    event_type: enums::EventType,
+    payments_response: api::PaymentsResponse,
+) -> CustomResult<(), errors::WebhooksFlowError> {
+    let payment_id = payments_response
+        .payment_id
+        .clone()
+        .get_required_value("payment_id")
+        .change_context(errors::WebhooksFlowError::PaymentsCoreFailed)?;
+
+    create_event_and_trigger_outgoing_webhook::<api::OutgoingWebhook>(
+        state.clone(),
+        merchant_account.clone(),
+        event_type,
+        enums::EventClass::Payments,
+        None,
+        payment_id,
+        enums::EventObjectType::PaymentDetails,
+        api::OutgoingWebhookContent::PaymentDetails(payments_response),
+    )
+    .await
+}
+
 #[allow(clippy::too_many_arguments)]
 #[instrument(skip_all)]
 async fn create_event_and_trigger_outgoing_webhook<W: api::OutgoingWebhookType>(
@@ -180,14 +421,40 @@ async fn create_event_and_trigger_outgoing_webhook<W: api::OutgoingWebhookType>(
     primary_object_type: enums::EventObjectType,
     content: api::OutgoingWebhookContent,
 ) -> CustomResult<(), errors::WebhooksFlowError> {
+    let event_id = generate_id(consts::ID_LENGTH, "evt");
+    let created_at = common_utils::date_time::now();
+
+    let outgoing_webhook = api::OutgoingWebhook {
+        merchant_id: merchant_account.merchant_id.clone(),
+        event_id: event_id.clone(),
+        event_type: event_type.foreign_into(),
+        content,
+        timestamp: created_at,
+    };
+
+    let request_body = Encode::<W>::encode_to_value(&W::from(outgoing_webhook))
+        .change_context(errors::WebhooksFlowError::WebhookEventCreationFailed)
+        .attach_printable("Failed to encode the outgoing webhook")?;
+
+    let webhook_endpoints = utils::get_subscribed_webhook_endpoints(&merchant_account, event_type)?;
+    let webhook_delivery_status = if webhook_endpoints.is_empty() {
+        enums::WebhookDeliveryStatus::Skipped
+    } else {
+        enums::WebhookDeliveryStatus::Pending
+    };
+
     let new_event = storage::EventNew {
-        event_id: generate_id(consts::ID_LENGTH, "evt"),
+        event_id,
         event_type,
         event_class,
         is_webhook_notified: false,
         intent_reference_id,
         primary_object_id,
         primary_object_type,
+        webhook_delivery_status,
+        merchant_id: Some(merchant_account.merchant_id.clone()),
+        request_body: Some(request_body.clone()),
+        created_at,
     };
 
     let event = state
@@ -197,81 +464,239 @@ async fn create_event_and_trigger_outgoing_webhook<W: api::OutgoingWebhookType>(
         .change_context(errors::WebhooksFlowError::WebhookEventCreationFailed)?;
 
     if state.conf.webhooks.outgoing_enabled {
-        let arbiter = actix::Arbiter::try_current()
-            .ok_or(errors::WebhooksFlowError::ForkFlowFailed)
-            .into_report()?;
-
-        let outgoing_webhook = api::OutgoingWebhook {
-            merchant_id: merchant_account.merchant_id.clone(),
-            event_id: event.event_id,
-            event_type: event.event_type.foreign_into(),
-            content,
-            timestamp: event.created_at,
-        };
-
-        arbiter.spawn(async move {
-            let result =
-                trigger_webhook_to_merchant::<W>(merchant_account, outgoing_webhook, state.store)
-                    .await;
-
-            if let Err(e) = result {
-                logger::error!(?e);
+        // Each subscribed endpoint gets its own delivery task, so that an endpoint which is down
+        // does not hold up the delivery to the others
+        for webhook_endpoint in webhook_endpoints {
+            let tracking_data = storage::OutgoingWebhookTrackingData {
+                merchant_id: merchant_account.merchant_id.clone(),
+                event_id: event.event_id.clone(),
+                request_body: request_body.clone(),
+                endpoint_id: Some(webhook_endpoint.endpoint_id.clone()),
+            };
+
+            // The delivery task is scheduled before the first attempt so that the webhook is
+            // retried even if the first attempt is interrupted
+            let process_tracker =
+                add_outgoing_webhook_retry_task(&*state.store, &tracking_data).await?;
+
+            let state = state.clone();
+            let merchant_account = merchant_account.clone();
+            let event = event.clone();
+            let delivery = async move {
+                let result = trigger_webhook_to_merchant(
+                    &state,
+                    &merchant_account,
+                    event,
+                    &webhook_endpoint,
+                    &tracking_data.request_body,
+                )
+                .await;
+
+                match result {
+                    Ok(()) => {
+                        if let Err(error) = process_tracker
+                            .finish_with_status(&*state.store, "COMPLETED_BY_PT".to_string())
+                            .await
+                        {
+                            logger::error!(?error);
+                        }
+                    }
+                    // The webhook is retried by the process tracker
+                    Err(error) => logger::error!(?error),
+                }
+            };
+
+            // Events are also created by the scheduler, which does not run on an actix runtime
+            match actix::Arbiter::try_current() {
+                Some(arbiter) => {
+                    arbiter.spawn(delivery);
+                }
+                None => {
+                    tokio::spawn(delivery);
+                }
             }
-        });
+        }
     }
 
     Ok(())
 }
 
-async fn trigger_webhook_to_merchant<W: api::OutgoingWebhookType>(
-    merchant_account: storage::MerchantAccount,
-    webhook: api::OutgoingWebhook,
-    _db: Box<dyn StorageInterface>,
-) -> CustomResult<(), errors::WebhooksFlowError> {
-    let webhook_details_json = merchant_account
-        .webhook_details
-        .get_required_value("webhook_details")
-        .change_context(errors::WebhooksFlowError::MerchantWebhookDetailsNotFound)?;
+#[instrument(skip_all)]
+async fn add_outgoing_webhook_retry_task(
+    db: &dyn StorageInterface,
+    tracking_data: &storage::OutgoingWebhookTrackingData,
+) -> CustomResult<storage::ProcessTracker, errors::WebhooksFlowError> {
+    let runner = "OUTGOING_WEBHOOK_RETRY_WORKFLOW";
+    let task = "OUTGOING_WEBHOOK_RETRY";
+    let schedule_time = outgoing_webhook_retry::get_webhook_delivery_retry_schedule_time(
+        db,
+        &tracking_data.merchant_id,
+        0,
+    )
+    .await
+    .ok_or(errors::WebhooksFlowError::OutgoingWebhookTaskCreationFailed)
+    .into_report()
+    .attach_printable("Failed to obtain the schedule time of the first webhook retry")?;
+
+    let current_time = common_utils::date_time::now();
+    let process_tracker_entry = storage::ProcessTrackerNew {
+        id: pt_utils::get_process_tracker_id(
+            runner,
+            task,
+            &format!(
+                "{}_{}",
+                tracking_data.event_id,
+                tracking_data
+                    .endpoint_id
+                    .as_deref()
+                    .unwrap_or(consts::DEFAULT_WEBHOOK_ENDPOINT_ID)
+            ),
+            &tracking_data.merchant_id,
+        ),
+        name: Some(String::from(task)),
+        tag: vec![String::from("WEBHOOK")],
+        runner: Some(String::from(runner)),
+        retry_count: 0,
+        schedule_time: Some(schedule_time),
+        rule: String::new(),
+        tracking_data: Encode::<storage::OutgoingWebhookTrackingData>::encode_to_value(
+            tracking_data,
+        )
+        .change_context(errors::WebhooksFlowError::OutgoingWebhookTaskCreationFailed)?,
+        business_status: String::from("Pending"),
+        status: enums::ProcessTrackerStatus::New,
+        event: vec![],
+        created_at: current_time,
+        updated_at: current_time,
+    };
 
-    let webhook_details: api::WebhookDetails =
-        webhook_details_json
-            .parse_value("WebhookDetails")
-            .change_context(errors::WebhooksFlowError::MerchantWebhookDetailsNotFound)?;
+    db.insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::WebhooksFlowError::OutgoingWebhookTaskCreationFailed)
+        .attach_printable_lazy(|| {
+            format!(
+                "Failed while inserting task in process_tracker: event_id: {}",
+                tracking_data.event_id
+            )
+        })
+}
 
-    let webhook_url = webhook_details
-        .webhook_url
-        .get_required_value("webhook_url")
-        .change_context(errors::WebhooksFlowError::MerchantWebhookURLNotConfigured)
-        .map(ExposeInterface::expose)?;
+/// Sends the webhook to the given endpoint of the merchant and records the delivery attempt.
+/// The event is marked as notified once any endpoint acknowledges the webhook.
+#[instrument(skip_all)]
+pub async fn trigger_webhook_to_merchant(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    event: storage::Event,
+    webhook_endpoint: &utils::WebhookEndpointDetails,
+    request_body: &serde_json::Value,
+) -> CustomResult<(), errors::WebhooksFlowError> {
+    let db = &*state.store;
 
-    let transformed_outgoing_webhook = W::from(webhook);
+    let payload = request_body.to_string();
+    let signature_header = get_webhook_signature_header(merchant_account, &payload)?;
 
     let response = reqwest::Client::new()
-        .post(&webhook_url)
+        .post(&webhook_endpoint.webhook_url)
         .header(reqwest::header::CONTENT_TYPE, "application/json")
-        .json(&transformed_outgoing_webhook)
+        .header(webhook_signature::SIGNATURE_HEADER, signature_header)
+        .body(payload)
         .timeout(core::time::Duration::from_millis(
             OUTGOING_WEBHOOK_TIMEOUT_MS,
         ))
         .send()
         .await;
 
-    match response {
-        Err(e) => {
-            // [#217]: Schedule webhook for retry.
-            Err(e)
+    let (delivery_result, response_status_code) = match response {
+        Err(error) => (
+            Err(error)
                 .into_report()
-                .change_context(errors::WebhooksFlowError::CallToMerchantFailed)?;
-        }
-        Ok(res) => {
-            if !res.status().is_success() {
-                // [#217]: Schedule webhook for retry.
-                Err(errors::WebhooksFlowError::NotReceivedByMerchant).into_report()?;
-            }
-        }
+                .change_context(errors::WebhooksFlowError::CallToMerchantFailed),
+            None,
+        ),
+        Ok(res) if res.status().is_success() => (Ok(()), Some(res.status().as_u16())),
+        Ok(res) => (
+            Err(errors::WebhooksFlowError::NotReceivedByMerchant).into_report(),
+            Some(res.status().as_u16()),
+        ),
+    };
+
+    let previous_attempts = db
+        .find_event_delivery_attempts_by_event_id(&event.event_id)
+        .await
+        .change_context(errors::WebhooksFlowError::WebhookDeliveryAttemptCreationFailed)?
+        .into_iter()
+        .filter(|attempt| attempt.endpoint_id == webhook_endpoint.endpoint_id)
+        .count();
+    let delivery_attempt = storage::EventDeliveryAttemptNew {
+        event_id: event.event_id.clone(),
+        merchant_id: merchant_account.merchant_id.clone(),
+        endpoint_id: webhook_endpoint.endpoint_id.clone(),
+        attempt_number: i32::try_from(previous_attempts)
+            .into_report()
+            .change_context(errors::WebhooksFlowError::WebhookDeliveryAttemptCreationFailed)?
+            + 1,
+        delivered: delivery_result.is_ok(),
+        response_status_code: response_status_code.map(i32::from),
+        error_message: delivery_result
+            .as_ref()
+            .err()
+            .map(|error| error.to_string()),
+    };
+    db.insert_event_delivery_attempt(delivery_attempt)
+        .await
+        .change_context(errors::WebhooksFlowError::WebhookDeliveryAttemptCreationFailed)?;
+
+    if delivery_result.is_ok() && !event.is_webhook_notified {
+        let event_update = storage::EventUpdate::DeliveryStatusUpdate {
+            is_webhook_notified: true,
+            webhook_delivery_status: enums::WebhookDeliveryStatus::Delivered,
+        };
+        db.update_event(event, event_update)
+            .await
+            .change_context(errors::WebhooksFlowError::WebhookEventUpdationFailed)?;
     }
 
-    Ok(())
+    delivery_result
+}
+
+/// Sign the webhook payload with the merchant's payment response hash key, and with the previous
+/// key as well if it was rotated recently. The key is created along with the merchant account or
+/// when the webhook secret is rotated, it is never generated while delivering a webhook.
+fn get_webhook_signature_header(
+    merchant_account: &storage::MerchantAccount,
+    payload: &str,
+) -> CustomResult<String, errors::WebhooksFlowError> {
+    let payment_response_hash_key = merchant_account
+        .payment_response_hash_key
+        .clone()
+        .get_required_value("payment_response_hash_key")
+        .change_context(errors::WebhooksFlowError::OutgoingWebhookSigningFailed)
+        .attach_printable(
+            "The merchant has no payment response hash key, the webhook secret has to be rotated",
+        )?;
+
+    let now = common_utils::date_time::now();
+    let previous_payment_response_hash_key = merchant_account
+        .previous_payment_response_hash_key
+        .as_ref()
+        .filter(|_| {
+            merchant_account
+                .previous_payment_response_hash_key_expires_at
+                .map_or(false, |expires_at| expires_at > now)
+        });
+
+    let secrets = std::iter::once(&payment_response_hash_key)
+        .chain(previous_payment_response_hash_key)
+        .map(String::as_bytes)
+        .collect::<Vec<_>>();
+
+    webhook_signature::generate_signature_header(
+        &secrets,
+        now.assume_utc().unix_timestamp(),
+        payload.as_bytes(),
+    )
+    .change_context(errors::WebhooksFlowError::OutgoingWebhookSigningFailed)
 }
 
 #[instrument(skip_all)]
@@ -377,6 +802,31 @@ pub async fn webhooks_core<W: api::OutgoingWebhookType>(
             .change_context(errors::ApiErrorResponse::InternalServerError)
             .attach_printable("Incoming webhook flow for refunds failed")?,
 
+            api::WebhookFlow::Dispute => disputes_incoming_webhook_flow::<W>(
+                state.clone(),
+                merchant_account,
+                webhook_details,
+                source_verified,
+                *connector,
+                &request_details,
+                event_type,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Incoming webhook flow for disputes failed")?,
+
+            api::WebhookFlow::Payout => payouts_incoming_webhook_flow(
+                state.clone(),
+                merchant_account,
+                webhook_details,
+                connector_name,
+                source_verified,
+                event_type,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Incoming webhook flow for payouts failed")?,
+
             api::WebhookFlow::ReturnResponse => {}
 
             _ => Err(errors::ApiErrorResponse::InternalServerError)
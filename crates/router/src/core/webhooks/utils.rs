@@ -1,10 +1,33 @@
+use error_stack::ResultExt;
+use masking::ExposeInterface;
+
 use crate::{
+    consts,
+    core::errors::{self, CustomResult},
     db::{get_and_deserialize_key, StorageInterface},
-    types::api,
+    types::{api, storage, transformers::ForeignInto},
+    utils::ValueExt,
 };
 
+/// A webhook endpoint of the merchant, along with the url webhooks are posted to
+#[derive(Clone, Debug)]
+pub struct WebhookEndpointDetails {
+    pub endpoint_id: String,
+    pub webhook_url: String,
+}
+
 fn default_webhook_config() -> api::MerchantWebhookConfig {
-    std::collections::HashSet::from([api::IncomingWebhookEvent::PaymentIntentSuccess])
+    std::collections::HashSet::from([
+        api::IncomingWebhookEvent::PaymentIntentSuccess,
+        api::IncomingWebhookEvent::PaymentIntentFailure,
+        api::IncomingWebhookEvent::DisputeOpened,
+        api::IncomingWebhookEvent::DisputeChallenged,
+        api::IncomingWebhookEvent::DisputeWon,
+        api::IncomingWebhookEvent::DisputeLost,
+        api::IncomingWebhookEvent::PayoutSuccess,
+        api::IncomingWebhookEvent::PayoutFailure,
+        api::IncomingWebhookEvent::PayoutReversed,
+    ])
 }
 
 pub async fn lookup_webhook_event(
@@ -22,3 +45,278 @@ pub async fn lookup_webhook_event(
 
     webhook_config.contains(event)
 }
+
+fn get_webhook_details(
+    merchant_account: &storage::MerchantAccount,
+) -> CustomResult<Option<api::WebhookDetails>, errors::WebhooksFlowError> {
+    merchant_account
+        .webhook_details
+        .clone()
+        .map(|webhook_details| webhook_details.parse_value("WebhookDetails"))
+        .transpose()
+        .change_context(errors::WebhooksFlowError::MerchantWebhookDetailsNotFound)
+}
+
+fn is_subscribed_to_event(
+    enabled_events: Option<&Vec<api::enums::EventType>>,
+    event_type: api::enums::EventType,
+) -> bool {
+    enabled_events.map_or(true, |enabled_events| enabled_events.contains(&event_type))
+}
+
+/// The webhook endpoints of the merchant which are subscribed to the given event type. The
+/// `payment_created_enabled`, `payment_succeeded_enabled` and `payment_failed_enabled` flags apply
+/// to `webhook_url` in addition to its `enabled_events`. Unlike the other two, the
+/// `payment_created` event has to be enabled by its flag.
+pub fn get_subscribed_webhook_endpoints(
+    merchant_account: &storage::MerchantAccount,
+    event_type: storage::enums::EventType,
+) -> CustomResult<Vec<WebhookEndpointDetails>, errors::WebhooksFlowError> {
+    let webhook_details = match get_webhook_details(merchant_account)? {
+        Some(webhook_details) => webhook_details,
+        None => return Ok(Vec::new()),
+    };
+    let event_type: api::enums::EventType = event_type.foreign_into();
+
+    let is_disabled_by_flag = match event_type {
+        api::enums::EventType::PaymentCreated => {
+            webhook_details.payment_created_enabled != Some(true)
+        }
+        api::enums::EventType::PaymentSucceeded => {
+            webhook_details.payment_succeeded_enabled == Some(false)
+        }
+        api::enums::EventType::PaymentFailed => {
+            webhook_details.payment_failed_enabled == Some(false)
+        }
+        _ => false,
+    };
+
+    let default_endpoint = webhook_details
+        .webhook_url
+        .filter(|_| {
+            !is_disabled_by_flag
+                && is_subscribed_to_event(webhook_details.enabled_events.as_ref(), event_type)
+        })
+        .map(|webhook_url| WebhookEndpointDetails {
+            endpoint_id: consts::DEFAULT_WEBHOOK_ENDPOINT_ID.to_string(),
+            webhook_url: webhook_url.expose(),
+        });
+
+    let additional_endpoints = webhook_details
+        .endpoints
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|endpoint| is_subscribed_to_event(endpoint.enabled_events.as_ref(), event_type))
+        .map(|endpoint| WebhookEndpointDetails {
+            endpoint_id: endpoint.endpoint_id,
+            webhook_url: endpoint.webhook_url.expose(),
+        });
+
+    Ok(default_endpoint
+        .into_iter()
+        .chain(additional_endpoints)
+        .collect())
+}
+
+/// Looks up a webhook endpoint of the merchant by its identifier, irrespective of the events it
+/// is subscribed to
+pub fn find_webhook_endpoint(
+    merchant_account: &storage::MerchantAccount,
+    endpoint_id: &str,
+) -> CustomResult<Option<WebhookEndpointDetails>, errors::WebhooksFlowError> {
+    let webhook_details = match get_webhook_details(merchant_account)? {
+        Some(webhook_details) => webhook_details,
+        None => return Ok(None),
+    };
+
+    if endpoint_id == consts::DEFAULT_WEBHOOK_ENDPOINT_ID {
+        return Ok(webhook_details
+            .webhook_url
+            .map(|webhook_url| WebhookEndpointDetails {
+                endpoint_id: endpoint_id.to_string(),
+                webhook_url: webhook_url.expose(),
+            }));
+    }
+
+    Ok(webhook_details
+        .endpoints
+        .unwrap_or_default()
+        .into_iter()
+        .find(|endpoint| endpoint.endpoint_id == endpoint_id)
+        .map(|endpoint| WebhookEndpointDetails {
+            endpoint_id: endpoint.endpoint_id,
+            webhook_url: endpoint.webhook_url.expose(),
+        }))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    fn get_merchant_account(
+        webhook_details: Option<serde_json::Value>,
+    ) -> storage::MerchantAccount {
+        storage::MerchantAccount {
+            id: 1,
+            merchant_id: "merchant_webhook_endpoints".to_string(),
+            api_key: None,
+            return_url: None,
+            enable_payment_response_hash: false,
+            payment_response_hash_key: None,
+            redirect_to_merchant_with_http_post: false,
+            merchant_name: None,
+            merchant_details: None,
+            webhook_details,
+            sub_merchants_enabled: None,
+            parent_merchant_id: None,
+            publishable_key: None,
+            storage_scheme: storage::enums::MerchantStorageScheme::PostgresOnly,
+            locker_id: None,
+            metadata: None,
+            routing_algorithm: None,
+            previous_payment_response_hash_key: None,
+            previous_payment_response_hash_key_expires_at: None,
+            rate_limits: None,
+            payment_expiry: None,
+        }
+    }
+
+    fn get_endpoint_ids(
+        merchant_account: &storage::MerchantAccount,
+        event_type: storage::enums::EventType,
+    ) -> Vec<String> {
+        get_subscribed_webhook_endpoints(merchant_account, event_type)
+            .unwrap()
+            .into_iter()
+            .map(|endpoint| endpoint.endpoint_id)
+            .collect()
+    }
+
+    #[test]
+    fn test_default_and_additional_endpoints_are_subscribed_by_event() {
+        let merchant_account = get_merchant_account(Some(serde_json::json!({
+            "webhook_url": "https://merchant.example/webhooks",
+            "endpoints": [
+                {
+                    "endpoint_id": "refunds_service",
+                    "webhook_url": "https://merchant.example/webhooks/refunds",
+                    "enabled_events": ["refund_succeeded", "refund_failed"]
+                },
+                {
+                    "endpoint_id": "audit_service",
+                    "webhook_url": "https://merchant.example/webhooks/audit"
+                }
+            ]
+        })));
+
+        assert_eq!(
+            get_endpoint_ids(
+                &merchant_account,
+                storage::enums::EventType::RefundSucceeded
+            ),
+            vec![
+                consts::DEFAULT_WEBHOOK_ENDPOINT_ID,
+                "refunds_service",
+                "audit_service"
+            ]
+        );
+        assert_eq!(
+            get_endpoint_ids(
+                &merchant_account,
+                storage::enums::EventType::PaymentSucceeded
+            ),
+            vec![consts::DEFAULT_WEBHOOK_ENDPOINT_ID, "audit_service"]
+        );
+
+        let webhook_endpoints = get_subscribed_webhook_endpoints(
+            &merchant_account,
+            storage::enums::EventType::RefundFailed,
+        )
+        .unwrap();
+        assert!(webhook_endpoints
+            .iter()
+            .any(|endpoint| endpoint.endpoint_id == "refunds_service"
+                && endpoint.webhook_url == "https://merchant.example/webhooks/refunds"));
+    }
+
+    #[test]
+    fn test_payment_flags_disable_only_the_default_endpoint() {
+        let merchant_account = get_merchant_account(Some(serde_json::json!({
+            "webhook_url": "https://merchant.example/webhooks",
+            "payment_succeeded_enabled": false,
+            "enabled_events": ["payment_succeeded", "payment_failed"],
+            "endpoints": [
+                {
+                    "endpoint_id": "payments_service",
+                    "webhook_url": "https://merchant.example/webhooks/payments",
+                    "enabled_events": ["payment_succeeded"]
+                }
+            ]
+        })));
+
+        assert_eq!(
+            get_endpoint_ids(
+                &merchant_account,
+                storage::enums::EventType::PaymentSucceeded
+            ),
+            vec!["payments_service"]
+        );
+        assert_eq!(
+            get_endpoint_ids(&merchant_account, storage::enums::EventType::PaymentFailed),
+            vec![consts::DEFAULT_WEBHOOK_ENDPOINT_ID]
+        );
+        // The default endpoint is not subscribed to events outside its enabled events
+        assert!(get_endpoint_ids(
+            &merchant_account,
+            storage::enums::EventType::RefundSucceeded
+        )
+        .is_empty());
+    }
+
+    #[test]
+    fn test_payment_created_is_posted_to_the_default_endpoint_only_when_enabled() {
+        let merchant_account = get_merchant_account(Some(serde_json::json!({
+            "webhook_url": "https://merchant.example/webhooks"
+        })));
+        assert!(
+            get_endpoint_ids(&merchant_account, storage::enums::EventType::PaymentCreated)
+                .is_empty()
+        );
+
+        let merchant_account = get_merchant_account(Some(serde_json::json!({
+            "webhook_url": "https://merchant.example/webhooks",
+            "payment_created_enabled": true
+        })));
+        assert_eq!(
+            get_endpoint_ids(&merchant_account, storage::enums::EventType::PaymentCreated),
+            vec![consts::DEFAULT_WEBHOOK_ENDPOINT_ID]
+        );
+    }
+
+    #[test]
+    fn test_no_endpoint_is_subscribed_without_webhook_details() {
+        let merchant_account = get_merchant_account(None);
+        assert!(get_endpoint_ids(
+            &merchant_account,
+            storage::enums::EventType::PaymentSucceeded
+        )
+        .is_empty());
+
+        // Webhook details without any url do not subscribe any endpoint either
+        let merchant_account = get_merchant_account(Some(serde_json::json!({
+            "webhook_version": "1.0.2"
+        })));
+        assert!(get_endpoint_ids(
+            &merchant_account,
+            storage::enums::EventType::PaymentSucceeded
+        )
+        .is_empty());
+        assert!(
+            find_webhook_endpoint(&merchant_account, consts::DEFAULT_WEBHOOK_ENDPOINT_ID)
+                .unwrap()
+                .is_none()
+        );
+    }
+}
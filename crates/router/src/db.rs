@@ -1,11 +1,15 @@
 pub mod address;
 pub mod api_keys;
 pub mod cache;
+pub mod capture;
 pub mod configs;
 pub mod connector_response;
 pub mod customers;
+pub mod dispute;
 pub mod ephemeral_key;
+pub mod event_delivery_attempt;
 pub mod events;
+pub mod file;
 pub mod locker_mock_up;
 pub mod mandate;
 pub mod merchant_account;
@@ -13,16 +17,20 @@ pub mod merchant_connector_account;
 pub mod payment_attempt;
 pub mod payment_intent;
 pub mod payment_method;
+pub mod payouts;
 pub mod process_tracker;
 pub mod queue;
 pub mod refund;
 pub mod reverse_lookup;
 
-use std::sync::Arc;
+use std::{collections::HashMap, sync::Arc};
 
 use futures::lock::Mutex;
 
-use crate::{services::Store, types::storage};
+use crate::{
+    services::Store,
+    types::{self, storage},
+};
 
 #[derive(PartialEq, Eq)]
 pub enum StorageImpl {
@@ -38,11 +46,15 @@ pub trait StorageInterface:
     + dyn_clone::DynClone
     + address::AddressInterface
     + api_keys::ApiKeyInterface
+    + capture::CaptureInterface
     + configs::ConfigInterface
     + connector_response::ConnectorResponseInterface
     + customers::CustomerInterface
+    + dispute::DisputeInterface
     + ephemeral_key::EphemeralKeyInterface
+    + event_delivery_attempt::EventDeliveryAttemptInterface
     + events::EventInterface
+    + file::FileMetadataInterface
     + locker_mock_up::LockerMockUpInterface
     + mandate::MandateInterface
     + merchant_account::MerchantAccountInterface
@@ -51,10 +63,12 @@ pub trait StorageInterface:
     + payment_attempt::PaymentAttemptInterface
     + payment_intent::PaymentIntentInterface
     + payment_method::PaymentMethodInterface
+    + payouts::PayoutInterface
     + process_tracker::ProcessTrackerInterface
     + queue::QueueInterface
     + refund::RefundInterface
     + reverse_lookup::ReverseLookupInterface
+    + RedisConnInterface
     + 'static
 {
     async fn close(&mut self) {}
@@ -71,6 +85,28 @@ impl StorageInterface for Store {
     }
 }
 
+/// Access to the redis connection of the store, for the functionality that is built directly on
+/// redis rather than on a database table
+pub trait RedisConnInterface {
+    fn get_redis_conn(
+        &self,
+    ) -> common_utils::errors::CustomResult<
+        Arc<redis_interface::RedisConnectionPool>,
+        redis_interface::errors::RedisError,
+    >;
+}
+
+impl RedisConnInterface for Store {
+    fn get_redis_conn(
+        &self,
+    ) -> common_utils::errors::CustomResult<
+        Arc<redis_interface::RedisConnectionPool>,
+        redis_interface::errors::RedisError,
+    > {
+        self.redis_conn()
+    }
+}
+
 #[derive(Clone)]
 pub struct MockDb {
     merchant_accounts: Arc<Mutex<Vec<storage::MerchantAccount>>>,
@@ -79,7 +115,18 @@ pub struct MockDb {
     payment_intents: Arc<Mutex<Vec<storage::PaymentIntent>>>,
     customers: Arc<Mutex<Vec<storage::Customer>>>,
     refunds: Arc<Mutex<Vec<storage::Refund>>>,
+    payouts: Arc<Mutex<Vec<storage::Payouts>>>,
+    disputes: Arc<Mutex<Vec<storage::Dispute>>>,
+    captures: Arc<Mutex<Vec<storage::Capture>>>,
+    files: Arc<Mutex<Vec<storage::FileMetadata>>>,
+    events: Arc<Mutex<Vec<storage::Event>>>,
+    event_delivery_attempts: Arc<Mutex<Vec<storage::EventDeliveryAttempt>>>,
     processes: Arc<Mutex<Vec<storage::ProcessTracker>>>,
+    streams: Arc<Mutex<HashMap<String, queue::MockStream>>>,
+    /// Expiry time of each of the locks held, by the key of the lock
+    locks: Arc<Mutex<HashMap<String, time::PrimitiveDateTime>>>,
+    /// Access tokens of the connectors, by the key of the access token
+    access_tokens: Arc<Mutex<HashMap<String, types::AccessToken>>>,
     connector_response: Arc<Mutex<Vec<storage::ConnectorResponse>>>,
     redis: Arc<redis_interface::RedisConnectionPool>,
 }
@@ -93,13 +140,33 @@ impl MockDb {
             payment_intents: Default::default(),
             customers: Default::default(),
             refunds: Default::default(),
+            payouts: Default::default(),
+            disputes: Default::default(),
+            captures: Default::default(),
+            files: Default::default(),
+            events: Default::default(),
+            event_delivery_attempts: Default::default(),
             processes: Default::default(),
+            streams: Default::default(),
+            locks: Default::default(),
+            access_tokens: Default::default(),
             connector_response: Default::default(),
             redis: Arc::new(crate::connection::redis_connection(redis).await),
         }
     }
 }
 
+impl RedisConnInterface for MockDb {
+    fn get_redis_conn(
+        &self,
+    ) -> common_utils::errors::CustomResult<
+        Arc<redis_interface::RedisConnectionPool>,
+        redis_interface::errors::RedisError,
+    > {
+        Ok(self.redis.clone())
+    }
+}
+
 #[async_trait::async_trait]
 impl StorageInterface for MockDb {
     #[allow(clippy::expect_used)]
@@ -1,10 +1,7 @@
-use error_stack::IntoReport;
-
-use super::{MockDb, Store};
+use super::MockDb;
 use crate::{
-    connection::pg_connection,
     core::errors::{self, CustomResult},
-    types::storage,
+    types::storage::{self, enums},
 };
 
 #[async_trait::async_trait]
@@ -13,16 +10,19 @@ pub trait AddressInterface {
         &self,
         address_id: String,
         address: storage::AddressUpdate,
+        storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<storage::Address, errors::StorageError>;
 
     async fn insert_address(
         &self,
         address: storage::AddressNew,
+        storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<storage::Address, errors::StorageError>;
 
     async fn find_address(
         &self,
         address_id: &str,
+        storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<storage::Address, errors::StorageError>;
 
     async fn update_address_by_merchant_id_customer_id(
@@ -30,62 +30,140 @@ pub trait AddressInterface {
         customer_id: &str,
         merchant_id: &str,
         address: storage::AddressUpdate,
+        storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<Vec<storage::Address>, errors::StorageError>;
 }
 
-#[async_trait::async_trait]
-impl AddressInterface for Store {
-    async fn find_address(
-        &self,
-        address_id: &str,
-    ) -> CustomResult<storage::Address, errors::StorageError> {
-        let conn = pg_connection(&self.master_pool).await?;
-        storage::Address::find_by_address_id(&conn, address_id)
-            .await
-            .map_err(Into::into)
-            .into_report()
-    }
+#[cfg(not(feature = "kv_store"))]
+mod storage {
+    use error_stack::IntoReport;
 
-    async fn update_address(
-        &self,
-        address_id: String,
-        address: storage::AddressUpdate,
-    ) -> CustomResult<storage::Address, errors::StorageError> {
-        let conn = pg_connection(&self.master_pool).await?;
-        storage::Address::update_by_address_id(&conn, address_id, address)
-            .await
-            .map_err(Into::into)
-            .into_report()
-    }
+    use super::AddressInterface;
+    use crate::{
+        connection::pg_connection,
+        core::errors::{self, CustomResult},
+        services::Store,
+        types::storage::{self as storage_types, enums},
+    };
 
-    async fn insert_address(
-        &self,
-        address: storage::AddressNew,
-    ) -> CustomResult<storage::Address, errors::StorageError> {
-        let conn = pg_connection(&self.master_pool).await?;
-        address
-            .insert(&conn)
-            .await
-            .map_err(Into::into)
-            .into_report()
-    }
+    #[async_trait::async_trait]
+    impl AddressInterface for Store {
+        async fn find_address(
+            &self,
+            address_id: &str,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage_types::Address, errors::StorageError> {
+            let conn = pg_connection(&self.master_pool).await?;
+            storage_types::Address::find_by_address_id(&conn, address_id)
+                .await
+                .map_err(Into::into)
+                .into_report()
+        }
 
-    async fn update_address_by_merchant_id_customer_id(
-        &self,
-        customer_id: &str,
-        merchant_id: &str,
-        address: storage::AddressUpdate,
-    ) -> CustomResult<Vec<storage::Address>, errors::StorageError> {
-        let conn = pg_connection(&self.master_pool).await?;
-        storage::Address::update_by_merchant_id_customer_id(
-            &conn,
-            customer_id,
-            merchant_id,
-            address,
-        )
-        .await
-        .map_err(Into::into)
-        .into_report()
+        async fn update_address(
+            &self,
+            address_id: String,
+            address: storage_types::AddressUpdate,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage_types::Address, errors::StorageError> {
+            let conn = pg_connection(&self.master_pool).await?;
+            storage_types::Address::update_by_address_id(&conn, address_id, address)
+                .await
+                .map_err(Into::into)
+                .into_report()
+        }
+
+        async fn insert_address(
+            &self,
+            address: storage_types::AddressNew,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage_types::Address, errors::StorageError> {
+            let conn = pg_connection(&self.master_pool).await?;
+            address
+                .insert(&conn)
+                .await
+                .map_err(Into::into)
+                .into_report()
+        }
+
+        async fn update_address_by_merchant_id_customer_id(
+            &self,
+            customer_id: &str,
+            merchant_id: &str,
+            address: storage_types::AddressUpdate,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<Vec<storage_types::Address>, errors::StorageError> {
+            let conn = pg_connection(&self.master_pool).await?;
+            match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => {
+                    storage_types::Address::update_by_merchant_id_customer_id(
+                        &conn,
+                        customer_id,
+                        merchant_id,
+                        address,
+                    )
+                    .await
+                    .map_err(Into::into)
+                    .into_report()
+                }
+                enums::MerchantStorageScheme::RedisKv => {
+                    // The addresses stored in redis may have inserts or updates pending in the
+                    // drainer stream, their updates are queued after those in the same stream
+                    let key = format!("{merchant_id}_{customer_id}");
+                    let redis_addresses = self
+                        .redis_conn()
+                        .map_err(Into::<errors::StorageError>::into)?
+                        .hscan_and_deserialize::<storage_types::Address>(&key, "add_*", None)
+                        .await
+                        .change_context(errors::StorageError::KVError)?;
+
+                    let mut updated_addresses = Vec::with_capacity(redis_addresses.len());
+                    for redis_address in redis_addresses {
+                        let field = format!("add_{}", redis_address.address_id);
+                        updated_addresses.push(
+                            self.update_address_in_redis(
+                                &key,
+                                &field,
+                                redis_address,
+                                address.clone(),
+                            )
+                            .await?,
+                        );
+                    }
+
+                    // The addresses created before the merchant moved to the KV storage scheme
+                    // are only present in the database, and are updated directly
+                    let database_result =
+                        storage_types::Address::update_by_merchant_id_customer_id_except_address_ids(
+                            &conn,
+                            customer_id,
+                            merchant_id,
+                            updated_addresses
+                                .iter()
+                                .map(|updated_address| updated_address.address_id.clone())
+                                .collect(),
+                            address,
+                        )
+                        .await
+                        .map_err(Into::<errors::StorageError>::into)
+                        .into_report();
+
+                    match database_result {
+                        Ok(database_addresses) => {
+                            updated_addresses.extend(database_addresses);
+                            Ok(updated_addresses)
+                        }
+                        Err(error)
+                            if error.current_context().is_db_not_found()
+                                && !updated_addresses.is_empty() =>
+                        {
+                            Ok(updated_addresses)
+                        }
+                        Err(error) => Err(error),
+                    }
+                }
+            }
+        }
     }
 }
 
@@ -94,6 +172,7 @@ impl AddressInterface for MockDb {
     async fn find_address(
         &self,
         _address_id: &str,
+        _storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<storage::Address, errors::StorageError> {
         // [#172]: Implement function for `MockDb`
         Err(errors::StorageError::MockDbError)?
@@ -103,6 +182,7 @@ impl AddressInterface for MockDb {
         &self,
         _address_id: String,
         _address: storage::AddressUpdate,
+        _storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<storage::Address, errors::StorageError> {
         // [#172]: Implement function for `MockDb`
         Err(errors::StorageError::MockDbError)?
@@ -111,6 +191,7 @@ impl AddressInterface for MockDb {
     async fn insert_address(
         &self,
         _address: storage::AddressNew,
+        _storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<storage::Address, errors::StorageError> {
         // [#172]: Implement function for `MockDb`
         Err(errors::StorageError::MockDbError)?
@@ -121,8 +202,106 @@ impl AddressInterface for MockDb {
         _customer_id: &str,
         _merchant_id: &str,
         _address: storage::AddressUpdate,
+        _storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<Vec<storage::Address>, errors::StorageError> {
         // [#172]: Implement function for `MockDb`
         Err(errors::StorageError::MockDbError)?
     }
 }
+
+#[cfg(all(test, feature = "kv_store"))]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+    use crate::{configs::settings::Settings, db::StorageImpl, routes::AppState, utils};
+
+    fn city_update(city: &str) -> storage::AddressUpdate {
+        storage::AddressUpdate::Update {
+            city: Some(city.to_string()),
+            country: None,
+            line1: None,
+            line2: None,
+            line3: None,
+            state: None,
+            zip: None,
+            first_name: None,
+            last_name: None,
+            phone_number: None,
+            country_code: None,
+        }
+    }
+
+    #[actix_rt::test]
+    async fn test_addresses_in_redis_are_updated_through_the_drainer() {
+        let state =
+            AppState::with_storage(Settings::new().unwrap(), StorageImpl::PostgresqlTest).await;
+        let merchant_id = utils::generate_id(16, "merchant");
+        let customer_id = utils::generate_id(16, "cus");
+        let address_new = || storage::AddressNew {
+            city: Some("Paris".to_string()),
+            customer_id: customer_id.clone(),
+            merchant_id: merchant_id.clone(),
+            ..Default::default()
+        };
+
+        // Created before the merchant moved to the KV storage scheme
+        let database_address = state
+            .store
+            .insert_address(address_new(), enums::MerchantStorageScheme::PostgresOnly)
+            .await
+            .unwrap();
+        let kv_address = state
+            .store
+            .insert_address(address_new(), enums::MerchantStorageScheme::RedisKv)
+            .await
+            .unwrap();
+
+        let updated_addresses = state
+            .store
+            .update_address_by_merchant_id_customer_id(
+                &customer_id,
+                &merchant_id,
+                city_update("London"),
+                enums::MerchantStorageScheme::RedisKv,
+            )
+            .await
+            .unwrap();
+        assert_eq!(updated_addresses.len(), 2);
+        assert!(updated_addresses
+            .iter()
+            .all(|address| address.city.as_deref() == Some("London")));
+
+        // The address only present in the database is updated directly
+        let found_database_address = state
+            .store
+            .find_address(
+                &database_address.address_id,
+                enums::MerchantStorageScheme::PostgresOnly,
+            )
+            .await
+            .unwrap();
+        assert_eq!(found_database_address.city.as_deref(), Some("London"));
+
+        // The address in redis is updated in redis, and written to the database by the drainer
+        let found_kv_address = state
+            .store
+            .find_address(
+                &kv_address.address_id,
+                enums::MerchantStorageScheme::RedisKv,
+            )
+            .await
+            .unwrap();
+        assert_eq!(found_kv_address.city.as_deref(), Some("London"));
+
+        let database_error = state
+            .store
+            .find_address(
+                &kv_address.address_id,
+                enums::MerchantStorageScheme::PostgresOnly,
+            )
+            .await
+            .unwrap_err();
+        assert!(database_error.current_context().is_db_not_found());
+    }
+}
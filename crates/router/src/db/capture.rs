@@ -0,0 +1,191 @@
+use error_stack::IntoReport;
+use storage_models::errors::DatabaseError;
+
+use super::{MockDb, Store};
+use crate::{
+    connection::pg_connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait CaptureInterface {
+    async fn insert_capture(
+        &self,
+        capture: storage::CaptureNew,
+    ) -> CustomResult<storage::Capture, errors::StorageError>;
+
+    async fn find_capture_by_merchant_id_capture_id(
+        &self,
+        merchant_id: &str,
+        capture_id: &str,
+    ) -> CustomResult<storage::Capture, errors::StorageError>;
+
+    async fn find_all_captures_by_merchant_id_payment_id_attempt_id(
+        &self,
+        merchant_id: &str,
+        payment_id: &str,
+        attempt_id: &str,
+    ) -> CustomResult<Vec<storage::Capture>, errors::StorageError>;
+
+    async fn update_capture(
+        &self,
+        this: storage::Capture,
+        capture: storage::CaptureUpdate,
+    ) -> CustomResult<storage::Capture, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl CaptureInterface for Store {
+    async fn insert_capture(
+        &self,
+        capture: storage::CaptureNew,
+    ) -> CustomResult<storage::Capture, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await?;
+        capture
+            .insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_capture_by_merchant_id_capture_id(
+        &self,
+        merchant_id: &str,
+        capture_id: &str,
+    ) -> CustomResult<storage::Capture, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await?;
+        storage::Capture::find_by_merchant_id_capture_id(&conn, merchant_id, capture_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_all_captures_by_merchant_id_payment_id_attempt_id(
+        &self,
+        merchant_id: &str,
+        payment_id: &str,
+        attempt_id: &str,
+    ) -> CustomResult<Vec<storage::Capture>, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await?;
+        storage::Capture::find_all_by_merchant_id_payment_id_attempt_id(
+            &conn,
+            merchant_id,
+            payment_id,
+            attempt_id,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+
+    async fn update_capture(
+        &self,
+        this: storage::Capture,
+        capture: storage::CaptureUpdate,
+    ) -> CustomResult<storage::Capture, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await?;
+        this.update(&conn, capture)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl CaptureInterface for MockDb {
+    async fn insert_capture(
+        &self,
+        new: storage::CaptureNew,
+    ) -> CustomResult<storage::Capture, errors::StorageError> {
+        let mut captures = self.captures.lock().await;
+        if captures.iter().any(|capture| {
+            capture.merchant_id == new.merchant_id && capture.capture_id == new.capture_id
+        }) {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "capture",
+                key: Some(new.capture_id.clone()),
+            })?
+        }
+        let current_time = common_utils::date_time::now();
+
+        let capture = storage::Capture {
+            #[allow(clippy::as_conversions)]
+            id: captures.len() as i32,
+            capture_id: new.capture_id,
+            payment_id: new.payment_id,
+            merchant_id: new.merchant_id,
+            attempt_id: new.attempt_id,
+            status: new.status,
+            amount: new.amount,
+            currency: new.currency,
+            connector: new.connector,
+            connector_capture_id: None,
+            capture_sequence: new.capture_sequence,
+            error_message: None,
+            error_code: None,
+            created_at: current_time,
+            modified_at: current_time,
+        };
+        captures.push(capture.clone());
+        Ok(capture)
+    }
+
+    async fn find_capture_by_merchant_id_capture_id(
+        &self,
+        merchant_id: &str,
+        capture_id: &str,
+    ) -> CustomResult<storage::Capture, errors::StorageError> {
+        let captures = self.captures.lock().await;
+
+        captures
+            .iter()
+            .find(|capture| capture.merchant_id == merchant_id && capture.capture_id == capture_id)
+            .cloned()
+            .ok_or_else(|| {
+                errors::StorageError::DatabaseError(DatabaseError::NotFound.into()).into()
+            })
+    }
+
+    async fn find_all_captures_by_merchant_id_payment_id_attempt_id(
+        &self,
+        merchant_id: &str,
+        payment_id: &str,
+        attempt_id: &str,
+    ) -> CustomResult<Vec<storage::Capture>, errors::StorageError> {
+        let captures = self.captures.lock().await;
+
+        let mut captures = captures
+            .iter()
+            .filter(|capture| {
+                capture.merchant_id == merchant_id
+                    && capture.payment_id == payment_id
+                    && capture.attempt_id == attempt_id
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        captures.sort_by_key(|capture| capture.capture_sequence);
+        Ok(captures)
+    }
+
+    async fn update_capture(
+        &self,
+        this: storage::Capture,
+        capture: storage::CaptureUpdate,
+    ) -> CustomResult<storage::Capture, errors::StorageError> {
+        let mut captures = self.captures.lock().await;
+
+        let stored = captures
+            .iter_mut()
+            .find(|stored| {
+                stored.merchant_id == this.merchant_id && stored.capture_id == this.capture_id
+            })
+            .ok_or_else(|| {
+                error_stack::report!(errors::StorageError::DatabaseError(
+                    DatabaseError::NotFound.into()
+                ))
+            })?;
+        *stored = capture.apply_changeset(stored.clone());
+        Ok(stored.clone())
+    }
+}
@@ -1,8 +1,5 @@
-use error_stack::IntoReport;
-
-use super::{MockDb, Store};
+use super::MockDb;
 use crate::{
-    connection::pg_connection,
     core::errors::{self, CustomResult},
     types::storage::{self, enums},
 };
@@ -31,51 +28,254 @@ pub trait ConnectorResponseInterface {
     ) -> CustomResult<storage::ConnectorResponse, errors::StorageError>;
 }
 
-#[async_trait::async_trait]
-impl ConnectorResponseInterface for Store {
-    async fn insert_connector_response(
-        &self,
-        connector_response: storage::ConnectorResponseNew,
-        _storage_scheme: enums::MerchantStorageScheme,
-    ) -> CustomResult<storage::ConnectorResponse, errors::StorageError> {
-        let conn = pg_connection(&self.master_pool).await?;
-        connector_response
-            .insert(&conn)
+#[cfg(not(feature = "kv_store"))]
+mod storage {
+    use error_stack::IntoReport;
+
+    use super::ConnectorResponseInterface;
+    use crate::{
+        connection::pg_connection,
+        core::errors::{self, CustomResult},
+        services::Store,
+        types::storage::{self as storage_types, enums},
+    };
+
+    #[async_trait::async_trait]
+    impl ConnectorResponseInterface for Store {
+        async fn insert_connector_response(
+            &self,
+            connector_response: storage_types::ConnectorResponseNew,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage_types::ConnectorResponse, errors::StorageError> {
+            let conn = pg_connection(&self.master_pool).await?;
+            connector_response
+                .insert(&conn)
+                .await
+                .map_err(Into::into)
+                .into_report()
+        }
+
+        async fn find_connector_response_by_payment_id_merchant_id_attempt_id(
+            &self,
+            payment_id: &str,
+            merchant_id: &str,
+            attempt_id: &str,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage_types::ConnectorResponse, errors::StorageError> {
+            let conn = pg_connection(&self.master_pool).await?;
+            storage_types::ConnectorResponse::find_by_payment_id_merchant_id_attempt_id(
+                &conn,
+                payment_id,
+                merchant_id,
+                attempt_id,
+            )
             .await
             .map_err(Into::into)
             .into_report()
-    }
+        }
 
-    async fn find_connector_response_by_payment_id_merchant_id_attempt_id(
-        &self,
-        payment_id: &str,
-        merchant_id: &str,
-        attempt_id: &str,
-        _storage_scheme: enums::MerchantStorageScheme,
-    ) -> CustomResult<storage::ConnectorResponse, errors::StorageError> {
-        let conn = pg_connection(&self.master_pool).await?;
-        storage::ConnectorResponse::find_by_payment_id_merchant_id_attempt_id(
-            &conn,
-            payment_id,
-            merchant_id,
-            attempt_id,
-        )
-        .await
-        .map_err(Into::into)
-        .into_report()
+        async fn update_connector_response(
+            &self,
+            this: storage_types::ConnectorResponse,
+            connector_response_update: storage_types::ConnectorResponseUpdate,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage_types::ConnectorResponse, errors::StorageError> {
+            let conn = pg_connection(&self.master_pool).await?;
+            this.update(&conn, connector_response_update)
+                .await
+                .map_err(Into::into)
+                .into_report()
+        }
     }
+}
 
-    async fn update_connector_response(
-        &self,
-        this: storage::ConnectorResponse,
-        connector_response_update: storage::ConnectorResponseUpdate,
-        _storage_scheme: enums::MerchantStorageScheme,
-    ) -> CustomResult<storage::ConnectorResponse, errors::StorageError> {
-        let conn = pg_connection(&self.master_pool).await?;
-        this.update(&conn, connector_response_update)
-            .await
-            .map_err(Into::into)
-            .into_report()
+#[cfg(feature = "kv_store")]
+mod storage {
+    use error_stack::{IntoReport, ResultExt};
+    use redis_interface::HsetnxReply;
+
+    use super::ConnectorResponseInterface;
+    use crate::{
+        connection::pg_connection,
+        core::errors::{self, CustomResult},
+        services::Store,
+        types::storage::{self as storage_types, enums, kv},
+        utils::{self, db_utils, storage_partitioning::PartitionKey},
+    };
+
+    #[async_trait::async_trait]
+    impl ConnectorResponseInterface for Store {
+        async fn insert_connector_response(
+            &self,
+            connector_response: storage_types::ConnectorResponseNew,
+            storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage_types::ConnectorResponse, errors::StorageError> {
+            match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => {
+                    let conn = pg_connection(&self.master_pool).await?;
+                    connector_response
+                        .insert(&conn)
+                        .await
+                        .map_err(Into::into)
+                        .into_report()
+                }
+                enums::MerchantStorageScheme::RedisKv => {
+                    let merchant_id = &connector_response.merchant_id;
+                    let payment_id = &connector_response.payment_id;
+                    let attempt_id = &connector_response.attempt_id;
+
+                    // The connector response is stored in the same hash as the payment attempt
+                    let key = format!("{merchant_id}_{payment_id}");
+                    let field = format!("connector_resp_{merchant_id}_{payment_id}_{attempt_id}");
+                    let created_connector_response = storage_types::ConnectorResponse {
+                        id: 0i32,
+                        payment_id: connector_response.payment_id.clone(),
+                        merchant_id: connector_response.merchant_id.clone(),
+                        attempt_id: connector_response.attempt_id.clone(),
+                        created_at: connector_response.created_at,
+                        modified_at: connector_response.modified_at,
+                        connector_name: connector_response.connector_name.clone(),
+                        connector_transaction_id: connector_response
+                            .connector_transaction_id
+                            .clone(),
+                        authentication_data: connector_response.authentication_data.clone(),
+                        encoded_data: connector_response.encoded_data.clone(),
+                    };
+
+                    match self
+                        .redis_conn()
+                        .map_err(Into::<errors::StorageError>::into)?
+                        .serialize_and_set_hash_field_if_not_exist(
+                            &key,
+                            &field,
+                            &created_connector_response,
+                        )
+                        .await
+                    {
+                        Ok(HsetnxReply::KeyNotSet) => Err(errors::StorageError::DuplicateValue {
+                            entity: "connector_response",
+                            key: Some(created_connector_response.attempt_id),
+                        })
+                        .into_report(),
+                        Ok(HsetnxReply::KeySet) => {
+                            let redis_entry = kv::TypedSql {
+                                op: kv::DBOperation::Insert {
+                                    insertable: kv::Insertable::ConnectorResponse(
+                                        connector_response,
+                                    ),
+                                },
+                            };
+                            self.push_to_drainer_stream::<storage_types::ConnectorResponse>(
+                                redis_entry,
+                                PartitionKey::MerchantIdPaymentId {
+                                    merchant_id: &created_connector_response.merchant_id,
+                                    payment_id: &created_connector_response.payment_id,
+                                },
+                            )
+                            .await?;
+
+                            Ok(created_connector_response)
+                        }
+                        Err(er) => Err(er).change_context(errors::StorageError::KVError),
+                    }
+                }
+            }
+        }
+
+        async fn find_connector_response_by_payment_id_merchant_id_attempt_id(
+            &self,
+            payment_id: &str,
+            merchant_id: &str,
+            attempt_id: &str,
+            storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage_types::ConnectorResponse, errors::StorageError> {
+            let database_call = || async {
+                let conn = pg_connection(&self.master_pool).await?;
+                storage_types::ConnectorResponse::find_by_payment_id_merchant_id_attempt_id(
+                    &conn,
+                    payment_id,
+                    merchant_id,
+                    attempt_id,
+                )
+                .await
+                .map_err(Into::into)
+                .into_report()
+            };
+            match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => database_call().await,
+                enums::MerchantStorageScheme::RedisKv => {
+                    let key = format!("{merchant_id}_{payment_id}");
+                    let field = format!("connector_resp_{merchant_id}_{payment_id}_{attempt_id}");
+                    db_utils::try_redis_get_else_try_database_get(
+                        self.redis_conn()
+                            .map_err(Into::<errors::StorageError>::into)?
+                            .get_hash_field_and_deserialize(&key, &field, "ConnectorResponse"),
+                        database_call,
+                    )
+                    .await
+                }
+            }
+        }
+
+        async fn update_connector_response(
+            &self,
+            this: storage_types::ConnectorResponse,
+            connector_response_update: storage_types::ConnectorResponseUpdate,
+            storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage_types::ConnectorResponse, errors::StorageError> {
+            match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => {
+                    let conn = pg_connection(&self.master_pool).await?;
+                    this.update(&conn, connector_response_update)
+                        .await
+                        .map_err(Into::into)
+                        .into_report()
+                }
+                enums::MerchantStorageScheme::RedisKv => {
+                    let key = format!("{}_{}", this.merchant_id, this.payment_id);
+                    let field = format!(
+                        "connector_resp_{}_{}_{}",
+                        this.merchant_id, this.payment_id, this.attempt_id
+                    );
+                    let updated_connector_response = connector_response_update
+                        .clone()
+                        .apply_changeset(this.clone());
+
+                    let redis_value =
+                        utils::Encode::<storage_types::ConnectorResponse>::encode_to_string_of_json(
+                            &updated_connector_response,
+                        )
+                        .change_context(errors::StorageError::SerializationFailed)?;
+
+                    self.redis_conn()
+                        .map_err(Into::<errors::StorageError>::into)?
+                        .set_hash_fields(&key, (&field, redis_value))
+                        .await
+                        .change_context(errors::StorageError::KVError)?;
+
+                    let redis_entry = kv::TypedSql {
+                        op: kv::DBOperation::Update {
+                            updatable: kv::Updateable::ConnectorResponseUpdate(
+                                kv::ConnectorResponseUpdateMems {
+                                    orig: this,
+                                    update_data: connector_response_update,
+                                },
+                            ),
+                        },
+                    };
+                    self.push_to_drainer_stream::<storage_types::ConnectorResponse>(
+                        redis_entry,
+                        PartitionKey::MerchantIdPaymentId {
+                            merchant_id: &updated_connector_response.merchant_id,
+                            payment_id: &updated_connector_response.payment_id,
+                        },
+                    )
+                    .await?;
+
+                    Ok(updated_connector_response)
+                }
+            }
+        }
     }
 }
 
@@ -1,13 +1,7 @@
-use error_stack::IntoReport;
-
-use super::{MockDb, Store};
+use super::MockDb;
 use crate::{
-    connection::pg_connection,
-    core::{
-        customers::REDACTED,
-        errors::{self, CustomResult},
-    },
-    types::storage,
+    core::errors::{self, CustomResult},
+    types::storage::{self, enums},
 };
 
 #[async_trait::async_trait]
@@ -16,12 +10,14 @@ pub trait CustomerInterface {
         &self,
         customer_id: &str,
         merchant_id: &str,
+        storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<bool, errors::StorageError>;
 
     async fn find_customer_optional_by_customer_id_merchant_id(
         &self,
         customer_id: &str,
         merchant_id: &str,
+        storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<Option<storage::Customer>, errors::StorageError>;
 
     async fn update_customer_by_customer_id_merchant_id(
@@ -29,103 +25,465 @@ pub trait CustomerInterface {
         customer_id: String,
         merchant_id: String,
         customer: storage::CustomerUpdate,
+        storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<storage::Customer, errors::StorageError>;
 
     async fn find_customer_by_customer_id_merchant_id(
         &self,
         customer_id: &str,
         merchant_id: &str,
+        storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<storage::Customer, errors::StorageError>;
 
     async fn insert_customer(
         &self,
         customer_data: storage::CustomerNew,
+        storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<storage::Customer, errors::StorageError>;
 }
 
-#[async_trait::async_trait]
-impl CustomerInterface for Store {
-    async fn find_customer_optional_by_customer_id_merchant_id(
-        &self,
-        customer_id: &str,
-        merchant_id: &str,
-    ) -> CustomResult<Option<storage::Customer>, errors::StorageError> {
-        let conn = pg_connection(&self.master_pool).await?;
-        let maybe_customer = storage::Customer::find_optional_by_customer_id_merchant_id(
-            &conn,
-            customer_id,
-            merchant_id,
-        )
-        .await
-        .map_err(Into::into)
-        .into_report()?;
-        maybe_customer.map_or(Ok(None), |customer| {
-            // in the future, once #![feature(is_some_and)] is stable, we can make this more concise:
-            // `if customer.name.is_some_and(|ref name| name == REDACTED) ...`
+#[cfg(not(feature = "kv_store"))]
+mod storage {
+    use error_stack::IntoReport;
+
+    use super::CustomerInterface;
+    use crate::{
+        connection::pg_connection,
+        core::{
+            customers::REDACTED,
+            errors::{self, CustomResult},
+        },
+        services::Store,
+        types::storage::{self as storage_types, enums},
+    };
+
+    #[async_trait::async_trait]
+    impl CustomerInterface for Store {
+        async fn find_customer_optional_by_customer_id_merchant_id(
+            &self,
+            customer_id: &str,
+            merchant_id: &str,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<Option<storage_types::Customer>, errors::StorageError> {
+            let conn = pg_connection(&self.master_pool).await?;
+            let maybe_customer = storage_types::Customer::find_optional_by_customer_id_merchant_id(
+                &conn,
+                customer_id,
+                merchant_id,
+            )
+            .await
+            .map_err(Into::into)
+            .into_report()?;
+            maybe_customer.map_or(Ok(None), |customer| {
+                // in the future, once #![feature(is_some_and)] is stable, we can make this more concise:
+                // `if customer.name.is_some_and(|ref name| name == REDACTED) ...`
+                match customer.name {
+                    Some(ref name) if name == REDACTED => {
+                        Err(errors::StorageError::CustomerRedacted)?
+                    }
+                    _ => Ok(Some(customer)),
+                }
+            })
+        }
+
+        async fn update_customer_by_customer_id_merchant_id(
+            &self,
+            customer_id: String,
+            merchant_id: String,
+            customer: storage_types::CustomerUpdate,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage_types::Customer, errors::StorageError> {
+            let conn = pg_connection(&self.master_pool).await?;
+            storage_types::Customer::update_by_customer_id_merchant_id(
+                &conn,
+                customer_id,
+                merchant_id,
+                customer,
+            )
+            .await
+            .map_err(Into::into)
+            .into_report()
+        }
+
+        async fn find_customer_by_customer_id_merchant_id(
+            &self,
+            customer_id: &str,
+            merchant_id: &str,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage_types::Customer, errors::StorageError> {
+            let conn = pg_connection(&self.master_pool).await?;
+            let customer = storage_types::Customer::find_by_customer_id_merchant_id(
+                &conn,
+                customer_id,
+                merchant_id,
+            )
+            .await
+            .map_err(Into::into)
+            .into_report()?;
             match customer.name {
                 Some(ref name) if name == REDACTED => Err(errors::StorageError::CustomerRedacted)?,
-                _ => Ok(Some(customer)),
+                _ => Ok(customer),
             }
-        })
-    }
-
-    async fn update_customer_by_customer_id_merchant_id(
-        &self,
-        customer_id: String,
-        merchant_id: String,
-        customer: storage::CustomerUpdate,
-    ) -> CustomResult<storage::Customer, errors::StorageError> {
-        let conn = pg_connection(&self.master_pool).await?;
-        storage::Customer::update_by_customer_id_merchant_id(
-            &conn,
-            customer_id,
-            merchant_id,
-            customer,
-        )
-        .await
-        .map_err(Into::into)
-        .into_report()
-    }
+        }
 
-    async fn find_customer_by_customer_id_merchant_id(
-        &self,
-        customer_id: &str,
-        merchant_id: &str,
-    ) -> CustomResult<storage::Customer, errors::StorageError> {
-        let conn = pg_connection(&self.master_pool).await?;
-        let customer =
-            storage::Customer::find_by_customer_id_merchant_id(&conn, customer_id, merchant_id)
+        async fn insert_customer(
+            &self,
+            customer_data: storage_types::CustomerNew,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage_types::Customer, errors::StorageError> {
+            let conn = pg_connection(&self.master_pool).await?;
+            customer_data
+                .insert(&conn)
                 .await
                 .map_err(Into::into)
-                .into_report()?;
-        match customer.name {
-            Some(ref name) if name == REDACTED => Err(errors::StorageError::CustomerRedacted)?,
-            _ => Ok(customer),
+                .into_report()
         }
-    }
 
-    async fn insert_customer(
-        &self,
-        customer_data: storage::CustomerNew,
-    ) -> CustomResult<storage::Customer, errors::StorageError> {
-        let conn = pg_connection(&self.master_pool).await?;
-        customer_data
-            .insert(&conn)
+        async fn delete_customer_by_customer_id_merchant_id(
+            &self,
+            customer_id: &str,
+            merchant_id: &str,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<bool, errors::StorageError> {
+            let conn = pg_connection(&self.master_pool).await?;
+            storage_types::Customer::delete_by_customer_id_merchant_id(
+                &conn,
+                customer_id,
+                merchant_id,
+            )
             .await
             .map_err(Into::into)
             .into_report()
+        }
     }
+}
 
-    async fn delete_customer_by_customer_id_merchant_id(
-        &self,
-        customer_id: &str,
-        merchant_id: &str,
-    ) -> CustomResult<bool, errors::StorageError> {
-        let conn = pg_connection(&self.master_pool).await?;
-        storage::Customer::delete_by_customer_id_merchant_id(&conn, customer_id, merchant_id)
-            .await
-            .map_err(Into::into)
-            .into_report()
+#[cfg(feature = "kv_store")]
+mod storage {
+    use common_utils::date_time;
+    use error_stack::{report, IntoReport, ResultExt};
+    use redis_interface::HsetnxReply;
+
+    use super::CustomerInterface;
+    use crate::{
+        connection::pg_connection,
+        core::{
+            customers::REDACTED,
+            errors::{self, CustomResult},
+        },
+        services::Store,
+        types::storage::{self as storage_types, enums, kv},
+        utils::{self, db_utils, storage_partitioning::PartitionKey},
+    };
+
+    fn check_customer_redacted(
+        customer: storage_types::Customer,
+    ) -> CustomResult<storage_types::Customer, errors::StorageError> {
+        match customer.name {
+            Some(ref name) if name == REDACTED => Err(errors::StorageError::CustomerRedacted)?,
+            _ => Ok(customer),
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl CustomerInterface for Store {
+        async fn find_customer_optional_by_customer_id_merchant_id(
+            &self,
+            customer_id: &str,
+            merchant_id: &str,
+            storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<Option<storage_types::Customer>, errors::StorageError> {
+            let database_call = || async {
+                let conn = pg_connection(&self.master_pool).await?;
+                storage_types::Customer::find_optional_by_customer_id_merchant_id(
+                    &conn,
+                    customer_id,
+                    merchant_id,
+                )
+                .await
+                .map_err(Into::into)
+                .into_report()
+            };
+            let maybe_customer = match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => database_call().await,
+                enums::MerchantStorageScheme::RedisKv => {
+                    let key = format!("{merchant_id}_{customer_id}");
+                    let field = format!("cust_{customer_id}");
+                    let redis_conn = self
+                        .redis_conn()
+                        .map_err(Into::<errors::StorageError>::into)?;
+                    db_utils::try_redis_get_else_try_database_get(
+                        async {
+                            redis_conn
+                                .get_hash_field_and_deserialize(&key, &field, "Customer")
+                                .await
+                                .map(Some)
+                        },
+                        database_call,
+                    )
+                    .await
+                }
+            }?;
+            maybe_customer.map(check_customer_redacted).transpose()
+        }
+
+        async fn update_customer_by_customer_id_merchant_id(
+            &self,
+            customer_id: String,
+            merchant_id: String,
+            customer: storage_types::CustomerUpdate,
+            storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage_types::Customer, errors::StorageError> {
+            match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => {
+                    let conn = pg_connection(&self.master_pool).await?;
+                    storage_types::Customer::update_by_customer_id_merchant_id(
+                        &conn,
+                        customer_id,
+                        merchant_id,
+                        customer,
+                    )
+                    .await
+                    .map_err(Into::into)
+                    .into_report()
+                }
+                enums::MerchantStorageScheme::RedisKv => {
+                    let key = format!("{merchant_id}_{customer_id}");
+                    let field = format!("cust_{customer_id}");
+                    let redis_conn = self
+                        .redis_conn()
+                        .map_err(Into::<errors::StorageError>::into)?;
+
+                    // The customer may not have been written to redis yet, if it was created
+                    // before the merchant moved to the KV storage scheme
+                    let database_call = || async {
+                        let conn = pg_connection(&self.master_pool).await?;
+                        storage_types::Customer::find_by_customer_id_merchant_id(
+                            &conn,
+                            &customer_id,
+                            &merchant_id,
+                        )
+                        .await
+                        .map_err(Into::into)
+                        .into_report()
+                    };
+                    let existing_customer: storage_types::Customer =
+                        db_utils::try_redis_get_else_try_database_get(
+                            redis_conn.get_hash_field_and_deserialize(&key, &field, "Customer"),
+                            database_call,
+                        )
+                        .await?;
+
+                    let updated_customer =
+                        customer.clone().apply_changeset(existing_customer.clone());
+
+                    let redis_value =
+                        utils::Encode::<storage_types::Customer>::encode_to_string_of_json(
+                            &updated_customer,
+                        )
+                        .change_context(errors::StorageError::SerializationFailed)?;
+
+                    redis_conn
+                        .set_hash_fields(&key, (&field, redis_value))
+                        .await
+                        .change_context(errors::StorageError::KVError)?;
+
+                    let redis_entry = kv::TypedSql {
+                        op: kv::DBOperation::Update {
+                            updatable: kv::Updateable::CustomerUpdate(kv::CustomerUpdateMems {
+                                orig: existing_customer,
+                                update_data: customer,
+                            }),
+                        },
+                    };
+                    self.push_to_drainer_stream::<storage_types::Customer>(
+                        redis_entry,
+                        PartitionKey::MerchantIdCustomerId {
+                            merchant_id: &merchant_id,
+                            customer_id: &customer_id,
+                        },
+                    )
+                    .await?;
+
+                    Ok(updated_customer)
+                }
+            }
+        }
+
+        async fn find_customer_by_customer_id_merchant_id(
+            &self,
+            customer_id: &str,
+            merchant_id: &str,
+            storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage_types::Customer, errors::StorageError> {
+            let database_call = || async {
+                let conn = pg_connection(&self.master_pool).await?;
+                storage_types::Customer::find_by_customer_id_merchant_id(
+                    &conn,
+                    customer_id,
+                    merchant_id,
+                )
+                .await
+                .map_err(Into::into)
+                .into_report()
+            };
+            let customer = match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => database_call().await,
+                enums::MerchantStorageScheme::RedisKv => {
+                    let key = format!("{merchant_id}_{customer_id}");
+                    let field = format!("cust_{customer_id}");
+                    db_utils::try_redis_get_else_try_database_get(
+                        self.redis_conn()
+                            .map_err(Into::<errors::StorageError>::into)?
+                            .get_hash_field_and_deserialize(&key, &field, "Customer"),
+                        database_call,
+                    )
+                    .await
+                }
+            }?;
+            check_customer_redacted(customer)
+        }
+
+        async fn insert_customer(
+            &self,
+            customer_data: storage_types::CustomerNew,
+            storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage_types::Customer, errors::StorageError> {
+            match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => {
+                    let conn = pg_connection(&self.master_pool).await?;
+                    customer_data
+                        .insert(&conn)
+                        .await
+                        .map_err(Into::into)
+                        .into_report()
+                }
+                enums::MerchantStorageScheme::RedisKv => {
+                    let key = format!(
+                        "{}_{}",
+                        customer_data.merchant_id, customer_data.customer_id
+                    );
+                    let field = format!("cust_{}", customer_data.customer_id);
+                    let created_customer = storage_types::Customer {
+                        id: 0i32,
+                        customer_id: customer_data.customer_id.clone(),
+                        merchant_id: customer_data.merchant_id.clone(),
+                        name: customer_data.name.clone(),
+                        email: customer_data.email.clone(),
+                        phone: customer_data.phone.clone(),
+                        phone_country_code: customer_data.phone_country_code.clone(),
+                        description: customer_data.description.clone(),
+                        created_at: date_time::now(),
+                        metadata: customer_data.metadata.clone(),
+                    };
+
+                    match self
+                        .redis_conn()
+                        .map_err(Into::<errors::StorageError>::into)?
+                        .serialize_and_set_hash_field_if_not_exist(&key, &field, &created_customer)
+                        .await
+                    {
+                        Ok(HsetnxReply::KeyNotSet) => Err(errors::StorageError::DuplicateValue {
+                            entity: "customer",
+                            key: Some(created_customer.customer_id),
+                        })
+                        .into_report(),
+                        Ok(HsetnxReply::KeySet) => {
+                            let redis_entry = kv::TypedSql {
+                                op: kv::DBOperation::Insert {
+                                    insertable: kv::Insertable::Customer(customer_data),
+                                },
+                            };
+                            self.push_to_drainer_stream::<storage_types::Customer>(
+                                redis_entry,
+                                PartitionKey::MerchantIdCustomerId {
+                                    merchant_id: &created_customer.merchant_id,
+                                    customer_id: &created_customer.customer_id,
+                                },
+                            )
+                            .await?;
+
+                            Ok(created_customer)
+                        }
+                        Err(er) => Err(er).change_context(errors::StorageError::KVError),
+                    }
+                }
+            }
+        }
+
+        async fn delete_customer_by_customer_id_merchant_id(
+            &self,
+            customer_id: &str,
+            merchant_id: &str,
+            storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<bool, errors::StorageError> {
+            match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => {
+                    let conn = pg_connection(&self.master_pool).await?;
+                    storage_types::Customer::delete_by_customer_id_merchant_id(
+                        &conn,
+                        customer_id,
+                        merchant_id,
+                    )
+                    .await
+                    .map_err(Into::into)
+                    .into_report()
+                }
+                enums::MerchantStorageScheme::RedisKv => {
+                    let key = format!("{merchant_id}_{customer_id}");
+                    let field = format!("cust_{customer_id}");
+                    let deleted_from_redis = self
+                        .redis_conn()
+                        .map_err(Into::<errors::StorageError>::into)?
+                        .delete_hash_field(&key, &field)
+                        .await
+                        .change_context(errors::StorageError::KVError)?;
+
+                    // The customer may not have been written to redis, if it was created before
+                    // the merchant moved to the KV storage scheme
+                    let deleted = deleted_from_redis || {
+                        let conn = pg_connection(&self.master_pool).await?;
+                        storage_types::Customer::find_optional_by_customer_id_merchant_id(
+                            &conn,
+                            customer_id,
+                            merchant_id,
+                        )
+                        .await
+                        .map_err(Into::<errors::StorageError>::into)
+                        .into_report()?
+                        .is_some()
+                    };
+                    if !deleted {
+                        return Err(errors::StorageError::from(report!(
+                            storage_models::errors::DatabaseError::NotFound
+                        )))
+                        .into_report()
+                        .attach_printable("No records deleted");
+                    }
+
+                    // The customer is deleted from the database by the drainer, after the pending
+                    // insert and updates of the customer in the same drainer stream
+                    let redis_entry = kv::TypedSql {
+                        op: kv::DBOperation::Delete {
+                            deletable: kv::Deletable::Customer {
+                                customer_id: customer_id.to_string(),
+                                merchant_id: merchant_id.to_string(),
+                            },
+                        },
+                    };
+                    self.push_to_drainer_stream::<storage_types::Customer>(
+                        redis_entry,
+                        PartitionKey::MerchantIdCustomerId {
+                            merchant_id,
+                            customer_id,
+                        },
+                    )
+                    .await?;
+
+                    Ok(true)
+                }
+            }
+        }
     }
 }
 
@@ -136,6 +494,7 @@ impl CustomerInterface for MockDb {
         &self,
         customer_id: &str,
         merchant_id: &str,
+        _storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<Option<storage::Customer>, errors::StorageError> {
         let customers = self.customers.lock().await;
 
@@ -152,6 +511,7 @@ impl CustomerInterface for MockDb {
         _customer_id: String,
         _merchant_id: String,
         _customer: storage::CustomerUpdate,
+        _storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<storage::Customer, errors::StorageError> {
         // [#172]: Implement function for `MockDb`
         Err(errors::StorageError::MockDbError)?
@@ -161,6 +521,7 @@ impl CustomerInterface for MockDb {
         &self,
         _customer_id: &str,
         _merchant_id: &str,
+        _storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<storage::Customer, errors::StorageError> {
         // [#172]: Implement function for `MockDb`
         Err(errors::StorageError::MockDbError)?
@@ -170,6 +531,7 @@ impl CustomerInterface for MockDb {
     async fn insert_customer(
         &self,
         customer_data: storage::CustomerNew,
+        _storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<storage::Customer, errors::StorageError> {
         let mut customers = self.customers.lock().await;
         let customer = storage::Customer {
@@ -193,8 +555,245 @@ impl CustomerInterface for MockDb {
         &self,
         _customer_id: &str,
         _merchant_id: &str,
+        _storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<bool, errors::StorageError> {
         // [#172]: Implement function for `MockDb`
         Err(errors::StorageError::MockDbError)?
     }
 }
+
+#[cfg(all(test, feature = "kv_store"))]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+    use crate::{configs::settings::Settings, db::StorageImpl, routes::AppState, utils};
+
+    fn customer_new(merchant_id: &str, name: &str) -> storage::CustomerNew {
+        storage::CustomerNew {
+            customer_id: utils::generate_id(16, "cus"),
+            merchant_id: merchant_id.to_string(),
+            name: Some(name.to_string()),
+            ..Default::default()
+        }
+    }
+
+    fn name_update(name: &str) -> storage::CustomerUpdate {
+        storage::CustomerUpdate::Update {
+            name: Some(name.to_string()),
+            email: None,
+            phone: None,
+            description: None,
+            phone_country_code: None,
+            metadata: None,
+        }
+    }
+
+    #[actix_rt::test]
+    async fn test_customer_is_read_through_from_the_database() {
+        let state =
+            AppState::with_storage(Settings::new().unwrap(), StorageImpl::PostgresqlTest).await;
+        let merchant_id = utils::generate_id(16, "merchant");
+
+        // Created before the merchant moved to the KV storage scheme
+        let customer = state
+            .store
+            .insert_customer(
+                customer_new(&merchant_id, "John"),
+                enums::MerchantStorageScheme::PostgresOnly,
+            )
+            .await
+            .unwrap();
+
+        let found_customer = state
+            .store
+            .find_customer_by_customer_id_merchant_id(
+                &customer.customer_id,
+                &merchant_id,
+                enums::MerchantStorageScheme::RedisKv,
+            )
+            .await
+            .unwrap();
+        assert_eq!(found_customer.name.as_deref(), Some("John"));
+
+        // The update is queued for the drainer, the database is not updated yet
+        let updated_customer = state
+            .store
+            .update_customer_by_customer_id_merchant_id(
+                customer.customer_id.clone(),
+                merchant_id.clone(),
+                name_update("Jane"),
+                enums::MerchantStorageScheme::RedisKv,
+            )
+            .await
+            .unwrap();
+        assert_eq!(updated_customer.name.as_deref(), Some("Jane"));
+
+        let kv_customer = state
+            .store
+            .find_customer_by_customer_id_merchant_id(
+                &customer.customer_id,
+                &merchant_id,
+                enums::MerchantStorageScheme::RedisKv,
+            )
+            .await
+            .unwrap();
+        assert_eq!(kv_customer.name.as_deref(), Some("Jane"));
+
+        let database_customer = state
+            .store
+            .find_customer_by_customer_id_merchant_id(
+                &customer.customer_id,
+                &merchant_id,
+                enums::MerchantStorageScheme::PostgresOnly,
+            )
+            .await
+            .unwrap();
+        assert_eq!(database_customer.name.as_deref(), Some("John"));
+    }
+
+    #[actix_rt::test]
+    async fn test_customer_is_written_back_through_the_drainer() {
+        let state =
+            AppState::with_storage(Settings::new().unwrap(), StorageImpl::PostgresqlTest).await;
+        let merchant_id = utils::generate_id(16, "merchant");
+
+        let customer = state
+            .store
+            .insert_customer(
+                customer_new(&merchant_id, "John"),
+                enums::MerchantStorageScheme::RedisKv,
+            )
+            .await
+            .unwrap();
+
+        let kv_customer = state
+            .store
+            .find_customer_optional_by_customer_id_merchant_id(
+                &customer.customer_id,
+                &merchant_id,
+                enums::MerchantStorageScheme::RedisKv,
+            )
+            .await
+            .unwrap();
+        assert_eq!(kv_customer.unwrap().name.as_deref(), Some("John"));
+
+        // The insert is queued for the drainer, the database is not written to yet
+        let database_customer = state
+            .store
+            .find_customer_optional_by_customer_id_merchant_id(
+                &customer.customer_id,
+                &merchant_id,
+                enums::MerchantStorageScheme::PostgresOnly,
+            )
+            .await
+            .unwrap();
+        assert!(database_customer.is_none());
+
+        let duplicate_error = state
+            .store
+            .insert_customer(
+                storage::CustomerNew {
+                    customer_id: customer.customer_id.clone(),
+                    ..customer_new(&merchant_id, "Jane")
+                },
+                enums::MerchantStorageScheme::RedisKv,
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            duplicate_error.current_context(),
+            errors::StorageError::DuplicateValue { .. }
+        ));
+    }
+
+    #[actix_rt::test]
+    async fn test_customer_delete_is_queued_after_pending_writes() {
+        let state =
+            AppState::with_storage(Settings::new().unwrap(), StorageImpl::PostgresqlTest).await;
+        let merchant_id = utils::generate_id(16, "merchant");
+
+        let customer = state
+            .store
+            .insert_customer(
+                customer_new(&merchant_id, "John"),
+                enums::MerchantStorageScheme::RedisKv,
+            )
+            .await
+            .unwrap();
+
+        let deleted = state
+            .store
+            .delete_customer_by_customer_id_merchant_id(
+                &customer.customer_id,
+                &merchant_id,
+                enums::MerchantStorageScheme::RedisKv,
+            )
+            .await
+            .unwrap();
+        assert!(deleted);
+
+        // Neither redis nor the database has the customer, until the drainer inserts and then
+        // deletes it
+        let found_customer = state
+            .store
+            .find_customer_optional_by_customer_id_merchant_id(
+                &customer.customer_id,
+                &merchant_id,
+                enums::MerchantStorageScheme::RedisKv,
+            )
+            .await
+            .unwrap();
+        assert!(found_customer.is_none());
+
+        let delete_error = state
+            .store
+            .delete_customer_by_customer_id_merchant_id(
+                &customer.customer_id,
+                &merchant_id,
+                enums::MerchantStorageScheme::RedisKv,
+            )
+            .await
+            .unwrap_err();
+        assert!(delete_error.current_context().is_db_not_found());
+    }
+
+    #[actix_rt::test]
+    async fn test_customer_created_before_kv_is_deleted_through_the_drainer() {
+        let state =
+            AppState::with_storage(Settings::new().unwrap(), StorageImpl::PostgresqlTest).await;
+        let merchant_id = utils::generate_id(16, "merchant");
+
+        let customer = state
+            .store
+            .insert_customer(
+                customer_new(&merchant_id, "John"),
+                enums::MerchantStorageScheme::PostgresOnly,
+            )
+            .await
+            .unwrap();
+
+        let deleted = state
+            .store
+            .delete_customer_by_customer_id_merchant_id(
+                &customer.customer_id,
+                &merchant_id,
+                enums::MerchantStorageScheme::RedisKv,
+            )
+            .await
+            .unwrap();
+        assert!(deleted);
+
+        // The delete is queued for the drainer, the database is not written to yet
+        let database_customer = state
+            .store
+            .find_customer_optional_by_customer_id_merchant_id(
+                &customer.customer_id,
+                &merchant_id,
+                enums::MerchantStorageScheme::PostgresOnly,
+            )
+            .await
+            .unwrap();
+        assert!(database_customer.is_some());
+    }
+}
@@ -0,0 +1,232 @@
+use error_stack::IntoReport;
+use storage_models::errors::DatabaseError;
+
+use super::{MockDb, Store};
+use crate::{
+    connection::pg_connection,
+    core::errors::{self, CustomResult},
+    types::storage::{self, DisputeDbExt},
+};
+
+#[async_trait::async_trait]
+pub trait DisputeInterface {
+    async fn insert_dispute(
+        &self,
+        dispute: storage::DisputeNew,
+    ) -> CustomResult<storage::Dispute, errors::StorageError>;
+
+    async fn find_dispute_by_merchant_id_payment_id_connector_dispute_id(
+        &self,
+        merchant_id: &str,
+        payment_id: &str,
+        connector_dispute_id: &str,
+    ) -> CustomResult<Option<storage::Dispute>, errors::StorageError>;
+
+    async fn find_dispute_by_merchant_id_dispute_id(
+        &self,
+        merchant_id: &str,
+        dispute_id: &str,
+    ) -> CustomResult<storage::Dispute, errors::StorageError>;
+
+    async fn find_disputes_by_merchant_id(
+        &self,
+        merchant_id: &str,
+        dispute_constraints: api_models::disputes::DisputeListConstraints,
+    ) -> CustomResult<Vec<storage::Dispute>, errors::StorageError>;
+
+    async fn update_dispute(
+        &self,
+        this: storage::Dispute,
+        dispute: storage::DisputeUpdate,
+    ) -> CustomResult<storage::Dispute, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl DisputeInterface for Store {
+    async fn insert_dispute(
+        &self,
+        dispute: storage::DisputeNew,
+    ) -> CustomResult<storage::Dispute, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await?;
+        dispute
+            .insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_dispute_by_merchant_id_payment_id_connector_dispute_id(
+        &self,
+        merchant_id: &str,
+        payment_id: &str,
+        connector_dispute_id: &str,
+    ) -> CustomResult<Option<storage::Dispute>, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await?;
+        storage::Dispute::find_by_merchant_id_payment_id_connector_dispute_id(
+            &conn,
+            merchant_id,
+            payment_id,
+            connector_dispute_id,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+
+    async fn find_dispute_by_merchant_id_dispute_id(
+        &self,
+        merchant_id: &str,
+        dispute_id: &str,
+    ) -> CustomResult<storage::Dispute, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await?;
+        storage::Dispute::find_by_merchant_id_dispute_id(&conn, merchant_id, dispute_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_disputes_by_merchant_id(
+        &self,
+        merchant_id: &str,
+        dispute_constraints: api_models::disputes::DisputeListConstraints,
+    ) -> CustomResult<Vec<storage::Dispute>, errors::StorageError> {
+        let conn = pg_connection(&self.replica_pool).await?;
+        storage::Dispute::filter_by_constraints(&conn, merchant_id, dispute_constraints)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn update_dispute(
+        &self,
+        this: storage::Dispute,
+        dispute: storage::DisputeUpdate,
+    ) -> CustomResult<storage::Dispute, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await?;
+        this.update(&conn, dispute)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl DisputeInterface for MockDb {
+    async fn insert_dispute(
+        &self,
+        new: storage::DisputeNew,
+    ) -> CustomResult<storage::Dispute, errors::StorageError> {
+        let mut disputes = self.disputes.lock().await;
+        let current_time = common_utils::date_time::now();
+
+        let dispute = storage::Dispute {
+            #[allow(clippy::as_conversions)]
+            id: disputes.len() as i32,
+            dispute_id: new.dispute_id,
+            amount: new.amount,
+            currency: new.currency,
+            dispute_stage: new.dispute_stage,
+            dispute_status: new.dispute_status,
+            payment_id: new.payment_id,
+            attempt_id: new.attempt_id,
+            merchant_id: new.merchant_id,
+            connector: new.connector,
+            connector_status: new.connector_status,
+            connector_dispute_id: new.connector_dispute_id,
+            connector_reason: new.connector_reason,
+            connector_reason_code: new.connector_reason_code,
+            challenge_required_by: new.challenge_required_by,
+            evidence: None,
+            connector_created_at: new.connector_created_at,
+            connector_updated_at: new.connector_updated_at,
+            created_at: current_time,
+            modified_at: current_time,
+        };
+        disputes.push(dispute.clone());
+        Ok(dispute)
+    }
+
+    async fn find_dispute_by_merchant_id_payment_id_connector_dispute_id(
+        &self,
+        merchant_id: &str,
+        payment_id: &str,
+        connector_dispute_id: &str,
+    ) -> CustomResult<Option<storage::Dispute>, errors::StorageError> {
+        let disputes = self.disputes.lock().await;
+
+        Ok(disputes
+            .iter()
+            .find(|dispute| {
+                dispute.merchant_id == merchant_id
+                    && dispute.payment_id == payment_id
+                    && dispute.connector_dispute_id == connector_dispute_id
+            })
+            .cloned())
+    }
+
+    async fn find_dispute_by_merchant_id_dispute_id(
+        &self,
+        merchant_id: &str,
+        dispute_id: &str,
+    ) -> CustomResult<storage::Dispute, errors::StorageError> {
+        let disputes = self.disputes.lock().await;
+
+        disputes
+            .iter()
+            .find(|dispute| dispute.merchant_id == merchant_id && dispute.dispute_id == dispute_id)
+            .cloned()
+            .ok_or_else(|| {
+                errors::StorageError::DatabaseError(DatabaseError::NotFound.into()).into()
+            })
+    }
+
+    async fn find_disputes_by_merchant_id(
+        &self,
+        merchant_id: &str,
+        dispute_constraints: api_models::disputes::DisputeListConstraints,
+    ) -> CustomResult<Vec<storage::Dispute>, errors::StorageError> {
+        let disputes = self.disputes.lock().await;
+        let limit = dispute_constraints
+            .limit
+            .and_then(|limit| usize::try_from(limit).ok());
+
+        Ok(disputes
+            .iter()
+            .rev()
+            .filter(|dispute| {
+                dispute.merchant_id == merchant_id
+                    && dispute_constraints
+                        .payment_id
+                        .as_ref()
+                        .map_or(true, |payment_id| &dispute.payment_id == payment_id)
+                    && dispute_constraints
+                        .connector
+                        .as_ref()
+                        .map_or(true, |connector| &dispute.connector == connector)
+            })
+            .take(limit.unwrap_or(usize::MAX))
+            .cloned()
+            .collect())
+    }
+
+    async fn update_dispute(
+        &self,
+        this: storage::Dispute,
+        dispute: storage::DisputeUpdate,
+    ) -> CustomResult<storage::Dispute, errors::StorageError> {
+        let mut disputes = self.disputes.lock().await;
+
+        let stored = disputes
+            .iter_mut()
+            .find(|stored| {
+                stored.merchant_id == this.merchant_id && stored.dispute_id == this.dispute_id
+            })
+            .ok_or_else(|| {
+                error_stack::report!(errors::StorageError::DatabaseError(
+                    DatabaseError::NotFound.into()
+                ))
+            })?;
+        *stored = dispute.apply_changeset(stored.clone());
+        Ok(stored.clone())
+    }
+}
@@ -0,0 +1,85 @@
+use error_stack::IntoReport;
+
+use super::{MockDb, Store};
+use crate::{
+    connection::pg_connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait EventDeliveryAttemptInterface {
+    async fn insert_event_delivery_attempt(
+        &self,
+        attempt: storage::EventDeliveryAttemptNew,
+    ) -> CustomResult<storage::EventDeliveryAttempt, errors::StorageError>;
+
+    async fn find_event_delivery_attempts_by_event_id(
+        &self,
+        event_id: &str,
+    ) -> CustomResult<Vec<storage::EventDeliveryAttempt>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl EventDeliveryAttemptInterface for Store {
+    async fn insert_event_delivery_attempt(
+        &self,
+        attempt: storage::EventDeliveryAttemptNew,
+    ) -> CustomResult<storage::EventDeliveryAttempt, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await?;
+        attempt
+            .insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_event_delivery_attempts_by_event_id(
+        &self,
+        event_id: &str,
+    ) -> CustomResult<Vec<storage::EventDeliveryAttempt>, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await?;
+        storage::EventDeliveryAttempt::find_by_event_id(&conn, event_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl EventDeliveryAttemptInterface for MockDb {
+    async fn insert_event_delivery_attempt(
+        &self,
+        attempt: storage::EventDeliveryAttemptNew,
+    ) -> CustomResult<storage::EventDeliveryAttempt, errors::StorageError> {
+        let mut attempts = self.event_delivery_attempts.lock().await;
+
+        let stored_attempt = storage::EventDeliveryAttempt {
+            #[allow(clippy::as_conversions)]
+            id: attempts.len() as i32,
+            event_id: attempt.event_id,
+            merchant_id: attempt.merchant_id,
+            endpoint_id: attempt.endpoint_id,
+            attempt_number: attempt.attempt_number,
+            delivered: attempt.delivered,
+            response_status_code: attempt.response_status_code,
+            error_message: attempt.error_message,
+            created_at: common_utils::date_time::now(),
+        };
+        attempts.push(stored_attempt.clone());
+        Ok(stored_attempt)
+    }
+
+    async fn find_event_delivery_attempts_by_event_id(
+        &self,
+        event_id: &str,
+    ) -> CustomResult<Vec<storage::EventDeliveryAttempt>, errors::StorageError> {
+        let attempts = self.event_delivery_attempts.lock().await;
+
+        Ok(attempts
+            .iter()
+            .filter(|attempt| attempt.event_id == event_id)
+            .cloned()
+            .collect())
+    }
+}
@@ -1,10 +1,14 @@
 use error_stack::IntoReport;
+use storage_models::errors::DatabaseError;
 
 use super::{MockDb, Store};
 use crate::{
     connection::pg_connection,
     core::errors::{self, CustomResult},
-    types::storage,
+    types::{
+        storage::{self, EventDbExt},
+        transformers::ForeignInto,
+    },
 };
 
 #[async_trait::async_trait]
@@ -13,6 +17,23 @@ pub trait EventInterface {
         &self,
         event: storage::EventNew,
     ) -> CustomResult<storage::Event, errors::StorageError>;
+
+    async fn find_event_by_event_id(
+        &self,
+        event_id: &str,
+    ) -> CustomResult<storage::Event, errors::StorageError>;
+
+    async fn find_events_by_merchant_id(
+        &self,
+        merchant_id: &str,
+        event_constraints: api_models::events::EventListConstraints,
+    ) -> CustomResult<Vec<storage::Event>, errors::StorageError>;
+
+    async fn update_event(
+        &self,
+        this: storage::Event,
+        event: storage::EventUpdate,
+    ) -> CustomResult<storage::Event, errors::StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -24,15 +45,135 @@ impl EventInterface for Store {
         let conn = pg_connection(&self.master_pool).await?;
         event.insert(&conn).await.map_err(Into::into).into_report()
     }
+
+    async fn find_event_by_event_id(
+        &self,
+        event_id: &str,
+    ) -> CustomResult<storage::Event, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await?;
+        storage::Event::find_by_event_id(&conn, event_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_events_by_merchant_id(
+        &self,
+        merchant_id: &str,
+        event_constraints: api_models::events::EventListConstraints,
+    ) -> CustomResult<Vec<storage::Event>, errors::StorageError> {
+        let conn = pg_connection(&self.replica_pool).await?;
+        storage::Event::filter_by_constraints(&conn, merchant_id, event_constraints)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn update_event(
+        &self,
+        this: storage::Event,
+        event: storage::EventUpdate,
+    ) -> CustomResult<storage::Event, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await?;
+        this.update(&conn, event)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
 }
 
 #[async_trait::async_trait]
 impl EventInterface for MockDb {
     async fn insert_event(
         &self,
-        _event: storage::EventNew,
+        event: storage::EventNew,
+    ) -> CustomResult<storage::Event, errors::StorageError> {
+        let mut events = self.events.lock().await;
+
+        let stored_event = storage::Event {
+            #[allow(clippy::as_conversions)]
+            id: events.len() as i32,
+            event_id: event.event_id,
+            event_type: event.event_type,
+            event_class: event.event_class,
+            is_webhook_notified: event.is_webhook_notified,
+            intent_reference_id: event.intent_reference_id,
+            primary_object_id: event.primary_object_id,
+            primary_object_type: event.primary_object_type,
+            created_at: event.created_at,
+            webhook_delivery_status: event.webhook_delivery_status,
+            merchant_id: event.merchant_id,
+            request_body: event.request_body,
+        };
+        events.push(stored_event.clone());
+        Ok(stored_event)
+    }
+
+    async fn find_event_by_event_id(
+        &self,
+        event_id: &str,
+    ) -> CustomResult<storage::Event, errors::StorageError> {
+        let events = self.events.lock().await;
+
+        events
+            .iter()
+            .find(|event| event.event_id == event_id)
+            .cloned()
+            .ok_or_else(|| {
+                errors::StorageError::DatabaseError(DatabaseError::NotFound.into()).into()
+            })
+    }
+
+    async fn find_events_by_merchant_id(
+        &self,
+        merchant_id: &str,
+        event_constraints: api_models::events::EventListConstraints,
+    ) -> CustomResult<Vec<storage::Event>, errors::StorageError> {
+        let events = self.events.lock().await;
+        let limit = event_constraints
+            .limit
+            .and_then(|limit| usize::try_from(limit).ok());
+        let event_type: Option<storage::enums::EventType> =
+            event_constraints.event_type.map(ForeignInto::foreign_into);
+
+        Ok(events
+            .iter()
+            .rev()
+            .filter(|event| {
+                event.merchant_id.as_deref() == Some(merchant_id)
+                    && event_type.map_or(true, |event_type| event.event_type == event_type)
+                    && event_constraints
+                        .object_id
+                        .as_ref()
+                        .map_or(true, |object_id| &event.primary_object_id == object_id)
+                    && event_constraints
+                        .created_time_lt
+                        .map_or(true, |created_time_lt| event.created_at < created_time_lt)
+                    && event_constraints
+                        .created_time_gt
+                        .map_or(true, |created_time_gt| event.created_at > created_time_gt)
+            })
+            .take(limit.unwrap_or(usize::MAX))
+            .cloned()
+            .collect())
+    }
+
+    async fn update_event(
+        &self,
+        this: storage::Event,
+        event: storage::EventUpdate,
     ) -> CustomResult<storage::Event, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let mut events = self.events.lock().await;
+
+        let stored_event = events
+            .iter_mut()
+            .find(|stored_event| stored_event.event_id == this.event_id)
+            .ok_or_else(|| {
+                error_stack::report!(errors::StorageError::DatabaseError(
+                    DatabaseError::NotFound.into()
+                ))
+            })?;
+        *stored_event = event.apply_changeset(stored_event.clone());
+        Ok(stored_event.clone())
     }
 }
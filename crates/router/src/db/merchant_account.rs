@@ -215,6 +215,10 @@ impl MerchantAccountInterface for MockDb {
             storage_scheme: enums::MerchantStorageScheme::PostgresOnly,
             locker_id: merchant_account.locker_id,
             metadata: merchant_account.metadata,
+            previous_payment_response_hash_key: None,
+            previous_payment_response_hash_key_expires_at: None,
+            rate_limits: merchant_account.rate_limits,
+            payment_expiry: merchant_account.payment_expiry,
         };
         accounts.push(account.clone());
         Ok(account)
@@ -1,6 +1,7 @@
 use common_utils::ext_traits::{ByteSliceExt, Encode};
 use error_stack::{IntoReport, ResultExt};
 use masking::ExposeInterface;
+use storage_models::errors::DatabaseError;
 
 use super::{MockDb, Store};
 use crate::{
@@ -33,25 +34,38 @@ impl ConnectorAccessToken for Store {
         merchant_id: &str,
         connector_name: &str,
     ) -> CustomResult<Option<types::AccessToken>, errors::StorageError> {
-        //TODO: Handle race condition
-        // This function should acquire a global lock on some resource, if access token is already
-        // being refreshed by other request then wait till it finishes and use the same access token
         let key = format!("access_token_{merchant_id}_{connector_name}");
-        let maybe_token = self
+        let redis_conn = self
             .redis_conn()
-            .map_err(Into::<errors::StorageError>::into)?
+            .map_err(Into::<errors::StorageError>::into)?;
+        let maybe_token = redis_conn
             .get_key::<Option<Vec<u8>>>(&key)
             .await
             .change_context(errors::StorageError::KVError)
             .attach_printable("DB error when getting access token")?;
 
-        let access_token: Option<types::AccessToken> = maybe_token
-            .map(|token| token.parse_struct("AccessToken"))
-            .transpose()
-            .change_context(errors::ParsingError)
-            .change_context(errors::StorageError::DeserializationFailed)?;
+        let mut access_token: types::AccessToken = match maybe_token {
+            Some(token) => token
+                .parse_struct("AccessToken")
+                .change_context(errors::ParsingError)
+                .change_context(errors::StorageError::DeserializationFailed)?,
+            None => return Ok(None),
+        };
+
+        // The time left until the token expires is the time left until the key expires, the
+        // lifetime the token was issued with is stored along with it
+        let ttl = redis_conn
+            .get_ttl(&key)
+            .await
+            .change_context(errors::StorageError::KVError)
+            .attach_printable("DB error when getting the expiry of the access token")?;
+        if ttl <= 0 {
+            // The token expired between the two reads
+            return Ok(None);
+        }
+        access_token.expires = ttl;
 
-        Ok(access_token)
+        Ok(Some(access_token))
     }
 
     async fn set_access_token(
@@ -61,6 +75,10 @@ impl ConnectorAccessToken for Store {
         access_token: types::AccessToken,
     ) -> CustomResult<(), errors::StorageError> {
         let key = format!("access_token_{merchant_id}_{connector_name}");
+        let access_token = types::AccessToken {
+            lifetime: Some(access_token.lifetime.unwrap_or(access_token.expires)),
+            ..access_token
+        };
         let serialized_access_token =
             Encode::<types::AccessToken>::encode_to_string_of_json(&access_token)
                 .change_context(errors::StorageError::SerializationFailed)?;
@@ -80,18 +98,25 @@ impl ConnectorAccessToken for Store {
 impl ConnectorAccessToken for MockDb {
     async fn get_access_token(
         &self,
-        _merchant_id: &str,
-        _connector_name: &str,
+        merchant_id: &str,
+        connector_name: &str,
     ) -> CustomResult<Option<types::AccessToken>, errors::StorageError> {
-        Ok(None)
+        let key = format!("access_token_{merchant_id}_{connector_name}");
+        Ok(self.access_tokens.lock().await.get(&key).cloned())
     }
 
     async fn set_access_token(
         &self,
-        _merchant_id: &str,
-        _connector_name: &str,
-        _access_token: types::AccessToken,
+        merchant_id: &str,
+        connector_name: &str,
+        access_token: types::AccessToken,
     ) -> CustomResult<(), errors::StorageError> {
+        let key = format!("access_token_{merchant_id}_{connector_name}");
+        let access_token = types::AccessToken {
+            lifetime: Some(access_token.lifetime.unwrap_or(access_token.expires)),
+            ..access_token
+        };
+        self.access_tokens.lock().await.insert(key, access_token);
         Ok(())
     }
 }
@@ -251,14 +276,15 @@ impl MerchantConnectorAccountInterface for MockDb {
         connector: &str,
     ) -> CustomResult<storage::MerchantConnectorAccount, errors::StorageError> {
         let accounts = self.merchant_connector_accounts.lock().await;
-        let account = accounts
+        accounts
             .iter()
             .find(|account| {
                 account.merchant_id == merchant_id && account.connector_name == connector
             })
             .cloned()
-            .unwrap();
-        Ok(account)
+            .ok_or_else(|| {
+                errors::StorageError::DatabaseError(DatabaseError::NotFound.into()).into()
+            })
     }
 
     async fn find_by_merchant_connector_account_merchant_id_merchant_connector_id(
@@ -287,6 +313,8 @@ impl MerchantConnectorAccountInterface for MockDb {
             merchant_connector_id: t.merchant_connector_id,
             payment_methods_enabled: t.payment_methods_enabled,
             metadata: t.metadata,
+            authorization_ttl_hours: t.authorization_ttl_hours,
+            fee_schedule: t.fee_schedule,
             connector_type: t
                 .connector_type
                 .unwrap_or(crate::types::storage::enums::ConnectorType::FinOperations),
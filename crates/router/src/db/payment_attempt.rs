@@ -245,6 +245,8 @@ impl PaymentAttemptInterface for MockDb {
             payment_experience: payment_attempt.payment_experience,
             payment_method_type: payment_attempt.payment_method_type,
             payment_method_data: payment_attempt.payment_method_data,
+            capture_delay_hours: payment_attempt.capture_delay_hours,
+            estimated_fee: payment_attempt.estimated_fee,
         };
         payment_attempts.push(payment_attempt.clone());
         Ok(payment_attempt)
@@ -385,6 +387,8 @@ mod storage {
                         payment_experience: payment_attempt.payment_experience.clone(),
                         payment_method_type: payment_attempt.payment_method_type.clone(),
                         payment_method_data: payment_attempt.payment_method_data.clone(),
+                        capture_delay_hours: payment_attempt.capture_delay_hours,
+                        estimated_fee: payment_attempt.estimated_fee,
                     };
 
                     let field = format!("pa_{}", created_attempt.attempt_id);
@@ -603,7 +607,7 @@ mod storage {
             )
             .await
             .and_then(|attempt| match attempt.status {
-                enums::AttemptStatus::Charged => Ok(attempt),
+                enums::AttemptStatus::Charged | enums::AttemptStatus::PartialCharged => Ok(attempt),
                 _ => Err(errors::StorageError::ValueNotFound(format!(
                     "Successful payment attempt does not exist for {payment_id}_{merchant_id}"
                 )))
@@ -92,6 +92,8 @@ mod storage {
                         setup_future_usage: new.setup_future_usage,
                         off_session: new.off_session,
                         client_secret: new.client_secret.clone(),
+                        request_incremental_authorization: new.request_incremental_authorization,
+                        authorization_count: None,
                     };
 
                     match self
@@ -347,6 +349,8 @@ impl PaymentIntentInterface for MockDb {
             setup_future_usage: new.setup_future_usage,
             off_session: new.off_session,
             client_secret: new.client_secret,
+            request_incremental_authorization: new.request_incremental_authorization,
+            authorization_count: None,
         };
         payment_intents.push(payment_intent.clone());
         Ok(payment_intent)
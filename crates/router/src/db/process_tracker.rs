@@ -1,4 +1,5 @@
 use error_stack::IntoReport;
+use storage_models::errors::DatabaseError;
 use time::PrimitiveDateTime;
 
 use super::{MockDb, Store};
@@ -164,22 +165,46 @@ impl ProcessTrackerInterface for MockDb {
 
     async fn reinitialize_limbo_processes(
         &self,
-        _ids: Vec<String>,
-        _schedule_time: PrimitiveDateTime,
+        ids: Vec<String>,
+        schedule_time: PrimitiveDateTime,
     ) -> CustomResult<usize, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let mut processes = self.processes.lock().await;
+        let limbo_processes = processes.iter_mut().filter(|process| {
+            process.status == enums::ProcessTrackerStatus::ProcessStarted
+                && ids.contains(&process.id)
+        });
+
+        let mut count = 0;
+        for process in limbo_processes {
+            process.status = enums::ProcessTrackerStatus::Processing;
+            process.schedule_time = Some(schedule_time);
+            count += 1;
+        }
+        Ok(count)
     }
 
     async fn find_processes_by_time_status(
         &self,
-        _time_lower_limit: PrimitiveDateTime,
-        _time_upper_limit: PrimitiveDateTime,
-        _status: enums::ProcessTrackerStatus,
-        _limit: Option<i64>,
+        time_lower_limit: PrimitiveDateTime,
+        time_upper_limit: PrimitiveDateTime,
+        status: enums::ProcessTrackerStatus,
+        limit: Option<i64>,
     ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let processes = self.processes.lock().await;
+        let matching_processes = processes
+            .iter()
+            .filter(|process| {
+                process.status == status
+                    && process.schedule_time.map_or(false, |schedule_time| {
+                        (time_lower_limit..=time_upper_limit).contains(&schedule_time)
+                    })
+            })
+            .cloned();
+
+        Ok(match limit.and_then(|limit| usize::try_from(limit).ok()) {
+            Some(limit) => matching_processes.take(limit).collect(),
+            None => matching_processes.collect(),
+        })
     }
 
     async fn insert_process(
@@ -208,28 +233,46 @@ impl ProcessTrackerInterface for MockDb {
 
     async fn update_process(
         &self,
-        _this: storage::ProcessTracker,
-        _process: storage::ProcessTrackerUpdate,
+        this: storage::ProcessTracker,
+        process: storage::ProcessTrackerUpdate,
     ) -> CustomResult<storage::ProcessTracker, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let mut processes = self.processes.lock().await;
+
+        let stored_process = processes
+            .iter_mut()
+            .find(|stored_process| stored_process.id == this.id)
+            .ok_or_else(|| {
+                error_stack::report!(errors::StorageError::DatabaseError(
+                    DatabaseError::NotFound.into()
+                ))
+            })?;
+        *stored_process = process.apply_changeset(stored_process.clone());
+        Ok(stored_process.clone())
     }
 
     async fn update_process_tracker(
         &self,
-        _this: storage::ProcessTracker,
-        _process: storage::ProcessTrackerUpdate,
+        this: storage::ProcessTracker,
+        process: storage::ProcessTrackerUpdate,
     ) -> CustomResult<storage::ProcessTracker, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        self.update_process(this, process).await
     }
 
     async fn process_tracker_update_process_status_by_ids(
         &self,
-        _task_ids: Vec<String>,
-        _task_update: storage::ProcessTrackerUpdate,
+        task_ids: Vec<String>,
+        task_update: storage::ProcessTrackerUpdate,
     ) -> CustomResult<usize, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let mut processes = self.processes.lock().await;
+
+        let mut count = 0;
+        for process in processes
+            .iter_mut()
+            .filter(|process| task_ids.contains(&process.id))
+        {
+            *process = task_update.clone().apply_changeset(process.clone());
+            count += 1;
+        }
+        Ok(count)
     }
 }
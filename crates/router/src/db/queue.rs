@@ -1,9 +1,13 @@
+use std::collections::HashMap;
+
+use error_stack::{report, ResultExt};
 use redis_interface::{errors::RedisError, RedisEntryId, SetnxReply};
 use router_env::logger;
 
 use super::{MockDb, Store};
 use crate::{
     core::errors::{CustomResult, ProcessTrackerError},
+    scheduler::{consumer, ProcessTrackerBatch},
     types::storage,
 };
 
@@ -133,57 +137,350 @@ impl QueueInterface for Store {
     }
 }
 
+/// ID of an entry of a [`MockStream`], ordered the same way as redis stream entry IDs
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+struct MockStreamEntryId {
+    milliseconds: u64,
+    sequence_number: u64,
+}
+
+impl MockStreamEntryId {
+    fn parse(milliseconds: &str, sequence_number: &str) -> CustomResult<Self, RedisError> {
+        Ok(Self {
+            milliseconds: milliseconds
+                .parse()
+                .map_err(|_| report!(RedisError::InvalidRedisEntryId))?,
+            sequence_number: sequence_number
+                .parse()
+                .map_err(|_| report!(RedisError::InvalidRedisEntryId))?,
+        })
+    }
+
+    /// The ID redis would generate for an entry appended after this one
+    fn next(self) -> Self {
+        let now = common_utils::date_time::now()
+            .assume_utc()
+            .unix_timestamp_nanos()
+            / 1_000_000;
+        let now = u64::try_from(now).unwrap_or_default();
+
+        if now > self.milliseconds {
+            Self {
+                milliseconds: now,
+                sequence_number: 0,
+            }
+        } else {
+            Self {
+                milliseconds: self.milliseconds,
+                sequence_number: self.sequence_number + 1,
+            }
+        }
+    }
+}
+
+/// In-memory consumer group of a [`MockStream`]
+#[derive(Debug, Default)]
+struct MockConsumerGroup {
+    /// ID of the last entry delivered to the group
+    last_delivered_id: MockStreamEntryId,
+    /// IDs of the entries delivered to the group which are yet to be acknowledged
+    pending_entry_ids: Vec<MockStreamEntryId>,
+}
+
+/// In-memory redis stream, along with its consumer groups
+#[derive(Debug, Default)]
+pub struct MockStream {
+    entries: Vec<(MockStreamEntryId, HashMap<String, String>)>,
+    last_entry_id: MockStreamEntryId,
+    groups: HashMap<String, MockConsumerGroup>,
+}
+
+impl MockStream {
+    fn append(
+        &mut self,
+        entry_id: &RedisEntryId,
+        fields: Vec<(&str, String)>,
+    ) -> CustomResult<(), RedisError> {
+        let entry_id = match entry_id {
+            RedisEntryId::AutoGeneratedID => self.last_entry_id.next(),
+            RedisEntryId::UserSpecifiedID {
+                milliseconds,
+                sequence_number,
+            } => MockStreamEntryId::parse(milliseconds, sequence_number)
+                .change_context(RedisError::StreamAppendFailed)?,
+            RedisEntryId::AfterLastID | RedisEntryId::UndeliveredEntryID => {
+                Err(RedisError::StreamAppendFailed)?
+            }
+        };
+        if entry_id <= self.last_entry_id {
+            Err(report!(RedisError::StreamAppendFailed)).attach_printable(
+                "The entry ID must be greater than the ID of the last entry of the stream",
+            )?;
+        }
+
+        let fields = fields
+            .into_iter()
+            .map(|(field, value)| (field.to_owned(), value))
+            .collect();
+        self.entries.push((entry_id, fields));
+        self.last_entry_id = entry_id;
+        Ok(())
+    }
+
+    /// Delivers the oldest entry not yet delivered to the consumer group, the same way as
+    /// `XREADGROUP` with the `>` ID. The entry stays pending for the group until it is
+    /// acknowledged, and stays in the stream until it is deleted.
+    fn read_undelivered_entry(
+        &mut self,
+        group_name: &str,
+    ) -> CustomResult<Option<(MockStreamEntryId, HashMap<String, String>)>, RedisError> {
+        let group = self
+            .groups
+            .get_mut(group_name)
+            .ok_or_else(|| report!(RedisError::StreamReadFailed))
+            .attach_printable_lazy(|| format!("Consumer group {group_name} does not exist"))?;
+
+        let entry = self
+            .entries
+            .iter()
+            .find(|(entry_id, _)| *entry_id > group.last_delivered_id)
+            .cloned();
+        if let Some((entry_id, _)) = entry {
+            group.last_delivered_id = entry_id;
+            group.pending_entry_ids.push(entry_id);
+        }
+        Ok(entry)
+    }
+
+    /// Removes the entries from the pending entries of the consumer group, the same way as
+    /// `XACK`. Returns the number of entries which were pending.
+    fn acknowledge_entries(
+        &mut self,
+        group_name: &str,
+        entry_ids: &[MockStreamEntryId],
+    ) -> CustomResult<usize, RedisError> {
+        let group = self
+            .groups
+            .get_mut(group_name)
+            .ok_or_else(|| report!(RedisError::StreamAcknowledgeFailed))
+            .attach_printable_lazy(|| format!("Consumer group {group_name} does not exist"))?;
+
+        let pending_count = group.pending_entry_ids.len();
+        group
+            .pending_entry_ids
+            .retain(|entry_id| !entry_ids.contains(entry_id));
+        Ok(pending_count - group.pending_entry_ids.len())
+    }
+
+    /// Removes the entries from the stream, the same way as `XDEL`. Entries pending for a
+    /// consumer group stay pending, as in redis. Returns the number of entries removed.
+    fn delete_entries(&mut self, entry_ids: &[MockStreamEntryId]) -> usize {
+        let entry_count = self.entries.len();
+        self.entries
+            .retain(|(entry_id, _)| !entry_ids.contains(entry_id));
+        entry_count - self.entries.len()
+    }
+}
+
 #[async_trait::async_trait]
 impl QueueInterface for MockDb {
     async fn fetch_consumer_tasks(
         &self,
-        _stream_name: &str,
-        _group_name: &str,
+        stream_name: &str,
+        group_name: &str,
         _consumer_name: &str,
     ) -> CustomResult<Vec<storage::ProcessTracker>, ProcessTrackerError> {
-        // [#172]: Implement function for `MockDb`
-        Err(ProcessTrackerError::ResourceFetchingFailed {
-            resource_name: "consumer_tasks",
-        })?
+        let entry = {
+            let mut streams = self.streams.lock().await;
+            let stream = streams
+                .get_mut(stream_name)
+                .ok_or_else(|| report!(RedisError::StreamReadFailed))
+                .change_context(ProcessTrackerError::BatchNotFound)?;
+            let entry = stream
+                .read_undelivered_entry(group_name)
+                .change_context(ProcessTrackerError::BatchNotFound)?;
+
+            // The entry is acknowledged and deleted once read, the same way as the batches read
+            // from redis
+            if let Some((entry_id, _)) = entry {
+                stream
+                    .acknowledge_entries(group_name, &[entry_id])
+                    .change_context(ProcessTrackerError::BatchUpdateFailed)?;
+                stream.delete_entries(&[entry_id]);
+            }
+            entry
+        };
+
+        let batches = entry
+            .map(|(_, fields)| {
+                ProcessTrackerBatch::from_redis_stream_entry(
+                    fields
+                        .into_iter()
+                        .map(|(field, value)| (field, Some(value)))
+                        .collect(),
+                )
+            })
+            .into_iter()
+            .collect::<Result<Vec<_>, _>>()?;
+
+        consumer::start_tasks_in_batches(self, batches).await
     }
 
     async fn consumer_group_create(
         &self,
-        _stream: &str,
-        _group: &str,
-        _id: &RedisEntryId,
+        stream: &str,
+        group: &str,
+        id: &RedisEntryId,
     ) -> CustomResult<(), RedisError> {
-        // [#172]: Implement function for `MockDb`
-        Err(RedisError::ConsumerGroupCreateFailed)?
+        let mut streams = self.streams.lock().await;
+        let stream = streams.entry(stream.to_owned()).or_default();
+
+        let last_delivered_id = match id {
+            RedisEntryId::AfterLastID => stream.last_entry_id,
+            RedisEntryId::UserSpecifiedID {
+                milliseconds,
+                sequence_number,
+            } => MockStreamEntryId::parse(milliseconds, sequence_number)?,
+            RedisEntryId::AutoGeneratedID | RedisEntryId::UndeliveredEntryID => {
+                Err(RedisError::InvalidRedisEntryId)?
+            }
+        };
+        if stream.groups.contains_key(group) {
+            Err(report!(RedisError::ConsumerGroupCreateFailed))
+                .attach_printable_lazy(|| format!("Consumer group {group} already exists"))?;
+        }
+
+        stream.groups.insert(
+            group.to_owned(),
+            MockConsumerGroup {
+                last_delivered_id,
+                pending_entry_ids: Vec::new(),
+            },
+        );
+        Ok(())
     }
 
     async fn acquire_pt_lock(
         &self,
-        _tag: &str,
-        _lock_key: &str,
+        tag: &str,
+        lock_key: &str,
         _lock_val: &str,
-        _ttl: i64,
+        ttl: i64,
     ) -> CustomResult<bool, RedisError> {
-        // [#172]: Implement function for `MockDb`
-        Ok(false)
+        let mut locks = self.locks.lock().await;
+        let now = common_utils::date_time::now();
+
+        if locks.get(lock_key).map_or(false, |expiry| *expiry > now) {
+            logger::error!(%tag, "Lock not acquired, previous fetch still in progress");
+            return Ok(false);
+        }
+        locks.insert(
+            lock_key.to_owned(),
+            now.saturating_add(time::Duration::seconds(ttl)),
+        );
+        Ok(true)
     }
 
-    async fn release_pt_lock(&self, _tag: &str, _lock_key: &str) -> CustomResult<bool, RedisError> {
-        // [#172]: Implement function for `MockDb`
-        Ok(false)
+    async fn release_pt_lock(&self, _tag: &str, lock_key: &str) -> CustomResult<bool, RedisError> {
+        self.locks.lock().await.remove(lock_key);
+        Ok(true)
     }
 
     async fn stream_append_entry(
         &self,
-        _stream: &str,
-        _entry_id: &RedisEntryId,
-        _fields: Vec<(&str, String)>,
+        stream: &str,
+        entry_id: &RedisEntryId,
+        fields: Vec<(&str, String)>,
     ) -> CustomResult<(), RedisError> {
-        // [#172]: Implement function for `MockDb`
-        Err(RedisError::StreamAppendFailed)?
+        self.streams
+            .lock()
+            .await
+            .entry(stream.to_owned())
+            .or_default()
+            .append(entry_id, fields)
     }
 
     async fn get_key(&self, key: &str) -> CustomResult<Vec<u8>, RedisError> {
         self.redis.get_key(key).await
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_mock_stream_delivers_entries_once_in_order() {
+        let mut stream = MockStream::default();
+        stream
+            .append(
+                &RedisEntryId::AutoGeneratedID,
+                vec![("id", "first".to_string())],
+            )
+            .unwrap();
+        stream
+            .groups
+            .insert("group".to_string(), MockConsumerGroup::default());
+        stream
+            .append(
+                &RedisEntryId::AutoGeneratedID,
+                vec![("id", "second".to_string())],
+            )
+            .unwrap();
+
+        let read_id = |stream: &mut MockStream, group_name: &str| {
+            stream
+                .read_undelivered_entry(group_name)
+                .unwrap()
+                .map(|(entry_id, mut fields)| (entry_id, fields.remove("id")))
+        };
+        let (first_id, first) = read_id(&mut stream, "group").unwrap();
+        assert_eq!(first, Some("first".to_string()));
+        let (second_id, second) = read_id(&mut stream, "group").unwrap();
+        assert_eq!(second, Some("second".to_string()));
+        assert!(read_id(&mut stream, "group").is_none());
+        assert!(stream.read_undelivered_entry("other_group").is_err());
+
+        // Reading the entries neither acknowledges nor deletes them
+        assert_eq!(stream.entries.len(), 2);
+        assert_eq!(
+            stream.groups.get("group").unwrap().pending_entry_ids,
+            vec![first_id, second_id]
+        );
+        assert_eq!(stream.acknowledge_entries("group", &[first_id]).unwrap(), 1);
+        assert_eq!(stream.acknowledge_entries("group", &[first_id]).unwrap(), 0);
+        assert_eq!(
+            stream.groups.get("group").unwrap().pending_entry_ids,
+            vec![second_id]
+        );
+
+        // Another group is delivered the entries which are still in the stream
+        stream
+            .groups
+            .insert("other_group".to_string(), MockConsumerGroup::default());
+        assert_eq!(stream.delete_entries(&[first_id]), 1);
+        assert_eq!(stream.delete_entries(&[first_id]), 0);
+        let (entry_id, entry) = read_id(&mut stream, "other_group").unwrap();
+        assert_eq!(entry_id, second_id);
+        assert_eq!(entry, Some("second".to_string()));
+        assert!(read_id(&mut stream, "other_group").is_none());
+    }
+
+    #[test]
+    fn test_mock_stream_rejects_older_entry_ids() {
+        let mut stream = MockStream::default();
+        let entry_id = |milliseconds: &str| RedisEntryId::UserSpecifiedID {
+            milliseconds: milliseconds.to_string(),
+            sequence_number: "0".to_string(),
+        };
+
+        assert!(stream.append(&entry_id("0"), vec![]).is_err());
+        assert!(stream.append(&entry_id("10"), vec![]).is_ok());
+        assert!(stream.append(&entry_id("5"), vec![]).is_err());
+        assert!(stream.append(&RedisEntryId::AfterLastID, vec![]).is_err());
+        assert!(stream
+            .append(&RedisEntryId::AutoGeneratedID, vec![])
+            .is_ok());
+    }
+}
@@ -233,7 +233,7 @@ impl QueryEntity for EntityRequest {
                 .change_context(errors::ApiErrorResponse::PaymentNotFound),
             ),
             EntityRequest::Address { address_id } => Entity::Address(
-                db.find_address(address_id)
+                db.find_address(address_id, storage_scheme)
                     .await
                     .change_context(errors::ApiErrorResponse::AddressNotFound), //FIXME: do not change context
             ),
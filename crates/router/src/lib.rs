@@ -47,6 +47,11 @@ pub mod headers {
     pub const AUTHORIZATION: &str = "Authorization";
     pub const CONTENT_TYPE: &str = "Content-Type";
     pub const DATE: &str = "Date";
+    pub const IDEMPOTENCY_KEY: &str = "Idempotency-Key";
+    pub const RATE_LIMIT_LIMIT: &str = "RateLimit-Limit";
+    pub const RATE_LIMIT_REMAINING: &str = "RateLimit-Remaining";
+    pub const RATE_LIMIT_RESET: &str = "RateLimit-Reset";
+    pub const RETRY_AFTER: &str = "Retry-After";
     pub const TIMESTAMP: &str = "Timestamp";
     pub const X_API_KEY: &str = "X-API-KEY";
     pub const X_API_VERSION: &str = "X-ApiVersion";
@@ -97,6 +102,7 @@ pub fn mk_app(
             .service(routes::Configs::server(state.clone()))
             .service(routes::Refunds::server(state.clone()))
             .service(routes::Payouts::server(state.clone()))
+            .service(routes::Disputes::server(state.clone()))
             .service(routes::MerchantConnectorAccount::server(state.clone()))
             .service(routes::Mandates::server(state.clone()));
     }
@@ -106,6 +112,7 @@ pub fn mk_app(
         server_app = server_app
             .service(routes::PaymentMethods::server(state.clone()))
             .service(routes::EphemeralKey::server(state.clone()))
+            .service(routes::Files::server(state.clone()))
             .service(routes::Webhooks::server(state.clone()));
     }
 
@@ -113,7 +120,10 @@ pub fn mk_app(
     {
         server_app = server_app
             .service(routes::MerchantAccount::server(state.clone()))
-            .service(routes::ApiKeys::server(state.clone()));
+            .service(routes::ConnectorHealth::server(state.clone()))
+            .service(routes::ApiKeys::server(state.clone()))
+            .service(routes::Events::server(state.clone()))
+            .service(routes::Routing::server(state.clone()));
     }
 
     #[cfg(feature = "stripe")]
@@ -165,6 +175,7 @@ pub fn get_application_builder(
 
     actix_web::App::new()
         .app_data(json_cfg)
+        .wrap(middleware::BodyDigest)
         .wrap(middleware::RequestId)
         .wrap(router_env::tracing_actix_web::TracingLogger::default())
         .wrap(ErrorHandlers::new().handler(
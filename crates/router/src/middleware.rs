@@ -59,3 +59,91 @@ where
         })
     }
 }
+
+/// Digest of the raw body of a request made with an idempotency key. The request is fingerprinted
+/// with it, as the body is consumed by the handler before the request is checked for idempotency.
+#[derive(Clone, Debug)]
+pub struct RequestBodyDigest(pub String);
+
+/// Middleware to compute the [`RequestBodyDigest`] of requests made with an idempotency key.
+pub struct BodyDigest;
+
+impl<S, B> actix_web::dev::Transform<S, actix_web::dev::ServiceRequest> for BodyDigest
+where
+    S: actix_web::dev::Service<
+            actix_web::dev::ServiceRequest,
+            Response = actix_web::dev::ServiceResponse<B>,
+            Error = actix_web::Error,
+        > + 'static,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = actix_web::dev::ServiceResponse<B>;
+    type Error = actix_web::Error;
+    type Transform = BodyDigestMiddleware<S>;
+    type InitError = ();
+    type Future = std::future::Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        std::future::ready(Ok(BodyDigestMiddleware {
+            service: std::rc::Rc::new(service),
+        }))
+    }
+}
+
+pub struct BodyDigestMiddleware<S> {
+    service: std::rc::Rc<S>,
+}
+
+impl<S, B> actix_web::dev::Service<actix_web::dev::ServiceRequest> for BodyDigestMiddleware<S>
+where
+    S: actix_web::dev::Service<
+            actix_web::dev::ServiceRequest,
+            Response = actix_web::dev::ServiceResponse<B>,
+            Error = actix_web::Error,
+        > + 'static,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = actix_web::dev::ServiceResponse<B>;
+    type Error = actix_web::Error;
+    type Future = futures::future::LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    actix_web::dev::forward_ready!(service);
+
+    fn call(&self, req: actix_web::dev::ServiceRequest) -> Self::Future {
+        let service = std::rc::Rc::clone(&self.service);
+
+        Box::pin(async move {
+            let mut req = req;
+            if !req.method().is_safe()
+                && req.headers().contains_key(crate::headers::IDEMPOTENCY_KEY)
+            {
+                use actix_web::HttpMessage;
+                use common_utils::crypto::GenerateDigest;
+                use futures::StreamExt;
+
+                let mut payload = req.take_payload();
+                let mut body = bytes::BytesMut::new();
+                while let Some(chunk) = payload.next().await {
+                    body.extend_from_slice(&chunk?);
+                }
+                let body = body.freeze();
+
+                let digest = common_utils::crypto::Sha256
+                    .generate_digest(&body)
+                    .map_err(|_| {
+                        actix_web::error::ErrorInternalServerError(
+                            "Failed to compute the digest of the request body",
+                        )
+                    })?;
+                req.extensions_mut()
+                    .insert(RequestBodyDigest(hex::encode(digest)));
+                // The body is handed back to the handler, which has not read it yet
+                req.set_payload(actix_web::dev::Payload::from(body));
+            }
+
+            service.call(req).await
+        })
+    }
+}
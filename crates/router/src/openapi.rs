@@ -52,8 +52,14 @@ Never share your secret api keys. Keep them guarded and secure.
     tags(
         (name = "Merchant Account", description = "Create and manage merchant accounts"),
         (name = "Merchant Connector Account", description = "Create and manage merchant connector accounts"),
+        (name = "Connector Health", description = "Monitor the health of the connectors"),
         (name = "Payments", description = "Create and manage one-time payments, recurring payments and mandates"),
         (name = "Refunds", description = "Create and manage refunds for successful payments"),
+        (name = "Payouts", description = "Create and manage payouts to cards and bank accounts"),
+        (name = "Disputes", description = "Manage disputes raised against payments"),
+        (name = "Events", description = "View events and the delivery of their webhooks"),
+        (name = "Routing", description = "Simulate the routing of payments to connectors"),
+        (name = "Files", description = "Upload and manage files such as dispute evidence"),
         (name = "Mandates", description = "Manage mandates"),
         (name = "Customers", description = "Create and manage customers"),
         (name = "Payment Methods", description = "Create and manage payment methods of customers"),
@@ -64,10 +70,31 @@ Never share your secret api keys. Keep them guarded and secure.
         crate::routes::refunds::refunds_retrieve,
         crate::routes::refunds::refunds_update,
         crate::routes::refunds::refunds_list,
+        crate::routes::payouts::payouts_create,
+        crate::routes::payouts::payouts_retrieve,
+        crate::routes::payouts::payouts_update,
+        crate::routes::payouts::payouts_fulfill,
+        crate::routes::payouts::payouts_reverse,
+        crate::routes::payouts::payouts_cancel,
+        crate::routes::payouts::payouts_accounts,
+        crate::routes::payouts::payouts_list,
+        crate::routes::disputes::retrieve_dispute,
+        crate::routes::disputes::retrieve_disputes_list,
+        crate::routes::disputes::accept_dispute,
+        crate::routes::disputes::submit_dispute_evidence,
+        crate::routes::events::list_events,
+        crate::routes::events::retrieve_event,
+        crate::routes::events::redeliver_event,
+        crate::routes::routing::simulate_routing,
+        crate::routes::files::files_create,
+        crate::routes::files::files_delete,
+        crate::routes::files::files_retrieve,
         crate::routes::admin::merchant_account_create,
         crate::routes::admin::retrieve_merchant_account,
         crate::routes::admin::update_merchant_account,
         crate::routes::admin::delete_merchant_account,
+        crate::routes::admin::rotate_merchant_webhook_secret,
+        crate::routes::admin::connector_health_retrieve,
         crate::routes::admin::payment_connector_create,
         crate::routes::admin::payment_connector_retrieve,
         crate::routes::admin::payment_connector_list,
@@ -84,6 +111,8 @@ Never share your secret api keys. Keep them guarded and secure.
         crate::routes::payments::payments_connector_session,
        // crate::routes::payments::payments_redirect_response,
         crate::routes::payments::payments_cancel,
+        crate::routes::payments::payments_cancel_scheduled_capture,
+        crate::routes::payments::payments_incremental_authorization,
         crate::routes::payments::payments_list,
         crate::routes::payment_methods::create_payment_method_api,
         crate::routes::payment_methods::list_payment_method_api,
@@ -133,6 +162,8 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::enums::Currency,
         api_models::enums::IntentStatus,
         api_models::enums::CaptureMethod,
+        api_models::enums::CaptureStatus,
+        api_models::enums::CircuitBreakerState,
         api_models::enums::FutureUsage,
         api_models::enums::AuthenticationType,
         api_models::enums::Connector,
@@ -171,6 +202,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::PaymentsRetrieveRequest,
         api_models::payments::PaymentIdType,
         api_models::payments::PaymentsCaptureRequest,
+        api_models::payments::CaptureResponse,
         api_models::payments::PaymentsSessionRequest,
         api_models::payments::PaymentsSessionResponse,
         api_models::payments::SessionToken,
@@ -195,10 +227,43 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::ApplePayWalletData,
         api_models::payments::ApplepayPaymentMethod,
         api_models::payments::PaymentsCancelRequest,
+        api_models::payments::PaymentsIncrementalAuthorizationRequest,
         api_models::payments::PaymentListConstraints,
         api_models::payments::PaymentListResponse,
         api_models::refunds::RefundListRequest,
         api_models::refunds::RefundListResponse,
+        api_models::enums::PayoutStatus,
+        api_models::enums::PayoutType,
+        api_models::payouts::PayoutCreateRequest,
+        api_models::payouts::PayoutUpdateRequest,
+        api_models::payouts::PayoutMethodData,
+        api_models::payouts::PayoutCard,
+        api_models::payouts::PayoutBank,
+        api_models::payouts::PayoutResponse,
+        api_models::payouts::PayoutListConstraints,
+        api_models::payouts::PayoutListResponse,
+        api_models::payouts::PayoutAccount,
+        api_models::payouts::PayoutAccountsResponse,
+        api_models::enums::DisputeStage,
+        api_models::enums::DisputeStatus,
+        api_models::disputes::DisputeResponse,
+        api_models::disputes::DisputeListConstraints,
+        api_models::disputes::DisputeListResponse,
+        api_models::disputes::DisputeEvidence,
+        api_models::events::EventResponse,
+        api_models::events::EventDeliveryAttemptResponse,
+        api_models::events::EventListConstraints,
+        api_models::events::EventListResponse,
+        api_models::routing::RoutingSimulationRequest,
+        api_models::routing::RoutingSimulationResponse,
+        api_models::routing::ExcludedConnector,
+        api_models::enums::EventType,
+        api_models::enums::EventClass,
+        api_models::enums::EventObjectType,
+        api_models::enums::WebhookDeliveryStatus,
+        api_models::files::CreateFileRequestForm,
+        api_models::files::CreateFileResponse,
+        api_models::files::FilePurpose,
         api_models::mandates::MandateRevokedResponse,
         api_models::mandates::MandateResponse,
         api_models::mandates::MandateCardDetails,
@@ -206,6 +271,14 @@ Never share your secret api keys. Keep them guarded and secure.
         crate::types::api::admin::MerchantConnectorId,
         crate::types::api::admin::MerchantDetails,
         crate::types::api::admin::WebhookDetails,
+        crate::types::api::admin::WebhookEndpoint,
+        crate::types::api::admin::RateLimits,
+        crate::types::api::admin::PaymentExpiry,
+        crate::types::api::admin::ConnectorFeeSchedule,
+        crate::types::api::admin::FeeSurcharge,
+        crate::types::api::admin::WebhookSecretRotateRequest,
+        crate::types::api::admin::WebhookSecretRotateResponse,
+        crate::types::api::admin::ConnectorHealthResponse,
         crate::types::api::api_keys::ApiKeyExpiration,
         crate::types::api::api_keys::CreateApiKeyRequest,
         crate::types::api::api_keys::CreateApiKeyResponse,
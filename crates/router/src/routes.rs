@@ -3,7 +3,10 @@ pub mod api_keys;
 pub mod app;
 pub mod configs;
 pub mod customers;
+pub mod disputes;
 pub mod ephemeral_key;
+pub mod events;
+pub mod files;
 pub mod health;
 pub mod mandates;
 pub mod metrics;
@@ -11,11 +14,13 @@ pub mod payment_methods;
 pub mod payments;
 pub mod payouts;
 pub mod refunds;
+pub mod routing;
 pub mod webhooks;
 
 pub use self::app::{
-    ApiKeys, AppState, Configs, Customers, EphemeralKey, Health, Mandates, MerchantAccount,
-    MerchantConnectorAccount, PaymentMethods, Payments, Payouts, Refunds, Webhooks,
+    ApiKeys, AppState, Configs, ConnectorHealth, Customers, Disputes, EphemeralKey, Events, Files,
+    Health, Mandates, MerchantAccount, MerchantConnectorAccount, PaymentMethods, Payments, Payouts,
+    Refunds, Routing, Webhooks,
 };
 #[cfg(feature = "stripe")]
 pub use super::compatibility::stripe::StripeApis;
@@ -144,6 +144,48 @@ pub async fn delete_merchant_account(
     .await
 }
 
+/// Merchant Account - Rotate Webhook Secret
+///
+/// Rotate the key used for signing outgoing webhooks. Webhooks continue to be signed with the previous key as well until the overlap window elapses.
+#[utoipa::path(
+    post,
+    path = "/accounts/{account_id}/webhook_secret/rotate",
+    request_body = WebhookSecretRotateRequest,
+    params (("account_id" = String, Path, description = "The unique identifier for the merchant account")),
+    responses(
+        (status = 200, description = "Webhook Secret Rotated", body = WebhookSecretRotateResponse),
+        (status = 400, description = "Invalid data"),
+        (status = 404, description = "Merchant account not found")
+    ),
+    tag = "Merchant Account",
+    operation_id = "Rotate the Webhook Secret of a Merchant Account",
+    security(("admin_api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::MerchantsAccountWebhookSecretRotate))]
+pub async fn rotate_merchant_webhook_secret(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    mid: web::Path<String>,
+    json_payload: web::Json<admin::WebhookSecretRotateRequest>,
+) -> HttpResponse {
+    let merchant_id = mid.into_inner();
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        json_payload.into_inner(),
+        |state, _, req| {
+            rotate_webhook_secret(
+                &*state.store,
+                merchant_id.clone(),
+                req,
+                state.conf.webhooks.secret_rotation_overlap_in_seconds,
+            )
+        },
+        &auth::AdminApiAuth,
+    )
+    .await
+}
+
 /// PaymentsConnectors - Create
 ///
 /// Create a new Merchant Connector for the merchant account. The connector could be a payment processor / facilitator / acquirer or specialized services like Fraud / Accounting etc."
@@ -381,3 +423,31 @@ pub async fn merchant_account_kv_status(
     )
     .await
 }
+
+/// Connector Health - Retrieve
+///
+/// Retrieve the state of the circuit breaker of every connector, along with the outcomes of the requests made to the connector over the last window.
+#[utoipa::path(
+    get,
+    path = "/connector_health",
+    responses(
+        (status = 200, description = "Connector Health Retrieved", body = Vec<ConnectorHealthResponse>)
+    ),
+    tag = "Connector Health",
+    operation_id = "Retrieve the Health of the Connectors",
+    security(("admin_api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::ConnectorHealthRetrieve))]
+pub async fn connector_health_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+) -> HttpResponse {
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        (),
+        |state, _, _| get_connector_health(state),
+        &auth::AdminApiAuth,
+    )
+    .await
+}
@@ -2,15 +2,20 @@ use actix_web::{web, Scope};
 
 use super::health::*;
 #[cfg(feature = "olap")]
-use super::{admin::*, api_keys::*};
+use super::{admin::*, api_keys::*, events::*, routing::*};
 #[cfg(any(feature = "olap", feature = "oltp"))]
-use super::{configs::*, customers::*, mandates::*, payments::*, payouts::*, refunds::*};
+use super::{
+    configs::*, customers::*, disputes::*, mandates::*, payments::*, payouts::*, refunds::*,
+};
 #[cfg(feature = "oltp")]
-use super::{ephemeral_key::*, payment_methods::*, webhooks::*};
+use super::{ephemeral_key::*, files::*, payment_methods::*, webhooks::*};
 use crate::{
     configs::settings::Settings,
     db::{MockDb, StorageImpl, StorageInterface},
-    services::Store,
+    services::{
+        file_storage::{self, FileStorageInterface},
+        Store,
+    },
 };
 
 #[derive(Clone)]
@@ -18,6 +23,7 @@ pub struct AppState {
     pub flow_name: String,
     pub store: Box<dyn StorageInterface>,
     pub conf: Settings,
+    pub file_storage_client: Box<dyn FileStorageInterface>,
 }
 
 pub trait AppStateInfo {
@@ -48,10 +54,13 @@ impl AppState {
             StorageImpl::Mock => Box::new(MockDb::new(&conf).await),
         };
 
+        let file_storage_client = file_storage::get_file_storage_client(&conf.file_storage);
+
         Self {
             flow_name: String::from("default"),
             store,
             conf,
+            file_storage_client,
         }
     }
 
@@ -104,6 +113,14 @@ impl Payments {
                 .service(
                     web::resource("/{payment_id}/capture").route(web::post().to(payments_capture)),
                 )
+                .service(
+                    web::resource("/{payment_id}/cancel_scheduled_capture")
+                        .route(web::post().to(payments_cancel_scheduled_capture)),
+                )
+                .service(
+                    web::resource("/{payment_id}/incremental_authorization")
+                        .route(web::post().to(payments_incremental_authorization)),
+                )
                 .service(
                     web::resource("/start/{payment_id}/{merchant_id}/{attempt_id}")
                         .route(web::get().to(payments_start)),
@@ -185,22 +202,100 @@ impl Payouts {
 
         #[cfg(feature = "olap")]
         {
-            route =
-                route.service(web::resource("/accounts").route(web::get().to(payouts_accounts)));
+            route = route
+                .service(web::resource("/list").route(web::get().to(payouts_list)))
+                .service(web::resource("/accounts").route(web::get().to(payouts_accounts)));
         }
         #[cfg(feature = "oltp")]
         {
             route = route
                 .service(web::resource("/create").route(web::post().to(payouts_create)))
-                .service(web::resource("/retrieve").route(web::get().to(payouts_retrieve)))
-                .service(web::resource("/update").route(web::post().to(payouts_update)))
-                .service(web::resource("/reverse").route(web::post().to(payouts_reverse)))
-                .service(web::resource("/cancel").route(web::post().to(payouts_cancel)));
+                .service(
+                    web::resource("/{payout_id}")
+                        .route(web::get().to(payouts_retrieve))
+                        .route(web::post().to(payouts_update)),
+                )
+                .service(
+                    web::resource("/{payout_id}/fulfill").route(web::post().to(payouts_fulfill)),
+                )
+                .service(
+                    web::resource("/{payout_id}/reverse").route(web::post().to(payouts_reverse)),
+                )
+                .service(
+                    web::resource("/{payout_id}/cancel").route(web::post().to(payouts_cancel)),
+                );
         }
         route
     }
 }
 
+pub struct Events;
+
+#[cfg(feature = "olap")]
+impl Events {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/events")
+            .app_data(web::Data::new(state))
+            .service(web::resource("/list").route(web::get().to(list_events)))
+            .service(web::resource("/{event_id}").route(web::get().to(retrieve_event)))
+            .service(web::resource("/{event_id}/redeliver").route(web::post().to(redeliver_event)))
+    }
+}
+
+pub struct Routing;
+
+#[cfg(feature = "olap")]
+impl Routing {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/routing")
+            .app_data(web::Data::new(state))
+            .service(web::resource("/simulate").route(web::post().to(simulate_routing)))
+    }
+}
+
+pub struct Disputes;
+
+#[cfg(any(feature = "olap", feature = "oltp"))]
+impl Disputes {
+    pub fn server(state: AppState) -> Scope {
+        let mut route = web::scope("/disputes").app_data(web::Data::new(state));
+
+        #[cfg(feature = "olap")]
+        {
+            route =
+                route.service(web::resource("/list").route(web::get().to(retrieve_disputes_list)));
+        }
+        #[cfg(feature = "oltp")]
+        {
+            route = route
+                .service(
+                    web::resource("/accept/{dispute_id}").route(web::post().to(accept_dispute)),
+                )
+                .service(
+                    web::resource("/evidence/{dispute_id}")
+                        .route(web::post().to(submit_dispute_evidence)),
+                );
+        }
+        route.service(web::resource("/{dispute_id}").route(web::get().to(retrieve_dispute)))
+    }
+}
+
+pub struct Files;
+
+#[cfg(feature = "oltp")]
+impl Files {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/files")
+            .app_data(web::Data::new(state))
+            .service(web::resource("").route(web::post().to(files_create)))
+            .service(
+                web::resource("/{file_id}")
+                    .route(web::delete().to(files_delete))
+                    .route(web::get().to(files_retrieve)),
+            )
+    }
+}
+
 pub struct PaymentMethods;
 
 #[cfg(feature = "oltp")]
@@ -230,6 +325,10 @@ impl MerchantAccount {
         web::scope("/accounts")
             .app_data(web::Data::new(state))
             .service(web::resource("").route(web::post().to(merchant_account_create)))
+            .service(
+                web::resource("/{id}/webhook_secret/rotate")
+                    .route(web::post().to(rotate_merchant_webhook_secret)),
+            )
             .service(
                 web::resource("/{id}/kv")
                     .route(web::post().to(merchant_account_toggle_kv))
@@ -244,6 +343,17 @@ impl MerchantAccount {
     }
 }
 
+pub struct ConnectorHealth;
+
+#[cfg(feature = "olap")]
+impl ConnectorHealth {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/connector_health")
+            .app_data(web::Data::new(state))
+            .service(web::resource("").route(web::get().to(connector_health_retrieve)))
+    }
+}
+
 pub struct MerchantConnectorAccount;
 
 #[cfg(any(feature = "olap", feature = "oltp"))]
@@ -0,0 +1,155 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::disputes,
+    services::{api, authentication as auth},
+    types::api::disputes as dispute_types,
+};
+
+/// Disputes - Retrieve Dispute
+///
+/// To retrieve the details of a dispute
+#[utoipa::path(
+    get,
+    path = "/disputes/{dispute_id}",
+    params(
+        ("dispute_id" = String, Path, description = "The identifier for dispute")
+    ),
+    responses(
+        (status = 200, description = "The dispute was retrieved successfully", body = DisputeResponse),
+        (status = 404, description = "Dispute does not exist in our records")
+    ),
+    tag = "Disputes",
+    operation_id = "Retrieve a Dispute",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::DisputesRetrieve))]
+pub async fn retrieve_dispute(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        path.into_inner(),
+        disputes::retrieve_dispute,
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
+
+/// Disputes - List Disputes
+///
+/// To list the disputes received by the merchant, optionally filtered by the given constraints
+#[utoipa::path(
+    get,
+    path = "/disputes/list",
+    params(
+        ("limit" = Option<i64>, Query, description = "Limit on the number of objects to return"),
+        ("dispute_status" = Option<DisputeStatus>, Query, description = "Status of the dispute"),
+        ("dispute_stage" = Option<DisputeStage>, Query, description = "Stage of the dispute"),
+        ("connector" = Option<String>, Query, description = "The connector against which the payment was made"),
+        ("payment_id" = Option<String>, Query, description = "The identifier for the payment"),
+        ("received_time.lt" = Option<PrimitiveDateTime>, Query, description = "Time less than the dispute received time"),
+        ("received_time.gt" = Option<PrimitiveDateTime>, Query, description = "Time greater than the dispute received time")
+    ),
+    responses(
+        (status = 200, description = "The dispute list was retrieved successfully", body = DisputeListResponse),
+        (status = 401, description = "Unauthorized request")
+    ),
+    tag = "Disputes",
+    operation_id = "List Disputes",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::DisputesList))]
+#[cfg(feature = "olap")]
+pub async fn retrieve_disputes_list(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    payload: web::Query<dispute_types::DisputeListConstraints>,
+) -> HttpResponse {
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        payload.into_inner(),
+        disputes::retrieve_disputes_list,
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
+
+/// Disputes - Accept Dispute
+///
+/// To accept a dispute, conceding it to the customer
+#[utoipa::path(
+    post,
+    path = "/disputes/accept/{dispute_id}",
+    params(
+        ("dispute_id" = String, Path, description = "The identifier for dispute")
+    ),
+    responses(
+        (status = 200, description = "The dispute was accepted successfully", body = DisputeResponse),
+        (status = 400, description = "The dispute cannot be accepted in its current status"),
+        (status = 404, description = "Dispute does not exist in our records")
+    ),
+    tag = "Disputes",
+    operation_id = "Accept a Dispute",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::DisputesAccept))]
+pub async fn accept_dispute(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        path.into_inner(),
+        disputes::accept_dispute,
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
+
+/// Disputes - Submit Dispute Evidence
+///
+/// To submit evidence to the connector in order to challenge a dispute
+#[utoipa::path(
+    post,
+    path = "/disputes/evidence/{dispute_id}",
+    params(
+        ("dispute_id" = String, Path, description = "The identifier for dispute")
+    ),
+    request_body=DisputeEvidence,
+    responses(
+        (status = 200, description = "The evidence was submitted successfully", body = DisputeResponse),
+        (status = 400, description = "The dispute cannot be challenged in its current status"),
+        (status = 404, description = "Dispute does not exist in our records")
+    ),
+    tag = "Disputes",
+    operation_id = "Submit Dispute Evidence",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::DisputesEvidenceSubmit))]
+pub async fn submit_dispute_evidence(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<dispute_types::DisputeEvidence>,
+) -> HttpResponse {
+    let dispute_id = path.into_inner();
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        json_payload.into_inner(),
+        |state, merchant_account, evidence| {
+            disputes::submit_evidence(state, merchant_account, dispute_id.clone(), evidence)
+        },
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
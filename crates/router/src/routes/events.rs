@@ -0,0 +1,113 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::events,
+    services::{api, authentication as auth},
+    types::api::events as event_types,
+};
+
+/// Events - List Events
+///
+/// To list the events created for the merchant, optionally filtered by the given constraints
+#[utoipa::path(
+    get,
+    path = "/events/list",
+    params(
+        ("limit" = Option<i64>, Query, description = "Limit on the number of objects to return"),
+        ("event_type" = Option<EventType>, Query, description = "The type of the event"),
+        ("object_id" = Option<String>, Query, description = "The identifier of the object the event is about"),
+        ("created_time.lt" = Option<PrimitiveDateTime>, Query, description = "Time less than the event created time"),
+        ("created_time.gt" = Option<PrimitiveDateTime>, Query, description = "Time greater than the event created time")
+    ),
+    responses(
+        (status = 200, description = "The event list was retrieved successfully", body = EventListResponse),
+        (status = 401, description = "Unauthorized request")
+    ),
+    tag = "Events",
+    operation_id = "List Events",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::EventsList))]
+pub async fn list_events(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    payload: web::Query<event_types::EventListConstraints>,
+) -> HttpResponse {
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        payload.into_inner(),
+        events::list_events,
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
+
+/// Events - Retrieve Event
+///
+/// To retrieve an event along with the webhook payload and the attempts made to deliver it
+#[utoipa::path(
+    get,
+    path = "/events/{event_id}",
+    params(
+        ("event_id" = String, Path, description = "The identifier for the event")
+    ),
+    responses(
+        (status = 200, description = "The event was retrieved successfully", body = EventResponse),
+        (status = 404, description = "Event does not exist in our records")
+    ),
+    tag = "Events",
+    operation_id = "Retrieve an Event",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::EventsRetrieve))]
+pub async fn retrieve_event(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        path.into_inner(),
+        events::retrieve_event,
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
+
+/// Events - Redeliver Event
+///
+/// To send the webhook of an event to the merchant's webhook URL once more
+#[utoipa::path(
+    post,
+    path = "/events/{event_id}/redeliver",
+    params(
+        ("event_id" = String, Path, description = "The identifier for the event")
+    ),
+    responses(
+        (status = 200, description = "The webhook was redelivered, the outcome is available in the delivery attempts of the event", body = EventResponse),
+        (status = 400, description = "The webhook of the event cannot be redelivered"),
+        (status = 404, description = "Event does not exist in our records")
+    ),
+    tag = "Events",
+    operation_id = "Redeliver an Event",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::EventsRedeliver))]
+pub async fn redeliver_event(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        path.into_inner(),
+        events::redeliver_event,
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
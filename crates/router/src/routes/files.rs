@@ -0,0 +1,116 @@
+pub mod transformers;
+
+use actix_multipart::Multipart;
+use actix_web::{web, HttpRequest, HttpResponse};
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::files,
+    services::{api, authentication as auth},
+};
+
+/// Files - Create
+///
+/// To create a file
+#[utoipa::path(
+    post,
+    path = "/files",
+    request_body(content = CreateFileRequestForm, content_type = "multipart/form-data"),
+    responses(
+        (status = 200, description = "File created", body = CreateFileResponse),
+        (status = 400, description = "Bad Request")
+    ),
+    tag = "Files",
+    operation_id = "Create a File",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::CreateFile))]
+pub async fn files_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    payload: Multipart,
+) -> HttpResponse {
+    let create_file_request =
+        match transformers::get_create_file_request(payload, state.conf.file_storage.max_file_size)
+            .await
+        {
+            Ok(request) => request,
+            Err(error) => return api::log_and_return_error_response(error),
+        };
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        create_file_request,
+        files::files_create_core,
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
+
+/// Files - Delete
+///
+/// To delete a file
+#[utoipa::path(
+    delete,
+    path = "/files/{file_id}",
+    params(
+        ("file_id" = String, Path, description = "The identifier for file")
+    ),
+    responses(
+        (status = 200, description = "File deleted"),
+        (status = 404, description = "File not found")
+    ),
+    tag = "Files",
+    operation_id = "Delete a File",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::DeleteFile))]
+pub async fn files_delete(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        path.into_inner(),
+        files::files_delete_core,
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
+
+/// Files - Retrieve
+///
+/// To retrieve a file
+#[utoipa::path(
+    get,
+    path = "/files/{file_id}",
+    params(
+        ("file_id" = String, Path, description = "The identifier for file")
+    ),
+    responses(
+        (status = 200, description = "File body"),
+        (status = 400, description = "File not available"),
+        (status = 404, description = "File not found")
+    ),
+    tag = "Files",
+    operation_id = "Retrieve a File",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::RetrieveFile))]
+pub async fn files_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        path.into_inner(),
+        files::files_retrieve_core,
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
@@ -0,0 +1,102 @@
+use actix_multipart::Multipart;
+use error_stack::{report, IntoReport, ResultExt};
+use futures::{StreamExt, TryStreamExt};
+
+use crate::{
+    core::errors::{self, RouterResult},
+    types::api::files::{CreateFileRequest, FilePurpose},
+    utils::OptionExt,
+};
+
+/// Parses the multipart form of a file upload request, rejecting files larger than `max_file_size`
+pub async fn get_create_file_request(
+    mut payload: Multipart,
+    max_file_size: usize,
+) -> RouterResult<CreateFileRequest> {
+    let mut file: Option<Vec<u8>> = None;
+    let mut file_name: Option<String> = None;
+    let mut file_type: Option<mime::Mime> = None;
+    let mut purpose: Option<FilePurpose> = None;
+    let mut dispute_id: Option<String> = None;
+
+    while let Some(mut field) = payload.try_next().await.into_report().change_context(
+        errors::ApiErrorResponse::InvalidRequestData {
+            message: "Unable to parse the multipart form".to_string(),
+        },
+    )? {
+        match field.name() {
+            "file" => {
+                file_name = field
+                    .content_disposition()
+                    .get_filename()
+                    .map(ToOwned::to_owned);
+                file_type = field.content_type().cloned();
+                let mut file_data = Vec::new();
+                while let Some(chunk) = field.next().await {
+                    let chunk = chunk.into_report().change_context(
+                        errors::ApiErrorResponse::InvalidRequestData {
+                            message: "Unable to read the uploaded file".to_string(),
+                        },
+                    )?;
+                    file_data.extend_from_slice(&chunk);
+                    if file_data.len() > max_file_size {
+                        return Err(report!(errors::ApiErrorResponse::FileValidationFailed {
+                            reason: format!("file size exceeds the limit of {max_file_size} bytes"),
+                        }));
+                    }
+                }
+                file = Some(file_data);
+            }
+            "purpose" => {
+                let value = read_text_field(&mut field).await?;
+                purpose = Some(value.parse().into_report().change_context(
+                    errors::ApiErrorResponse::InvalidDataValue {
+                        field_name: "purpose",
+                    },
+                )?);
+            }
+            "dispute_id" => dispute_id = Some(read_text_field(&mut field).await?),
+            // Unknown fields are ignored
+            _ => (),
+        }
+    }
+
+    let file = file.get_required_value("file")?;
+    let file_size = i32::try_from(file.len()).into_report().change_context(
+        errors::ApiErrorResponse::FileValidationFailed {
+            reason: "file size is too large".to_string(),
+        },
+    )?;
+    if file_size == 0 {
+        return Err(report!(errors::ApiErrorResponse::FileValidationFailed {
+            reason: "file is empty".to_string(),
+        }));
+    }
+
+    Ok(CreateFileRequest {
+        file,
+        file_name,
+        file_size,
+        file_type: file_type.get_required_value("file content type")?,
+        purpose: purpose.get_required_value("purpose")?,
+        dispute_id,
+    })
+}
+
+async fn read_text_field(field: &mut actix_multipart::Field) -> RouterResult<String> {
+    let mut value = Vec::new();
+    while let Some(chunk) = field.next().await {
+        let chunk =
+            chunk
+                .into_report()
+                .change_context(errors::ApiErrorResponse::InvalidRequestData {
+                    message: "Unable to parse the multipart form".to_string(),
+                })?;
+        value.extend_from_slice(&chunk);
+    }
+    String::from_utf8(value).into_report().change_context(
+        errors::ApiErrorResponse::InvalidRequestData {
+            message: "Multipart form fields other than the file must be valid UTF-8".to_string(),
+        },
+    )
+}
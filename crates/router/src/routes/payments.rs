@@ -330,6 +330,101 @@ pub async fn payments_capture(
     .await
 }
 
+/// Payments - Cancel scheduled capture
+///
+/// To cancel the pending capture of a payment made with the `scheduled` capture method. The payment remains authorized, and can be captured or cancelled later.
+#[utoipa::path(
+    post,
+    path = "/payments/{payment_id}/cancel_scheduled_capture",
+    params(
+        ("payment_id" = String, Path, description = "The identifier for payment")
+    ),
+    responses(
+        (status = 200, description = "Scheduled capture cancelled", body = PaymentsResponse),
+        (status = 400, description = "The payment does not have a pending scheduled capture"),
+        (status = 404, description = "Payment not found")
+    ),
+    tag = "Payments",
+    operation_id = "Cancel the Scheduled Capture of a Payment",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsScheduledCaptureCancel))]
+// #[post("/{payment_id}/cancel_scheduled_capture")]
+pub async fn payments_cancel_scheduled_capture(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let payment_id = path.into_inner();
+
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        payment_id,
+        |state, merchant_account, payment_id| {
+            payments::cancel_scheduled_capture(state, merchant_account, payment_id)
+        },
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
+
+/// Payments - Incremental Authorization
+///
+/// To increase the amount authorized on a payment which is yet to be captured, for instance to add incidental charges. Applicable only to payments created with `request_incremental_authorization`.
+#[utoipa::path(
+    post,
+    path = "/payments/{payment_id}/incremental_authorization",
+    request_body=PaymentsIncrementalAuthorizationRequest,
+    params(
+        ("payment_id" = String, Path, description = "The identifier for payment")
+    ),
+    responses(
+        (status = 200, description = "Authorization incremented", body = PaymentsResponse),
+        (status = 400, description = "Incremental authorization was not requested for the payment"),
+        (status = 404, description = "Payment not found")
+    ),
+    tag = "Payments",
+    operation_id = "Increment the Authorization of a Payment",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsIncrementalAuthorization))]
+// #[post("/{payment_id}/incremental_authorization")]
+pub async fn payments_incremental_authorization(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<payment_types::PaymentsIncrementalAuthorizationRequest>,
+    path: web::Path<String>,
+) -> impl Responder {
+    let mut payload = json_payload.into_inner();
+    let payment_id = path.into_inner();
+    payload.payment_id = payment_id;
+
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        payload,
+        |state, merchant_account, req| {
+            payments::payments_core::<
+                api_types::IncrementalAuthorization,
+                payment_types::PaymentsResponse,
+                _,
+                _,
+                _,
+            >(
+                state,
+                merchant_account,
+                payments::PaymentIncrementalAuthorization,
+                req,
+                api::AuthFlow::Merchant,
+                payments::CallConnectorAction::Trigger,
+            )
+        },
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
+
 /// Payments - Session token
 ///
 /// To create the session object or to get session token for wallets
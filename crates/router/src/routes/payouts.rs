@@ -1,45 +1,286 @@
-use actix_web::{
-    body::{BoxBody, MessageBody},
-    HttpResponse, Responder,
-};
+use actix_web::{web, HttpRequest, HttpResponse};
 use router_env::{instrument, tracing, Flow};
 
+use super::app::AppState;
+use crate::{
+    core::payouts::*,
+    services::{api, authentication as auth},
+    types::api::payouts,
+};
+
+/// Payouts - Create
+///
+/// To create a payout to a card or bank account, the payout is submitted to the connector if `confirm` is set
+#[utoipa::path(
+    post,
+    path = "/payouts/create",
+    request_body=PayoutCreateRequest,
+    responses(
+        (status = 200, description = "Payout created", body = PayoutResponse),
+        (status = 400, description = "Missing Mandatory fields")
+    ),
+    tag = "Payouts",
+    operation_id = "Create a Payout",
+    security(("api_key" = []))
+)]
 #[instrument(skip_all, fields(flow = ?Flow::PayoutsCreate))]
 // #[post("/create")]
-pub async fn payouts_create() -> impl Responder {
-    http_response("create")
+pub async fn payouts_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<payouts::PayoutCreateRequest>,
+) -> HttpResponse {
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        json_payload.into_inner(),
+        payouts_create_core,
+        &auth::ApiKeyAuth,
+    )
+    .await
 }
 
+/// Payouts - Retrieve
+///
+/// To retrieve the properties of a Payout. This may be used to get the status of a previously initiated payout
+#[utoipa::path(
+    get,
+    path = "/payouts/{payout_id}",
+    params(
+        ("payout_id" = String, Path, description = "The identifier for payout")
+    ),
+    responses(
+        (status = 200, description = "Payout retrieved", body = PayoutResponse),
+        (status = 404, description = "Payout does not exist in our records")
+    ),
+    tag = "Payouts",
+    operation_id = "Retrieve a Payout",
+    security(("api_key" = []))
+)]
 #[instrument(skip_all, fields(flow = ?Flow::PayoutsRetrieve))]
-// #[get("/retrieve")]
-pub async fn payouts_retrieve() -> impl Responder {
-    http_response("retrieve")
+// #[get("/{payout_id}")]
+pub async fn payouts_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let payout_id = path.into_inner();
+
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        payout_id,
+        payouts_retrieve_core,
+        &auth::ApiKeyAuth,
+    )
+    .await
 }
 
+/// Payouts - Update
+///
+/// To update the properties of a Payout which has not been submitted to the connector yet, the payout is submitted to the connector if `confirm` is set
+#[utoipa::path(
+    post,
+    path = "/payouts/{payout_id}",
+    params(
+        ("payout_id" = String, Path, description = "The identifier for payout")
+    ),
+    request_body=PayoutUpdateRequest,
+    responses(
+        (status = 200, description = "Payout updated", body = PayoutResponse),
+        (status = 400, description = "Missing Mandatory fields")
+    ),
+    tag = "Payouts",
+    operation_id = "Update a Payout",
+    security(("api_key" = []))
+)]
 #[instrument(skip_all, fields(flow = ?Flow::PayoutsUpdate))]
-// #[post("/update")]
-pub async fn payouts_update() -> impl Responder {
-    http_response("update")
+// #[post("/{payout_id}")]
+pub async fn payouts_update(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<payouts::PayoutUpdateRequest>,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let payout_id = path.into_inner();
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        json_payload.into_inner(),
+        |state, merchant_account, req| {
+            payouts_update_core(state, merchant_account, payout_id.clone(), req)
+        },
+        &auth::ApiKeyAuth,
+    )
+    .await
 }
 
+/// Payouts - Fulfill
+///
+/// To fulfill a Payout which has been submitted to the connector, this releases the funds to the recipient
+#[utoipa::path(
+    post,
+    path = "/payouts/{payout_id}/fulfill",
+    params(
+        ("payout_id" = String, Path, description = "The identifier for payout")
+    ),
+    responses(
+        (status = 200, description = "Payout fulfilled", body = PayoutResponse),
+        (status = 400, description = "Payout cannot be fulfilled in its current state")
+    ),
+    tag = "Payouts",
+    operation_id = "Fulfill a Payout",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PayoutsFulfill))]
+// #[post("/{payout_id}/fulfill")]
+pub async fn payouts_fulfill(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let payout_id = path.into_inner();
+
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        payout_id,
+        payouts_fulfill_core,
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
+
+/// Payouts - Reverse
+///
+/// To reverse a Payout which has been submitted to the connector but not fulfilled yet, the payout is declined by the connector
+#[utoipa::path(
+    post,
+    path = "/payouts/{payout_id}/reverse",
+    params(
+        ("payout_id" = String, Path, description = "The identifier for payout")
+    ),
+    responses(
+        (status = 200, description = "Payout reversed", body = PayoutResponse),
+        (status = 400, description = "Payout cannot be reversed in its current state")
+    ),
+    tag = "Payouts",
+    operation_id = "Reverse a Payout",
+    security(("api_key" = []))
+)]
 #[instrument(skip_all, fields(flow = ?Flow::PayoutsReverse))]
-// #[post("/reverse")]
-pub async fn payouts_reverse() -> impl Responder {
-    http_response("reverse")
+// #[post("/{payout_id}/reverse")]
+pub async fn payouts_reverse(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let payout_id = path.into_inner();
+
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        payout_id,
+        payouts_reverse_core,
+        &auth::ApiKeyAuth,
+    )
+    .await
 }
 
+/// Payouts - Cancel
+///
+/// To cancel a Payout which has not been submitted to the connector yet
+#[utoipa::path(
+    post,
+    path = "/payouts/{payout_id}/cancel",
+    params(
+        ("payout_id" = String, Path, description = "The identifier for payout")
+    ),
+    responses(
+        (status = 200, description = "Payout cancelled", body = PayoutResponse),
+        (status = 400, description = "Payout cannot be cancelled in its current state")
+    ),
+    tag = "Payouts",
+    operation_id = "Cancel a Payout",
+    security(("api_key" = []))
+)]
 #[instrument(skip_all, fields(flow = ?Flow::PayoutsCancel))]
-// #[post("/cancel")]
-pub async fn payouts_cancel() -> impl Responder {
-    http_response("cancel")
+// #[post("/{payout_id}/cancel")]
+pub async fn payouts_cancel(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let payout_id = path.into_inner();
+
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        payout_id,
+        payouts_cancel_core,
+        &auth::ApiKeyAuth,
+    )
+    .await
 }
 
+/// Payouts - Accounts
+///
+/// To list the connector accounts of the merchant through which payouts can be sent
+#[utoipa::path(
+    get,
+    path = "/payouts/accounts",
+    responses(
+        (status = 200, description = "List of payout accounts", body = PayoutAccountsResponse),
+    ),
+    tag = "Payouts",
+    operation_id = "List all Payout Accounts",
+    security(("api_key" = []))
+)]
 #[instrument(skip_all, fields(flow = ?Flow::PayoutsAccounts))]
+#[cfg(feature = "olap")]
 // #[get("/accounts")]
-pub async fn payouts_accounts() -> impl Responder {
-    http_response("accounts")
+pub async fn payouts_accounts(state: web::Data<AppState>, req: HttpRequest) -> HttpResponse {
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        (),
+        |state, merchant_account, _| payouts_accounts_core(state, merchant_account),
+        &auth::ApiKeyAuth,
+    )
+    .await
 }
 
-fn http_response<T: MessageBody + 'static>(response: T) -> HttpResponse<BoxBody> {
-    HttpResponse::Ok().body(response)
+/// Payouts - List
+///
+/// To list the payouts created by the merchant
+#[utoipa::path(
+    get,
+    path = "/payouts/list",
+    params(
+        ("limit" = i64, Query, description = "Limit on the number of objects to return"),
+        ("offset" = i64, Query, description = "The number of objects to skip")
+    ),
+    responses(
+        (status = 200, description = "List of payouts", body = PayoutListResponse),
+    ),
+    tag = "Payouts",
+    operation_id = "List all Payouts",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PayoutsList))]
+#[cfg(feature = "olap")]
+// #[get("/list")]
+pub async fn payouts_list(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    payload: web::Query<payouts::PayoutListConstraints>,
+) -> HttpResponse {
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        payload.into_inner(),
+        payouts_list_core,
+        &auth::ApiKeyAuth,
+    )
+    .await
 }
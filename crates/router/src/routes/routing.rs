@@ -0,0 +1,41 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::routing,
+    services::{api, authentication as auth},
+    types::api::routing as routing_types,
+};
+
+/// Routing - Simulate
+///
+/// To see the connectors a payment with the given details would be routed to by the routing
+/// algorithm of the merchant, without making the payment
+#[utoipa::path(
+    post,
+    path = "/routing/simulate",
+    request_body = RoutingSimulationRequest,
+    responses(
+        (status = 200, description = "The payment was routed successfully", body = RoutingSimulationResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Routing",
+    operation_id = "Simulate Routing",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::RoutingSimulate))]
+pub async fn simulate_routing(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<routing_types::RoutingSimulationRequest>,
+) -> HttpResponse {
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        json_payload.into_inner(),
+        routing::simulate_routing,
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
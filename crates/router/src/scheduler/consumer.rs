@@ -16,6 +16,7 @@ use uuid::Uuid;
 use super::{
     metrics,
     workflows::{self, ProcessTrackerWorkflow},
+    ProcessTrackerBatch,
 };
 use crate::{
     configs::settings,
@@ -162,7 +163,14 @@ pub async fn fetch_consumer_tasks(
     consumer_name: &str,
 ) -> CustomResult<Vec<storage::ProcessTracker>, errors::ProcessTrackerError> {
     let batches = pt_utils::get_batches(redis_conn, stream_name, group_name, consumer_name).await?;
+    start_tasks_in_batches(db, batches).await
+}
 
+/// Marks the tasks of the batches read from the stream which are still to be run as started
+pub async fn start_tasks_in_batches(
+    db: &dyn StorageInterface,
+    batches: Vec<ProcessTrackerBatch>,
+) -> CustomResult<Vec<storage::ProcessTracker>, errors::ProcessTrackerError> {
     let mut tasks = batches.into_iter().fold(Vec::new(), |mut acc, batch| {
         acc.extend_from_slice(
             batch
@@ -39,3 +39,19 @@ impl Default for ConnectorPTMapping {
         }
     }
 }
+
+impl ConnectorPTMapping {
+    /// Retry mapping of outgoing webhooks, the delay between attempts doubles from a minute
+    /// up to about four hours, spanning roughly eight and a half hours in total
+    pub fn outgoing_webhook_default() -> Self {
+        Self {
+            custom_merchant_mapping: HashMap::new(),
+            default_mapping: RetryMapping {
+                start_after: 60,
+                frequency: vec![120, 240, 480, 960, 1920, 3840, 7680, 15360],
+                count: vec![1, 1, 1, 1, 1, 1, 1, 1],
+            },
+            max_retries_count: 8,
+        }
+    }
+}
@@ -4,6 +4,10 @@ use serde::{Deserialize, Serialize};
 use strum::EnumString;
 
 use crate::{core::errors, routes::AppState, scheduler::consumer, types::storage};
+pub mod access_token_refresh;
+pub mod outgoing_webhook_retry;
+pub mod payment_capture;
+pub mod payment_expiry;
 pub mod payment_sync;
 pub mod refund_router;
 
@@ -44,7 +48,11 @@ macro_rules! as_item {
 
 runners! {
     PaymentsSyncWorkflow,
-    RefundWorkflowRouter
+    RefundWorkflowRouter,
+    OutgoingWebhookRetryWorkflow,
+    PaymentsCaptureWorkflow,
+    PaymentsExpiryWorkflow,
+    AccessTokenRefreshWorkflow
 }
 
 #[async_trait]
@@ -87,4 +95,32 @@ mod workflow_tests {
         let enum_format: PTRunner = string_format.parse_enum("PTRunner").unwrap();
         assert_eq!(enum_format, PTRunner::PaymentsSyncWorkflow)
     }
+
+    #[test]
+    fn test_outgoing_webhook_runner_from_string() {
+        let string_format = "OUTGOING_WEBHOOK_RETRY_WORKFLOW".to_string();
+        let enum_format: PTRunner = string_format.parse_enum("PTRunner").unwrap();
+        assert_eq!(enum_format, PTRunner::OutgoingWebhookRetryWorkflow)
+    }
+
+    #[test]
+    fn test_payments_capture_runner_from_string() {
+        let string_format = "PAYMENTS_CAPTURE_WORKFLOW".to_string();
+        let enum_format: PTRunner = string_format.parse_enum("PTRunner").unwrap();
+        assert_eq!(enum_format, PTRunner::PaymentsCaptureWorkflow)
+    }
+
+    #[test]
+    fn test_payments_expiry_runner_from_string() {
+        let string_format = "PAYMENTS_EXPIRY_WORKFLOW".to_string();
+        let enum_format: PTRunner = string_format.parse_enum("PTRunner").unwrap();
+        assert_eq!(enum_format, PTRunner::PaymentsExpiryWorkflow)
+    }
+
+    #[test]
+    fn test_access_token_refresh_runner_from_string() {
+        let string_format = "ACCESS_TOKEN_REFRESH_WORKFLOW".to_string();
+        let enum_format: PTRunner = string_format.parse_enum("PTRunner").unwrap();
+        assert_eq!(enum_format, PTRunner::AccessTokenRefreshWorkflow)
+    }
 }
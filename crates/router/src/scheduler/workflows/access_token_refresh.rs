@@ -0,0 +1,125 @@
+use router_env::logger;
+
+use super::{AccessTokenRefreshWorkflow, ProcessTrackerWorkflow};
+use crate::{
+    core::payments::access_token,
+    db::StorageInterface,
+    errors,
+    routes::AppState,
+    scheduler::consumer,
+    types::{
+        api,
+        storage::{self, ProcessTrackerExt},
+    },
+    utils::ValueExt,
+};
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow for AccessTokenRefreshWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: storage::AccessTokenRefreshTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("AccessTokenRefreshTrackingData")?;
+
+        if !state.conf.access_token_refresh.background_refresh {
+            return process
+                .finish_with_status(db, "BACKGROUND_REFRESH_DISABLED".to_string())
+                .await;
+        }
+
+        // The token is no longer needed once the connector account is removed or disabled
+        let merchant_connector_account = match db
+            .find_merchant_connector_account_by_merchant_id_connector(
+                &tracking_data.merchant_id,
+                &tracking_data.connector,
+            )
+            .await
+        {
+            Ok(merchant_connector_account) if merchant_connector_account.disabled != Some(true) => {
+                merchant_connector_account
+            }
+            Ok(_) => {
+                return process
+                    .finish_with_status(db, "CONNECTOR_DISABLED".to_string())
+                    .await
+            }
+            Err(error) if error.current_context().is_db_not_found() => {
+                return process
+                    .finish_with_status(db, "CONNECTOR_NOT_FOUND".to_string())
+                    .await
+            }
+            Err(error) => Err(error)?,
+        };
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(&tracking_data.merchant_id)
+            .await?;
+        let connector = api::ConnectorData::get_connector_by_name(
+            &state.conf.connectors,
+            &tracking_data.connector,
+            api::GetToken::Connector,
+        )?;
+
+        // The token may have been refreshed by a payment since the refresh was scheduled
+        let access_token = match access_token::get_access_token(
+            state,
+            &merchant_account.merchant_id,
+            connector.connector.id(),
+        )
+        .await?
+        {
+            Some(access_token) if !access_token::is_refresh_due(state, &access_token) => {
+                Ok(Some(access_token))
+            }
+            current_access_token => {
+                let router_data = access_token::construct_access_token_router_data(
+                    merchant_connector_account,
+                    &process.id,
+                )?;
+                access_token::refresh_access_token(
+                    state,
+                    &connector,
+                    &merchant_account,
+                    &router_data,
+                    current_access_token,
+                )
+                .await?
+            }
+        };
+
+        match access_token {
+            Ok(Some(access_token)) => {
+                let schedule_time = access_token::get_refresh_schedule_time(state, &access_token);
+                process.retry(db, schedule_time).await
+            }
+            // The refresh is resumed by the next payment which refreshes the token
+            Ok(None) => {
+                logger::error!("Connector did not return an access token");
+                process
+                    .finish_with_status(db, "REFRESH_FAILED".to_string())
+                    .await
+            }
+            Err(error) => {
+                logger::error!(?error, "Failed to refresh the access token");
+                process
+                    .finish_with_status(db, "REFRESH_FAILED".to_string())
+                    .await
+            }
+        }
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state, process, error).await
+    }
+}
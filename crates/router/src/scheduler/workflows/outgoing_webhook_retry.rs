@@ -0,0 +1,180 @@
+use router_env::logger;
+
+use super::{OutgoingWebhookRetryWorkflow, ProcessTrackerWorkflow};
+use crate::{
+    consts,
+    core::webhooks::{self, utils as webhooks_utils},
+    db::{get_and_deserialize_key, StorageInterface},
+    errors,
+    routes::AppState,
+    scheduler::{consumer, process_data, utils},
+    types::storage::{self, enums, ProcessTrackerExt},
+    utils::ValueExt,
+};
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow for OutgoingWebhookRetryWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: storage::OutgoingWebhookTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("OutgoingWebhookTrackingData")?;
+
+        let event = db.find_event_by_event_id(&tracking_data.event_id).await?;
+        let endpoint_id = tracking_data
+            .endpoint_id
+            .as_deref()
+            .unwrap_or(consts::DEFAULT_WEBHOOK_ENDPOINT_ID);
+
+        // The webhook may have been delivered to the endpoint by an earlier attempt
+        let is_delivered_to_endpoint = db
+            .find_event_delivery_attempts_by_event_id(&event.event_id)
+            .await?
+            .iter()
+            .any(|attempt| attempt.delivered && attempt.endpoint_id == endpoint_id);
+        if is_delivered_to_endpoint {
+            return process
+                .finish_with_status(db, "COMPLETED_BY_PT".to_string())
+                .await;
+        }
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(&tracking_data.merchant_id)
+            .await?;
+
+        let webhook_endpoint =
+            match webhooks_utils::find_webhook_endpoint(&merchant_account, endpoint_id)? {
+                Some(webhook_endpoint) => webhook_endpoint,
+                None => {
+                    logger::info!(
+                        endpoint_id,
+                        "Webhook endpoint was removed, skipping the delivery"
+                    );
+                    return process
+                        .finish_with_status(db, "ENDPOINT_REMOVED".to_string())
+                        .await;
+                }
+            };
+
+        let delivery_result = webhooks::trigger_webhook_to_merchant(
+            state,
+            &merchant_account,
+            event,
+            &webhook_endpoint,
+            &tracking_data.request_body,
+        )
+        .await;
+
+        match delivery_result {
+            Ok(()) => {
+                process
+                    .finish_with_status(db, "COMPLETED_BY_PT".to_string())
+                    .await?
+            }
+            Err(error) => {
+                logger::error!(?error, "Failed to deliver the outgoing webhook");
+                retry_webhook_delivery_task(db, &tracking_data, process).await?
+            }
+        }
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state, process, error).await
+    }
+}
+
+/// Schedule time of the next delivery attempt, as per the retry mapping configured in redis
+/// under `pt_mapping_outgoing_webhooks`. Returns `None` once all the retries are exhausted.
+pub async fn get_webhook_delivery_retry_schedule_time(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    retry_count: i32,
+) -> Option<time::PrimitiveDateTime> {
+    let redis_mapping: errors::CustomResult<process_data::ConnectorPTMapping, errors::RedisError> =
+        get_and_deserialize_key(db, "pt_mapping_outgoing_webhooks", "ConnectorPTMapping").await;
+    let mapping = match redis_mapping {
+        Ok(mapping) => mapping,
+        Err(error) => {
+            logger::info!("Redis Mapping Error: {}", error);
+            process_data::ConnectorPTMapping::outgoing_webhook_default()
+        }
+    };
+    let time_delta = utils::get_schedule_time(mapping, merchant_id, retry_count);
+
+    utils::get_time_from_delta(time_delta)
+}
+
+async fn retry_webhook_delivery_task(
+    db: &dyn StorageInterface,
+    tracking_data: &storage::OutgoingWebhookTrackingData,
+    process: storage::ProcessTracker,
+) -> Result<(), errors::ProcessTrackerError> {
+    let schedule_time = get_webhook_delivery_retry_schedule_time(
+        db,
+        &tracking_data.merchant_id,
+        process.retry_count + 1,
+    )
+    .await;
+
+    match schedule_time {
+        Some(schedule_time) => process.retry(db, schedule_time).await,
+        None => {
+            // The event is fetched again as another endpoint may have acknowledged the webhook
+            // in the meantime
+            let event = db.find_event_by_event_id(&tracking_data.event_id).await?;
+            if !event.is_webhook_notified {
+                db.update_event(
+                    event,
+                    storage::EventUpdate::DeliveryStatusUpdate {
+                        is_webhook_notified: false,
+                        webhook_delivery_status: enums::WebhookDeliveryStatus::Failed,
+                    },
+                )
+                .await?;
+            }
+            process
+                .finish_with_status(db, "RETRIES_EXCEEDED".to_string())
+                .await
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_outgoing_webhook_retry_delay_doubles() {
+        let delays: Vec<i32> = (1..=8)
+            .map(|retry_count| {
+                utils::get_schedule_time(
+                    process_data::ConnectorPTMapping::outgoing_webhook_default(),
+                    "-",
+                    retry_count,
+                )
+                .unwrap()
+            })
+            .collect();
+        assert!(delays.windows(2).all(|pair| pair[1] == pair[0] * 2));
+        assert_eq!(
+            utils::get_schedule_time(
+                process_data::ConnectorPTMapping::outgoing_webhook_default(),
+                "-",
+                9
+            ),
+            None
+        );
+    }
+}
@@ -0,0 +1,122 @@
+use router_env::logger;
+
+use super::{PaymentsCaptureWorkflow, ProcessTrackerWorkflow};
+use crate::{
+    core::{
+        payments::{self as payment_flows, operations},
+        webhooks,
+    },
+    db::StorageInterface,
+    errors,
+    routes::AppState,
+    scheduler::consumer,
+    services,
+    types::{
+        api,
+        storage::{self, enums, ProcessTrackerExt},
+    },
+    utils::{OptionExt, ValueExt},
+};
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow for PaymentsCaptureWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: api::PaymentsCaptureRequest = process
+            .tracking_data
+            .clone()
+            .parse_value("PaymentsCaptureRequest")?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(
+                tracking_data
+                    .merchant_id
+                    .as_ref()
+                    .get_required_value("merchant_id")?,
+            )
+            .await?;
+
+        let payment_intent = db
+            .find_payment_intent_by_payment_id_merchant_id(
+                tracking_data
+                    .payment_id
+                    .as_ref()
+                    .get_required_value("payment_id")?,
+                &merchant_account.merchant_id,
+                merchant_account.storage_scheme,
+            )
+            .await?;
+
+        // The payment may have been captured or cancelled through the API in the meantime
+        if payment_intent.status != enums::IntentStatus::RequiresCapture {
+            return process
+                .finish_with_status(db, "CAPTURE_NOT_REQUIRED".to_string())
+                .await;
+        }
+
+        let business_status = match payment_flows::payments_operation_core::<api::Capture, _, _, _>(
+            state,
+            merchant_account.clone(),
+            operations::PaymentCapture,
+            tracking_data,
+            payment_flows::CallConnectorAction::Trigger,
+        )
+        .await
+        {
+            Ok(_) => "COMPLETED_BY_PT",
+            Err(error) => {
+                logger::error!(?error, "Failed to capture the payment");
+                "CAPTURE_FAILED"
+            }
+        };
+
+        // The webhook is sent with the status of the payment after the capture, including when
+        // the capture has failed
+        let payments_response =
+            payment_flows::payments_core::<api::PSync, api::PaymentsResponse, _, _, _>(
+                state,
+                merchant_account.clone(),
+                operations::PaymentStatus,
+                api::PaymentsRetrieveRequest {
+                    resource_id: api::PaymentIdType::PaymentIntentId(payment_intent.payment_id),
+                    merchant_id: Some(merchant_account.merchant_id.clone()),
+                    force_sync: false,
+                    connector: None,
+                    param: None,
+                },
+                services::AuthFlow::Merchant,
+                payment_flows::CallConnectorAction::Trigger,
+            )
+            .await?;
+
+        if let services::ApplicationResponse::Json(payments_response) = payments_response {
+            // The outcome of the capture is already recorded, so the task is not retried if the
+            // event could not be created
+            if let Err(error) =
+                webhooks::create_payment_event(state, &merchant_account, payments_response).await
+            {
+                logger::error!(
+                    ?error,
+                    "Failed to create the event for the scheduled capture"
+                );
+            }
+        }
+
+        process
+            .finish_with_status(db, business_status.to_string())
+            .await
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state, process, error).await
+    }
+}
@@ -0,0 +1,179 @@
+use error_stack::ResultExt;
+use router_env::logger;
+
+use super::{PaymentsExpiryWorkflow, ProcessTrackerWorkflow};
+use crate::{
+    core::{
+        payments::{self as payment_flows, expiry, operations},
+        webhooks,
+    },
+    db::StorageInterface,
+    errors,
+    routes::AppState,
+    scheduler::consumer,
+    services,
+    types::{
+        api,
+        storage::{self, enums, ProcessTrackerExt},
+    },
+    utils::ValueExt,
+};
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow for PaymentsExpiryWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: storage::PaymentExpiryTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("PaymentExpiryTrackingData")?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(&tracking_data.merchant_id)
+            .await?;
+
+        let payment_intent = db
+            .find_payment_intent_by_payment_id_merchant_id(
+                &tracking_data.payment_id,
+                &merchant_account.merchant_id,
+                merchant_account.storage_scheme,
+            )
+            .await?;
+
+        // The payment may have moved on since the expiry was scheduled, in which case there is
+        // nothing to expire
+        let business_status = match (process.name.as_deref(), payment_intent.status) {
+            (Some(expiry::AUTHORIZATION_EXPIRY_TASK), enums::IntentStatus::RequiresCapture) => {
+                match payment_flows::payments_operation_core::<api::Void, _, _, _>(
+                    state,
+                    merchant_account.clone(),
+                    operations::PaymentCancel,
+                    api::PaymentsCancelRequest {
+                        payment_id: payment_intent.payment_id.clone(),
+                        cancellation_reason: Some("authorization_expired".to_string()),
+                    },
+                    payment_flows::CallConnectorAction::Trigger,
+                )
+                .await
+                {
+                    Ok(_) => "COMPLETED_BY_PT",
+                    Err(error) => {
+                        logger::error!(?error, "Failed to cancel the expired authorization");
+                        "VOID_FAILED"
+                    }
+                }
+            }
+            (
+                Some(expiry::INTENT_EXPIRY_TASK),
+                enums::IntentStatus::RequiresPaymentMethod
+                | enums::IntentStatus::RequiresConfirmation,
+            ) => {
+                // The payment is locked as it is by its confirmation, which may be in progress
+                let resource = services::locking::LockResource::Payment {
+                    merchant_id: &merchant_account.merchant_id,
+                    payment_id: &payment_intent.payment_id,
+                };
+                let is_expired = services::locking::with_lock(
+                    state,
+                    resource,
+                    expire_payment_intent(db, &merchant_account, &payment_intent.payment_id),
+                )
+                .await?;
+
+                if !is_expired {
+                    return process
+                        .finish_with_status(db, "EXPIRY_NOT_REQUIRED".to_string())
+                        .await;
+                }
+                "COMPLETED_BY_PT"
+            }
+            _ => {
+                return process
+                    .finish_with_status(db, "EXPIRY_NOT_REQUIRED".to_string())
+                    .await
+            }
+        };
+
+        let payments_response =
+            payment_flows::payments_core::<api::PSync, api::PaymentsResponse, _, _, _>(
+                state,
+                merchant_account.clone(),
+                operations::PaymentStatus,
+                api::PaymentsRetrieveRequest {
+                    resource_id: api::PaymentIdType::PaymentIntentId(payment_intent.payment_id),
+                    merchant_id: Some(merchant_account.merchant_id.clone()),
+                    force_sync: false,
+                    connector: None,
+                    param: None,
+                },
+                services::AuthFlow::Merchant,
+                payment_flows::CallConnectorAction::Trigger,
+            )
+            .await?;
+
+        if let services::ApplicationResponse::Json(payments_response) = payments_response {
+            // The payment is already expired, so the task is not retried if the event could not
+            // be created
+            if let Err(error) =
+                webhooks::create_payment_event(state, &merchant_account, payments_response).await
+            {
+                logger::error!(?error, "Failed to create the event for the expired payment");
+            }
+        }
+
+        process
+            .finish_with_status(db, business_status.to_string())
+            .await
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state, process, error).await
+    }
+}
+
+/// Expires the payment if it is still awaiting a payment method or a confirmation, the status is
+/// read again as the payment may have been confirmed before the lock was acquired
+async fn expire_payment_intent(
+    db: &dyn StorageInterface,
+    merchant_account: &storage::MerchantAccount,
+    payment_id: &str,
+) -> errors::RouterResult<bool> {
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            payment_id,
+            &merchant_account.merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while fetching the payment intent")?;
+
+    if !matches!(
+        payment_intent.status,
+        enums::IntentStatus::RequiresPaymentMethod | enums::IntentStatus::RequiresConfirmation
+    ) {
+        return Ok(false);
+    }
+
+    db.update_payment_intent(
+        payment_intent,
+        storage::PaymentIntentUpdate::PGStatusUpdate {
+            status: enums::IntentStatus::Expired,
+        },
+        merchant_account.storage_scheme,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed while expiring the payment intent")?;
+
+    Ok(true)
+}
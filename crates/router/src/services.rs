@@ -1,9 +1,15 @@
 pub mod api;
 pub mod authentication;
+pub mod circuit_breaker;
 #[cfg(feature = "basilisk")]
 pub mod encryption;
+pub mod file_storage;
 pub mod kms;
+pub mod locking;
 pub mod logger;
+pub mod rate_limit;
+pub mod sliding_window;
+pub mod success_rate;
 
 use std::sync::{atomic, Arc};
 
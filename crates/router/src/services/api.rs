@@ -1,4 +1,5 @@
 mod client;
+mod idempotency;
 pub(crate) mod request;
 
 use std::{
@@ -10,14 +11,14 @@ use std::{
 };
 
 use actix_web::{body, http::header, HttpRequest, HttpResponse, Responder};
-use common_utils::errors::ReportSwitchExt;
+use common_utils::errors::{ErrorSwitch, ReportSwitchExt};
 use error_stack::{report, IntoReport, Report, ResultExt};
 use masking::ExposeOptionInterface;
 use router_env::{instrument, tracing, Tag};
 use serde::Serialize;
 
-use self::request::{ContentType, HeaderExt, RequestBuilderExt};
-pub use self::request::{Method, Request, RequestBuilder};
+pub use self::request::{ContentType, Method, Request, RequestBuilder};
+use self::request::{HeaderExt, RequestBuilderExt};
 use crate::{
     configs::settings::Connectors,
     consts,
@@ -28,7 +29,7 @@ use crate::{
     db::StorageInterface,
     logger,
     routes::{app::AppStateInfo, AppState},
-    services::authentication as auth,
+    services::{authentication as auth, circuit_breaker, rate_limit},
     types::{self, api, storage, ErrorResponse},
 };
 
@@ -82,6 +83,14 @@ pub trait ConnectorIntegration<T, Req, Resp>: ConnectorIntegrationAny<T, Req, Re
         Ok(None)
     }
 
+    /// Multipart form to be sent to the connector, used by flows such as file upload
+    fn get_request_form_data(
+        &self,
+        _req: &types::RouterData<T, Req, Resp>,
+    ) -> CustomResult<Option<reqwest::multipart::Form>, errors::ConnectorError> {
+        Ok(None)
+    }
+
     /// This module can be called before executing a payment flow where a pre-task is needed
     /// Eg: Some connectors requires one-time session token before making a payment, we can add the session token creation logic in this block
     async fn execute_pretasks(
@@ -185,6 +194,12 @@ where
                     logger::debug!(connector_request=?request);
                     let response = call_connector_api(state, request).await;
                     logger::debug!(connector_response=?response);
+                    if let Some(outcome) =
+                        circuit_breaker::ConnectorCallOutcome::from_response(&response)
+                    {
+                        circuit_breaker::record_connector_outcome(state, &req.connector, outcome)
+                            .await;
+                    }
                     match response {
                         Ok(body) => {
                             let response = match body {
@@ -261,6 +276,15 @@ async fn send_request(
                     logger::debug!(?url_encoded_payload);
                     client.body(url_encoded_payload)
                 }
+                Some(ContentType::FormData) => {
+                    let form_data = request
+                        .form_data
+                        .ok_or(errors::ApiClientError::RequestNotSent(
+                            "Form data missing in multipart request".to_string(),
+                        ))
+                        .into_report()?;
+                    client.multipart(form_data)
+                }
                 // If payload needs processing the body cannot have default
                 None => client.body(request.payload.expose_option().unwrap_or_default()),
             }
@@ -368,6 +392,7 @@ pub enum ApplicationResponse<R> {
     TextPlain(String),
     JsonForRedirection(api::RedirectionResponse),
     Form(RedirectForm),
+    FileData((Vec<u8>, mime::Mime)),
 }
 
 #[derive(Debug, Eq, PartialEq, Serialize)]
@@ -420,28 +445,64 @@ pub enum AuthFlow {
     Merchant,
 }
 
-#[instrument(skip(request, payload, state, func, api_auth))]
+/// Authenticates the request and processes it, provided the rate limit of the API key it was made
+/// with is not exceeded. JSON responses are encoded with `encode_json`, so that the response can
+/// be converted to another API, such as the Stripe compatible one.
+#[instrument(skip(request, payload, state, func, api_auth, encode_json))]
 pub async fn server_wrap_util<'a, 'b, A, U, T, Q, F, Fut, E, OErr>(
     state: &'b A,
     request: &'a HttpRequest,
     payload: T,
     func: F,
     api_auth: &dyn auth::AuthenticateAndFetch<U, A>,
-) -> CustomResult<ApplicationResponse<Q>, OErr>
+    encode_json: impl Fn(Q) -> Result<String, HttpResponse>,
+) -> HttpResponse
 where
     F: Fn(&'b A, U, T) -> Fut,
     Fut: Future<Output = CustomResult<ApplicationResponse<Q>, E>>,
     Q: Serialize + Debug + 'a,
     T: Debug,
     A: AppStateInfo,
+    OErr: actix_web::ResponseError + error_stack::Context + Clone,
     CustomResult<ApplicationResponse<Q>, E>: ReportSwitchExt<ApplicationResponse<Q>, OErr>,
-    CustomResult<U, errors::ApiErrorResponse>: ReportSwitchExt<U, OErr>,
+    errors::ApiErrorResponse: ErrorSwitch<OErr>,
 {
-    let auth_out = api_auth
+    let auth_result: Result<_, Report<OErr>> = api_auth
         .authenticate_and_fetch(request.headers(), state)
         .await
-        .switch()?;
-    func(state, auth_out, payload).await.switch()
+        .switch();
+    let (auth_out, auth_type) = match auth_result {
+        Ok(auth_result) => auth_result,
+        Err(error) => return log_and_return_error_response(error),
+    };
+
+    let rate_limit_status = rate_limit::check_rate_limit(state, request, &auth_type).await;
+    let response = match rate_limit_status {
+        Some(ref status) if status.is_exceeded() => {
+            let error = report!(errors::ApiErrorResponse::RateLimitExceeded {
+                retry_after_seconds: status.reset_after_seconds,
+            });
+            let switched_error = error.current_context().switch();
+            log_and_return_error_response(error.change_context(switched_error))
+        }
+        _ => {
+            process_request(
+                state,
+                request,
+                payload,
+                func,
+                auth_out,
+                auth_type.get_merchant_id(),
+                encode_json,
+            )
+            .await
+        }
+    };
+
+    match rate_limit_status {
+        Some(status) => status.apply_headers(response),
+        None => response,
+    }
 }
 
 #[instrument(
@@ -472,16 +533,78 @@ where
     let start_instant = Instant::now();
     logger::info!(tag = ?Tag::BeginRequest);
 
-    let res = match server_wrap_util(state, request, payload, func, api_auth).await {
-        Ok(ApplicationResponse::Json(response)) => match serde_json::to_string(&response) {
-            Ok(res) => http_response_json(res),
-            Err(_) => http_response_err(
-                r#"{
+    let res = server_wrap_util::<_, _, _, _, _, _, _, api_models::errors::types::ApiErrorResponse>(
+        state,
+        request,
+        payload,
+        func,
+        api_auth,
+        encode_json_response,
+    )
+    .await;
+
+    let response_code = res.status().as_u16();
+    let end_instant = Instant::now();
+    let request_duration = end_instant.saturating_duration_since(start_instant);
+    logger::info!(
+        tag = ?Tag::EndRequest,
+        status_code = response_code,
+        time_taken_ms = request_duration.as_millis(),
+    );
+
+    res
+}
+
+fn encode_json_response<Q: Serialize>(response: Q) -> Result<String, HttpResponse> {
+    serde_json::to_string(&response).map_err(|_| {
+        http_response_err(
+            r#"{
                     "error": {
                         "message": "Error serializing response from connector"
                     }
                 }"#,
-            ),
+        )
+    })
+}
+
+/// Processes an authenticated request, replaying the response of the request instead if it was
+/// already processed with the same idempotency key
+async fn process_request<'a, 'b, A, T, U, Q, F, Fut, E, OErr>(
+    state: &'b A,
+    request: &'a HttpRequest,
+    payload: T,
+    func: F,
+    auth_out: U,
+    merchant_id: Option<&str>,
+    encode_json: impl Fn(Q) -> Result<String, HttpResponse>,
+) -> HttpResponse
+where
+    F: Fn(&'b A, U, T) -> Fut,
+    Fut: Future<Output = CustomResult<ApplicationResponse<Q>, E>>,
+    Q: Serialize + Debug + 'a,
+    A: AppStateInfo,
+    OErr: actix_web::ResponseError + error_stack::Context + Clone,
+    CustomResult<ApplicationResponse<Q>, E>: ReportSwitchExt<ApplicationResponse<Q>, OErr>,
+    errors::ApiErrorResponse: ErrorSwitch<OErr>,
+{
+    let idempotency_check: Result<_, Report<OErr>> =
+        idempotency::check_request(state, request, merchant_id)
+            .await
+            .switch();
+    let idempotent_request = match idempotency_check {
+        Ok(idempotency::IdempotencyCheck::Proceed(idempotent_request)) => idempotent_request,
+        Ok(idempotency::IdempotencyCheck::Replay(response)) => return http_response_json(response),
+        Err(error) => return log_and_return_error_response(error),
+    };
+
+    let mut successful_response_body = None;
+    let res = match func(state, auth_out, payload).await.switch() {
+        Ok(ApplicationResponse::Json(response)) => match encode_json(response) {
+            Ok(res) => {
+                successful_response_body = Some(res.clone());
+                http_response_json(res)
+            }
+            Err(error_response) => error_response,
         },
         Ok(ApplicationResponse::StatusOk) => http_response_ok(),
         Ok(ApplicationResponse::TextPlain(text)) => http_response_plaintext(text),
@@ -500,18 +623,18 @@ where
         Ok(ApplicationResponse::Form(response)) => build_redirection_form(&response)
             .respond_to(request)
             .map_into_boxed_body(),
+        Ok(ApplicationResponse::FileData((file_data, content_type))) => {
+            http_response_file_data(file_data, content_type)
+        }
 
         Err(error) => log_and_return_error_response(error),
     };
 
-    let response_code = res.status().as_u16();
-    let end_instant = Instant::now();
-    let request_duration = end_instant.saturating_duration_since(start_instant);
-    logger::info!(
-        tag = ?Tag::EndRequest,
-        status_code = response_code,
-        time_taken_ms = request_duration.as_millis(),
-    );
+    if let Some(idempotent_request) = idempotent_request {
+        idempotent_request
+            .finish(state, successful_response_body.as_deref())
+            .await;
+    }
 
     res
 }
@@ -551,6 +674,17 @@ pub fn http_response_plaintext<T: body::MessageBody + 'static>(res: T) -> HttpRe
         .body(res)
 }
 
+pub fn http_response_file_data<T: body::MessageBody + 'static>(
+    res: T,
+    content_type: mime::Mime,
+) -> HttpResponse {
+    HttpResponse::Ok()
+        .content_type(content_type)
+        .append_header((header::VIA, "Juspay_router"))
+        .append_header((header::STRICT_TRANSPORT_SECURITY, consts::HSTS_HEADER_VALUE))
+        .body(res)
+}
+
 pub fn http_response_ok() -> HttpResponse {
     HttpResponse::Ok()
         .append_header((header::VIA, "Juspay_router"))
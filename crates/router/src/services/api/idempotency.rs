@@ -0,0 +1,303 @@
+//! Replay of the responses of requests retried with the same `Idempotency-Key` header.
+//!
+//! The first request made by a merchant with a key stores a fingerprint of the request in redis,
+//! along with the response once the request is processed. Retries of the request with the same
+//! key get the stored response without the request being processed again, while requests which
+//! reuse the key with different parameters are rejected. Only successful responses are stored,
+//! so that a request which failed can be retried with the same key.
+
+use actix_web::{HttpMessage, HttpRequest};
+use common_utils::{
+    crypto::{GenerateDigest, Sha256},
+    ext_traits::{ByteSliceExt, Encode},
+};
+use error_stack::{report, IntoReport, ResultExt};
+use redis_interface::SetnxReply;
+use router_env::{instrument, logger, tracing};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    core::errors::{self, RouterResult},
+    db::RedisConnInterface,
+    headers, middleware,
+    routes::app::AppStateInfo,
+};
+
+const MAX_IDEMPOTENCY_KEY_LENGTH: usize = 255;
+
+#[derive(Debug, Deserialize, Serialize)]
+struct IdempotencyRecord {
+    request_fingerprint: String,
+    /// The body of the response, once the request has been processed successfully
+    response: Option<String>,
+}
+
+/// Outcome of checking a request against the requests made earlier with its idempotency key
+pub enum IdempotencyCheck {
+    /// The request is to be processed. The key is present if the request was made with one.
+    Proceed(Option<IdempotentRequest>),
+    /// The request was processed already, and this is the body of its response
+    Replay(String),
+}
+
+/// A request being processed under an idempotency key, whose response is to be recorded
+#[derive(Debug)]
+pub struct IdempotentRequest {
+    redis_key: String,
+    in_progress_record: String,
+    request_fingerprint: String,
+}
+
+/// The idempotency key of the request. Requests which do not modify any resource, or are not
+/// made on behalf of a merchant, are not checked for idempotency.
+fn get_idempotency_key(request: &HttpRequest) -> RouterResult<Option<&str>> {
+    if request.method().is_safe() {
+        return Ok(None);
+    }
+
+    request
+        .headers()
+        .get(headers::IDEMPOTENCY_KEY)
+        .map(|idempotency_key| {
+            idempotency_key
+                .to_str()
+                .ok()
+                .filter(|key| !key.is_empty() && key.len() <= MAX_IDEMPOTENCY_KEY_LENGTH)
+                .ok_or_else(|| {
+                    report!(errors::ApiErrorResponse::InvalidRequestData {
+                        message: format!(
+                            "{} must be a non-empty string of at most {MAX_IDEMPOTENCY_KEY_LENGTH} characters",
+                            headers::IDEMPOTENCY_KEY
+                        ),
+                    })
+                })
+        })
+        .transpose()
+}
+
+/// Fingerprint of the request, to detect an idempotency key being reused for a different
+/// request. The raw body of the request is part of the fingerprint through its digest, so that
+/// requests differing only in fields which are masked when logged are told apart.
+fn get_request_fingerprint(request: &HttpRequest) -> RouterResult<String> {
+    let body_digest = request
+        .extensions()
+        .get::<middleware::RequestBodyDigest>()
+        .map(|body_digest| body_digest.0.clone())
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .into_report()
+        .attach_printable("The digest of the body of the idempotent request was not computed")?;
+
+    let request_details = format!(
+        "{} {} {} {body_digest}",
+        request.method(),
+        request.path(),
+        request.query_string()
+    );
+    Sha256
+        .generate_digest(request_details.as_bytes())
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to compute the request fingerprint")
+        .map(hex::encode)
+}
+
+/// Checks the request against the earlier requests of the merchant made with the same
+/// idempotency key. The key is marked as in progress until the request is finished with
+/// [`IdempotentRequest::finish`].
+#[instrument(skip_all)]
+pub async fn check_request<A: AppStateInfo>(
+    state: &A,
+    request: &HttpRequest,
+    merchant_id: Option<&str>,
+) -> RouterResult<IdempotencyCheck> {
+    let (merchant_id, idempotency_key) = match (merchant_id, get_idempotency_key(request)?) {
+        (Some(merchant_id), Some(idempotency_key)) => (merchant_id, idempotency_key),
+        _ => return Ok(IdempotencyCheck::Proceed(None)),
+    };
+
+    let config = state.conf().idempotency;
+    let redis_conn = state
+        .store()
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+    let redis_key = format!("idempotency_{merchant_id}_{idempotency_key}");
+    let request_fingerprint = get_request_fingerprint(request)?;
+    let in_progress_record =
+        Encode::<IdempotencyRecord>::encode_to_string_of_json(&IdempotencyRecord {
+            request_fingerprint: request_fingerprint.clone(),
+            response: None,
+        })
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    // The key is looked up once more if it expires between being set and being read
+    for _ in 0..2 {
+        let reply = redis_conn
+            .set_key_if_not_exists_with_expiry(
+                &redis_key,
+                in_progress_record.as_str(),
+                config.in_progress_ttl_in_seconds.into(),
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to store the idempotency key")?;
+
+        if let SetnxReply::KeySet = reply {
+            return Ok(IdempotencyCheck::Proceed(Some(IdempotentRequest {
+                redis_key,
+                in_progress_record,
+                request_fingerprint,
+            })));
+        }
+
+        let existing_record = match redis_conn
+            .get_key::<Option<Vec<u8>>>(&redis_key)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)?
+        {
+            Some(existing_record) => existing_record,
+            None => continue,
+        };
+        let existing_record: IdempotencyRecord = existing_record
+            .parse_struct("IdempotencyRecord")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse the stored idempotency record")?;
+
+        if existing_record.request_fingerprint != request_fingerprint {
+            return Err(report!(errors::ApiErrorResponse::IdempotencyKeyReused))
+                .attach_printable_lazy(|| format!("Idempotency key {idempotency_key} was reused"));
+        }
+
+        return match existing_record.response {
+            Some(response) => {
+                logger::info!(%idempotency_key, "Replaying the response of the idempotent request");
+                Ok(IdempotencyCheck::Replay(response))
+            }
+            None => Err(report!(errors::ApiErrorResponse::ResourceBusy {
+                resource: "idempotency key".to_string(),
+            })),
+        };
+    }
+
+    // The key keeps expiring as soon as it is set by other requests
+    Err(report!(errors::ApiErrorResponse::ResourceBusy {
+        resource: "idempotency key".to_string(),
+    }))
+}
+
+impl IdempotentRequest {
+    /// Stores the response of the request if it succeeded, to be replayed to the retries of the
+    /// request. Otherwise the key is freed, so that the request can be retried with it.
+    #[instrument(skip_all)]
+    pub async fn finish<A: AppStateInfo>(self, state: &A, response: Option<&str>) {
+        if let Err(error) = self.record_response(state, response).await {
+            logger::error!(
+                ?error,
+                "Failed to record the response of the idempotent request"
+            );
+        }
+    }
+
+    async fn record_response<A: AppStateInfo>(
+        self,
+        state: &A,
+        response: Option<&str>,
+    ) -> RouterResult<()> {
+        let redis_conn = state
+            .store()
+            .get_redis_conn()
+            .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+        match response {
+            Some(response) => {
+                let record =
+                    Encode::<IdempotencyRecord>::encode_to_string_of_json(&IdempotencyRecord {
+                        request_fingerprint: self.request_fingerprint,
+                        response: Some(response.to_string()),
+                    })
+                    .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+                redis_conn
+                    .set_key_with_expiry(
+                        &self.redis_key,
+                        record,
+                        state.conf().idempotency.ttl_in_seconds.into(),
+                    )
+                    .await
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+            }
+            // The key is freed only if it has not expired and been used by another request
+            None => redis_conn
+                .delete_key_if_value_matches(&self.redis_key, &self.in_progress_record)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .map(|_| ()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    fn get_request(idempotency_key: &str, body: &[u8]) -> HttpRequest {
+        let request = actix_web::test::TestRequest::post()
+            .uri("/payments")
+            .insert_header((headers::IDEMPOTENCY_KEY, idempotency_key))
+            .to_http_request();
+        let body_digest = Sha256.generate_digest(body).unwrap();
+        request
+            .extensions_mut()
+            .insert(middleware::RequestBodyDigest(hex::encode(body_digest)));
+        request
+    }
+
+    #[test]
+    fn test_idempotency_key_is_read_from_unsafe_requests_only() {
+        let request = get_request("key_idempotent", b"{}");
+        assert_eq!(
+            get_idempotency_key(&request).unwrap(),
+            Some("key_idempotent")
+        );
+
+        let request = actix_web::test::TestRequest::get()
+            .uri("/payments/pay_idempotent")
+            .insert_header((headers::IDEMPOTENCY_KEY, "key_idempotent"))
+            .to_http_request();
+        assert_eq!(get_idempotency_key(&request).unwrap(), None);
+
+        let request = actix_web::test::TestRequest::post()
+            .uri("/payments")
+            .to_http_request();
+        assert_eq!(get_idempotency_key(&request).unwrap(), None);
+
+        for idempotency_key in [String::new(), "k".repeat(MAX_IDEMPOTENCY_KEY_LENGTH + 1)] {
+            let error = get_idempotency_key(&get_request(&idempotency_key, b"{}")).unwrap_err();
+            assert!(matches!(
+                error.current_context(),
+                errors::ApiErrorResponse::InvalidRequestData { .. }
+            ));
+        }
+    }
+
+    #[test]
+    fn test_request_fingerprint_depends_on_the_raw_body() {
+        let fingerprint =
+            |body: &[u8]| get_request_fingerprint(&get_request("key_idempotent", body)).unwrap();
+
+        assert_eq!(
+            fingerprint(br#"{"amount":6540}"#),
+            fingerprint(br#"{"amount":6540}"#)
+        );
+        assert_ne!(
+            fingerprint(br#"{"amount":6540}"#),
+            fingerprint(br#"{"amount":100}"#)
+        );
+
+        // The digest of the body is computed by the middleware before the request is checked
+        let request = actix_web::test::TestRequest::post()
+            .uri("/payments")
+            .to_http_request();
+        assert!(get_request_fingerprint(&request).is_err());
+    }
+}
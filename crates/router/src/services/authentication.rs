@@ -13,8 +13,8 @@ use crate::{
     },
     db::StorageInterface,
     routes::{app::AppStateInfo, AppState},
-    services::api,
-    types::storage,
+    services::{api, rate_limit},
+    types::{api as api_types, storage},
     utils::OptionExt,
 };
 
@@ -27,7 +27,42 @@ where
         &self,
         request_headers: &HeaderMap,
         state: &A,
-    ) -> RouterResult<T>;
+    ) -> RouterResult<(T, AuthenticationType)>;
+}
+
+/// The means by which a request was authenticated, for the request processing common to all the
+/// APIs
+#[derive(Clone, Debug)]
+pub enum AuthenticationType {
+    ApiKey {
+        merchant_id: String,
+        key_id: String,
+        /// The rate limits configured for the API key, or else for the merchant
+        rate_limits: api_types::RateLimits,
+    },
+    AdminApiKey,
+    MerchantId {
+        merchant_id: String,
+    },
+    PublishableKey {
+        merchant_id: String,
+    },
+    Jwt {
+        merchant_id: Option<String>,
+    },
+}
+
+impl AuthenticationType {
+    /// The merchant on whose behalf the request is made, if any
+    pub fn get_merchant_id(&self) -> Option<&str> {
+        match self {
+            Self::ApiKey { merchant_id, .. }
+            | Self::MerchantId { merchant_id }
+            | Self::PublishableKey { merchant_id } => Some(merchant_id),
+            Self::Jwt { merchant_id } => merchant_id.as_deref(),
+            Self::AdminApiKey => None,
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -42,7 +77,7 @@ where
         &self,
         request_headers: &HeaderMap,
         state: &A,
-    ) -> RouterResult<storage::MerchantAccount> {
+    ) -> RouterResult<(storage::MerchantAccount, AuthenticationType)> {
         let api_key = get_api_key(request_headers)
             .change_context(errors::ApiErrorResponse::Unauthorized)?
             .trim();
@@ -79,7 +114,7 @@ where
                 .attach_printable("API key has expired");
         }
 
-        state
+        let merchant_account = state
             .store()
             .find_merchant_account_by_merchant_id(&stored_api_key.merchant_id)
             .await
@@ -89,7 +124,17 @@ where
                 } else {
                     e.change_context(errors::ApiErrorResponse::InternalServerError)
                 }
-            })
+            })?;
+
+        let rate_limits = rate_limit::get_rate_limit_overrides(&stored_api_key, &merchant_account);
+        Ok((
+            merchant_account,
+            AuthenticationType::ApiKey {
+                merchant_id: stored_api_key.merchant_id,
+                key_id: stored_api_key.key_id,
+                rate_limits,
+            },
+        ))
     }
 }
 
@@ -105,7 +150,7 @@ where
         &self,
         request_headers: &HeaderMap,
         state: &A,
-    ) -> RouterResult<()> {
+    ) -> RouterResult<((), AuthenticationType)> {
         let admin_api_key =
             get_api_key(request_headers).change_context(errors::ApiErrorResponse::Unauthorized)?;
         let conf = state.conf();
@@ -113,7 +158,7 @@ where
             Err(report!(errors::ApiErrorResponse::Unauthorized)
                 .attach_printable("Admin Authentication Failure"))?;
         }
-        Ok(())
+        Ok(((), AuthenticationType::AdminApiKey))
     }
 }
 
@@ -126,8 +171,8 @@ impl AuthenticateAndFetch<storage::MerchantAccount, AppState> for MerchantIdAuth
         &self,
         _request_headers: &HeaderMap,
         state: &AppState,
-    ) -> RouterResult<storage::MerchantAccount> {
-        state
+    ) -> RouterResult<(storage::MerchantAccount, AuthenticationType)> {
+        let merchant_account = state
             .store
             .find_merchant_account_by_merchant_id(self.0.as_ref())
             .await
@@ -137,7 +182,12 @@ impl AuthenticateAndFetch<storage::MerchantAccount, AppState> for MerchantIdAuth
                 } else {
                     e.change_context(errors::ApiErrorResponse::InternalServerError)
                 }
-            })
+            })?;
+        let merchant_id = merchant_account.merchant_id.clone();
+        Ok((
+            merchant_account,
+            AuthenticationType::MerchantId { merchant_id },
+        ))
     }
 }
 
@@ -150,10 +200,10 @@ impl AuthenticateAndFetch<storage::MerchantAccount, AppState> for PublishableKey
         &self,
         request_headers: &HeaderMap,
         state: &AppState,
-    ) -> RouterResult<storage::MerchantAccount> {
+    ) -> RouterResult<(storage::MerchantAccount, AuthenticationType)> {
         let publishable_key =
             get_api_key(request_headers).change_context(errors::ApiErrorResponse::Unauthorized)?;
-        state
+        let merchant_account = state
             .store
             .find_merchant_account_by_publishable_key(publishable_key)
             .await
@@ -163,7 +213,12 @@ impl AuthenticateAndFetch<storage::MerchantAccount, AppState> for PublishableKey
                 } else {
                     e.change_context(errors::ApiErrorResponse::InternalServerError)
                 }
-            })
+            })?;
+        let merchant_id = merchant_account.merchant_id.clone();
+        Ok((
+            merchant_account,
+            AuthenticationType::PublishableKey { merchant_id },
+        ))
     }
 }
 
@@ -185,10 +240,11 @@ where
         &self,
         request_headers: &HeaderMap,
         state: &A,
-    ) -> RouterResult<()> {
+    ) -> RouterResult<((), AuthenticationType)> {
         let mut token = get_jwt(request_headers)?;
         token = strip_jwt_token(token)?;
-        decode_jwt::<JwtAuthPayloadFetchUnit>(token, state).map(|_| ())
+        decode_jwt::<JwtAuthPayloadFetchUnit>(token, state)
+            .map(|_| ((), AuthenticationType::Jwt { merchant_id: None }))
     }
 }
 
@@ -206,15 +262,21 @@ where
         &self,
         request_headers: &HeaderMap,
         state: &A,
-    ) -> RouterResult<storage::MerchantAccount> {
+    ) -> RouterResult<(storage::MerchantAccount, AuthenticationType)> {
         let mut token = get_jwt(request_headers)?;
         token = strip_jwt_token(token)?;
         let payload = decode_jwt::<JwtAuthPayloadFetchMerchantAccount>(token, state)?;
-        state
+        let merchant_account = state
             .store()
             .find_merchant_account_by_merchant_id(&payload.merchant_id)
             .await
-            .change_context(errors::ApiErrorResponse::InvalidJwtToken)
+            .change_context(errors::ApiErrorResponse::InvalidJwtToken)?;
+        Ok((
+            merchant_account,
+            AuthenticationType::Jwt {
+                merchant_id: Some(payload.merchant_id),
+            },
+        ))
     }
 }
 
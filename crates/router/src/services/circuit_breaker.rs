@@ -0,0 +1,379 @@
+//! Circuit breakers on the connectors.
+//!
+//! The outcomes of the requests made to each connector are counted in redis over a sliding window.
+//! When the requests to a connector time out, cannot be sent, or are answered with a 5xx status
+//! code at a rate above the configured threshold, the circuit of the connector is opened and
+//! routing skips the connector. Once the circuit has been open for the configured duration, it is
+//! half-open, and a single trial request at a time is let through to the connector. The circuit is
+//! closed when a request to the connector succeeds, and opened again when it fails.
+//!
+//! Connectors are treated as available when their circuit cannot be read from redis, so that an
+//! unavailable redis does not take all the connectors out of routing.
+
+use api_models::{admin as admin_api, enums as api_enums};
+use common_utils::date_time;
+use redis_interface::{errors::RedisError, SetnxReply};
+use router_env::{instrument, logger, tracing};
+
+use super::sliding_window::{SlidingWindow, WindowCounts};
+use crate::{
+    configs::settings,
+    core::errors::{self, CustomResult},
+    db::RedisConnInterface,
+    routes::AppState,
+    types,
+};
+
+const REQUESTS_FIELD: &str = "requests";
+const ERRORS_FIELD: &str = "errors";
+const TIMEOUTS_FIELD: &str = "timeouts";
+const SERVER_ERRORS_FIELD: &str = "server_errors";
+
+/// Seconds after which the trial request to a connector whose circuit is half-open is considered
+/// lost, in case the connector was skipped after all or the outcome could not be recorded
+const TRIAL_REQUEST_TTL_IN_SECONDS: i64 = 60;
+
+/// Outcome of a request made to a connector, as far as the health of the connector is concerned
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ConnectorCallOutcome {
+    /// The connector responded without a server error. Error responses with a 4xx status code are
+    /// caused by the request, and are not counted against the connector.
+    Success,
+    /// The connector did not respond before the request timed out
+    Timeout,
+    /// The connector responded with a 5xx status code
+    ServerError,
+    /// The request could not be sent to the connector, or its response could not be read
+    RequestFailed,
+}
+
+impl ConnectorCallOutcome {
+    /// The outcome of the request made to a connector, `None` if the request failed before it was
+    /// sent, for reasons which have nothing to do with the connector
+    pub fn from_response(
+        response: &CustomResult<Result<types::Response, types::Response>, errors::ApiClientError>,
+    ) -> Option<Self> {
+        match response {
+            Ok(Ok(_)) => Some(Self::Success),
+            Ok(Err(response)) if (500..=599).contains(&response.status_code) => {
+                Some(Self::ServerError)
+            }
+            Ok(Err(_)) => Some(Self::Success),
+            Err(error) => match error.current_context() {
+                errors::ApiClientError::RequestTimeoutReceived => Some(Self::Timeout),
+                errors::ApiClientError::RequestNotSent(_)
+                | errors::ApiClientError::ResponseDecodingFailed => Some(Self::RequestFailed),
+                _ => None,
+            },
+        }
+    }
+
+    fn is_failure(self) -> bool {
+        self != Self::Success
+    }
+
+    /// The counters incremented for a request with this outcome
+    fn counter_fields(self) -> &'static [&'static str] {
+        match self {
+            Self::Success => &[REQUESTS_FIELD],
+            Self::Timeout => &[REQUESTS_FIELD, ERRORS_FIELD, TIMEOUTS_FIELD],
+            Self::ServerError => &[REQUESTS_FIELD, ERRORS_FIELD, SERVER_ERRORS_FIELD],
+            Self::RequestFailed => &[REQUESTS_FIELD, ERRORS_FIELD],
+        }
+    }
+}
+
+/// Number of requests made to a connector over the sliding window ending now
+#[derive(Debug, Default, PartialEq)]
+struct RequestCounts {
+    requests: f64,
+    errors: f64,
+    timeouts: f64,
+    server_errors: f64,
+}
+
+impl RequestCounts {
+    fn new(counts: &WindowCounts) -> Self {
+        Self {
+            requests: counts.get(REQUESTS_FIELD),
+            errors: counts.get(ERRORS_FIELD),
+            timeouts: counts.get(TIMEOUTS_FIELD),
+            server_errors: counts.get(SERVER_ERRORS_FIELD),
+        }
+    }
+
+    /// Percentage of the requests which failed
+    fn error_rate(&self) -> f64 {
+        if self.requests > 0.0 {
+            self.errors * 100.0 / self.requests
+        } else {
+            0.0
+        }
+    }
+
+    fn exceeds_threshold(&self, config: &settings::CircuitBreaker) -> bool {
+        self.requests >= f64::from(config.minimum_requests)
+            && self.error_rate() >= f64::from(config.error_rate_threshold)
+    }
+}
+
+fn get_circuit_state(
+    opened_at: Option<i64>,
+    now: i64,
+    config: &settings::CircuitBreaker,
+) -> api_enums::CircuitBreakerState {
+    match opened_at {
+        None => api_enums::CircuitBreakerState::Closed,
+        Some(opened_at) if now - opened_at < i64::from(config.open_duration_in_seconds) => {
+            api_enums::CircuitBreakerState::Open
+        }
+        Some(_) => api_enums::CircuitBreakerState::HalfOpen,
+    }
+}
+
+fn get_window(connector: &str, now: i64, config: &settings::CircuitBreaker) -> SlidingWindow {
+    SlidingWindow::new(
+        format!("circuit_breaker_{connector}"),
+        now,
+        config.window_in_seconds,
+    )
+}
+
+fn get_opened_at_key(connector: &str) -> String {
+    format!("circuit_breaker_{connector}_opened_at")
+}
+
+fn get_trial_key(connector: &str) -> String {
+    format!("circuit_breaker_{connector}_trial")
+}
+
+/// Records the outcome of a request made to the connector, and opens or closes the circuit of the
+/// connector if required. Failures to update the circuit in redis are logged and otherwise
+/// ignored, so that they do not affect the request.
+#[instrument(skip_all)]
+pub async fn record_connector_outcome(
+    state: &AppState,
+    connector: &str,
+    outcome: ConnectorCallOutcome,
+) {
+    let config = &state.conf.circuit_breaker;
+    if !config.enabled {
+        return;
+    }
+
+    if let Err(error) = update_circuit(state, config, connector, outcome).await {
+        logger::error!(
+            ?error,
+            %connector,
+            "Failed to record the outcome of the connector request"
+        );
+    }
+}
+
+async fn update_circuit(
+    state: &AppState,
+    config: &settings::CircuitBreaker,
+    connector: &str,
+    outcome: ConnectorCallOutcome,
+) -> CustomResult<(), RedisError> {
+    let redis_conn = state.store.get_redis_conn()?;
+    let now = date_time::now_unix_timestamp();
+    let window = get_window(connector, now, config);
+    let opened_at_key = get_opened_at_key(connector);
+
+    window
+        .increment_counters(&redis_conn, outcome.counter_fields())
+        .await?;
+
+    let opened_at = redis_conn.get_key::<Option<i64>>(&opened_at_key).await?;
+    match get_circuit_state(opened_at, now, config) {
+        api_enums::CircuitBreakerState::Closed => {
+            if !outcome.is_failure() {
+                return Ok(());
+            }
+
+            let counts = RequestCounts::new(&window.get_counts(&redis_conn).await?);
+            if counts.exceeds_threshold(config)
+                && redis_conn.set_key_if_not_exist(&opened_at_key, now).await? == SetnxReply::KeySet
+            {
+                logger::warn!(
+                    %connector,
+                    error_rate = counts.error_rate(),
+                    "Opened the circuit of the connector"
+                );
+            }
+        }
+        // Requests sent before the circuit was opened may complete after it was opened, and do
+        // not affect the circuit
+        api_enums::CircuitBreakerState::Open => {}
+        api_enums::CircuitBreakerState::HalfOpen => {
+            if outcome.is_failure() {
+                redis_conn.set_key(&opened_at_key, now).await?;
+                logger::warn!(%connector, "Opened the circuit of the connector again");
+            } else {
+                // The failures counted before the circuit was opened would otherwise open it again
+                window.reset_counters(&redis_conn).await?;
+                redis_conn.delete_key(&opened_at_key).await?;
+                logger::info!(%connector, "Closed the circuit of the connector");
+            }
+            redis_conn.delete_key(&get_trial_key(connector)).await?;
+        }
+    }
+    Ok(())
+}
+
+async fn is_connector_available(
+    state: &AppState,
+    config: &settings::CircuitBreaker,
+    connector: &str,
+) -> CustomResult<bool, RedisError> {
+    let redis_conn = state.store.get_redis_conn()?;
+    let now = date_time::now_unix_timestamp();
+
+    let opened_at = redis_conn
+        .get_key::<Option<i64>>(&get_opened_at_key(connector))
+        .await?;
+    match get_circuit_state(opened_at, now, config) {
+        api_enums::CircuitBreakerState::Closed => Ok(true),
+        api_enums::CircuitBreakerState::Open => Ok(false),
+        // Only one request at a time is let through to a connector whose circuit is half-open
+        api_enums::CircuitBreakerState::HalfOpen => redis_conn
+            .set_key_if_not_exists_with_expiry(
+                &get_trial_key(connector),
+                now,
+                TRIAL_REQUEST_TTL_IN_SECONDS,
+            )
+            .await
+            .map(|reply| reply == SetnxReply::KeySet),
+    }
+}
+
+/// Removes the connectors which are to be skipped from the connectors given by routing, keeping
+/// the order of the rest. All the connectors are kept if none of them is available, as the payment
+/// cannot be processed otherwise.
+#[instrument(skip_all)]
+pub async fn filter_available_connectors(
+    state: &AppState,
+    connectors: Vec<api_enums::RoutableConnectors>,
+) -> Vec<api_enums::RoutableConnectors> {
+    let config = &state.conf.circuit_breaker;
+    if !config.enabled {
+        return connectors;
+    }
+
+    let mut available_connectors = Vec::with_capacity(connectors.len());
+    for connector in &connectors {
+        let connector_name = connector.to_string();
+        let is_available = is_connector_available(state, config, &connector_name)
+            .await
+            .unwrap_or_else(|error| {
+                logger::error!(
+                    ?error,
+                    connector = %connector_name,
+                    "Failed to check the circuit of the connector"
+                );
+                true
+            });
+
+        if is_available {
+            available_connectors.push(*connector);
+        } else {
+            logger::info!(connector = %connector_name, "Skipping connector with open circuit");
+        }
+    }
+
+    if available_connectors.is_empty() {
+        logger::warn!("The circuits of all the routed connectors are open");
+        return connectors;
+    }
+    available_connectors
+}
+
+/// The state of the circuit of the connector, along with the outcomes of the requests made to it
+/// over the last window
+#[instrument(skip_all)]
+pub async fn get_connector_health(
+    state: &AppState,
+    connector: api_enums::RoutableConnectors,
+) -> CustomResult<admin_api::ConnectorHealthResponse, RedisError> {
+    let config = &state.conf.circuit_breaker;
+    let redis_conn = state.store.get_redis_conn()?;
+    let now = date_time::now_unix_timestamp();
+    let connector_name = connector.to_string();
+
+    let counts = RequestCounts::new(
+        &get_window(&connector_name, now, config)
+            .get_counts(&redis_conn)
+            .await?,
+    );
+    let opened_at = redis_conn
+        .get_key::<Option<i64>>(&get_opened_at_key(&connector_name))
+        .await?;
+
+    #[allow(clippy::as_conversions)]
+    let round = |count: f64| count.round() as u64;
+    Ok(admin_api::ConnectorHealthResponse {
+        connector,
+        state: get_circuit_state(opened_at, now, config),
+        requests: round(counts.requests),
+        errors: round(counts.errors),
+        timeouts: round(counts.timeouts),
+        server_errors: round(counts.server_errors),
+        error_rate: counts.error_rate(),
+        opened_at: opened_at
+            .and_then(|opened_at| time::OffsetDateTime::from_unix_timestamp(opened_at).ok())
+            .map(date_time::convert_to_pdt),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> settings::CircuitBreaker {
+        settings::CircuitBreaker {
+            enabled: true,
+            window_in_seconds: 60,
+            minimum_requests: 20,
+            error_rate_threshold: 50,
+            open_duration_in_seconds: 30,
+        }
+    }
+
+    #[test]
+    fn test_circuit_state() {
+        let config = config();
+
+        assert_eq!(
+            get_circuit_state(None, 1_677_657_615, &config),
+            api_enums::CircuitBreakerState::Closed
+        );
+        assert_eq!(
+            get_circuit_state(Some(1_677_657_600), 1_677_657_615, &config),
+            api_enums::CircuitBreakerState::Open
+        );
+        assert_eq!(
+            get_circuit_state(Some(1_677_657_600), 1_677_657_630, &config),
+            api_enums::CircuitBreakerState::HalfOpen
+        );
+    }
+
+    #[test]
+    fn test_request_counts_exceed_threshold() {
+        let counts = |requests: f64, errors: f64| RequestCounts {
+            requests,
+            errors,
+            ..Default::default()
+        };
+        let config = config();
+
+        let request_counts = counts(20.0, 10.0);
+        assert_eq!(request_counts.error_rate(), 50.0);
+        assert!(request_counts.exceeds_threshold(&config));
+
+        // Too few requests to decide on the health of the connector
+        assert!(!counts(10.0, 10.0).exceeds_threshold(&config));
+
+        assert!(!counts(50.0, 10.0).exceeds_threshold(&config));
+    }
+}
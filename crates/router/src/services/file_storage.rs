@@ -0,0 +1,132 @@
+use std::path::PathBuf;
+
+use error_stack::{IntoReport, ResultExt};
+
+use crate::{
+    configs::settings::{FileStorageBackend, FileStorageConfig},
+    core::errors::{CustomResult, FileStorageError},
+};
+
+/// Storage in which the files uploaded by merchants are kept, identified by their file keys
+#[async_trait::async_trait]
+pub trait FileStorageInterface: dyn_clone::DynClone + Send + Sync {
+    async fn upload_file(
+        &self,
+        file_key: &str,
+        file: Vec<u8>,
+    ) -> CustomResult<(), FileStorageError>;
+
+    async fn retrieve_file(&self, file_key: &str) -> CustomResult<Vec<u8>, FileStorageError>;
+
+    async fn delete_file(&self, file_key: &str) -> CustomResult<(), FileStorageError>;
+}
+
+dyn_clone::clone_trait_object!(FileStorageInterface);
+
+pub fn get_file_storage_client(config: &FileStorageConfig) -> Box<dyn FileStorageInterface> {
+    match config.backend {
+        FileStorageBackend::FileSystem => Box::new(FileSystemStorage {
+            base_path: PathBuf::from(&config.file_system_path),
+        }),
+    }
+}
+
+/// Stores the files in a directory on the local file system
+#[derive(Clone, Debug)]
+pub struct FileSystemStorage {
+    base_path: PathBuf,
+}
+
+impl FileSystemStorage {
+    fn get_file_path(&self, file_key: &str) -> PathBuf {
+        self.base_path.join(file_key)
+    }
+}
+
+#[async_trait::async_trait]
+impl FileStorageInterface for FileSystemStorage {
+    async fn upload_file(
+        &self,
+        file_key: &str,
+        file: Vec<u8>,
+    ) -> CustomResult<(), FileStorageError> {
+        let file_path = self.get_file_path(file_key);
+        if let Some(parent) = file_path.parent() {
+            tokio::fs::create_dir_all(parent)
+                .await
+                .into_report()
+                .change_context(FileStorageError::UploadFailed)
+                .attach_printable("Failed to create the directory for the file")?;
+        }
+        tokio::fs::write(&file_path, file)
+            .await
+            .into_report()
+            .change_context(FileStorageError::UploadFailed)
+            .attach_printable_lazy(|| format!("Failed to write the file {}", file_path.display()))
+    }
+
+    async fn retrieve_file(&self, file_key: &str) -> CustomResult<Vec<u8>, FileStorageError> {
+        let file_path = self.get_file_path(file_key);
+        tokio::fs::read(&file_path)
+            .await
+            .into_report()
+            .change_context(FileStorageError::RetrieveFailed)
+            .attach_printable_lazy(|| format!("Failed to read the file {}", file_path.display()))
+    }
+
+    async fn delete_file(&self, file_key: &str) -> CustomResult<(), FileStorageError> {
+        let file_path = self.get_file_path(file_key);
+        tokio::fs::remove_file(&file_path)
+            .await
+            .into_report()
+            .change_context(FileStorageError::DeleteFailed)
+            .attach_printable_lazy(|| format!("Failed to delete the file {}", file_path.display()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    #[actix_rt::test]
+    async fn test_file_system_storage_round_trip() {
+        let base_path = std::env::temp_dir().join(uuid::Uuid::new_v4().to_string());
+        let file_storage = get_file_storage_client(&FileStorageConfig {
+            backend: FileStorageBackend::FileSystem,
+            file_system_path: base_path.to_string_lossy().into_owned(),
+            max_file_size: 1024,
+        });
+        let file_key = "merchant_id/file_id";
+
+        file_storage
+            .upload_file(file_key, b"dispute evidence".to_vec())
+            .await
+            .unwrap();
+        assert_eq!(
+            file_storage.retrieve_file(file_key).await.unwrap(),
+            b"dispute evidence".to_vec()
+        );
+
+        file_storage.delete_file(file_key).await.unwrap();
+        assert!(matches!(
+            file_storage
+                .retrieve_file(file_key)
+                .await
+                .unwrap_err()
+                .current_context(),
+            FileStorageError::RetrieveFailed
+        ));
+        assert!(matches!(
+            file_storage
+                .delete_file(file_key)
+                .await
+                .unwrap_err()
+                .current_context(),
+            FileStorageError::DeleteFailed
+        ));
+
+        tokio::fs::remove_dir_all(base_path).await.unwrap();
+    }
+}
@@ -0,0 +1,206 @@
+//! Locks held on payments and refunds while they are being processed, so that concurrent requests
+//! for the same resource, such as a confirm racing a capture or an incoming webhook racing a
+//! payment sync, are processed one after the other. Access tokens of connectors are also locked
+//! while they are being refreshed, so that only one request refreshes them.
+//!
+//! A lock is a redis key which is set only if it does not exist already. The key holds a token
+//! unique to the owner of the lock, so that only the owner can release it, and expires after
+//! the configured duration so that a lock whose owner failed to release it does not block the
+//! resource forever.
+
+use std::future::Future;
+
+use error_stack::{report, ResultExt};
+use redis_interface::SetnxReply;
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    consts,
+    core::errors::{self, RouterResult},
+    db::RedisConnInterface,
+    routes::AppState,
+    utils,
+};
+
+/// A resource which is locked while it is being modified
+#[derive(Clone, Copy, Debug)]
+pub enum LockResource<'a> {
+    Payment {
+        merchant_id: &'a str,
+        payment_id: &'a str,
+    },
+    Refund {
+        merchant_id: &'a str,
+        refund_id: &'a str,
+    },
+    AccessToken {
+        merchant_id: &'a str,
+        connector: &'a str,
+    },
+}
+
+impl LockResource<'_> {
+    fn get_key(&self) -> String {
+        match self {
+            Self::Payment {
+                merchant_id,
+                payment_id,
+            } => format!("lock_payment_{merchant_id}_{payment_id}"),
+            Self::Refund {
+                merchant_id,
+                refund_id,
+            } => format!("lock_refund_{merchant_id}_{refund_id}"),
+            Self::AccessToken {
+                merchant_id,
+                connector,
+            } => format!("lock_access_token_{merchant_id}_{connector}"),
+        }
+    }
+
+    fn get_resource_name(&self) -> &'static str {
+        match self {
+            Self::Payment { .. } => "payment",
+            Self::Refund { .. } => "refund",
+            Self::AccessToken { .. } => "access_token",
+        }
+    }
+}
+
+/// A lock held on a resource. The lock must be released with [`RedisLock::release`] once the
+/// resource is processed, otherwise the resource stays locked until the lock expires.
+#[must_use]
+#[derive(Debug)]
+pub struct RedisLock {
+    key: String,
+    owner_token: String,
+}
+
+/// Acquires the lock on the resource, waiting for the lock to be released if it is held by
+/// another request. Fails with [`errors::ApiErrorResponse::ResourceBusy`] if the lock is still
+/// held once all the retries are exhausted.
+#[instrument(skip(state))]
+pub async fn acquire_lock(state: &AppState, resource: LockResource<'_>) -> RouterResult<RedisLock> {
+    let lock_settings = &state.conf.lock_settings;
+
+    for attempt in 0..=lock_settings.lock_retries {
+        if attempt > 0 {
+            tokio::time::sleep(std::time::Duration::from_millis(
+                lock_settings.delay_between_retries_in_milliseconds.into(),
+            ))
+            .await;
+        }
+
+        match try_acquire_lock(state, resource).await? {
+            Some(lock) => return Ok(lock),
+            None => {
+                logger::debug!(key = %resource.get_key(), attempt, "Lock is held by another request")
+            }
+        }
+    }
+
+    Err(report!(errors::ApiErrorResponse::ResourceBusy {
+        resource: resource.get_resource_name().to_string(),
+    }))
+    .attach_printable_lazy(|| format!("Lock {} is held by another request", resource.get_key()))
+}
+
+/// Acquires the lock on the resource without waiting, `None` if the lock is held by another
+/// request
+#[instrument(skip(state))]
+pub async fn try_acquire_lock(
+    state: &AppState,
+    resource: LockResource<'_>,
+) -> RouterResult<Option<RedisLock>> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get the redis connection for acquiring the lock")?;
+
+    let key = resource.get_key();
+    let owner_token = utils::generate_id(consts::ID_LENGTH, "lock");
+
+    let reply = redis_conn
+        .set_key_if_not_exists_with_expiry(
+            &key,
+            owner_token.as_str(),
+            state.conf.lock_settings.redis_lock_expiry_seconds.into(),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| format!("Failed to acquire the lock {key}"))?;
+
+    Ok(match reply {
+        SetnxReply::KeySet => Some(RedisLock { key, owner_token }),
+        SetnxReply::KeyNotSet => None,
+    })
+}
+
+impl RedisLock {
+    /// Releases the lock, provided it is still held by this owner. A lock which has expired and
+    /// been acquired by another request in the meantime is left untouched. Failures are only
+    /// logged, as the lock is released on expiry anyway.
+    #[instrument(skip(state))]
+    pub async fn release(self, state: &AppState) {
+        let result = match state.store.get_redis_conn() {
+            Ok(redis_conn) => {
+                redis_conn
+                    .delete_key_if_value_matches(&self.key, &self.owner_token)
+                    .await
+            }
+            Err(error) => Err(error),
+        };
+
+        match result {
+            Ok(true) => {}
+            Ok(false) => logger::warn!(key = %self.key, "Lock expired before it was released"),
+            Err(error) => logger::error!(?error, key = %self.key, "Failed to release the lock"),
+        }
+    }
+}
+
+/// Performs the operation while holding the lock on the resource. The lock is released once the
+/// operation completes, irrespective of whether it succeeds.
+pub async fn with_lock<T>(
+    state: &AppState,
+    resource: LockResource<'_>,
+    operation: impl Future<Output = RouterResult<T>>,
+) -> RouterResult<T> {
+    let lock = acquire_lock(state, resource).await?;
+    let result = operation.await;
+    lock.release(state).await;
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lock_keys_are_distinct_per_resource() {
+        let payment = LockResource::Payment {
+            merchant_id: "merchant_locking",
+            payment_id: "pay_locking",
+        };
+        let refund = LockResource::Refund {
+            merchant_id: "merchant_locking",
+            refund_id: "pay_locking",
+        };
+        let access_token = LockResource::AccessToken {
+            merchant_id: "merchant_locking",
+            connector: "stripe",
+        };
+
+        assert_eq!(
+            payment.get_key(),
+            "lock_payment_merchant_locking_pay_locking"
+        );
+        assert_eq!(refund.get_key(), "lock_refund_merchant_locking_pay_locking");
+        assert_eq!(
+            access_token.get_key(),
+            "lock_access_token_merchant_locking_stripe"
+        );
+        assert_eq!(payment.get_resource_name(), "payment");
+        assert_eq!(refund.get_resource_name(), "refund");
+    }
+}
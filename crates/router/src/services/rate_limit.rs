@@ -0,0 +1,279 @@
+//! Rate limits on the requests made with API keys.
+//!
+//! The requests made with each API key are counted in redis over a sliding window, separately for
+//! requests creating or confirming payments, requests retrieving or listing resources, and any other
+//! requests. The limits are configured for the server, and can be overridden for a merchant and
+//! for each of its API keys. The state of the limit is returned to the caller in the `RateLimit-*`
+//! headers of the response.
+//!
+//! Requests are let through when the counters cannot be read from redis, so that an unavailable
+//! redis does not reject all the requests.
+
+use actix_web::{
+    http::header::{HeaderName, HeaderValue},
+    HttpRequest, HttpResponse,
+};
+use common_utils::{date_time, ext_traits::ValueExt};
+use error_stack::{report, ResultExt};
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    configs::settings,
+    core::errors::{self, RouterResult},
+    db::RedisConnInterface,
+    headers,
+    routes::app::AppStateInfo,
+    services::{authentication::AuthenticationType, sliding_window::SlidingWindow},
+    types::{api, storage},
+};
+
+/// The kinds of requests which are counted against separate limits
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum RateLimitCategory {
+    PaymentWrite,
+    Read,
+    Write,
+}
+
+impl RateLimitCategory {
+    fn from_request(request: &HttpRequest) -> Self {
+        if request.method().is_safe() {
+            return Self::Read;
+        }
+
+        match request.match_pattern().as_deref() {
+            Some("/payments" | "/payments/{payment_id}/confirm") => Self::PaymentWrite,
+            _ => Self::Write,
+        }
+    }
+
+    fn get_limit(self, overrides: &api::RateLimits, config: &settings::RateLimit) -> u32 {
+        match self {
+            Self::PaymentWrite => overrides
+                .payment_write_requests
+                .unwrap_or(config.payment_write_requests),
+            Self::Read => overrides.read_requests.unwrap_or(config.read_requests),
+            Self::Write => overrides.write_requests.unwrap_or(config.write_requests),
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::PaymentWrite => "payment_write",
+            Self::Read => "read",
+            Self::Write => "write",
+        }
+    }
+}
+
+/// The state of the rate limit applicable to a request
+#[derive(Clone, Debug)]
+pub struct RateLimitStatus {
+    pub limit: u64,
+    pub remaining: u64,
+    /// Number of seconds until the current window ends
+    pub reset_after_seconds: u64,
+    exceeded: bool,
+}
+
+impl RateLimitStatus {
+    /// Whether the request is to be rejected, as the limit has been reached
+    pub fn is_exceeded(&self) -> bool {
+        self.exceeded
+    }
+
+    /// Adds the `RateLimit-*` headers describing the state of the limit to the response
+    pub fn apply_headers(&self, mut response: HttpResponse) -> HttpResponse {
+        let mut rate_limit_headers = vec![
+            (headers::RATE_LIMIT_LIMIT, self.limit),
+            (headers::RATE_LIMIT_REMAINING, self.remaining),
+            (headers::RATE_LIMIT_RESET, self.reset_after_seconds),
+        ];
+        if self.exceeded {
+            rate_limit_headers.push((headers::RETRY_AFTER, self.reset_after_seconds));
+        }
+
+        let response_headers = response.headers_mut();
+        for (name, value) in rate_limit_headers {
+            // The header names are valid, but not in the lowercase form `from_static` requires
+            if let Ok(name) = HeaderName::try_from(name) {
+                response_headers.insert(name, HeaderValue::from(value));
+            }
+        }
+        response
+    }
+}
+
+/// Counts the request against the rate limit of the API key it was made with. Returns `None` for
+/// requests which are not rate limited, or if the rate limit could not be checked.
+#[instrument(skip_all)]
+pub async fn check_rate_limit<A: AppStateInfo>(
+    state: &A,
+    request: &HttpRequest,
+    auth_type: &AuthenticationType,
+) -> Option<RateLimitStatus> {
+    let (key_id, overrides) = match auth_type {
+        AuthenticationType::ApiKey {
+            key_id,
+            rate_limits,
+            ..
+        } => (key_id, rate_limits),
+        _ => return None,
+    };
+
+    let config = state.conf().rate_limit;
+    if !config.enabled {
+        return None;
+    }
+
+    let category = RateLimitCategory::from_request(request);
+    let limit = u64::from(category.get_limit(overrides, &config));
+
+    // The keys of both the windows share a hash tag, as the script accessing them requires them
+    // to be in the same slot of a redis cluster
+    let window = SlidingWindow::new(
+        format!("{{rate_limit_{key_id}_{}}}", category.as_str()),
+        date_time::now_unix_timestamp(),
+        config.window_in_seconds,
+    );
+
+    let redis_conn = match state.store().get_redis_conn() {
+        Ok(redis_conn) => redis_conn,
+        Err(error) => {
+            logger::error!(
+                ?error,
+                "Failed to get the redis connection for rate limiting"
+            );
+            return None;
+        }
+    };
+    let result = redis_conn
+        .increment_sliding_window_counter(
+            &window.current_window_key(),
+            &window.previous_window_key(),
+            window.position.previous_window_weight,
+            limit,
+            window.key_expiry_in_seconds(),
+        )
+        .await;
+
+    match result {
+        Ok((counted, count)) => {
+            if !counted {
+                logger::info!(%key_id, category = category.as_str(), "Rate limit exceeded");
+            }
+            Some(RateLimitStatus {
+                limit,
+                remaining: limit.saturating_sub(count),
+                reset_after_seconds: window.position.reset_after_seconds,
+                exceeded: !counted,
+            })
+        }
+        Err(error) => {
+            logger::error!(?error, "Failed to check the rate limit");
+            None
+        }
+    }
+}
+
+/// The rate limits configured for the API key, with the limits not configured for the key
+/// inherited from the merchant account
+pub fn get_rate_limit_overrides(
+    api_key: &storage::ApiKey,
+    merchant_account: &storage::MerchantAccount,
+) -> api::RateLimits {
+    let parse_rate_limits = |rate_limits: &Option<serde_json::Value>| {
+        rate_limits
+            .clone()
+            .map(|rate_limits| rate_limits.parse_value::<api::RateLimits>("RateLimits"))
+            .transpose()
+            .map_err(|error| logger::error!(?error, "Failed to parse the stored rate limits"))
+            .ok()
+            .flatten()
+            .unwrap_or_default()
+    };
+
+    let key_rate_limits = parse_rate_limits(&api_key.rate_limits);
+    let merchant_rate_limits = parse_rate_limits(&merchant_account.rate_limits);
+    api::RateLimits {
+        payment_write_requests: key_rate_limits
+            .payment_write_requests
+            .or(merchant_rate_limits.payment_write_requests),
+        read_requests: key_rate_limits
+            .read_requests
+            .or(merchant_rate_limits.read_requests),
+        write_requests: key_rate_limits
+            .write_requests
+            .or(merchant_rate_limits.write_requests),
+    }
+}
+
+pub fn validate_rate_limits(rate_limits: &api::RateLimits) -> RouterResult<()> {
+    let limits = [
+        rate_limits.payment_write_requests,
+        rate_limits.read_requests,
+        rate_limits.write_requests,
+    ];
+
+    if limits.contains(&Some(0)) {
+        return Err(report!(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "rate_limits",
+        }))
+        .attach_printable("Rate limits must allow at least 1 request in a window");
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rate_limit_headers() {
+        let header = |response: &HttpResponse, name: &str| {
+            response
+                .headers()
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .map(str::to_string)
+        };
+        let status = |remaining: u64, exceeded: bool| RateLimitStatus {
+            limit: 10,
+            remaining,
+            reset_after_seconds: 45,
+            exceeded,
+        };
+
+        let response = status(9, false).apply_headers(HttpResponse::Ok().finish());
+        assert_eq!(
+            header(&response, headers::RATE_LIMIT_LIMIT).as_deref(),
+            Some("10")
+        );
+        assert_eq!(
+            header(&response, headers::RATE_LIMIT_REMAINING).as_deref(),
+            Some("9")
+        );
+        assert_eq!(
+            header(&response, headers::RATE_LIMIT_RESET).as_deref(),
+            Some("45")
+        );
+        assert_eq!(header(&response, headers::RETRY_AFTER), None);
+
+        let response = status(0, true).apply_headers(HttpResponse::TooManyRequests().finish());
+        assert_eq!(
+            header(&response, headers::RETRY_AFTER).as_deref(),
+            Some("45")
+        );
+    }
+
+    #[test]
+    fn test_zero_rate_limit_is_rejected() {
+        let rate_limits = api::RateLimits {
+            read_requests: Some(0),
+            ..Default::default()
+        };
+
+        assert!(validate_rate_limits(&rate_limits).is_err());
+        assert!(validate_rate_limits(&api::RateLimits::default()).is_ok());
+    }
+}
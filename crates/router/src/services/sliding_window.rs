@@ -0,0 +1,184 @@
+//! Counters kept in redis over a sliding window, shared by the rate limits, the circuit breakers
+//! and the success rates of the connectors.
+//!
+//! The sliding window ending now is estimated from two fixed windows: the counts of the current
+//! window are added to the counts of the previous window, weighted by the fraction of the previous
+//! window which overlaps the sliding window. The counters of each fixed window are stored under
+//! their own key, which expires once the window following it has ended.
+
+use std::collections::HashMap;
+
+use redis_interface::{errors::RedisError, RedisConnectionPool};
+
+use crate::core::errors::CustomResult;
+
+/// Position of a point in time within the fixed windows the sliding window is estimated from
+#[derive(Debug, PartialEq)]
+pub(super) struct WindowPosition {
+    pub(super) window_index: i64,
+    /// Fraction of the previous window which overlaps the sliding window ending now
+    pub(super) previous_window_weight: f64,
+    /// Number of seconds until the current window ends
+    pub(super) reset_after_seconds: u64,
+}
+
+impl WindowPosition {
+    pub(super) fn new(timestamp: i64, window_in_seconds: u32) -> Self {
+        let window_in_seconds = i64::from(window_in_seconds);
+        let elapsed_seconds = timestamp.rem_euclid(window_in_seconds);
+        let remaining_seconds = window_in_seconds - elapsed_seconds;
+
+        #[allow(clippy::as_conversions)]
+        let previous_window_weight = remaining_seconds as f64 / window_in_seconds as f64;
+        Self {
+            window_index: timestamp.div_euclid(window_in_seconds),
+            previous_window_weight,
+            reset_after_seconds: remaining_seconds.unsigned_abs(),
+        }
+    }
+}
+
+/// The counters stored under a key prefix, as seen at a point in time
+#[derive(Debug)]
+pub(super) struct SlidingWindow {
+    key_prefix: String,
+    window_in_seconds: u32,
+    pub(super) position: WindowPosition,
+}
+
+impl SlidingWindow {
+    pub(super) fn new(key_prefix: String, timestamp: i64, window_in_seconds: u32) -> Self {
+        Self {
+            key_prefix,
+            window_in_seconds,
+            position: WindowPosition::new(timestamp, window_in_seconds),
+        }
+    }
+
+    pub(super) fn current_window_key(&self) -> String {
+        format!("{}_{}", self.key_prefix, self.position.window_index)
+    }
+
+    pub(super) fn previous_window_key(&self) -> String {
+        format!("{}_{}", self.key_prefix, self.position.window_index - 1)
+    }
+
+    /// The counters of the current window are read until the end of the next window
+    pub(super) fn key_expiry_in_seconds(&self) -> i64 {
+        2 * i64::from(self.window_in_seconds)
+    }
+
+    /// Increments the given fields of the counters of the current window by one
+    pub(super) async fn increment_counters(
+        &self,
+        redis_conn: &RedisConnectionPool,
+        fields: &[&str],
+    ) -> CustomResult<(), RedisError> {
+        redis_conn
+            .increment_hash_fields(
+                &self.current_window_key(),
+                fields,
+                self.key_expiry_in_seconds(),
+            )
+            .await
+    }
+
+    pub(super) async fn get_counts(
+        &self,
+        redis_conn: &RedisConnectionPool,
+    ) -> CustomResult<WindowCounts, RedisError> {
+        let current = redis_conn
+            .get_all_hash_fields::<HashMap<String, u64>>(&self.current_window_key())
+            .await?;
+        let previous = redis_conn
+            .get_all_hash_fields::<HashMap<String, u64>>(&self.previous_window_key())
+            .await?;
+
+        Ok(WindowCounts::estimate(
+            &current,
+            &previous,
+            self.position.previous_window_weight,
+        ))
+    }
+
+    /// Deletes the counters of both the windows the sliding window is estimated from
+    pub(super) async fn reset_counters(
+        &self,
+        redis_conn: &RedisConnectionPool,
+    ) -> CustomResult<(), RedisError> {
+        redis_conn.delete_key(&self.current_window_key()).await?;
+        redis_conn.delete_key(&self.previous_window_key()).await
+    }
+}
+
+/// The counts over the sliding window ending now, estimated from the counts of the current and
+/// previous fixed windows
+#[derive(Debug, Default, PartialEq)]
+pub(super) struct WindowCounts(HashMap<String, f64>);
+
+impl WindowCounts {
+    #[allow(clippy::as_conversions)]
+    pub(super) fn estimate(
+        current: &HashMap<String, u64>,
+        previous: &HashMap<String, u64>,
+        previous_window_weight: f64,
+    ) -> Self {
+        let mut counts = HashMap::with_capacity(current.len().max(previous.len()));
+        for (field, count) in current {
+            *counts.entry(field.clone()).or_default() += *count as f64;
+        }
+        for (field, count) in previous {
+            *counts.entry(field.clone()).or_default() += *count as f64 * previous_window_weight;
+        }
+        Self(counts)
+    }
+
+    /// The count of the field, zero if it was never incremented
+    pub(super) fn get(&self, field: &str) -> f64 {
+        self.0.get(field).copied().unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_window_position() {
+        let position = WindowPosition::new(1_677_657_615, 60);
+
+        assert_eq!(position.window_index, 27_960_960);
+        assert_eq!(position.previous_window_weight, 0.75);
+        assert_eq!(position.reset_after_seconds, 45);
+    }
+
+    #[test]
+    fn test_window_position_at_window_start() {
+        let position = WindowPosition::new(1_677_657_600, 60);
+
+        assert_eq!(position.window_index, 27_960_960);
+        assert_eq!(position.previous_window_weight, 1.0);
+        assert_eq!(position.reset_after_seconds, 60);
+    }
+
+    #[test]
+    fn test_window_keys() {
+        let window = SlidingWindow::new("counter".to_string(), 1_677_657_615, 60);
+
+        assert_eq!(window.current_window_key(), "counter_27960960");
+        assert_eq!(window.previous_window_key(), "counter_27960959");
+        assert_eq!(window.key_expiry_in_seconds(), 120);
+    }
+
+    #[test]
+    fn test_window_counts_estimate() {
+        let current = HashMap::from([("requests".to_string(), 10), ("errors".to_string(), 2)]);
+        let previous = HashMap::from([("requests".to_string(), 20), ("timeouts".to_string(), 4)]);
+
+        let counts = WindowCounts::estimate(&current, &previous, 0.25);
+        assert_eq!(counts.get("requests"), 15.0);
+        assert_eq!(counts.get("errors"), 2.0);
+        assert_eq!(counts.get("timeouts"), 1.0);
+        assert_eq!(counts.get("successes"), 0.0);
+    }
+}
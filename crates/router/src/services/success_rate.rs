@@ -0,0 +1,368 @@
+//! Authorization success rates of the connectors, used by the `max_conversion` routing algorithm.
+//!
+//! The outcomes of the authorizations of the payments of each merchant are counted in redis over a
+//! sliding window, per connector and per slice of payments sharing the payment method, card
+//! network, currency and card issuing country. They are also counted across all the payments of
+//! the merchant, so that a connector can be ranked from its overall success rate when there are
+//! too few payments in the slice of the payment being routed.
+
+use std::collections::HashMap;
+
+use api_models::{enums as api_enums, payments as payment_types};
+use common_utils::date_time;
+use redis_interface::{errors::RedisError, RedisConnectionPool};
+use router_env::{instrument, logger, tracing};
+
+use super::sliding_window::{SlidingWindow, WindowCounts};
+use crate::{
+    configs::settings,
+    core::errors::CustomResult,
+    db::RedisConnInterface,
+    routes::AppState,
+    types::{
+        api,
+        storage::{self, enums as storage_enums},
+    },
+    utils::ValueExt,
+};
+
+const ATTEMPTS_FIELD: &str = "attempts";
+const SUCCESSES_FIELD: &str = "successes";
+
+/// Slice of the counters which spans all the payments of the merchant
+const ALL_PAYMENTS_SLICE: &str = "all";
+
+/// Outcome of the authorization of a payment by a connector
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AuthorizationOutcome {
+    Success,
+    Failure,
+}
+
+impl AuthorizationOutcome {
+    /// The outcome of the authorization when the payment attempt moves from `previous_status` to
+    /// `status`, `None` if the authorization was not completed by this change
+    pub fn from_status_change(
+        previous_status: storage_enums::AttemptStatus,
+        status: storage_enums::AttemptStatus,
+    ) -> Option<Self> {
+        let was_pending = matches!(
+            previous_status,
+            storage_enums::AttemptStatus::Started
+                | storage_enums::AttemptStatus::AuthenticationPending
+                | storage_enums::AttemptStatus::AuthenticationSuccessful
+                | storage_enums::AttemptStatus::Authorizing
+                | storage_enums::AttemptStatus::Pending
+                | storage_enums::AttemptStatus::PaymentMethodAwaited
+                | storage_enums::AttemptStatus::ConfirmationAwaited
+        );
+        if !was_pending {
+            return None;
+        }
+
+        match status {
+            storage_enums::AttemptStatus::Authorized
+            | storage_enums::AttemptStatus::Charged
+            | storage_enums::AttemptStatus::PartialCharged => Some(Self::Success),
+            storage_enums::AttemptStatus::AuthenticationFailed
+            | storage_enums::AttemptStatus::AuthorizationFailed
+            | storage_enums::AttemptStatus::Failure => Some(Self::Failure),
+            _ => None,
+        }
+    }
+
+    /// The counters incremented for an authorization with this outcome
+    fn counter_fields(self) -> &'static [&'static str] {
+        match self {
+            Self::Success => &[ATTEMPTS_FIELD, SUCCESSES_FIELD],
+            Self::Failure => &[ATTEMPTS_FIELD],
+        }
+    }
+}
+
+/// Details of a payment which decide the slice of payments its outcome is counted in
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PaymentSlice {
+    pub payment_method: Option<storage_enums::PaymentMethod>,
+    pub card_network: Option<String>,
+    pub currency: Option<storage_enums::Currency>,
+    /// Two-letter ISO code of the country in which the card was issued
+    pub card_issuing_country: Option<String>,
+}
+
+impl PaymentSlice {
+    /// The card details are taken from the payment method data of the request when available, and
+    /// from the details stored with the payment attempt otherwise
+    pub fn new(
+        payment_attempt: &storage::PaymentAttempt,
+        payment_method_data: Option<&api::PaymentMethodData>,
+    ) -> Self {
+        let additional_data = match payment_method_data {
+            Some(payment_method_data) => Some(payment_types::AdditionalPaymentData::from(
+                payment_method_data,
+            )),
+            None => payment_attempt
+                .payment_method_data
+                .clone()
+                .and_then(|data| data.parse_value("AdditionalPaymentData").ok()),
+        };
+
+        let (card_network, card_issuing_country) = match additional_data {
+            Some(payment_types::AdditionalPaymentData::Card {
+                card_network,
+                card_issuing_country,
+                ..
+            }) => (card_network, card_issuing_country),
+            _ => (None, None),
+        };
+
+        Self {
+            payment_method: payment_attempt.payment_method,
+            card_network,
+            currency: payment_attempt.currency,
+            card_issuing_country,
+        }
+    }
+
+    fn key_segment(&self) -> String {
+        let segment = |value: Option<String>| value.unwrap_or_else(|| "any".to_string());
+
+        format!(
+            "{}_{}_{}_{}",
+            segment(self.payment_method.map(|method| method.to_string())),
+            segment(
+                self.card_network
+                    .as_ref()
+                    .map(|network| network.to_lowercase())
+            ),
+            segment(self.currency.map(|currency| currency.to_string())),
+            segment(
+                self.card_issuing_country
+                    .as_ref()
+                    .map(|country| country.to_uppercase())
+            ),
+        )
+    }
+}
+
+/// Number of authorizations over the sliding window ending now
+#[derive(Debug, Default, PartialEq)]
+struct AuthorizationCounts {
+    attempts: f64,
+    successes: f64,
+}
+
+impl AuthorizationCounts {
+    fn new(counts: &WindowCounts) -> Self {
+        Self {
+            attempts: counts.get(ATTEMPTS_FIELD),
+            successes: counts.get(SUCCESSES_FIELD),
+        }
+    }
+
+    /// Percentage of the authorizations which succeeded, `None` if there were too few
+    /// authorizations for the percentage to be meaningful
+    fn success_rate(&self, config: &settings::SuccessRateRouting) -> Option<f64> {
+        (self.attempts > 0.0 && self.attempts >= f64::from(config.minimum_attempts))
+            .then(|| self.successes * 100.0 / self.attempts)
+    }
+}
+
+fn get_window(
+    merchant_id: &str,
+    connector: &str,
+    slice: &str,
+    now: i64,
+    config: &settings::SuccessRateRouting,
+) -> SlidingWindow {
+    SlidingWindow::new(
+        format!("success_rate_{merchant_id}_{connector}_{slice}"),
+        now,
+        config.window_in_seconds,
+    )
+}
+
+/// Counts the outcome of the authorization of the payment against the connector it was made
+/// with, if the change in the status of the payment attempt completed the authorization. Failures
+/// to update the counters in redis are logged and otherwise ignored, so that they do not affect
+/// the payment.
+#[instrument(skip_all)]
+pub async fn record_authorization_outcome(
+    state: &AppState,
+    previous_status: storage_enums::AttemptStatus,
+    payment_attempt: &storage::PaymentAttempt,
+    slice: &PaymentSlice,
+) {
+    let (outcome, connector) = match (
+        AuthorizationOutcome::from_status_change(previous_status, payment_attempt.status),
+        payment_attempt.connector.as_deref(),
+    ) {
+        (Some(outcome), Some(connector)) => (outcome, connector),
+        _ => return,
+    };
+
+    if let Err(error) = increment_counters(
+        state,
+        &payment_attempt.merchant_id,
+        connector,
+        slice,
+        outcome,
+    )
+    .await
+    {
+        logger::error!(
+            ?error,
+            %connector,
+            "Failed to record the outcome of the authorization"
+        );
+    }
+}
+
+async fn increment_counters(
+    state: &AppState,
+    merchant_id: &str,
+    connector: &str,
+    slice: &PaymentSlice,
+    outcome: AuthorizationOutcome,
+) -> CustomResult<(), RedisError> {
+    let config = &state.conf.success_rate_routing;
+    let redis_conn = state.store.get_redis_conn()?;
+    let now = date_time::now_unix_timestamp();
+
+    let payment_slice_key = slice.key_segment();
+    for slice_key in [payment_slice_key.as_str(), ALL_PAYMENTS_SLICE] {
+        get_window(merchant_id, connector, slice_key, now, config)
+            .increment_counters(&redis_conn, outcome.counter_fields())
+            .await?;
+    }
+    Ok(())
+}
+
+/// The success rate of the connector for the slice of the payment, or across all the payments of
+/// the merchant if there were too few payments in the slice
+async fn get_success_rate(
+    redis_conn: &RedisConnectionPool,
+    config: &settings::SuccessRateRouting,
+    merchant_id: &str,
+    connector: &str,
+    slice: &PaymentSlice,
+    now: i64,
+) -> CustomResult<Option<f64>, RedisError> {
+    let payment_slice_key = slice.key_segment();
+    for slice_key in [payment_slice_key.as_str(), ALL_PAYMENTS_SLICE] {
+        let counts = AuthorizationCounts::new(
+            &get_window(merchant_id, connector, slice_key, now, config)
+                .get_counts(redis_conn)
+                .await?,
+        );
+        if let Some(success_rate) = counts.success_rate(config) {
+            return Ok(Some(success_rate));
+        }
+    }
+    Ok(None)
+}
+
+/// The recent authorization success rates of the connectors, in percent, for payments similar to
+/// the one being routed. Connectors with too few recent payments are left out, as are all the
+/// connectors if the success rates cannot be read from redis.
+#[instrument(skip_all)]
+pub async fn get_success_rates(
+    state: &AppState,
+    merchant_id: &str,
+    connectors: &[api_enums::RoutableConnectors],
+    slice: &PaymentSlice,
+) -> HashMap<api_enums::RoutableConnectors, f64> {
+    let config = &state.conf.success_rate_routing;
+    let redis_conn = match state.store.get_redis_conn() {
+        Ok(redis_conn) => redis_conn,
+        Err(error) => {
+            logger::error!(?error, "Failed to get the redis connection for routing");
+            return HashMap::new();
+        }
+    };
+    let now = date_time::now_unix_timestamp();
+
+    let mut success_rates = HashMap::with_capacity(connectors.len());
+    for connector in connectors {
+        let connector_name = connector.to_string();
+        match get_success_rate(
+            &redis_conn,
+            config,
+            merchant_id,
+            &connector_name,
+            slice,
+            now,
+        )
+        .await
+        {
+            Ok(Some(success_rate)) => {
+                success_rates.insert(*connector, success_rate);
+            }
+            Ok(None) => {}
+            Err(error) => {
+                logger::error!(
+                    ?error,
+                    connector = %connector_name,
+                    "Failed to get the success rate of the connector"
+                );
+                return HashMap::new();
+            }
+        }
+    }
+    success_rates
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_outcome_from_status_change() {
+        assert_eq!(
+            AuthorizationOutcome::from_status_change(
+                storage_enums::AttemptStatus::Pending,
+                storage_enums::AttemptStatus::Charged
+            ),
+            Some(AuthorizationOutcome::Success)
+        );
+        assert_eq!(
+            AuthorizationOutcome::from_status_change(
+                storage_enums::AttemptStatus::AuthenticationPending,
+                storage_enums::AttemptStatus::AuthorizationFailed
+            ),
+            Some(AuthorizationOutcome::Failure)
+        );
+        // The authorization is still in progress
+        assert_eq!(
+            AuthorizationOutcome::from_status_change(
+                storage_enums::AttemptStatus::Pending,
+                storage_enums::AttemptStatus::AuthenticationPending
+            ),
+            None
+        );
+        // The capture of an authorized payment does not count as another authorization
+        assert_eq!(
+            AuthorizationOutcome::from_status_change(
+                storage_enums::AttemptStatus::Authorized,
+                storage_enums::AttemptStatus::Charged
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_success_rate_requires_minimum_attempts() {
+        let counts = |attempts: f64, successes: f64| AuthorizationCounts {
+            attempts,
+            successes,
+        };
+        let config = settings::SuccessRateRouting {
+            window_in_seconds: 3600,
+            minimum_attempts: 10,
+            default_exploration_percentage: 10,
+        };
+
+        assert_eq!(counts(15.0, 10.5).success_rate(&config), Some(70.0));
+        assert_eq!(counts(8.0, 8.0).success_rate(&config), None);
+    }
+}
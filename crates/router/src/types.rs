@@ -28,11 +28,22 @@ pub type PaymentsSyncRouterData = RouterData<api::PSync, PaymentsSyncData, Payme
 pub type PaymentsCaptureRouterData =
     RouterData<api::Capture, PaymentsCaptureData, PaymentsResponseData>;
 pub type PaymentsCancelRouterData = RouterData<api::Void, PaymentsCancelData, PaymentsResponseData>;
+pub type PaymentsIncrementalAuthorizationRouterData = RouterData<
+    api::IncrementalAuthorization,
+    PaymentsIncrementalAuthorizationData,
+    PaymentsResponseData,
+>;
 pub type PaymentsSessionRouterData =
     RouterData<api::Session, PaymentsSessionData, PaymentsResponseData>;
 pub type RefundsRouterData<F> = RouterData<F, RefundsData, RefundsResponseData>;
 pub type RefundExecuteRouterData = RouterData<api::Execute, RefundsData, RefundsResponseData>;
 pub type RefundSyncRouterData = RouterData<api::RSync, RefundsData, RefundsResponseData>;
+pub type PayoutsRouterData<F> = RouterData<F, PayoutsData, PayoutsResponseData>;
+pub type AcceptDisputeRouterData =
+    RouterData<api::Accept, AcceptDisputeRequestData, AcceptDisputeResponse>;
+pub type SubmitEvidenceRouterData =
+    RouterData<api::Evidence, SubmitEvidenceRequestData, SubmitEvidenceResponse>;
+pub type UploadFileRouterData = RouterData<api::Upload, UploadFileRequestData, UploadFileResponse>;
 
 pub type RefreshTokenRouterData =
     RouterData<api::AccessTokenAuth, AccessTokenRequestData, AccessToken>;
@@ -47,9 +58,17 @@ pub type PaymentsSessionResponseRouterData<R> =
     ResponseRouterData<api::Session, R, PaymentsSessionData, PaymentsResponseData>;
 pub type PaymentsCaptureResponseRouterData<R> =
     ResponseRouterData<api::Capture, R, PaymentsCaptureData, PaymentsResponseData>;
+pub type PaymentsIncrementalAuthorizationResponseRouterData<R> = ResponseRouterData<
+    api::IncrementalAuthorization,
+    R,
+    PaymentsIncrementalAuthorizationData,
+    PaymentsResponseData,
+>;
 
 pub type RefundsResponseRouterData<F, R> =
     ResponseRouterData<F, R, RefundsData, RefundsResponseData>;
+pub type PayoutsResponseRouterData<F, R> =
+    ResponseRouterData<F, R, PayoutsData, PayoutsResponseData>;
 
 pub type PaymentsAuthorizeType =
     dyn services::ConnectorIntegration<api::Authorize, PaymentsAuthorizeData, PaymentsResponseData>;
@@ -66,12 +85,37 @@ pub type PaymentsSessionType =
     dyn services::ConnectorIntegration<api::Session, PaymentsSessionData, PaymentsResponseData>;
 pub type PaymentsVoidType =
     dyn services::ConnectorIntegration<api::Void, PaymentsCancelData, PaymentsResponseData>;
+pub type PaymentsIncrementalAuthorizationType = dyn services::ConnectorIntegration<
+    api::IncrementalAuthorization,
+    PaymentsIncrementalAuthorizationData,
+    PaymentsResponseData,
+>;
 
 pub type RefundExecuteType =
     dyn services::ConnectorIntegration<api::Execute, RefundsData, RefundsResponseData>;
 pub type RefundSyncType =
     dyn services::ConnectorIntegration<api::RSync, RefundsData, RefundsResponseData>;
 
+pub type PayoutCreateType =
+    dyn services::ConnectorIntegration<api::PoCreate, PayoutsData, PayoutsResponseData>;
+pub type PayoutFulfillType =
+    dyn services::ConnectorIntegration<api::PoFulfill, PayoutsData, PayoutsResponseData>;
+pub type PayoutReverseType =
+    dyn services::ConnectorIntegration<api::PoReverse, PayoutsData, PayoutsResponseData>;
+
+pub type AcceptDisputeType = dyn services::ConnectorIntegration<
+    api::Accept,
+    AcceptDisputeRequestData,
+    AcceptDisputeResponse,
+>;
+pub type SubmitEvidenceType = dyn services::ConnectorIntegration<
+    api::Evidence,
+    SubmitEvidenceRequestData,
+    SubmitEvidenceResponse,
+>;
+pub type UploadFileType =
+    dyn services::ConnectorIntegration<api::Upload, UploadFileRequestData, UploadFileResponse>;
+
 pub type RefreshTokenType =
     dyn services::ConnectorIntegration<api::AccessTokenAuth, AccessTokenRequestData, AccessToken>;
 
@@ -127,6 +171,7 @@ pub struct PaymentsAuthorizeData {
     pub order_details: Option<api_models::payments::OrderDetails>,
     pub payment_experience: Option<storage_enums::PaymentExperience>,
     pub payment_method_type: Option<storage_enums::PaymentMethodType>,
+    pub request_incremental_authorization: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -135,6 +180,18 @@ pub struct PaymentsCaptureData {
     pub currency: storage_enums::Currency,
     pub connector_transaction_id: String,
     pub amount: i64,
+    pub multiple_capture_data: Option<MultipleCaptureData>,
+}
+
+/// Details of a partial capture, when the payment is captured through several captures
+#[derive(Debug, Clone)]
+pub struct MultipleCaptureData {
+    /// The position of the capture among the captures made on the payment, starting from 1
+    pub capture_sequence: i32,
+    pub capture_reference: String,
+    /// Whether the capture settles the rest of the authorized amount, after which no further
+    /// captures can be made
+    pub final_capture: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -161,6 +218,16 @@ pub struct PaymentsCancelData {
     pub cancellation_reason: Option<String>,
 }
 
+#[derive(Debug, Clone)]
+pub struct PaymentsIncrementalAuthorizationData {
+    /// The total amount to be authorized on the payment, including the amount already authorized
+    pub total_amount: i64,
+    /// The amount by which the authorization is incremented
+    pub additional_amount: i64,
+    pub currency: storage_enums::Currency,
+    pub connector_transaction_id: String,
+}
+
 #[derive(Debug, Clone)]
 pub struct PaymentsSessionData {
     pub amount: i64,
@@ -196,7 +263,12 @@ pub struct AddAccessTokenResult {
 #[derive(serde::Deserialize, serde::Serialize, Debug, Clone)]
 pub struct AccessToken {
     pub token: String,
+    /// Number of seconds until the token expires
     pub expires: i64,
+    /// Number of seconds the token was valid for when it was issued, stored along with the token.
+    /// A token without it is one just issued by the connector, valid for `expires` seconds.
+    #[serde(default)]
+    pub lifetime: Option<i64>,
 }
 
 #[derive(Debug, Clone)]
@@ -254,6 +326,76 @@ pub struct RefundsData {
     pub connector_metadata: Option<serde_json::Value>,
 }
 
+#[derive(Debug, Clone)]
+pub struct AcceptDisputeRequestData {
+    pub dispute_id: String,
+    pub connector_dispute_id: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct AcceptDisputeResponse {
+    pub dispute_status: storage_enums::DisputeStatus,
+    pub connector_status: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct SubmitEvidenceRequestData {
+    pub dispute_id: String,
+    pub connector_dispute_id: String,
+    pub evidence: api::DisputeEvidence,
+}
+
+#[derive(Debug, Clone)]
+pub struct SubmitEvidenceResponse {
+    pub dispute_status: storage_enums::DisputeStatus,
+    pub connector_status: Option<String>,
+}
+
+#[derive(Clone)]
+pub struct UploadFileRequestData {
+    pub file_key: String,
+    pub file: Vec<u8>,
+    pub file_type: mime::Mime,
+    pub file_size: i32,
+    pub connector_dispute_id: String,
+}
+
+// Skip the file contents when logging the request data
+impl std::fmt::Debug for UploadFileRequestData {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("UploadFileRequestData")
+            .field("file_key", &self.file_key)
+            .field("file_type", &self.file_type)
+            .field("file_size", &self.file_size)
+            .field("connector_dispute_id", &self.connector_dispute_id)
+            .finish()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct UploadFileResponse {
+    pub provider_file_id: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct PayoutsData {
+    pub payout_id: String,
+    pub amount: i64,
+    pub currency: storage_enums::Currency,
+    pub payout_type: storage_enums::PayoutType,
+    /// Card or bank details of the recipient, only available while submitting the payout
+    pub payout_method_data: Option<api::PayoutMethodData>,
+    pub connector_payout_id: Option<String>,
+    pub customer_id: Option<String>,
+    pub email: Option<masking::Secret<String, Email>>,
+}
+
+#[derive(Debug, Clone)]
+pub struct PayoutsResponseData {
+    pub connector_payout_id: String,
+    pub status: storage_enums::PayoutStatus,
+}
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct BrowserInformation {
     pub color_depth: u8,
@@ -2,11 +2,16 @@ pub mod admin;
 pub mod api_keys;
 pub mod configs;
 pub mod customers;
+pub mod disputes;
 pub mod enums;
+pub mod events;
+pub mod files;
 pub mod mandates;
 pub mod payment_methods;
 pub mod payments;
+pub mod payouts;
 pub mod refunds;
+pub mod routing;
 pub mod webhooks;
 
 use std::{fmt::Debug, str::FromStr};
@@ -14,8 +19,8 @@ use std::{fmt::Debug, str::FromStr};
 use error_stack::{report, IntoReport, ResultExt};
 
 pub use self::{
-    admin::*, api_keys::*, configs::*, customers::*, payment_methods::*, payments::*, refunds::*,
-    webhooks::*,
+    admin::*, api_keys::*, configs::*, customers::*, disputes::*, events::*, files::*,
+    payment_methods::*, payments::*, payouts::*, refunds::*, routing::*, webhooks::*,
 };
 use super::ErrorResponse;
 use crate::{
@@ -89,7 +94,16 @@ pub trait ConnectorCommonExt<Flow, Req, Resp>:
 pub trait Router {}
 
 pub trait Connector:
-    Send + Refund + Payment + Debug + ConnectorRedirectResponse + IncomingWebhook + ConnectorAccessToken
+    Send
+    + Refund
+    + Payment
+    + Payout
+    + Dispute
+    + FileUpload
+    + Debug
+    + ConnectorRedirectResponse
+    + IncomingWebhook
+    + ConnectorAccessToken
 {
 }
 
@@ -100,6 +114,9 @@ pub struct Pe;
 impl<
         T: Refund
             + Payment
+            + Payout
+            + Dispute
+            + FileUpload
             + Debug
             + ConnectorRedirectResponse
             + Send
@@ -128,6 +145,9 @@ pub enum ConnectorCallType {
     Routing,
     Multiple(Vec<ConnectorData>),
     Single(ConnectorData),
+    /// Connectors in order of preference, the next connector is tried if the payment fails with
+    /// a retryable error on the previous one
+    Retryable(Vec<ConnectorData>),
 }
 
 impl ConnectorCallType {
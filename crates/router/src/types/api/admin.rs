@@ -1,8 +1,10 @@
 pub use api_models::admin::{
-    MerchantAccountCreate, MerchantAccountDeleteResponse, MerchantAccountResponse,
-    MerchantAccountUpdate, MerchantConnector, MerchantConnectorDeleteResponse, MerchantConnectorId,
-    MerchantDetails, MerchantId, PaymentMethodsEnabled, RoutingAlgorithm, ToggleKVRequest,
-    ToggleKVResponse, WebhookDetails,
+    ConnectorFeeSchedule, ConnectorHealthResponse, FeeSurcharge, MerchantAccountCreate,
+    MerchantAccountDeleteResponse, MerchantAccountResponse, MerchantAccountUpdate,
+    MerchantConnector, MerchantConnectorDeleteResponse, MerchantConnectorId, MerchantDetails,
+    MerchantId, PaymentExpiry, PaymentMethodsEnabled, RateLimits, RoutingAlgorithm,
+    ToggleKVRequest, ToggleKVResponse, WebhookDetails, WebhookEndpoint, WebhookSecretRotateRequest,
+    WebhookSecretRotateResponse,
 };
 
 use crate::types::{storage, transformers::ForeignFrom};
@@ -26,6 +28,10 @@ impl ForeignFrom<storage::MerchantAccount> for MerchantAccountResponse {
             publishable_key: item.publishable_key,
             metadata: item.metadata,
             locker_id: item.locker_id,
+            previous_payment_response_hash_key_expires_at: item
+                .previous_payment_response_hash_key_expires_at,
+            rate_limits: item.rate_limits,
+            payment_expiry: item.payment_expiry,
         }
     }
 }
@@ -0,0 +1,72 @@
+pub use api_models::disputes::{
+    DisputeEvidence, DisputeListConstraints, DisputeListResponse, DisputeResponse,
+};
+use serde::Deserialize;
+use time::PrimitiveDateTime;
+
+use super::ConnectorCommon;
+use crate::{
+    services::api,
+    types::{
+        self, storage,
+        transformers::{ForeignFrom, ForeignInto},
+    },
+};
+
+/// Details of a dispute received from the connector through a webhook
+#[derive(Clone, Debug, Default)]
+pub struct DisputePayload {
+    pub amount: i64,
+    pub currency: String,
+    pub dispute_stage: storage::enums::DisputeStage,
+    pub connector_status: String,
+    pub connector_dispute_id: String,
+    pub connector_reason: Option<String>,
+    pub connector_reason_code: Option<String>,
+    pub challenge_required_by: Option<PrimitiveDateTime>,
+    pub created_at: Option<PrimitiveDateTime>,
+    pub updated_at: Option<PrimitiveDateTime>,
+}
+
+impl ForeignFrom<storage::Dispute> for DisputeResponse {
+    fn foreign_from(dispute: storage::Dispute) -> Self {
+        Self {
+            dispute_id: dispute.dispute_id,
+            payment_id: dispute.payment_id,
+            attempt_id: dispute.attempt_id,
+            amount: dispute.amount,
+            currency: dispute.currency.to_string(),
+            dispute_stage: dispute.dispute_stage.foreign_into(),
+            dispute_status: dispute.dispute_status.foreign_into(),
+            connector: dispute.connector,
+            connector_status: dispute.connector_status,
+            connector_dispute_id: dispute.connector_dispute_id,
+            connector_reason: dispute.connector_reason,
+            connector_reason_code: dispute.connector_reason_code,
+            challenge_required_by: dispute.challenge_required_by,
+            evidence: dispute
+                .evidence
+                .and_then(|evidence| DisputeEvidence::deserialize(evidence).ok()),
+            connector_created_at: dispute.connector_created_at,
+            connector_updated_at: dispute.connector_updated_at,
+            created_at: dispute.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Accept;
+#[derive(Debug, Clone)]
+pub struct Evidence;
+
+pub trait AcceptDispute:
+    api::ConnectorIntegration<Accept, types::AcceptDisputeRequestData, types::AcceptDisputeResponse>
+{
+}
+
+pub trait SubmitEvidence:
+    api::ConnectorIntegration<Evidence, types::SubmitEvidenceRequestData, types::SubmitEvidenceResponse>
+{
+}
+
+pub trait Dispute: ConnectorCommon + AcceptDispute + SubmitEvidence {}
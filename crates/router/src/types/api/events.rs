@@ -0,0 +1,40 @@
+pub use api_models::events::{
+    EventDeliveryAttemptResponse, EventListConstraints, EventListResponse, EventResponse,
+};
+
+use crate::types::{
+    storage,
+    transformers::{ForeignFrom, ForeignInto},
+};
+
+impl ForeignFrom<storage::Event> for EventResponse {
+    fn foreign_from(event: storage::Event) -> Self {
+        Self {
+            event_id: event.event_id,
+            merchant_id: event.merchant_id.unwrap_or_default(),
+            event_type: event.event_type.foreign_into(),
+            event_class: event.event_class.foreign_into(),
+            primary_object_id: event.primary_object_id,
+            primary_object_type: event.primary_object_type.foreign_into(),
+            intent_reference_id: event.intent_reference_id,
+            is_webhook_notified: event.is_webhook_notified,
+            webhook_delivery_status: event.webhook_delivery_status.foreign_into(),
+            created_at: event.created_at,
+            request_body: None,
+            delivery_attempts: None,
+        }
+    }
+}
+
+impl ForeignFrom<storage::EventDeliveryAttempt> for EventDeliveryAttemptResponse {
+    fn foreign_from(delivery_attempt: storage::EventDeliveryAttempt) -> Self {
+        Self {
+            endpoint_id: delivery_attempt.endpoint_id,
+            attempt_number: delivery_attempt.attempt_number,
+            delivered: delivery_attempt.delivered,
+            response_status_code: delivery_attempt.response_status_code,
+            error_message: delivery_attempt.error_message,
+            created_at: delivery_attempt.created_at,
+        }
+    }
+}
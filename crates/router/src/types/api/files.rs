@@ -0,0 +1,56 @@
+pub use api_models::files::{CreateFileResponse, FilePurpose};
+
+use super::ConnectorCommon;
+use crate::{
+    core::errors::{self, CustomResult},
+    services::api,
+    types,
+};
+
+/// File upload request parsed from the multipart form sent by the merchant
+#[derive(Clone)]
+pub struct CreateFileRequest {
+    pub file: Vec<u8>,
+    pub file_name: Option<String>,
+    pub file_size: i32,
+    pub file_type: mime::Mime,
+    pub purpose: FilePurpose,
+    pub dispute_id: Option<String>,
+}
+
+// Skip the file contents when logging the request
+impl std::fmt::Debug for CreateFileRequest {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CreateFileRequest")
+            .field("file_name", &self.file_name)
+            .field("file_size", &self.file_size)
+            .field("file_type", &self.file_type)
+            .field("purpose", &self.purpose)
+            .field("dispute_id", &self.dispute_id)
+            .finish()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Upload;
+
+pub trait UploadFile:
+    api::ConnectorIntegration<Upload, types::UploadFileRequestData, types::UploadFileResponse>
+{
+}
+
+pub trait FileUpload: ConnectorCommon + UploadFile {
+    /// Checks whether the file can be uploaded to the connector.
+    /// Connectors which support file upload override this to validate the purpose, size and type of the file.
+    fn validate_file_upload(
+        &self,
+        _purpose: FilePurpose,
+        _file_size: i32,
+        _file_type: mime::Mime,
+    ) -> CustomResult<(), errors::ConnectorError> {
+        Err(errors::ConnectorError::FileValidationFailed {
+            reason: format!("file upload is not supported by {}", self.id()),
+        }
+        .into())
+    }
+}
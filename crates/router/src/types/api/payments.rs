@@ -1,9 +1,10 @@
 pub use api_models::payments::{
-    AcceptanceType, Address, AddressDetails, Amount, AuthenticationForStartResponse, Card,
-    CustomerAcceptance, MandateData, MandateTxnType, MandateType, MandateValidationFields,
-    NextAction, NextActionType, OnlineMandate, PayLaterData, PaymentIdType, PaymentListConstraints,
-    PaymentListResponse, PaymentMethodData, PaymentMethodDataResponse, PaymentOp,
-    PaymentRetrieveBody, PaymentsCancelRequest, PaymentsCaptureRequest, PaymentsRedirectRequest,
+    AcceptanceType, Address, AddressDetails, Amount, AuthenticationForStartResponse,
+    CaptureResponse, Card, CustomerAcceptance, MandateData, MandateTxnType, MandateType,
+    MandateValidationFields, NextAction, NextActionType, OnlineMandate, PayLaterData,
+    PaymentIdType, PaymentListConstraints, PaymentListResponse, PaymentMethodData,
+    PaymentMethodDataResponse, PaymentOp, PaymentRetrieveBody, PaymentsCancelRequest,
+    PaymentsCaptureRequest, PaymentsIncrementalAuthorizationRequest, PaymentsRedirectRequest,
     PaymentsRedirectionResponse, PaymentsRequest, PaymentsResponse, PaymentsResponseForm,
     PaymentsRetrieveRequest, PaymentsSessionRequest, PaymentsSessionResponse, PaymentsStartRequest,
     PgRedirectResponse, PhoneDetails, RedirectionResponse, SessionToken, UrlDetails, VerifyRequest,
@@ -70,6 +71,9 @@ pub struct AuthorizeSessionToken;
 #[derive(Debug, Clone)]
 pub struct Capture;
 
+#[derive(Debug, Clone)]
+pub struct IncrementalAuthorization;
+
 #[derive(Debug, Clone)]
 pub struct PSync;
 #[derive(Debug, Clone)]
@@ -134,6 +138,23 @@ impl ForeignFrom<storage::PaymentIntent> for PaymentsResponse {
     }
 }
 
+impl ForeignFrom<storage::Capture> for CaptureResponse {
+    fn foreign_from(capture: storage::Capture) -> Self {
+        Self {
+            capture_id: capture.capture_id,
+            status: capture.status.foreign_into(),
+            amount: capture.amount,
+            currency: capture.currency.map(ForeignInto::foreign_into),
+            connector: capture.connector,
+            connector_capture_id: capture.connector_capture_id,
+            capture_sequence: capture.capture_sequence,
+            error_code: capture.error_code,
+            error_message: capture.error_message,
+            created_at: capture.created_at,
+        }
+    }
+}
+
 // Extract only the last 4 digits of card
 
 pub trait PaymentAuthorize:
@@ -156,6 +177,15 @@ pub trait PaymentCapture:
 {
 }
 
+pub trait PaymentIncrementalAuthorization:
+    api::ConnectorIntegration<
+    IncrementalAuthorization,
+    types::PaymentsIncrementalAuthorizationData,
+    types::PaymentsResponseData,
+>
+{
+}
+
 pub trait PaymentSession:
     api::ConnectorIntegration<Session, types::PaymentsSessionData, types::PaymentsResponseData>
 {
@@ -172,6 +202,7 @@ pub trait Payment:
     + PaymentSync
     + PaymentCapture
     + PaymentVoid
+    + PaymentIncrementalAuthorization
     + PreVerify
     + PaymentSession
 {
@@ -193,6 +224,7 @@ mod payments_test {
             card_cvc: "123".to_string().into(),
             card_issuer: Some("HDFC".to_string()),
             card_network: Some(api_models::enums::CardNetwork::Visa),
+            card_issuing_country: None,
         }
     }
 
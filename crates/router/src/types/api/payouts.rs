@@ -0,0 +1,70 @@
+pub use api_models::payouts::{
+    PayoutAccount, PayoutAccountsResponse, PayoutBank, PayoutCard, PayoutCreateRequest,
+    PayoutListConstraints, PayoutListResponse, PayoutMethodData, PayoutResponse,
+    PayoutUpdateRequest,
+};
+
+use super::ConnectorCommon;
+use crate::{
+    services::api,
+    types::{
+        self, storage,
+        transformers::{ForeignFrom, ForeignInto},
+    },
+};
+
+impl ForeignFrom<storage::Payouts> for PayoutResponse {
+    fn foreign_from(payout: storage::Payouts) -> Self {
+        Self {
+            payout_id: payout.payout_id,
+            merchant_id: payout.merchant_id,
+            customer_id: payout.customer_id,
+            amount: payout.amount,
+            currency: payout.currency.foreign_into(),
+            connector: payout.connector,
+            connector_payout_id: payout.connector_payout_id,
+            payout_type: payout.payout_type.foreign_into(),
+            status: payout.status.foreign_into(),
+            description: payout.description,
+            metadata: payout.metadata,
+            error_code: payout.error_code,
+            error_message: payout.error_message,
+            created_at: payout.created_at,
+            updated_at: payout.modified_at,
+        }
+    }
+}
+
+impl ForeignFrom<storage::MerchantConnectorAccount> for PayoutAccount {
+    fn foreign_from(merchant_connector_account: storage::MerchantConnectorAccount) -> Self {
+        Self {
+            connector: merchant_connector_account.connector_name,
+            merchant_connector_id: merchant_connector_account.merchant_connector_id,
+            test_mode: merchant_connector_account.test_mode,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PoCreate;
+#[derive(Debug, Clone)]
+pub struct PoFulfill;
+#[derive(Debug, Clone)]
+pub struct PoReverse;
+
+pub trait PayoutCreate:
+    api::ConnectorIntegration<PoCreate, types::PayoutsData, types::PayoutsResponseData>
+{
+}
+
+pub trait PayoutFulfill:
+    api::ConnectorIntegration<PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+{
+}
+
+pub trait PayoutReverse:
+    api::ConnectorIntegration<PoReverse, types::PayoutsData, types::PayoutsResponseData>
+{
+}
+
+pub trait Payout: ConnectorCommon + PayoutCreate + PayoutFulfill + PayoutReverse {}
@@ -0,0 +1,3 @@
+pub use api_models::routing::{
+    ExcludedConnector, RoutingSimulationRequest, RoutingSimulationResponse,
+};
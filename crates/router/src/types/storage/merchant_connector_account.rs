@@ -1,4 +1,4 @@
 pub use storage_models::merchant_connector_account::{
-    MerchantConnectorAccount, MerchantConnectorAccountNew, MerchantConnectorAccountUpdate,
-    MerchantConnectorAccountUpdateInternal,
+    AccessTokenRefreshTrackingData, MerchantConnectorAccount, MerchantConnectorAccountNew,
+    MerchantConnectorAccountUpdate, MerchantConnectorAccountUpdateInternal,
 };
@@ -0,0 +1 @@
+pub use storage_models::payouts::{Payouts, PayoutsNew, PayoutsUpdate, PayoutsUpdateInternal};
@@ -156,6 +156,9 @@ impl ForeignTryFrom<api_enums::IntentStatus> for storage_enums::EventType {
     fn foreign_try_from(value: api_enums::IntentStatus) -> Result<Self, Self::Error> {
         match value {
             api_enums::IntentStatus::Succeeded => Ok(Self::PaymentSucceeded),
+            api_enums::IntentStatus::Failed => Ok(Self::PaymentFailed),
+            api_enums::IntentStatus::Cancelled => Ok(Self::PaymentCancelled),
+            api_enums::IntentStatus::Expired => Ok(Self::PaymentExpired),
             _ => Err(errors::ValidationError::IncorrectValueProvided {
                 field_name: "intent_status",
             }),
@@ -163,6 +166,20 @@ impl ForeignTryFrom<api_enums::IntentStatus> for storage_enums::EventType {
     }
 }
 
+impl ForeignTryFrom<storage_enums::MandateStatus> for storage_enums::EventType {
+    type Error = errors::ValidationError;
+
+    fn foreign_try_from(value: storage_enums::MandateStatus) -> Result<Self, Self::Error> {
+        match value {
+            storage_enums::MandateStatus::Active => Ok(Self::MandateActive),
+            storage_enums::MandateStatus::Revoked => Ok(Self::MandateRevoked),
+            _ => Err(errors::ValidationError::IncorrectValueProvided {
+                field_name: "mandate_status",
+            }),
+        }
+    }
+}
+
 impl ForeignTryFrom<storage_enums::RefundStatus> for storage_enums::EventType {
     type Error = errors::ValidationError;
 
@@ -193,12 +210,89 @@ impl ForeignTryFrom<api_models::webhooks::IncomingWebhookEvent> for storage_enum
     }
 }
 
+impl ForeignTryFrom<api_models::webhooks::IncomingWebhookEvent> for storage_enums::DisputeStatus {
+    type Error = errors::ValidationError;
+
+    fn foreign_try_from(
+        value: api_models::webhooks::IncomingWebhookEvent,
+    ) -> Result<Self, Self::Error> {
+        match value {
+            api_models::webhooks::IncomingWebhookEvent::DisputeOpened => Ok(Self::DisputeOpened),
+            api_models::webhooks::IncomingWebhookEvent::DisputeChallenged => {
+                Ok(Self::DisputeChallenged)
+            }
+            api_models::webhooks::IncomingWebhookEvent::DisputeWon => Ok(Self::DisputeWon),
+            api_models::webhooks::IncomingWebhookEvent::DisputeLost => Ok(Self::DisputeLost),
+            _ => Err(errors::ValidationError::IncorrectValueProvided {
+                field_name: "incoming_webhook_event_type",
+            }),
+        }
+    }
+}
+
+impl ForeignTryFrom<api_models::webhooks::IncomingWebhookEvent> for storage_enums::PayoutStatus {
+    type Error = errors::ValidationError;
+
+    fn foreign_try_from(
+        value: api_models::webhooks::IncomingWebhookEvent,
+    ) -> Result<Self, Self::Error> {
+        match value {
+            api_models::webhooks::IncomingWebhookEvent::PayoutSuccess => Ok(Self::Success),
+            api_models::webhooks::IncomingWebhookEvent::PayoutFailure => Ok(Self::Failed),
+            api_models::webhooks::IncomingWebhookEvent::PayoutReversed => Ok(Self::Reversed),
+            _ => Err(errors::ValidationError::IncorrectValueProvided {
+                field_name: "incoming_webhook_event_type",
+            }),
+        }
+    }
+}
+
+impl ForeignTryFrom<storage_enums::DisputeStatus> for storage_enums::EventType {
+    type Error = errors::ValidationError;
+
+    fn foreign_try_from(value: storage_enums::DisputeStatus) -> Result<Self, Self::Error> {
+        match value {
+            storage_enums::DisputeStatus::DisputeOpened => Ok(Self::DisputeOpened),
+            storage_enums::DisputeStatus::DisputeChallenged => Ok(Self::DisputeChallenged),
+            storage_enums::DisputeStatus::DisputeWon => Ok(Self::DisputeWon),
+            storage_enums::DisputeStatus::DisputeLost => Ok(Self::DisputeLost),
+            _ => Err(errors::ValidationError::IncorrectValueProvided {
+                field_name: "dispute_status",
+            }),
+        }
+    }
+}
+
 impl ForeignFrom<storage_enums::EventType> for api_enums::EventType {
     fn foreign_from(event_type: storage_enums::EventType) -> Self {
         frunk::labelled_convert_from(event_type)
     }
 }
 
+impl ForeignFrom<api_enums::EventType> for storage_enums::EventType {
+    fn foreign_from(event_type: api_enums::EventType) -> Self {
+        frunk::labelled_convert_from(event_type)
+    }
+}
+
+impl ForeignFrom<storage_enums::EventClass> for api_enums::EventClass {
+    fn foreign_from(event_class: storage_enums::EventClass) -> Self {
+        frunk::labelled_convert_from(event_class)
+    }
+}
+
+impl ForeignFrom<storage_enums::EventObjectType> for api_enums::EventObjectType {
+    fn foreign_from(event_object_type: storage_enums::EventObjectType) -> Self {
+        frunk::labelled_convert_from(event_object_type)
+    }
+}
+
+impl ForeignFrom<storage_enums::WebhookDeliveryStatus> for api_enums::WebhookDeliveryStatus {
+    fn foreign_from(webhook_delivery_status: storage_enums::WebhookDeliveryStatus) -> Self {
+        frunk::labelled_convert_from(webhook_delivery_status)
+    }
+}
+
 impl ForeignFrom<api_enums::FutureUsage> for storage_enums::FutureUsage {
     fn foreign_from(future_usage: api_enums::FutureUsage) -> Self {
         frunk::labelled_convert_from(future_usage)
@@ -217,6 +311,48 @@ impl ForeignFrom<storage_enums::RefundStatus> for api_enums::RefundStatus {
     }
 }
 
+impl ForeignFrom<storage_enums::PayoutStatus> for api_enums::PayoutStatus {
+    fn foreign_from(status: storage_enums::PayoutStatus) -> Self {
+        frunk::labelled_convert_from(status)
+    }
+}
+
+impl ForeignFrom<api_enums::PayoutType> for storage_enums::PayoutType {
+    fn foreign_from(payout_type: api_enums::PayoutType) -> Self {
+        frunk::labelled_convert_from(payout_type)
+    }
+}
+
+impl ForeignFrom<storage_enums::PayoutType> for api_enums::PayoutType {
+    fn foreign_from(payout_type: storage_enums::PayoutType) -> Self {
+        frunk::labelled_convert_from(payout_type)
+    }
+}
+
+impl ForeignFrom<storage_enums::DisputeStage> for api_enums::DisputeStage {
+    fn foreign_from(dispute_stage: storage_enums::DisputeStage) -> Self {
+        frunk::labelled_convert_from(dispute_stage)
+    }
+}
+
+impl ForeignFrom<api_enums::DisputeStage> for storage_enums::DisputeStage {
+    fn foreign_from(dispute_stage: api_enums::DisputeStage) -> Self {
+        frunk::labelled_convert_from(dispute_stage)
+    }
+}
+
+impl ForeignFrom<storage_enums::DisputeStatus> for api_enums::DisputeStatus {
+    fn foreign_from(dispute_status: storage_enums::DisputeStatus) -> Self {
+        frunk::labelled_convert_from(dispute_status)
+    }
+}
+
+impl ForeignFrom<api_enums::DisputeStatus> for storage_enums::DisputeStatus {
+    fn foreign_from(dispute_status: api_enums::DisputeStatus) -> Self {
+        frunk::labelled_convert_from(dispute_status)
+    }
+}
+
 impl ForeignFrom<api_enums::CaptureMethod> for storage_enums::CaptureMethod {
     fn foreign_from(capture_method: api_enums::CaptureMethod) -> Self {
         frunk::labelled_convert_from(capture_method)
@@ -229,6 +365,42 @@ impl ForeignFrom<storage_enums::CaptureMethod> for api_enums::CaptureMethod {
     }
 }
 
+impl ForeignFrom<storage_enums::AttemptStatus> for storage_enums::CaptureStatus {
+    fn foreign_from(status: storage_enums::AttemptStatus) -> Self {
+        match status {
+            storage_enums::AttemptStatus::Charged
+            | storage_enums::AttemptStatus::PartialCharged => Self::Charged,
+
+            storage_enums::AttemptStatus::AuthenticationFailed
+            | storage_enums::AttemptStatus::AuthorizationFailed
+            | storage_enums::AttemptStatus::RouterDeclined
+            | storage_enums::AttemptStatus::CaptureFailed
+            | storage_enums::AttemptStatus::VoidFailed
+            | storage_enums::AttemptStatus::Failure => Self::Failed,
+
+            storage_enums::AttemptStatus::Started
+            | storage_enums::AttemptStatus::AuthenticationPending
+            | storage_enums::AttemptStatus::AuthenticationSuccessful
+            | storage_enums::AttemptStatus::Authorized
+            | storage_enums::AttemptStatus::Authorizing
+            | storage_enums::AttemptStatus::CodInitiated
+            | storage_enums::AttemptStatus::Voided
+            | storage_enums::AttemptStatus::VoidInitiated
+            | storage_enums::AttemptStatus::CaptureInitiated
+            | storage_enums::AttemptStatus::AutoRefunded
+            | storage_enums::AttemptStatus::Pending
+            | storage_enums::AttemptStatus::PaymentMethodAwaited
+            | storage_enums::AttemptStatus::ConfirmationAwaited => Self::Pending,
+        }
+    }
+}
+
+impl ForeignFrom<storage_enums::CaptureStatus> for api_enums::CaptureStatus {
+    fn foreign_from(capture_status: storage_enums::CaptureStatus) -> Self {
+        frunk::labelled_convert_from(capture_status)
+    }
+}
+
 impl ForeignFrom<api_enums::AuthenticationType> for storage_enums::AuthenticationType {
     fn foreign_from(auth_type: api_enums::AuthenticationType) -> Self {
         frunk::labelled_convert_from(auth_type)
@@ -336,6 +508,14 @@ impl ForeignTryFrom<storage::MerchantConnectorAccount> for api_models::admin::Me
             disabled: merchant_ca.disabled,
             metadata: merchant_ca.metadata,
             payment_methods_enabled,
+            authorization_ttl_hours: merchant_ca
+                .authorization_ttl_hours
+                .and_then(|hours| u16::try_from(hours).ok()),
+            fee_schedule: merchant_ca
+                .fee_schedule
+                .map(|fee_schedule| fee_schedule.parse_value("ConnectorFeeSchedule"))
+                .transpose()
+                .change_context(errors::ApiErrorResponse::InternalServerError)?,
         })
     }
 }
@@ -397,6 +577,7 @@ impl
             api_key: StrongSecret::from(plaintext_api_key.peek().to_owned()),
             created: api_key.created_at,
             expiration: api_key.expires_at.into(),
+            rate_limits: api_key.rate_limits,
         }
     }
 }
@@ -413,20 +594,32 @@ impl ForeignFrom<storage_models::api_keys::ApiKey>
             prefix: api_key.prefix.into(),
             created: api_key.created_at,
             expiration: api_key.expires_at.into(),
+            rate_limits: api_key.rate_limits,
         }
     }
 }
 
-impl ForeignFrom<api_models::api_keys::UpdateApiKeyRequest>
+impl ForeignTryFrom<api_models::api_keys::UpdateApiKeyRequest>
     for storage_models::api_keys::ApiKeyUpdate
 {
-    fn foreign_from(api_key: api_models::api_keys::UpdateApiKeyRequest) -> Self {
-        Self::Update {
+    type Error = error_stack::Report<errors::ParsingError>;
+
+    fn foreign_try_from(
+        api_key: api_models::api_keys::UpdateApiKeyRequest,
+    ) -> Result<Self, Self::Error> {
+        use common_utils::ext_traits::Encode;
+
+        Ok(Self::Update {
             name: api_key.name,
             description: api_key.description,
             expires_at: api_key.expiration.map(Into::into),
             last_used: None,
-        }
+            rate_limits: api_key
+                .rate_limits
+                .as_ref()
+                .map(Encode::<api_models::admin::RateLimits>::encode_to_value)
+                .transpose()?,
+        })
     }
 }
 
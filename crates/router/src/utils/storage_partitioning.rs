@@ -13,6 +13,10 @@ pub(crate) enum PartitionKey<'a> {
         merchant_id: &'a str,
         payment_id: &'a str,
     },
+    MerchantIdCustomerId {
+        merchant_id: &'a str,
+        customer_id: &'a str,
+    },
 }
 
 impl<'a> std::fmt::Display for PartitionKey<'a> {
@@ -22,6 +26,10 @@ impl<'a> std::fmt::Display for PartitionKey<'a> {
                 merchant_id,
                 payment_id,
             } => f.write_str(&format!("mid_{merchant_id}_pid_{payment_id}")),
+            PartitionKey::MerchantIdCustomerId {
+                merchant_id,
+                customer_id,
+            } => f.write_str(&format!("mid_{merchant_id}_cid_{customer_id}")),
         }
     }
 }
@@ -41,6 +41,7 @@ fn construct_payment_router_data() -> types::PaymentsAuthorizeRouterData {
                 card_cvc: Secret::new("999".to_string()),
                 card_issuer: None,
                 card_network: None,
+                card_issuing_country: None,
             }),
             confirm: true,
             statement_descriptor_suffix: None,
@@ -55,6 +56,7 @@ fn construct_payment_router_data() -> types::PaymentsAuthorizeRouterData {
             email: None,
             payment_experience: None,
             payment_method_type: None,
+            request_incremental_authorization: false,
         },
         response: Err(types::ErrorResponse::default()),
         payment_method_id: None,
@@ -168,6 +170,7 @@ async fn payments_create_failure() {
                 card_cvc: Secret::new("99".to_string()),
                 card_issuer: None,
                 card_network: None,
+                card_issuing_country: None,
             });
 
         let response = services::api::execute_connector_processing_step(
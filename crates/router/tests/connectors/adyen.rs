@@ -69,6 +69,7 @@ impl AdyenTest {
                 card_cvc: Secret::new(card_cvc.to_string()),
                 card_issuer: None,
                 card_network: None,
+                card_issuing_country: None,
             }),
             confirm: true,
             statement_descriptor_suffix: None,
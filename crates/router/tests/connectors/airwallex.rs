@@ -40,6 +40,7 @@ fn get_access_token() -> Option<AccessToken> {
         types::ConnectorAuthType::BodyKey { api_key, key1 } => Some(AccessToken {
             token: api_key,
             expires: key1.parse::<i64>().unwrap(),
+            lifetime: None,
         }),
         _ => None,
     }
@@ -61,6 +62,7 @@ fn payment_method_details() -> Option<types::PaymentsAuthorizeData> {
             card_cvc: Secret::new("123".to_string()),
             card_issuer: None,
             card_network: None,
+            card_issuing_country: None,
         }),
         capture_method: Some(storage_models::enums::CaptureMethod::Manual),
         ..utils::PaymentAuthorizeType::default().0
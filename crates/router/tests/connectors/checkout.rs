@@ -38,6 +38,7 @@ fn construct_payment_router_data() -> types::PaymentsAuthorizeRouterData {
                 card_cvc: "123".to_string().into(),
                 card_issuer: None,
                 card_network: None,
+                card_issuing_country: None,
             }),
             confirm: true,
             statement_descriptor_suffix: None,
@@ -52,6 +53,7 @@ fn construct_payment_router_data() -> types::PaymentsAuthorizeRouterData {
             email: None,
             payment_experience: None,
             payment_method_type: None,
+            request_incremental_authorization: false,
         },
         response: Err(types::ErrorResponse::default()),
         payment_method_id: None,
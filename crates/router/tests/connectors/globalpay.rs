@@ -55,6 +55,7 @@ fn get_default_payment_info() -> Option<PaymentInfo> {
         access_token: Some(types::AccessToken {
             token: "<access_token>".to_string(),
             expires: 18600,
+            lifetime: None,
         }),
         ..Default::default()
     })
@@ -35,6 +35,7 @@ fn get_access_token() -> Option<AccessToken> {
         ConnectorAuthType::BodyKey { api_key, key1 } => Some(AccessToken {
             token: api_key,
             expires: key1.parse::<i64>().unwrap(),
+            lifetime: None,
         }),
         _ => None,
     }
@@ -46,6 +46,7 @@ async fn should_only_authorize_payment() {
                     card_cvc: Secret::new("123".to_string()),
                     card_issuer: None,
                     card_network: None,
+                    card_issuing_country: None,
                 }),
                 capture_method: Some(storage_models::enums::CaptureMethod::Manual),
                 ..utils::PaymentAuthorizeType::default().0
@@ -70,6 +71,7 @@ async fn should_authorize_and_capture_payment() {
                     card_cvc: Secret::new("123".to_string()),
                     card_issuer: None,
                     card_network: None,
+                    card_issuing_country: None,
                 }),
                 ..utils::PaymentAuthorizeType::default().0
             }),
@@ -465,6 +465,7 @@ impl Default for CCardType {
             card_cvc: Secret::new("999".to_string()),
             card_issuer: None,
             card_network: None,
+            card_issuing_country: None,
         })
     }
 }
@@ -488,6 +489,7 @@ impl Default for PaymentAuthorizeType {
             email: None,
             payment_experience: None,
             payment_method_type: None,
+            request_incremental_authorization: false,
         };
         Self(data)
     }
@@ -500,6 +502,7 @@ impl Default for PaymentCaptureType {
             currency: enums::Currency::USD,
             connector_transaction_id: "".to_string(),
             amount: 100,
+            multiple_capture_data: None,
         })
     }
 }
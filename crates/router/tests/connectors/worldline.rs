@@ -71,6 +71,7 @@ impl WorldlineTest {
                 card_cvc: Secret::new(card_cvc.to_string()),
                 card_issuer: None,
                 card_network: None,
+                card_issuing_country: None,
             }),
             confirm: true,
             statement_descriptor_suffix: None,
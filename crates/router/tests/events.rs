@@ -0,0 +1,81 @@
+#![allow(clippy::unwrap_used)]
+
+mod utils;
+
+#[actix_web::test]
+async fn events_unauthorized() {
+    utils::setup().await;
+
+    let client = awc::Client::default();
+    let mut response;
+    let mut response_body;
+    let get_endpoints = vec!["list", "evt_test"];
+    let post_endpoints = vec!["evt_test/redeliver"];
+
+    for endpoint in get_endpoints {
+        response = client
+            .get(format!("http://127.0.0.1:8080/events/{endpoint}"))
+            .send()
+            .await
+            .unwrap();
+        response_body = response.body().await;
+        println!("{endpoint} =:= {response:?} : {response_body:?}");
+        assert_eq!(response.status(), awc::http::StatusCode::UNAUTHORIZED);
+    }
+
+    for endpoint in post_endpoints {
+        response = client
+            .post(format!("http://127.0.0.1:8080/events/{endpoint}"))
+            .send()
+            .await
+            .unwrap();
+        response_body = response.body().await;
+        println!("{endpoint} =:= {response:?} : {response_body:?}");
+        assert_eq!(response.status(), awc::http::StatusCode::UNAUTHORIZED);
+    }
+}
+
+#[actix_web::test]
+#[ignore]
+// verify the API-KEY/merchant id exists
+async fn events_of_another_merchant_are_not_found() {
+    utils::setup().await;
+
+    let event_id = format!("evt_{}", uuid::Uuid::new_v4().simple());
+    let api_key = ("API-KEY", "MySecretApiKey");
+
+    let client = awc::Client::default();
+    let mut response;
+    let response_body: serde_json::Value;
+
+    // list events
+    response = client
+        .get("http://127.0.0.1:8080/events/list")
+        .insert_header(api_key)
+        .send()
+        .await
+        .unwrap();
+    response_body = response.json().await.unwrap();
+    println!("events-list: {response:?} : {response_body:?}");
+    assert_eq!(response.status(), awc::http::StatusCode::OK);
+    assert!(response_body["data"].is_array());
+
+    // the events which do not belong to the merchant can neither be retrieved nor redelivered
+    response = client
+        .get(format!("http://127.0.0.1:8080/events/{event_id}"))
+        .insert_header(api_key)
+        .send()
+        .await
+        .unwrap();
+    println!("events-retrieve: {response:?}");
+    assert_eq!(response.status(), awc::http::StatusCode::NOT_FOUND);
+
+    response = client
+        .post(format!("http://127.0.0.1:8080/events/{event_id}/redeliver"))
+        .insert_header(api_key)
+        .send()
+        .await
+        .unwrap();
+    println!("events-redeliver: {response:?}");
+    assert_eq!(response.status(), awc::http::StatusCode::NOT_FOUND);
+}
@@ -307,6 +307,7 @@ async fn payments_create_core() {
             card_cvc: "123".to_string().into(),
             card_issuer: None,
             card_network: None,
+            card_issuing_country: None,
         })),
         payment_method: Some(api_enums::PaymentMethod::Card),
         shipping: Some(api::Address {
@@ -453,6 +454,7 @@ async fn payments_create_core_adyen_no_redirect() {
             card_cvc: "737".to_string().into(),
             card_issuer: None,
             card_network: None,
+            card_issuing_country: None,
         })),
         payment_method: Some(api_enums::PaymentMethod::Card),
         shipping: Some(api::Address {
@@ -496,3 +498,63 @@ async fn payments_create_core_adyen_no_redirect() {
         .unwrap();
     assert_eq!(expected_response, actual_response);
 }
+
+#[actix_web::test]
+#[ignore]
+// verify the API-KEY/merchant id exists
+async fn payments_create_idempotent() {
+    utils::setup().await;
+
+    let idempotency_key = format!("key_{}", uuid::Uuid::new_v4().simple());
+    let api_key = ("API-KEY", "MySecretApiKey");
+
+    let request = serde_json::json!({
+        "amount" : 6540,
+        "currency" : "USD",
+        "confirm" : false,
+    });
+
+    let client = awc::Client::default();
+    let mut response;
+    let mut response_body: serde_json::Value;
+
+    response = client
+        .post("http://127.0.0.1:8080/payments")
+        .insert_header(api_key)
+        .insert_header(("Idempotency-Key", idempotency_key.as_str()))
+        .send_json(&request)
+        .await
+        .unwrap();
+    response_body = response.json().await.unwrap();
+    println!("payments-create: {response:?} : {response_body:?}");
+    assert_eq!(response.status(), awc::http::StatusCode::OK);
+    let payment_id = response_body["payment_id"].clone();
+
+    // the retry gets the response of the first request instead of creating another payment
+    response = client
+        .post("http://127.0.0.1:8080/payments")
+        .insert_header(api_key)
+        .insert_header(("Idempotency-Key", idempotency_key.as_str()))
+        .send_json(&request)
+        .await
+        .unwrap();
+    response_body = response.json().await.unwrap();
+    println!("payments-create-retry: {response:?} : {response_body:?}");
+    assert_eq!(response.status(), awc::http::StatusCode::OK);
+    assert_eq!(response_body["payment_id"], payment_id);
+
+    // the key cannot be reused for a different request
+    response = client
+        .post("http://127.0.0.1:8080/payments")
+        .insert_header(api_key)
+        .insert_header(("Idempotency-Key", idempotency_key.as_str()))
+        .send_json(&serde_json::json!({
+            "amount" : 100,
+            "currency" : "USD",
+            "confirm" : false,
+        }))
+        .await
+        .unwrap();
+    println!("payments-create-reused-key: {response:?}");
+    assert_eq!(response.status(), awc::http::StatusCode::BAD_REQUEST);
+}
@@ -34,3 +34,191 @@ async fn payouts_todo() {
         assert_eq!(response.status(), awc::http::StatusCode::OK);
     }
 }
+
+#[actix_web::test]
+async fn payouts_unauthorized() {
+    utils::setup().await;
+
+    let client = awc::Client::default();
+    let mut response;
+    let mut response_body;
+    let get_endpoints = vec!["list", "accounts", "payout_test"];
+    let post_endpoints = vec![
+        "payout_test/fulfill",
+        "payout_test/reverse",
+        "payout_test/cancel",
+    ];
+
+    for endpoint in get_endpoints {
+        response = client
+            .get(format!("http://127.0.0.1:8080/payouts/{endpoint}"))
+            .send()
+            .await
+            .unwrap();
+        response_body = response.body().await;
+        println!("{endpoint} =:= {response:?} : {response_body:?}");
+        assert_eq!(response.status(), awc::http::StatusCode::UNAUTHORIZED);
+    }
+
+    for endpoint in post_endpoints {
+        response = client
+            .post(format!("http://127.0.0.1:8080/payouts/{endpoint}"))
+            .send()
+            .await
+            .unwrap();
+        response_body = response.body().await;
+        println!("{endpoint} =:= {response:?} : {response_body:?}");
+        assert_eq!(response.status(), awc::http::StatusCode::UNAUTHORIZED);
+    }
+}
+
+#[actix_web::test]
+#[ignore]
+// verify the API-KEY/merchant id exists, payouts which are not confirmed are not sent to the connector
+async fn payouts_created_payout_success() {
+    utils::setup().await;
+
+    let payout_id = format!("payout_{}", uuid::Uuid::new_v4().simple());
+    let api_key = ("API-KEY", "MySecretApiKey");
+
+    let request = serde_json::json!({
+        "payout_id": payout_id,
+        "amount": 1000,
+        "currency": "EUR",
+        "connector": "adyen",
+        "payout_type": "card",
+    });
+
+    let client = awc::Client::default();
+    let mut response;
+    let mut response_body: serde_json::Value;
+
+    // create payout
+    response = client
+        .post("http://127.0.0.1:8080/payouts/create")
+        .insert_header(api_key)
+        .send_json(&request)
+        .await
+        .unwrap();
+    response_body = response.json().await.unwrap();
+    println!("payout-create: {response:?} : {response_body:?}");
+    assert_eq!(response.status(), awc::http::StatusCode::OK);
+    assert_eq!(response_body["status"], "created");
+
+    // update payout
+    response = client
+        .post(format!("http://127.0.0.1:8080/payouts/{payout_id}"))
+        .insert_header(api_key)
+        .send_json(&serde_json::json!({ "amount": 2000 }))
+        .await
+        .unwrap();
+    response_body = response.json().await.unwrap();
+    println!("payout-update: {response:?} : {response_body:?}");
+    assert_eq!(response.status(), awc::http::StatusCode::OK);
+    assert_eq!(response_body["amount"], 2000);
+
+    // a payout which has not been submitted to the connector can neither be fulfilled nor reversed
+    for endpoint in ["fulfill", "reverse"] {
+        response = client
+            .post(format!(
+                "http://127.0.0.1:8080/payouts/{payout_id}/{endpoint}"
+            ))
+            .insert_header(api_key)
+            .send()
+            .await
+            .unwrap();
+        println!("payout-{endpoint}: {response:?}");
+        assert_eq!(response.status(), awc::http::StatusCode::BAD_REQUEST);
+    }
+
+    // cancel payout
+    response = client
+        .post(format!("http://127.0.0.1:8080/payouts/{payout_id}/cancel"))
+        .insert_header(api_key)
+        .send()
+        .await
+        .unwrap();
+    response_body = response.json().await.unwrap();
+    println!("payout-cancel: {response:?} : {response_body:?}");
+    assert_eq!(response.status(), awc::http::StatusCode::OK);
+    assert_eq!(response_body["status"], "cancelled");
+
+    // retrieve payout
+    response = client
+        .get(format!("http://127.0.0.1:8080/payouts/{payout_id}"))
+        .insert_header(api_key)
+        .send()
+        .await
+        .unwrap();
+    response_body = response.json().await.unwrap();
+    println!("payout-retrieve: {response:?} : {response_body:?}");
+    assert_eq!(response.status(), awc::http::StatusCode::OK);
+    assert_eq!(response_body["status"], "cancelled");
+
+    // a cancelled payout cannot be cancelled again
+    response = client
+        .post(format!("http://127.0.0.1:8080/payouts/{payout_id}/cancel"))
+        .insert_header(api_key)
+        .send()
+        .await
+        .unwrap();
+    println!("payout-cancel: {response:?}");
+    assert_eq!(response.status(), awc::http::StatusCode::BAD_REQUEST);
+}
+
+#[actix_web::test]
+#[ignore]
+// verify the API-KEY/merchant id exists
+async fn payouts_confirm_without_recipient_fails() {
+    utils::setup().await;
+
+    let payout_id = format!("payout_{}", uuid::Uuid::new_v4().simple());
+    let api_key = ("API-KEY", "MySecretApiKey");
+
+    let client = awc::Client::default();
+
+    // the connector does not support payouts
+    let mut response = client
+        .post("http://127.0.0.1:8080/payouts/create")
+        .insert_header(api_key)
+        .send_json(&serde_json::json!({
+            "payout_id": payout_id,
+            "amount": 1000,
+            "currency": "EUR",
+            "connector": "stripe",
+            "payout_type": "card",
+        }))
+        .await
+        .unwrap();
+    println!("payout-create: {response:?}");
+    assert_eq!(response.status(), awc::http::StatusCode::BAD_REQUEST);
+
+    // the payout is confirmed without the recipient details
+    response = client
+        .post("http://127.0.0.1:8080/payouts/create")
+        .insert_header(api_key)
+        .send_json(&serde_json::json!({
+            "payout_id": payout_id,
+            "amount": 1000,
+            "currency": "EUR",
+            "connector": "adyen",
+            "payout_type": "card",
+            "confirm": true,
+        }))
+        .await
+        .unwrap();
+    println!("payout-create: {response:?}");
+    assert_eq!(response.status(), awc::http::StatusCode::BAD_REQUEST);
+
+    // the payout is stored but not submitted to the connector
+    response = client
+        .get(format!("http://127.0.0.1:8080/payouts/{payout_id}"))
+        .insert_header(api_key)
+        .send()
+        .await
+        .unwrap();
+    let response_body: serde_json::Value = response.json().await.unwrap();
+    println!("payout-retrieve: {response:?} : {response_body:?}");
+    assert_eq!(response.status(), awc::http::StatusCode::OK);
+    assert_eq!(response_body["status"], "created");
+}
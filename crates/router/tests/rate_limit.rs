@@ -0,0 +1,32 @@
+#![allow(clippy::unwrap_used)]
+
+mod utils;
+
+#[actix_web::test]
+#[ignore]
+// verify the API-KEY/merchant id exists and the rate limit is enabled
+async fn rate_limit_headers_are_returned() {
+    utils::setup().await;
+
+    let payment_id = format!("test_{}", uuid::Uuid::new_v4());
+    let api_key = ("API-KEY", "MySecretApiKey");
+
+    let client = awc::Client::default();
+
+    // both the native and the Stripe compatible routes are rate limited
+    for url in [
+        format!("http://127.0.0.1:8080/payments/{payment_id}"),
+        format!("http://127.0.0.1:8080/vs/v1/payment_intents/{payment_id}"),
+    ] {
+        let response = client
+            .get(url.as_str())
+            .insert_header(api_key)
+            .send()
+            .await
+            .unwrap();
+        println!("{url} =:= {response:?}");
+        assert!(response.headers().contains_key("RateLimit-Limit"));
+        assert!(response.headers().contains_key("RateLimit-Remaining"));
+        assert!(response.headers().contains_key("RateLimit-Reset"));
+    }
+}
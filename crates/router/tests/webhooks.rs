@@ -1,3 +1,5 @@
+#![allow(clippy::unwrap_used)]
+
 // use std::sync;
 
 // use router::{configs::settings, connection, core::webhooks, types::api};
@@ -56,3 +58,140 @@
 
 //     assert!(lookup_res);
 // }
+
+use router::{
+    connector,
+    types::{
+        api::{self, IncomingWebhook},
+        storage::enums,
+        transformers::ForeignTryInto,
+    },
+};
+
+mod utils;
+
+fn stripe_dispute_webhook(event_type: &str, dispute_id: &str, status: &str) -> Vec<u8> {
+    serde_json::json!({
+        "type": event_type,
+        "data": {
+            "object": {
+                "id": dispute_id,
+                "amount": 1000,
+                "currency": "usd",
+                "payment_intent": "pi_disputed",
+                "reason": "fraudulent",
+                "status": status,
+                "evidence_details": { "due_by": 1680307199 },
+                "created": 1679000000
+            }
+        }
+    })
+    .to_string()
+    .into_bytes()
+}
+
+#[actix_web::test]
+async fn stripe_dispute_webhook_details() {
+    let headers = actix_web::http::header::HeaderMap::new();
+    for (event_type, connector_status, dispute_status) in [
+        (
+            "charge.dispute.created",
+            "needs_response",
+            enums::DisputeStatus::DisputeOpened,
+        ),
+        (
+            "charge.dispute.updated",
+            "under_review",
+            enums::DisputeStatus::DisputeChallenged,
+        ),
+        (
+            "charge.dispute.closed",
+            "won",
+            enums::DisputeStatus::DisputeWon,
+        ),
+        (
+            "charge.dispute.closed",
+            "lost",
+            enums::DisputeStatus::DisputeLost,
+        ),
+    ] {
+        let body = stripe_dispute_webhook(event_type, "dp_stripe", connector_status);
+        let request_details = api::IncomingWebhookRequestDetails {
+            method: actix_web::http::Method::POST,
+            headers: &headers,
+            body: &body,
+        };
+
+        assert_eq!(
+            connector::Stripe
+                .get_webhook_object_reference_id(&request_details)
+                .unwrap(),
+            "pi_disputed"
+        );
+        let event = connector::Stripe
+            .get_webhook_event_type(&request_details)
+            .unwrap();
+        assert!(matches!(
+            api::WebhookFlow::from(event.clone()),
+            api::WebhookFlow::Dispute
+        ));
+        let status: enums::DisputeStatus = event.foreign_try_into().unwrap();
+        assert_eq!(status, dispute_status);
+
+        let dispute_details = connector::Stripe
+            .get_dispute_details(&request_details)
+            .unwrap();
+        assert_eq!(dispute_details.connector_dispute_id, "dp_stripe");
+        assert_eq!(dispute_details.connector_status, connector_status);
+        assert_eq!(dispute_details.amount, 1000);
+        assert!(dispute_details.challenge_required_by.is_some());
+    }
+}
+
+#[actix_web::test]
+async fn stripe_closed_warning_dispute_webhook_is_not_processed() {
+    let body = stripe_dispute_webhook("charge.dispute.closed", "dp_warning", "warning_closed");
+    let headers = actix_web::http::header::HeaderMap::new();
+    let request_details = api::IncomingWebhookRequestDetails {
+        method: actix_web::http::Method::POST,
+        headers: &headers,
+        body: &body,
+    };
+
+    assert!(connector::Stripe
+        .get_webhook_event_type(&request_details)
+        .is_err());
+}
+
+#[actix_web::test]
+async fn disputes_unauthorized() {
+    utils::setup().await;
+
+    let client = awc::Client::default();
+    let mut response;
+    let mut response_body;
+    let get_endpoints = vec!["list", "dp_test"];
+    let post_endpoints = vec!["accept/dp_test", "evidence/dp_test"];
+
+    for endpoint in get_endpoints {
+        response = client
+            .get(format!("http://127.0.0.1:8080/disputes/{endpoint}"))
+            .send()
+            .await
+            .unwrap();
+        response_body = response.body().await;
+        println!("{endpoint} =:= {response:?} : {response_body:?}");
+        assert_eq!(response.status(), awc::http::StatusCode::UNAUTHORIZED);
+    }
+
+    for endpoint in post_endpoints {
+        response = client
+            .post(format!("http://127.0.0.1:8080/disputes/{endpoint}"))
+            .send()
+            .await
+            .unwrap();
+        response_body = response.body().await;
+        println!("{endpoint} =:= {response:?} : {response_body:?}");
+        assert_eq!(response.status(), awc::http::StatusCode::UNAUTHORIZED);
+    }
+}
@@ -21,6 +21,8 @@ enum Derives {
     Verify,
     Session,
     SessionData,
+    IncrementalAuthorization,
+    IncrementalAuthorizationData,
 }
 
 impl From<String> for Derives {
@@ -39,6 +41,8 @@ impl From<String> for Derives {
             "verifydata" => Self::VerifyData,
             "session" => Self::Session,
             "sessiondata" => Self::SessionData,
+            "incrementalauthorization" => Self::IncrementalAuthorization,
+            "incrementalauthorizationdata" => Self::IncrementalAuthorizationData,
             _ => Self::Authorize,
         }
     }
@@ -115,6 +119,12 @@ impl Conversion {
             Derives::VerifyData => syn::Ident::new("VerifyRequestData", Span::call_site()),
             Derives::Session => syn::Ident::new("PaymentsSessionRequest", Span::call_site()),
             Derives::SessionData => syn::Ident::new("PaymentsSessionData", Span::call_site()),
+            Derives::IncrementalAuthorization => {
+                syn::Ident::new("PaymentsIncrementalAuthorizationRequest", Span::call_site())
+            }
+            Derives::IncrementalAuthorizationData => {
+                syn::Ident::new("PaymentsIncrementalAuthorizationData", Span::call_site())
+            }
         }
     }
 
@@ -328,6 +338,7 @@ pub fn operation_derive_inner(input: DeriveInput) -> syn::Result<proc_macro::Tok
                     PaymentsCancelData,
                     PaymentsAuthorizeData,
                     PaymentsSessionData,
+                    PaymentsIncrementalAuthorizationData,
 
                     api::{
                         PaymentsCaptureRequest,
@@ -336,6 +347,7 @@ pub fn operation_derive_inner(input: DeriveInput) -> syn::Result<proc_macro::Tok
                         PaymentsRequest,
                         PaymentsStartRequest,
                         PaymentsSessionRequest,
+                        PaymentsIncrementalAuthorizationRequest,
                         VerifyRequest
                     }
                 };
@@ -62,6 +62,10 @@ pub enum Flow {
     MerchantsAccountUpdate,
     /// Merchants account delete flow.
     MerchantsAccountDelete,
+    /// Merchants account webhook secret rotate flow.
+    MerchantsAccountWebhookSecretRotate,
+    /// Connector health retrieve flow.
+    ConnectorHealthRetrieve,
     /// Merchant Connectors create flow.
     MerchantConnectorsCreate,
     /// Merchant Connectors retrieve flow.
@@ -120,6 +124,10 @@ pub enum Flow {
     PaymentsCapture,
     /// Payments cancel flow.
     PaymentsCancel,
+    /// Payments scheduled capture cancel flow.
+    PaymentsScheduledCaptureCancel,
+    /// Payments incremental authorization flow.
+    PaymentsIncrementalAuthorization,
     /// Payments Session Token flow
     PaymentsSessionToken,
     /// Payments start flow.
@@ -132,12 +140,16 @@ pub enum Flow {
     PayoutsRetrieve,
     /// Payouts update flow.
     PayoutsUpdate,
+    /// Payouts fulfill flow.
+    PayoutsFulfill,
     /// Payouts reverse flow.
     PayoutsReverse,
     /// Payouts cancel flow.
     PayoutsCancel,
     /// Payouts accounts flow.
     PayoutsAccounts,
+    /// Payouts list flow.
+    PayoutsList,
     /// Refunds create flow.
     RefundsCreate,
     /// Refunds retrieve flow.
@@ -146,6 +158,28 @@ pub enum Flow {
     RefundsUpdate,
     /// Refunds list flow.
     RefundsList,
+    /// Disputes retrieve flow.
+    DisputesRetrieve,
+    /// Disputes list flow.
+    DisputesList,
+    /// Disputes accept flow.
+    DisputesAccept,
+    /// Disputes evidence submit flow.
+    DisputesEvidenceSubmit,
+    /// Events list flow.
+    EventsList,
+    /// Events retrieve flow.
+    EventsRetrieve,
+    /// Events redeliver flow.
+    EventsRedeliver,
+    /// Routing simulate flow.
+    RoutingSimulate,
+    /// File create flow.
+    CreateFile,
+    /// File delete flow.
+    DeleteFile,
+    /// File retrieve flow.
+    RetrieveFile,
     /// Incoming Webhook Receive
     IncomingWebhookReceive,
     /// Validate payment method flow
@@ -29,9 +29,7 @@ pub struct AddressNew {
 #[derive(Clone, Debug, Deserialize, Serialize, Identifiable, Queryable, frunk::LabelledGeneric)]
 #[diesel(table_name = address)]
 pub struct Address {
-    #[serde(skip_serializing)]
     pub id: i32,
-    #[serde(skip_serializing)]
     pub address_id: String,
     pub city: Option<String>,
     pub country: Option<String>,
@@ -44,17 +42,15 @@ pub struct Address {
     pub last_name: Option<Secret<String>>,
     pub phone_number: Option<Secret<String>>,
     pub country_code: Option<String>,
-    #[serde(skip_serializing)]
     #[serde(with = "custom_serde::iso8601")]
     pub created_at: PrimitiveDateTime,
-    #[serde(skip_serializing)]
     #[serde(with = "custom_serde::iso8601")]
     pub modified_at: PrimitiveDateTime,
     pub customer_id: String,
     pub merchant_id: String,
 }
 
-#[derive(Debug, frunk::LabelledGeneric)]
+#[derive(Clone, Debug, Deserialize, Serialize, frunk::LabelledGeneric)]
 pub enum AddressUpdate {
     Update {
         city: Option<String>,
@@ -88,6 +84,27 @@ pub struct AddressUpdateInternal {
     modified_at: PrimitiveDateTime,
 }
 
+impl AddressUpdate {
+    pub fn apply_changeset(self, source: Address) -> Address {
+        let update: AddressUpdateInternal = self.into();
+        Address {
+            city: update.city.or(source.city),
+            country: update.country.or(source.country),
+            line1: update.line1.or(source.line1),
+            line2: update.line2.or(source.line2),
+            line3: update.line3.or(source.line3),
+            state: update.state.or(source.state),
+            zip: update.zip.or(source.zip),
+            first_name: update.first_name.or(source.first_name),
+            last_name: update.last_name.or(source.last_name),
+            phone_number: update.phone_number.or(source.phone_number),
+            country_code: update.country_code.or(source.country_code),
+            modified_at: update.modified_at,
+            ..source
+        }
+    }
+}
+
 impl From<AddressUpdate> for AddressUpdateInternal {
     fn from(address_update: AddressUpdate) -> Self {
         match address_update {
@@ -15,6 +15,7 @@ pub struct ApiKey {
     pub created_at: PrimitiveDateTime,
     pub expires_at: Option<PrimitiveDateTime>,
     pub last_used: Option<PrimitiveDateTime>,
+    pub rate_limits: Option<serde_json::Value>,
 }
 
 #[derive(Debug, Insertable)]
@@ -29,6 +30,7 @@ pub struct ApiKeyNew {
     pub created_at: PrimitiveDateTime,
     pub expires_at: Option<PrimitiveDateTime>,
     pub last_used: Option<PrimitiveDateTime>,
+    pub rate_limits: Option<serde_json::Value>,
 }
 
 #[derive(Debug)]
@@ -38,6 +40,7 @@ pub enum ApiKeyUpdate {
         description: Option<String>,
         expires_at: Option<Option<PrimitiveDateTime>>,
         last_used: Option<PrimitiveDateTime>,
+        rate_limits: Option<serde_json::Value>,
     },
     LastUsedUpdate {
         last_used: PrimitiveDateTime,
@@ -51,6 +54,7 @@ pub(crate) struct ApiKeyUpdateInternal {
     pub description: Option<String>,
     pub expires_at: Option<Option<PrimitiveDateTime>>,
     pub last_used: Option<PrimitiveDateTime>,
+    pub rate_limits: Option<serde_json::Value>,
 }
 
 impl From<ApiKeyUpdate> for ApiKeyUpdateInternal {
@@ -61,17 +65,20 @@ impl From<ApiKeyUpdate> for ApiKeyUpdateInternal {
                 description,
                 expires_at,
                 last_used,
+                rate_limits,
             } => Self {
                 name,
                 description,
                 expires_at,
                 last_used,
+                rate_limits,
             },
             ApiKeyUpdate::LastUsedUpdate { last_used } => Self {
                 last_used: Some(last_used),
                 name: None,
                 description: None,
                 expires_at: None,
+                rate_limits: None,
             },
         }
     }
@@ -0,0 +1,103 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::captures};
+
+#[derive(Clone, Debug, Eq, Identifiable, Queryable, PartialEq, serde::Serialize)]
+#[diesel(table_name = captures)]
+pub struct Capture {
+    pub id: i32,
+    pub capture_id: String,
+    pub payment_id: String,
+    pub merchant_id: String,
+    pub attempt_id: String,
+    pub status: storage_enums::CaptureStatus,
+    pub amount: i64,
+    pub currency: Option<storage_enums::Currency>,
+    pub connector: String,
+    pub connector_capture_id: Option<String>,
+    pub capture_sequence: i32,
+    pub error_message: Option<String>,
+    pub error_code: Option<String>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay, serde::Serialize)]
+#[diesel(table_name = captures)]
+pub struct CaptureNew {
+    pub capture_id: String,
+    pub payment_id: String,
+    pub merchant_id: String,
+    pub attempt_id: String,
+    pub status: storage_enums::CaptureStatus,
+    pub amount: i64,
+    pub currency: Option<storage_enums::Currency>,
+    pub connector: String,
+    pub capture_sequence: i32,
+}
+
+#[derive(Debug)]
+pub enum CaptureUpdate {
+    ResponseUpdate {
+        status: storage_enums::CaptureStatus,
+        connector_capture_id: Option<String>,
+    },
+    ErrorUpdate {
+        status: storage_enums::CaptureStatus,
+        error_message: Option<String>,
+        error_code: Option<String>,
+    },
+}
+
+#[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = captures)]
+pub struct CaptureUpdateInternal {
+    status: Option<storage_enums::CaptureStatus>,
+    connector_capture_id: Option<String>,
+    error_message: Option<String>,
+    error_code: Option<String>,
+    modified_at: Option<PrimitiveDateTime>,
+}
+
+impl From<CaptureUpdate> for CaptureUpdateInternal {
+    fn from(capture_update: CaptureUpdate) -> Self {
+        let modified_at = Some(common_utils::date_time::now());
+        match capture_update {
+            CaptureUpdate::ResponseUpdate {
+                status,
+                connector_capture_id,
+            } => Self {
+                status: Some(status),
+                connector_capture_id,
+                modified_at,
+                ..Default::default()
+            },
+            CaptureUpdate::ErrorUpdate {
+                status,
+                error_message,
+                error_code,
+            } => Self {
+                status: Some(status),
+                error_message,
+                error_code,
+                modified_at,
+                ..Default::default()
+            },
+        }
+    }
+}
+
+impl CaptureUpdate {
+    pub fn apply_changeset(self, source: Capture) -> Capture {
+        let update: CaptureUpdateInternal = self.into();
+        Capture {
+            status: update.status.unwrap_or(source.status),
+            connector_capture_id: update.connector_capture_id.or(source.connector_capture_id),
+            error_message: update.error_message.or(source.error_message),
+            error_code: update.error_code.or(source.error_code),
+            modified_at: update.modified_at.unwrap_or(source.modified_at),
+            ..source
+        }
+    }
+}
@@ -1,11 +1,14 @@
 use common_utils::pii;
 use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
 use masking::Secret;
+use serde::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
 
 use crate::schema::customers;
 
-#[derive(Default, Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[derive(
+    Default, Clone, Debug, Deserialize, Serialize, Insertable, router_derive::DebugAsDisplay,
+)]
 #[diesel(table_name = customers)]
 pub struct CustomerNew {
     pub customer_id: String,
@@ -18,7 +21,7 @@ pub struct CustomerNew {
     pub metadata: Option<pii::SecretSerdeValue>,
 }
 
-#[derive(Clone, Debug, Identifiable, Queryable)]
+#[derive(Clone, Debug, Deserialize, Serialize, Identifiable, Queryable)]
 #[diesel(table_name = customers)]
 pub struct Customer {
     pub id: i32,
@@ -33,7 +36,7 @@ pub struct Customer {
     pub metadata: Option<pii::SecretSerdeValue>,
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub enum CustomerUpdate {
     Update {
         name: Option<String>,
@@ -56,6 +59,21 @@ pub struct CustomerUpdateInternal {
     metadata: Option<pii::SecretSerdeValue>,
 }
 
+impl CustomerUpdate {
+    pub fn apply_changeset(self, source: Customer) -> Customer {
+        let update: CustomerUpdateInternal = self.into();
+        Customer {
+            name: update.name.or(source.name),
+            email: update.email.or(source.email),
+            phone: update.phone.or(source.phone),
+            description: update.description.or(source.description),
+            phone_country_code: update.phone_country_code.or(source.phone_country_code),
+            metadata: update.metadata.or(source.metadata),
+            ..source
+        }
+    }
+}
+
 impl From<CustomerUpdate> for CustomerUpdateInternal {
     fn from(customer_update: CustomerUpdate) -> Self {
         match customer_update {
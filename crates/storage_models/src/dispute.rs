@@ -1 +1,155 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use time::PrimitiveDateTime;
 
+use crate::{enums as storage_enums, schema::dispute};
+
+#[derive(Clone, Debug, Eq, Identifiable, Queryable, PartialEq, serde::Serialize)]
+#[diesel(table_name = dispute)]
+pub struct Dispute {
+    pub id: i32,
+    pub dispute_id: String,
+    pub amount: i64,
+    pub currency: storage_enums::Currency,
+    pub dispute_stage: storage_enums::DisputeStage,
+    pub dispute_status: storage_enums::DisputeStatus,
+    pub payment_id: String,
+    pub attempt_id: String,
+    pub merchant_id: String,
+    pub connector: String,
+    pub connector_status: String,
+    pub connector_dispute_id: String,
+    pub connector_reason: Option<String>,
+    pub connector_reason_code: Option<String>,
+    pub challenge_required_by: Option<PrimitiveDateTime>,
+    pub evidence: Option<serde_json::Value>,
+    pub connector_created_at: Option<PrimitiveDateTime>,
+    pub connector_updated_at: Option<PrimitiveDateTime>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay, serde::Serialize)]
+#[diesel(table_name = dispute)]
+pub struct DisputeNew {
+    pub dispute_id: String,
+    pub amount: i64,
+    pub currency: storage_enums::Currency,
+    pub dispute_stage: storage_enums::DisputeStage,
+    pub dispute_status: storage_enums::DisputeStatus,
+    pub payment_id: String,
+    pub attempt_id: String,
+    pub merchant_id: String,
+    pub connector: String,
+    pub connector_status: String,
+    pub connector_dispute_id: String,
+    pub connector_reason: Option<String>,
+    pub connector_reason_code: Option<String>,
+    pub challenge_required_by: Option<PrimitiveDateTime>,
+    pub connector_created_at: Option<PrimitiveDateTime>,
+    pub connector_updated_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(Debug)]
+pub enum DisputeUpdate {
+    Update {
+        dispute_stage: storage_enums::DisputeStage,
+        dispute_status: storage_enums::DisputeStatus,
+        connector_status: String,
+        connector_reason: Option<String>,
+        connector_reason_code: Option<String>,
+        challenge_required_by: Option<PrimitiveDateTime>,
+        connector_updated_at: Option<PrimitiveDateTime>,
+    },
+    StatusUpdate {
+        dispute_status: storage_enums::DisputeStatus,
+        connector_status: Option<String>,
+    },
+    EvidenceUpdate {
+        evidence: serde_json::Value,
+        dispute_status: storage_enums::DisputeStatus,
+        connector_status: Option<String>,
+    },
+}
+
+#[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = dispute)]
+pub struct DisputeUpdateInternal {
+    dispute_stage: Option<storage_enums::DisputeStage>,
+    dispute_status: Option<storage_enums::DisputeStatus>,
+    connector_status: Option<String>,
+    connector_reason: Option<String>,
+    connector_reason_code: Option<String>,
+    challenge_required_by: Option<PrimitiveDateTime>,
+    evidence: Option<serde_json::Value>,
+    connector_updated_at: Option<PrimitiveDateTime>,
+    modified_at: Option<PrimitiveDateTime>,
+}
+
+impl From<DisputeUpdate> for DisputeUpdateInternal {
+    fn from(dispute_update: DisputeUpdate) -> Self {
+        let modified_at = Some(common_utils::date_time::now());
+        match dispute_update {
+            DisputeUpdate::Update {
+                dispute_stage,
+                dispute_status,
+                connector_status,
+                connector_reason,
+                connector_reason_code,
+                challenge_required_by,
+                connector_updated_at,
+            } => Self {
+                dispute_stage: Some(dispute_stage),
+                dispute_status: Some(dispute_status),
+                connector_status: Some(connector_status),
+                connector_reason,
+                connector_reason_code,
+                challenge_required_by,
+                connector_updated_at,
+                modified_at,
+                ..Default::default()
+            },
+            DisputeUpdate::StatusUpdate {
+                dispute_status,
+                connector_status,
+            } => Self {
+                dispute_status: Some(dispute_status),
+                connector_status,
+                modified_at,
+                ..Default::default()
+            },
+            DisputeUpdate::EvidenceUpdate {
+                evidence,
+                dispute_status,
+                connector_status,
+            } => Self {
+                evidence: Some(evidence),
+                dispute_status: Some(dispute_status),
+                connector_status,
+                modified_at,
+                ..Default::default()
+            },
+        }
+    }
+}
+
+impl DisputeUpdate {
+    pub fn apply_changeset(self, source: Dispute) -> Dispute {
+        let update: DisputeUpdateInternal = self.into();
+        Dispute {
+            dispute_stage: update.dispute_stage.unwrap_or(source.dispute_stage),
+            dispute_status: update.dispute_status.unwrap_or(source.dispute_status),
+            connector_status: update.connector_status.unwrap_or(source.connector_status),
+            connector_reason: update.connector_reason.or(source.connector_reason),
+            connector_reason_code: update
+                .connector_reason_code
+                .or(source.connector_reason_code),
+            challenge_required_by: update
+                .challenge_required_by
+                .or(source.challenge_required_by),
+            evidence: update.evidence.or(source.evidence),
+            connector_updated_at: update.connector_updated_at.or(source.connector_updated_at),
+            modified_at: update.modified_at.unwrap_or(source.modified_at),
+            ..source
+        }
+    }
+}
@@ -0,0 +1,34 @@
+use common_utils::custom_serde;
+use diesel::{Identifiable, Insertable, Queryable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::schema::event_delivery_attempt;
+
+#[derive(Clone, Debug, Deserialize, Insertable, Serialize, router_derive::DebugAsDisplay)]
+#[diesel(table_name = event_delivery_attempt)]
+pub struct EventDeliveryAttemptNew {
+    pub event_id: String,
+    pub merchant_id: String,
+    pub attempt_number: i32,
+    pub delivered: bool,
+    pub response_status_code: Option<i32>,
+    pub error_message: Option<String>,
+    pub endpoint_id: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, Identifiable, Queryable)]
+#[diesel(table_name = event_delivery_attempt)]
+pub struct EventDeliveryAttempt {
+    #[serde(skip_serializing)]
+    pub id: i32,
+    pub event_id: String,
+    pub merchant_id: String,
+    pub attempt_number: i32,
+    pub delivered: bool,
+    pub response_status_code: Option<i32>,
+    pub error_message: Option<String>,
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    pub endpoint_id: String,
+}
@@ -1,5 +1,5 @@
 use common_utils::custom_serde;
-use diesel::{Identifiable, Insertable, Queryable};
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
 use serde::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
 
@@ -16,6 +16,12 @@ pub struct EventNew {
     pub intent_reference_id: Option<String>,
     pub primary_object_id: String,
     pub primary_object_type: storage_enums::EventObjectType,
+    pub webhook_delivery_status: storage_enums::WebhookDeliveryStatus,
+    pub merchant_id: Option<String>,
+    /// The webhook body in the format expected by the merchant
+    pub request_body: Option<serde_json::Value>,
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize, Identifiable, Queryable)]
@@ -32,4 +38,64 @@ pub struct Event {
     pub primary_object_type: storage_enums::EventObjectType,
     #[serde(with = "custom_serde::iso8601")]
     pub created_at: PrimitiveDateTime,
+    pub webhook_delivery_status: storage_enums::WebhookDeliveryStatus,
+    pub merchant_id: Option<String>,
+    pub request_body: Option<serde_json::Value>,
+}
+
+#[derive(Debug)]
+pub enum EventUpdate {
+    DeliveryStatusUpdate {
+        is_webhook_notified: bool,
+        webhook_delivery_status: storage_enums::WebhookDeliveryStatus,
+    },
+}
+
+#[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = events)]
+pub struct EventUpdateInternal {
+    is_webhook_notified: Option<bool>,
+    webhook_delivery_status: Option<storage_enums::WebhookDeliveryStatus>,
+}
+
+impl From<EventUpdate> for EventUpdateInternal {
+    fn from(event_update: EventUpdate) -> Self {
+        match event_update {
+            EventUpdate::DeliveryStatusUpdate {
+                is_webhook_notified,
+                webhook_delivery_status,
+            } => Self {
+                is_webhook_notified: Some(is_webhook_notified),
+                webhook_delivery_status: Some(webhook_delivery_status),
+            },
+        }
+    }
+}
+
+impl EventUpdate {
+    pub fn apply_changeset(self, source: Event) -> Event {
+        let update: EventUpdateInternal = self.into();
+        Event {
+            is_webhook_notified: update
+                .is_webhook_notified
+                .unwrap_or(source.is_webhook_notified),
+            webhook_delivery_status: update
+                .webhook_delivery_status
+                .unwrap_or(source.webhook_delivery_status),
+            ..source
+        }
+    }
+}
+
+/// Tracking data of the process tracker task which delivers an outgoing webhook
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct OutgoingWebhookTrackingData {
+    pub merchant_id: String,
+    pub event_id: String,
+    /// The webhook body in the format expected by the merchant
+    pub request_body: serde_json::Value,
+    /// The webhook endpoint to deliver the webhook to, the merchant's primary `webhook_url` if
+    /// not present
+    #[serde(default)]
+    pub endpoint_id: Option<String>,
 }
@@ -0,0 +1,77 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::file_metadata};
+
+#[derive(Clone, Debug, Eq, Identifiable, Queryable, PartialEq, serde::Serialize)]
+#[diesel(table_name = file_metadata)]
+pub struct FileMetadata {
+    pub id: i32,
+    pub file_id: String,
+    pub merchant_id: String,
+    pub file_name: Option<String>,
+    pub file_size: i32,
+    pub file_type: String,
+    pub provider_file_id: Option<String>,
+    pub file_upload_provider: Option<storage_enums::FileUploadProvider>,
+    pub available: bool,
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay, serde::Serialize)]
+#[diesel(table_name = file_metadata)]
+pub struct FileMetadataNew {
+    pub file_id: String,
+    pub merchant_id: String,
+    pub file_name: Option<String>,
+    pub file_size: i32,
+    pub file_type: String,
+    pub provider_file_id: Option<String>,
+    pub file_upload_provider: Option<storage_enums::FileUploadProvider>,
+    pub available: bool,
+}
+
+#[derive(Debug)]
+pub enum FileMetadataUpdate {
+    Update {
+        provider_file_id: Option<String>,
+        file_upload_provider: Option<storage_enums::FileUploadProvider>,
+        available: bool,
+    },
+}
+
+#[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = file_metadata)]
+pub struct FileMetadataUpdateInternal {
+    provider_file_id: Option<String>,
+    file_upload_provider: Option<storage_enums::FileUploadProvider>,
+    available: Option<bool>,
+}
+
+impl From<FileMetadataUpdate> for FileMetadataUpdateInternal {
+    fn from(file_metadata_update: FileMetadataUpdate) -> Self {
+        match file_metadata_update {
+            FileMetadataUpdate::Update {
+                provider_file_id,
+                file_upload_provider,
+                available,
+            } => Self {
+                provider_file_id,
+                file_upload_provider,
+                available: Some(available),
+            },
+        }
+    }
+}
+
+impl FileMetadataUpdate {
+    pub fn apply_changeset(self, source: FileMetadata) -> FileMetadata {
+        let update: FileMetadataUpdateInternal = self.into();
+        FileMetadata {
+            provider_file_id: update.provider_file_id.or(source.provider_file_id),
+            file_upload_provider: update.file_upload_provider.or(source.file_upload_provider),
+            available: update.available.unwrap_or(source.available),
+            ..source
+        }
+    }
+}
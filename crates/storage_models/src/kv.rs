@@ -2,6 +2,9 @@ use error_stack::{IntoReport, ResultExt};
 use serde::{Deserialize, Serialize};
 
 use crate::{
+    address::{Address, AddressNew, AddressUpdate},
+    connector_response::{ConnectorResponse, ConnectorResponseNew, ConnectorResponseUpdate},
+    customers::{Customer, CustomerNew, CustomerUpdate},
     errors,
     payment_attempt::{PaymentAttempt, PaymentAttemptNew, PaymentAttemptUpdate},
     payment_intent::{PaymentIntent, PaymentIntentNew, PaymentIntentUpdate},
@@ -13,7 +16,7 @@ use crate::{
 pub enum DBOperation {
     Insert { insertable: Insertable },
     Update { updatable: Updateable },
-    Delete,
+    Delete { deletable: Deletable },
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -39,6 +42,9 @@ pub enum Insertable {
     PaymentIntent(PaymentIntentNew),
     PaymentAttempt(PaymentAttemptNew),
     Refund(RefundNew),
+    Customer(CustomerNew),
+    Address(AddressNew),
+    ConnectorResponse(ConnectorResponseNew),
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -47,6 +53,18 @@ pub enum Updateable {
     PaymentIntentUpdate(PaymentIntentUpdateMems),
     PaymentAttemptUpdate(PaymentAttemptUpdateMems),
     RefundUpdate(RefundUpdateMems),
+    CustomerUpdate(CustomerUpdateMems),
+    AddressUpdate(AddressUpdateMems),
+    ConnectorResponseUpdate(ConnectorResponseUpdateMems),
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case", tag = "table", content = "data")]
+pub enum Deletable {
+    Customer {
+        customer_id: String,
+        merchant_id: String,
+    },
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -66,3 +84,21 @@ pub struct RefundUpdateMems {
     pub orig: Refund,
     pub update_data: RefundUpdate,
 }
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CustomerUpdateMems {
+    pub orig: Customer,
+    pub update_data: CustomerUpdate,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AddressUpdateMems {
+    pub orig: Address,
+    pub update_data: AddressUpdate,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ConnectorResponseUpdateMems {
+    pub orig: ConnectorResponse,
+    pub update_data: ConnectorResponseUpdate,
+}
@@ -1,5 +1,6 @@
 pub mod address;
 pub mod api_keys;
+pub mod capture;
 pub mod configs;
 pub mod connector_response;
 pub mod customers;
@@ -7,7 +8,9 @@ pub mod dispute;
 pub mod enums;
 pub mod ephemeral_key;
 pub mod errors;
+pub mod event_delivery_attempt;
 pub mod events;
+pub mod file;
 #[cfg(feature = "kv_store")]
 pub mod kv;
 pub mod locker_mock_up;
@@ -17,6 +20,7 @@ pub mod merchant_connector_account;
 pub mod payment_attempt;
 pub mod payment_intent;
 pub mod payment_method;
+pub mod payouts;
 pub mod process_tracker;
 pub mod query;
 pub mod refund;
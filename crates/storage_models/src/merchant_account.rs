@@ -1,6 +1,7 @@
 use common_utils::pii;
 use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
 use masking::StrongSecret;
+use time::PrimitiveDateTime;
 
 use crate::{enums as storage_enums, schema::merchant_account};
 
@@ -34,6 +35,10 @@ pub struct MerchantAccount {
     pub locker_id: Option<String>,
     pub metadata: Option<pii::SecretSerdeValue>,
     pub routing_algorithm: Option<serde_json::Value>,
+    pub previous_payment_response_hash_key: Option<String>,
+    pub previous_payment_response_hash_key_expires_at: Option<PrimitiveDateTime>,
+    pub rate_limits: Option<serde_json::Value>,
+    pub payment_expiry: Option<serde_json::Value>,
 }
 
 #[derive(Clone, Debug, Default, Insertable, router_derive::DebugAsDisplay)]
@@ -54,6 +59,8 @@ pub struct MerchantAccountNew {
     pub locker_id: Option<String>,
     pub metadata: Option<pii::SecretSerdeValue>,
     pub routing_algorithm: Option<serde_json::Value>,
+    pub rate_limits: Option<serde_json::Value>,
+    pub payment_expiry: Option<serde_json::Value>,
 }
 
 #[derive(Debug)]
@@ -73,10 +80,17 @@ pub enum MerchantAccountUpdate {
         locker_id: Option<String>,
         metadata: Option<pii::SecretSerdeValue>,
         routing_algorithm: Option<serde_json::Value>,
+        rate_limits: Option<serde_json::Value>,
+        payment_expiry: Option<serde_json::Value>,
     },
     StorageSchemeUpdate {
         storage_scheme: storage_enums::MerchantStorageScheme,
     },
+    PaymentResponseHashKeyUpdate {
+        payment_response_hash_key: String,
+        previous_payment_response_hash_key: Option<String>,
+        previous_payment_response_hash_key_expires_at: Option<PrimitiveDateTime>,
+    },
 }
 
 #[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
@@ -97,6 +111,10 @@ pub struct MerchantAccountUpdateInternal {
     locker_id: Option<String>,
     metadata: Option<pii::SecretSerdeValue>,
     routing_algorithm: Option<serde_json::Value>,
+    previous_payment_response_hash_key: Option<String>,
+    previous_payment_response_hash_key_expires_at: Option<PrimitiveDateTime>,
+    rate_limits: Option<serde_json::Value>,
+    payment_expiry: Option<serde_json::Value>,
 }
 
 impl From<MerchantAccountUpdate> for MerchantAccountUpdateInternal {
@@ -117,6 +135,8 @@ impl From<MerchantAccountUpdate> for MerchantAccountUpdateInternal {
                 publishable_key,
                 locker_id,
                 metadata,
+                rate_limits,
+                payment_expiry,
             } => Self {
                 merchant_name,
                 api_key,
@@ -132,12 +152,24 @@ impl From<MerchantAccountUpdate> for MerchantAccountUpdateInternal {
                 publishable_key,
                 locker_id,
                 metadata,
+                rate_limits,
+                payment_expiry,
                 ..Default::default()
             },
             MerchantAccountUpdate::StorageSchemeUpdate { storage_scheme } => Self {
                 storage_scheme: Some(storage_scheme),
                 ..Default::default()
             },
+            MerchantAccountUpdate::PaymentResponseHashKeyUpdate {
+                payment_response_hash_key,
+                previous_payment_response_hash_key,
+                previous_payment_response_hash_key_expires_at,
+            } => Self {
+                payment_response_hash_key: Some(payment_response_hash_key),
+                previous_payment_response_hash_key,
+                previous_payment_response_hash_key_expires_at,
+                ..Default::default()
+            },
         }
     }
 }
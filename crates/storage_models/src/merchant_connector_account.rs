@@ -28,6 +28,8 @@ pub struct MerchantConnectorAccount {
     pub payment_methods_enabled: Option<Vec<serde_json::Value>>,
     pub connector_type: storage_enums::ConnectorType,
     pub metadata: Option<pii::SecretSerdeValue>,
+    pub authorization_ttl_hours: Option<i32>,
+    pub fee_schedule: Option<serde_json::Value>,
 }
 
 #[derive(Clone, Debug, Default, Insertable, router_derive::DebugAsDisplay)]
@@ -42,6 +44,8 @@ pub struct MerchantConnectorAccountNew {
     pub merchant_connector_id: String,
     pub payment_methods_enabled: Option<Vec<serde_json::Value>>,
     pub metadata: Option<pii::SecretSerdeValue>,
+    pub authorization_ttl_hours: Option<i32>,
+    pub fee_schedule: Option<serde_json::Value>,
 }
 
 #[derive(Debug)]
@@ -56,6 +60,8 @@ pub enum MerchantConnectorAccountUpdate {
         merchant_connector_id: Option<String>,
         payment_methods_enabled: Option<Vec<serde_json::Value>>,
         metadata: Option<pii::SecretSerdeValue>,
+        authorization_ttl_hours: Option<i32>,
+        fee_schedule: Option<serde_json::Value>,
     },
 }
 #[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
@@ -70,6 +76,8 @@ pub struct MerchantConnectorAccountUpdateInternal {
     merchant_connector_id: Option<String>,
     payment_methods_enabled: Option<Vec<serde_json::Value>>,
     metadata: Option<pii::SecretSerdeValue>,
+    authorization_ttl_hours: Option<i32>,
+    fee_schedule: Option<serde_json::Value>,
 }
 
 impl From<MerchantConnectorAccountUpdate> for MerchantConnectorAccountUpdateInternal {
@@ -85,6 +93,8 @@ impl From<MerchantConnectorAccountUpdate> for MerchantConnectorAccountUpdateInte
                 merchant_connector_id,
                 payment_methods_enabled,
                 metadata,
+                authorization_ttl_hours,
+                fee_schedule,
             } => Self {
                 merchant_id,
                 connector_type,
@@ -95,7 +105,17 @@ impl From<MerchantConnectorAccountUpdate> for MerchantConnectorAccountUpdateInte
                 merchant_connector_id,
                 payment_methods_enabled,
                 metadata,
+                authorization_ttl_hours,
+                fee_schedule,
             },
         }
     }
 }
+
+/// Tracking data of the process tracker task which refreshes the access token of a connector
+/// account ahead of its expiry
+#[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct AccessTokenRefreshTrackingData {
+    pub merchant_id: String,
+    pub connector: String,
+}
@@ -40,6 +40,8 @@ pub struct PaymentAttempt {
     pub payment_experience: Option<storage_enums::PaymentExperience>,
     pub payment_method_type: Option<storage_enums::PaymentMethodType>,
     pub payment_method_data: Option<serde_json::Value>,
+    pub capture_delay_hours: Option<i32>,
+    pub estimated_fee: Option<i64>,
 }
 
 #[derive(
@@ -80,6 +82,8 @@ pub struct PaymentAttemptNew {
     pub payment_experience: Option<storage_enums::PaymentExperience>,
     pub payment_method_type: Option<storage_enums::PaymentMethodType>,
     pub payment_method_data: Option<serde_json::Value>,
+    pub capture_delay_hours: Option<i32>,
+    pub estimated_fee: Option<i64>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -114,6 +118,7 @@ pub enum PaymentAttemptUpdate {
         payment_method_data: Option<serde_json::Value>,
         payment_method_type: Option<storage_enums::PaymentMethodType>,
         payment_experience: Option<storage_enums::PaymentExperience>,
+        estimated_fee: Option<i64>,
     },
     VoidUpdate {
         status: storage_enums::AttemptStatus,
@@ -131,6 +136,9 @@ pub enum PaymentAttemptUpdate {
     StatusUpdate {
         status: storage_enums::AttemptStatus,
     },
+    AmountUpdate {
+        amount: i64,
+    },
     ErrorUpdate {
         connector: Option<String>,
         status: storage_enums::AttemptStatus,
@@ -161,6 +169,7 @@ pub struct PaymentAttemptUpdateInternal {
     payment_method_data: Option<serde_json::Value>,
     payment_method_type: Option<storage_enums::PaymentMethodType>,
     payment_experience: Option<storage_enums::PaymentExperience>,
+    estimated_fee: Option<i64>,
 }
 
 impl PaymentAttemptUpdate {
@@ -183,6 +192,7 @@ impl PaymentAttemptUpdate {
             browser_info: pa_update.browser_info.or(source.browser_info),
             modified_at: common_utils::date_time::now(),
             payment_token: pa_update.payment_token.or(source.payment_token),
+            estimated_fee: pa_update.estimated_fee.or(source.estimated_fee),
             ..source
         }
     }
@@ -235,6 +245,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 payment_method_data,
                 payment_method_type,
                 payment_experience,
+                estimated_fee,
             } => Self {
                 amount: Some(amount),
                 currency: Some(currency),
@@ -248,6 +259,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 payment_method_data,
                 payment_method_type,
                 payment_experience,
+                estimated_fee,
                 ..Default::default()
             },
             PaymentAttemptUpdate::VoidUpdate {
@@ -294,6 +306,11 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 status: Some(status),
                 ..Default::default()
             },
+            PaymentAttemptUpdate::AmountUpdate { amount } => Self {
+                amount: Some(amount),
+                modified_at: Some(common_utils::date_time::now()),
+                ..Default::default()
+            },
             PaymentAttemptUpdate::UpdateTrackers {
                 payment_token,
                 connector,
@@ -30,6 +30,9 @@ pub struct PaymentIntent {
     pub setup_future_usage: Option<storage_enums::FutureUsage>,
     pub off_session: Option<bool>,
     pub client_secret: Option<String>,
+    pub request_incremental_authorization: Option<bool>,
+    /// The number of times the authorization of the payment has been incremented
+    pub authorization_count: Option<i32>,
 }
 
 #[derive(
@@ -66,6 +69,7 @@ pub struct PaymentIntentNew {
     pub client_secret: Option<String>,
     pub setup_future_usage: Option<storage_enums::FutureUsage>,
     pub off_session: Option<bool>,
+    pub request_incremental_authorization: Option<bool>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -103,6 +107,10 @@ pub enum PaymentIntentUpdate {
         billing_address_id: Option<String>,
         return_url: Option<String>,
     },
+    IncrementalAuthorizationUpdate {
+        amount: i64,
+        authorization_count: Option<i32>,
+    },
 }
 
 #[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
@@ -122,6 +130,7 @@ pub struct PaymentIntentUpdateInternal {
     pub billing_address_id: Option<String>,
     pub shipping_address_id: Option<String>,
     pub modified_at: Option<PrimitiveDateTime>,
+    pub authorization_count: Option<i32>,
 }
 
 impl PaymentIntentUpdate {
@@ -148,6 +157,9 @@ impl PaymentIntentUpdate {
             shipping_address_id: internal_update
                 .shipping_address_id
                 .or(source.shipping_address_id),
+            authorization_count: internal_update
+                .authorization_count
+                .or(source.authorization_count),
             modified_at: common_utils::date_time::now(),
             ..source
         }
@@ -235,6 +247,15 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 modified_at: Some(common_utils::date_time::now()),
                 ..Default::default()
             },
+            PaymentIntentUpdate::IncrementalAuthorizationUpdate {
+                amount,
+                authorization_count,
+            } => Self {
+                amount: Some(amount),
+                authorization_count,
+                modified_at: Some(common_utils::date_time::now()),
+                ..Default::default()
+            },
         }
     }
 }
@@ -248,3 +269,11 @@ fn make_client_secret_null_if_success(
         None
     }
 }
+
+/// Tracking data of the process tracker task which expires a payment once it has been left
+/// unattended for longer than the time configured by the merchant
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+pub struct PaymentExpiryTrackingData {
+    pub merchant_id: String,
+    pub payment_id: String,
+}
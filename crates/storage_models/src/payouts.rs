@@ -0,0 +1,154 @@
+use common_utils::pii;
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::payouts};
+
+#[derive(
+    Clone, Debug, Eq, Identifiable, Queryable, PartialEq, serde::Serialize, serde::Deserialize,
+)]
+#[diesel(table_name = payouts)]
+pub struct Payouts {
+    pub id: i32,
+    pub payout_id: String,
+    pub merchant_id: String,
+    pub customer_id: Option<String>,
+    pub connector: String,
+    pub connector_payout_id: Option<String>,
+    pub payout_type: storage_enums::PayoutType,
+    pub amount: i64,
+    pub currency: storage_enums::Currency,
+    pub status: storage_enums::PayoutStatus,
+    pub description: Option<String>,
+    pub metadata: Option<pii::SecretSerdeValue>,
+    pub error_message: Option<String>,
+    pub error_code: Option<String>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(
+    Clone,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    Insertable,
+    router_derive::DebugAsDisplay,
+    serde::Serialize,
+    serde::Deserialize,
+    router_derive::Setter,
+)]
+#[diesel(table_name = payouts)]
+pub struct PayoutsNew {
+    pub payout_id: String,
+    pub merchant_id: String,
+    pub customer_id: Option<String>,
+    pub connector: String,
+    pub connector_payout_id: Option<String>,
+    pub payout_type: storage_enums::PayoutType,
+    pub amount: i64,
+    pub currency: storage_enums::Currency,
+    pub status: storage_enums::PayoutStatus,
+    pub description: Option<String>,
+    pub metadata: Option<pii::SecretSerdeValue>,
+    pub created_at: Option<PrimitiveDateTime>,
+    pub modified_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub enum PayoutsUpdate {
+    Update {
+        amount: Option<i64>,
+        currency: Option<storage_enums::Currency>,
+        customer_id: Option<String>,
+        description: Option<String>,
+        metadata: Option<pii::SecretSerdeValue>,
+    },
+    StatusUpdate {
+        connector_payout_id: Option<String>,
+        status: storage_enums::PayoutStatus,
+    },
+    ErrorUpdate {
+        status: storage_enums::PayoutStatus,
+        error_message: Option<String>,
+        error_code: Option<String>,
+    },
+}
+
+#[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payouts)]
+pub struct PayoutsUpdateInternal {
+    amount: Option<i64>,
+    currency: Option<storage_enums::Currency>,
+    customer_id: Option<String>,
+    description: Option<String>,
+    metadata: Option<pii::SecretSerdeValue>,
+    connector_payout_id: Option<String>,
+    status: Option<storage_enums::PayoutStatus>,
+    error_message: Option<String>,
+    error_code: Option<String>,
+    modified_at: Option<PrimitiveDateTime>,
+}
+
+impl From<PayoutsUpdate> for PayoutsUpdateInternal {
+    fn from(payout_update: PayoutsUpdate) -> Self {
+        let modified_at = Some(common_utils::date_time::now());
+        match payout_update {
+            PayoutsUpdate::Update {
+                amount,
+                currency,
+                customer_id,
+                description,
+                metadata,
+            } => Self {
+                amount,
+                currency,
+                customer_id,
+                description,
+                metadata,
+                modified_at,
+                ..Default::default()
+            },
+            PayoutsUpdate::StatusUpdate {
+                connector_payout_id,
+                status,
+            } => Self {
+                connector_payout_id,
+                status: Some(status),
+                modified_at,
+                ..Default::default()
+            },
+            PayoutsUpdate::ErrorUpdate {
+                status,
+                error_message,
+                error_code,
+            } => Self {
+                status: Some(status),
+                error_message,
+                error_code,
+                modified_at,
+                ..Default::default()
+            },
+        }
+    }
+}
+
+impl PayoutsUpdate {
+    pub fn apply_changeset(self, source: Payouts) -> Payouts {
+        let update: PayoutsUpdateInternal = self.into();
+        Payouts {
+            amount: update.amount.unwrap_or(source.amount),
+            currency: update.currency.unwrap_or(source.currency),
+            customer_id: update.customer_id.or(source.customer_id),
+            description: update.description.or(source.description),
+            metadata: update.metadata.or(source.metadata),
+            connector_payout_id: update.connector_payout_id.or(source.connector_payout_id),
+            status: update.status.unwrap_or(source.status),
+            error_message: update.error_message.or(source.error_message),
+            error_code: update.error_code.or(source.error_code),
+            modified_at: update.modified_at.unwrap_or(source.modified_at),
+            ..source
+        }
+    }
+}
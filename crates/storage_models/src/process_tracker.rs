@@ -52,7 +52,7 @@ pub struct ProcessTrackerNew {
     pub updated_at: PrimitiveDateTime,
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub enum ProcessTrackerUpdate {
     Update {
         name: Option<String>,
@@ -74,6 +74,22 @@ pub enum ProcessTrackerUpdate {
     },
 }
 
+impl ProcessTrackerUpdate {
+    pub fn apply_changeset(self, source: ProcessTracker) -> ProcessTracker {
+        let update: ProcessTrackerUpdateInternal = self.into();
+        ProcessTracker {
+            name: update.name.or(source.name),
+            retry_count: update.retry_count.unwrap_or(source.retry_count),
+            schedule_time: update.schedule_time.or(source.schedule_time),
+            tracking_data: update.tracking_data.unwrap_or(source.tracking_data),
+            business_status: update.business_status.unwrap_or(source.business_status),
+            status: update.status.unwrap_or(source.status),
+            updated_at: update.updated_at.unwrap_or(source.updated_at),
+            ..source
+        }
+    }
+}
+
 #[derive(Debug, Clone, AsChangeset, router_derive::DebugAsDisplay)]
 #[diesel(table_name = process_tracker)]
 pub struct ProcessTrackerUpdateInternal {
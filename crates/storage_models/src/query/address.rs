@@ -75,6 +75,25 @@ impl Address {
         .await
     }
 
+    /// Updates the addresses of the customer, other than the addresses with the given IDs
+    pub async fn update_by_merchant_id_customer_id_except_address_ids(
+        conn: &PgPooledConn,
+        customer_id: &str,
+        merchant_id: &str,
+        address_ids: Vec<String>,
+        address: AddressUpdate,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_update_with_results::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::customer_id.eq(customer_id.to_owned()))
+                .and(dsl::address_id.ne_all(address_ids)),
+            AddressUpdateInternal::from(address),
+        )
+        .await
+    }
+
     #[instrument(skip(conn))]
     pub async fn find_by_address_id<'a>(
         conn: &PgPooledConn,
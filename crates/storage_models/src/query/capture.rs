@@ -0,0 +1,78 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+use router_env::{instrument, tracing};
+
+use super::generics;
+use crate::{
+    capture::{Capture, CaptureNew, CaptureUpdate, CaptureUpdateInternal},
+    errors,
+    schema::captures::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl CaptureNew {
+    #[instrument(skip(conn))]
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<Capture> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl Capture {
+    #[instrument(skip(conn))]
+    pub async fn find_by_merchant_id_capture_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        capture_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::capture_id.eq(capture_id.to_owned())),
+        )
+        .await
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn find_all_by_merchant_id_payment_id_attempt_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        payment_id: &str,
+        attempt_id: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::payment_id.eq(payment_id.to_owned()))
+                .and(dsl::attempt_id.eq(attempt_id.to_owned())),
+            None,
+            None,
+            Some(dsl::capture_sequence.asc()),
+        )
+        .await
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn update(self, conn: &PgPooledConn, capture: CaptureUpdate) -> StorageResult<Self> {
+        match generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(self.merchant_id.to_owned())
+                .and(dsl::capture_id.eq(self.capture_id.to_owned())),
+            CaptureUpdateInternal::from(capture),
+        )
+        .await
+        {
+            Err(error) => match error.current_context() {
+                errors::DatabaseError::NoFieldsToUpdate => Ok(self),
+                _ => Err(error),
+            },
+            result => result,
+        }
+    }
+}
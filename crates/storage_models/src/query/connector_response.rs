@@ -26,9 +26,13 @@ impl ConnectorResponse {
         conn: &PgPooledConn,
         connector_response: ConnectorResponseUpdate,
     ) -> StorageResult<Self> {
-        match generics::generic_update_by_id::<<Self as HasTable>::Table, _, _, _>(
+        match generics::generic_update_with_results::<<Self as HasTable>::Table, _, _, _>(
             conn,
-            self.id,
+            dsl::merchant_id.eq(self.merchant_id.to_owned()).and(
+                dsl::payment_id
+                    .eq(self.payment_id.to_owned())
+                    .and(dsl::attempt_id.eq(self.attempt_id.to_owned())),
+            ),
             ConnectorResponseUpdateInternal::from(connector_response),
         )
         .await
@@ -37,7 +41,9 @@ impl ConnectorResponse {
                 errors::DatabaseError::NoFieldsToUpdate => Ok(self),
                 _ => Err(error),
             },
-            result => result,
+            Ok(mut connector_responses) => connector_responses
+                .pop()
+                .ok_or(error_stack::report!(errors::DatabaseError::NotFound)),
         }
     }
 
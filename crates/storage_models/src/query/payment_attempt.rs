@@ -28,8 +28,8 @@ impl PaymentAttempt {
     ) -> StorageResult<Self> {
         match generics::generic_update_with_results::<<Self as HasTable>::Table, _, _, _>(
             conn,
-            dsl::payment_id
-                .eq(self.payment_id.to_owned())
+            dsl::attempt_id
+                .eq(self.attempt_id.to_owned())
                 .and(dsl::merchant_id.eq(self.merchant_id.to_owned())),
             PaymentAttemptUpdateInternal::from(payment_attempt),
         )
@@ -45,34 +45,44 @@ impl PaymentAttempt {
         }
     }
 
+    /// Finds the latest attempt made for the payment
     #[instrument(skip(conn))]
     pub async fn find_by_payment_id_merchant_id(
         conn: &PgPooledConn,
         payment_id: &str,
         merchant_id: &str,
     ) -> StorageResult<Self> {
-        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
-            conn,
-            dsl::merchant_id
-                .eq(merchant_id.to_owned())
-                .and(dsl::payment_id.eq(payment_id.to_owned())),
-        )
-        .await
+        Self::find_optional_by_payment_id_merchant_id(conn, payment_id, merchant_id)
+            .await?
+            .ok_or(errors::DatabaseError::NotFound)
+            .into_report()
     }
 
+    /// Finds the latest attempt made for the payment, if any
     #[instrument(skip(conn))]
     pub async fn find_optional_by_payment_id_merchant_id(
         conn: &PgPooledConn,
         payment_id: &str,
         merchant_id: &str,
     ) -> StorageResult<Option<Self>> {
-        generics::generic_find_one_optional::<<Self as HasTable>::Table, _, _>(
+        // perform ordering on the application level instead of database level
+        Ok(generics::generic_filter::<
+            <Self as HasTable>::Table,
+            _,
+            <<Self as HasTable>::Table as Table>::PrimaryKey,
+            Self,
+        >(
             conn,
             dsl::merchant_id
                 .eq(merchant_id.to_owned())
                 .and(dsl::payment_id.eq(payment_id.to_owned())),
+            None,
+            None,
+            None,
         )
-        .await
+        .await?
+        .into_iter()
+        .max_by_key(|payment_attempt| payment_attempt.created_at))
     }
 
     #[instrument(skip(conn))]
@@ -108,7 +118,10 @@ impl PaymentAttempt {
             dsl::payment_id
                 .eq(payment_id.to_owned())
                 .and(dsl::merchant_id.eq(merchant_id.to_owned()))
-                .and(dsl::status.eq(enums::AttemptStatus::Charged)),
+                .and(dsl::status.eq_any(vec![
+                    enums::AttemptStatus::Charged,
+                    enums::AttemptStatus::PartialCharged,
+                ])),
             None,
             None,
             None,
@@ -0,0 +1,92 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+use router_env::{instrument, tracing};
+
+use super::generics;
+use crate::{
+    errors,
+    payouts::{Payouts, PayoutsNew, PayoutsUpdate, PayoutsUpdateInternal},
+    schema::payouts::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl PayoutsNew {
+    #[instrument(skip(conn))]
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<Payouts> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl Payouts {
+    #[instrument(skip(conn))]
+    pub async fn update(self, conn: &PgPooledConn, payout: PayoutsUpdate) -> StorageResult<Self> {
+        match generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::payout_id
+                .eq(self.payout_id.to_owned())
+                .and(dsl::merchant_id.eq(self.merchant_id.to_owned())),
+            PayoutsUpdateInternal::from(payout),
+        )
+        .await
+        {
+            Err(error) => match error.current_context() {
+                errors::DatabaseError::NoFieldsToUpdate => Ok(self),
+                _ => Err(error),
+            },
+            result => result,
+        }
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn find_by_merchant_id_payout_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        payout_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::payout_id.eq(payout_id.to_owned())),
+        )
+        .await
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn find_by_merchant_id_connector_payout_id_connector(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        connector_payout_id: &str,
+        connector: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::connector_payout_id.eq(connector_payout_id.to_owned()))
+                .and(dsl::connector.eq(connector.to_owned())),
+        )
+        .await
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn find_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id.eq(merchant_id.to_owned()),
+            limit,
+            offset,
+            Some(dsl::created_at.desc()),
+        )
+        .await
+    }
+}
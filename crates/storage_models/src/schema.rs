@@ -39,6 +39,30 @@ diesel::table! {
         created_at -> Timestamp,
         expires_at -> Nullable<Timestamp>,
         last_used -> Nullable<Timestamp>,
+        rate_limits -> Nullable<Json>,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    captures (id) {
+        id -> Int4,
+        capture_id -> Varchar,
+        payment_id -> Varchar,
+        merchant_id -> Varchar,
+        attempt_id -> Varchar,
+        status -> CaptureStatus,
+        amount -> Int8,
+        currency -> Nullable<Currency>,
+        connector -> Varchar,
+        connector_capture_id -> Nullable<Varchar>,
+        capture_sequence -> Int4,
+        error_message -> Nullable<Text>,
+        error_code -> Nullable<Varchar>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
     }
 }
 
@@ -89,6 +113,34 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    dispute (id) {
+        id -> Int4,
+        dispute_id -> Varchar,
+        amount -> Int8,
+        currency -> Currency,
+        dispute_stage -> DisputeStage,
+        dispute_status -> DisputeStatus,
+        payment_id -> Varchar,
+        attempt_id -> Varchar,
+        merchant_id -> Varchar,
+        connector -> Varchar,
+        connector_status -> Varchar,
+        connector_dispute_id -> Varchar,
+        connector_reason -> Nullable<Varchar>,
+        connector_reason_code -> Nullable<Varchar>,
+        challenge_required_by -> Nullable<Timestamp>,
+        evidence -> Nullable<Json>,
+        connector_created_at -> Nullable<Timestamp>,
+        connector_updated_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -103,6 +155,44 @@ diesel::table! {
         primary_object_id -> Varchar,
         primary_object_type -> EventObjectType,
         created_at -> Timestamp,
+        webhook_delivery_status -> WebhookDeliveryStatus,
+        merchant_id -> Nullable<Varchar>,
+        request_body -> Nullable<Json>,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    event_delivery_attempt (id) {
+        id -> Int4,
+        event_id -> Varchar,
+        merchant_id -> Varchar,
+        attempt_number -> Int4,
+        delivered -> Bool,
+        response_status_code -> Nullable<Int4>,
+        error_message -> Nullable<Text>,
+        created_at -> Timestamp,
+        endpoint_id -> Varchar,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    file_metadata (id) {
+        id -> Int4,
+        file_id -> Varchar,
+        merchant_id -> Varchar,
+        file_name -> Nullable<Varchar>,
+        file_size -> Int4,
+        file_type -> Varchar,
+        provider_file_id -> Nullable<Varchar>,
+        file_upload_provider -> Nullable<FileUploadProvider>,
+        available -> Bool,
+        created_at -> Timestamp,
     }
 }
 
@@ -177,6 +267,10 @@ diesel::table! {
         locker_id -> Nullable<Varchar>,
         metadata -> Nullable<Jsonb>,
         routing_algorithm -> Nullable<Json>,
+        previous_payment_response_hash_key -> Nullable<Varchar>,
+        previous_payment_response_hash_key_expires_at -> Nullable<Timestamp>,
+        rate_limits -> Nullable<Json>,
+        payment_expiry -> Nullable<Json>,
     }
 }
 
@@ -195,6 +289,8 @@ diesel::table! {
         payment_methods_enabled -> Nullable<Array<Nullable<Json>>>,
         connector_type -> ConnectorType,
         metadata -> Nullable<Jsonb>,
+        authorization_ttl_hours -> Nullable<Int4>,
+        fee_schedule -> Nullable<Json>,
     }
 }
 
@@ -236,6 +332,8 @@ diesel::table! {
         payment_experience -> Nullable<Varchar>,
         payment_method_type -> Nullable<Varchar>,
         payment_method_data -> Nullable<Jsonb>,
+        capture_delay_hours -> Nullable<Int4>,
+        estimated_fee -> Nullable<Int8>,
     }
 }
 
@@ -266,6 +364,8 @@ diesel::table! {
         setup_future_usage -> Nullable<FutureUsage>,
         off_session -> Nullable<Bool>,
         client_secret -> Nullable<Varchar>,
+        request_incremental_authorization -> Nullable<Bool>,
+        authorization_count -> Nullable<Int4>,
     }
 }
 
@@ -298,6 +398,30 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    payouts (id) {
+        id -> Int4,
+        payout_id -> Varchar,
+        merchant_id -> Varchar,
+        customer_id -> Nullable<Varchar>,
+        connector -> Varchar,
+        connector_payout_id -> Nullable<Varchar>,
+        payout_type -> PayoutType,
+        amount -> Int8,
+        currency -> Currency,
+        status -> PayoutStatus,
+        description -> Nullable<Varchar>,
+        metadata -> Nullable<Json>,
+        error_message -> Nullable<Text>,
+        error_code -> Nullable<Varchar>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -366,10 +490,14 @@ diesel::table! {
 diesel::allow_tables_to_appear_in_same_query!(
     address,
     api_keys,
+    captures,
     configs,
     connector_response,
     customers,
+    dispute,
+    event_delivery_attempt,
     events,
+    file_metadata,
     locker_mock_up,
     mandate,
     merchant_account,
@@ -377,6 +505,7 @@ diesel::allow_tables_to_appear_in_same_query!(
     payment_attempt,
     payment_intent,
     payment_methods,
+    payouts,
     process_tracker,
     refund,
     reverse_lookup,
@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX payment_attempt_attempt_id_merchant_id_index;
+
+DROP INDEX payment_attempt_payment_id_merchant_id_index;
+
+CREATE UNIQUE INDEX payment_attempt_payment_id_merchant_id_index ON payment_attempt (payment_id, merchant_id);
@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE dispute;
+
+DROP TYPE "DisputeStage";
+
+DROP TYPE "DisputeStatus";
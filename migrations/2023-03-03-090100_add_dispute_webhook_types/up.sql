@@ -0,0 +1,12 @@
+-- Your SQL goes here
+ALTER TYPE "EventClass" ADD VALUE 'disputes';
+
+ALTER TYPE "EventObjectType" ADD VALUE 'dispute_details';
+
+ALTER TYPE "EventType" ADD VALUE 'dispute_opened';
+
+ALTER TYPE "EventType" ADD VALUE 'dispute_challenged';
+
+ALTER TYPE "EventType" ADD VALUE 'dispute_won';
+
+ALTER TYPE "EventType" ADD VALUE 'dispute_lost';
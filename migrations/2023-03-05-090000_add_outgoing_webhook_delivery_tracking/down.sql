@@ -0,0 +1,8 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE event_delivery_attempt;
+
+DROP INDEX events_event_id_index;
+
+ALTER TABLE events DROP COLUMN webhook_delivery_status;
+
+DROP TYPE "WebhookDeliveryStatus";
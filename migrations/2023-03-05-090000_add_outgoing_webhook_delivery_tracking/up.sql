@@ -0,0 +1,20 @@
+-- Your SQL goes here
+CREATE TYPE "WebhookDeliveryStatus" AS ENUM ('pending', 'delivered', 'failed');
+
+ALTER TABLE events
+ADD COLUMN webhook_delivery_status "WebhookDeliveryStatus" NOT NULL DEFAULT 'pending';
+
+CREATE UNIQUE INDEX events_event_id_index ON events (event_id);
+
+CREATE TABLE event_delivery_attempt (
+    id SERIAL PRIMARY KEY,
+    event_id VARCHAR(255) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    attempt_number INTEGER NOT NULL,
+    delivered BOOLEAN NOT NULL,
+    response_status_code INTEGER,
+    error_message TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE INDEX event_delivery_attempt_event_id_index ON event_delivery_attempt (event_id);
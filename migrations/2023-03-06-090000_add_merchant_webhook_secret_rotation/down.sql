@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE merchant_account
+DROP COLUMN previous_payment_response_hash_key,
+DROP COLUMN previous_payment_response_hash_key_expires_at;
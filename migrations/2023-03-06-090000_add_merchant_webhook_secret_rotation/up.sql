@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TABLE merchant_account
+ADD COLUMN previous_payment_response_hash_key VARCHAR(255) DEFAULT NULL,
+ADD COLUMN previous_payment_response_hash_key_expires_at TIMESTAMP DEFAULT NULL;
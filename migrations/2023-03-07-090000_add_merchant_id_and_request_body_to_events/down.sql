@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX events_merchant_id_created_at_index;
+
+ALTER TABLE events
+DROP COLUMN merchant_id,
+DROP COLUMN request_body;
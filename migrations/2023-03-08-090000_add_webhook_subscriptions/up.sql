@@ -0,0 +1,15 @@
+-- Your SQL goes here
+ALTER TYPE "EventClass" ADD VALUE 'mandates';
+
+ALTER TYPE "EventObjectType" ADD VALUE 'mandate_details';
+
+ALTER TYPE "EventType" ADD VALUE 'payment_failed';
+
+ALTER TYPE "EventType" ADD VALUE 'mandate_active';
+
+ALTER TYPE "EventType" ADD VALUE 'mandate_revoked';
+
+ALTER TYPE "WebhookDeliveryStatus" ADD VALUE 'skipped';
+
+ALTER TABLE event_delivery_attempt
+ADD COLUMN endpoint_id VARCHAR(64) NOT NULL DEFAULT 'default';
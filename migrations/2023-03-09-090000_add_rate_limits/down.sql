@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE merchant_account
+DROP COLUMN rate_limits;
+
+ALTER TABLE api_keys
+DROP COLUMN rate_limits;
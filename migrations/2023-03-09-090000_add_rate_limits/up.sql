@@ -0,0 +1,6 @@
+-- Your SQL goes here
+ALTER TABLE merchant_account
+ADD COLUMN rate_limits JSON DEFAULT NULL;
+
+ALTER TABLE api_keys
+ADD COLUMN rate_limits JSON DEFAULT NULL;
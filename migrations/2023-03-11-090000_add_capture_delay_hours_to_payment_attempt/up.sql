@@ -0,0 +1,3 @@
+-- Your SQL goes here
+ALTER TABLE payment_attempt
+ADD COLUMN capture_delay_hours INTEGER DEFAULT NULL;
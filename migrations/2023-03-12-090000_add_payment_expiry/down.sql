@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE merchant_account
+DROP COLUMN payment_expiry;
+
+ALTER TABLE merchant_connector_account
+DROP COLUMN authorization_ttl_hours;
@@ -0,0 +1,12 @@
+-- Your SQL goes here
+ALTER TYPE "IntentStatus" ADD VALUE 'expired';
+
+ALTER TYPE "EventType" ADD VALUE 'payment_cancelled';
+
+ALTER TYPE "EventType" ADD VALUE 'payment_expired';
+
+ALTER TABLE merchant_account
+ADD COLUMN payment_expiry JSON DEFAULT NULL;
+
+ALTER TABLE merchant_connector_account
+ADD COLUMN authorization_ttl_hours INTEGER DEFAULT NULL;
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE payment_intent
+DROP COLUMN request_incremental_authorization,
+DROP COLUMN authorization_count;
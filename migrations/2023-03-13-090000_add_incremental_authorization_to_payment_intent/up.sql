@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TABLE payment_intent
+ADD COLUMN request_incremental_authorization BOOLEAN DEFAULT NULL,
+ADD COLUMN authorization_count INTEGER DEFAULT NULL;
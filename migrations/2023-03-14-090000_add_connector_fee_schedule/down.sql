@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE merchant_connector_account
+DROP COLUMN fee_schedule;
+
+ALTER TABLE payment_attempt
+DROP COLUMN estimated_fee;
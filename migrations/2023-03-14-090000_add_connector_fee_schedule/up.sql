@@ -0,0 +1,6 @@
+-- Your SQL goes here
+ALTER TABLE merchant_connector_account
+ADD COLUMN fee_schedule JSON DEFAULT NULL;
+
+ALTER TABLE payment_attempt
+ADD COLUMN estimated_fee BIGINT DEFAULT NULL;
@@ -0,0 +1,2 @@
+-- Your SQL goes here
+ALTER TYPE "PayoutStatus" ADD VALUE 'reversed';
@@ -0,0 +1,2 @@
+-- Your SQL goes here
+ALTER TYPE "EventType" ADD VALUE 'payment_created';
@@ -0,0 +1,6 @@
+-- Your SQL goes here
+-- Outgoing webhooks are signed with the payment response hash key, which is now created along
+-- with the merchant account instead of when the first webhook is delivered
+UPDATE merchant_account
+SET payment_response_hash_key = REPLACE(gen_random_uuid()::TEXT || gen_random_uuid()::TEXT, '-', '')
+WHERE payment_response_hash_key IS NULL;